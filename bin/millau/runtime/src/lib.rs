@@ -45,7 +45,7 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{Block as BlockT, IdentityLookup, Keccak256, NumberFor, OpaqueKeys},
-	transaction_validity::{TransactionSource, TransactionValidity},
+	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, FixedPointNumber, Perquintill,
 };
 use sp_std::prelude::*;
@@ -379,11 +379,33 @@ impl pallet_session::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	/// Identifier of the named reserve, used to hold stake of registered relayers.
+	pub const RelayerStakeReserveId: [u8; 8] = *b"brdgrlrs";
+	/// Stake that is reserved from the account of every registered relayer.
+	pub const RelayerStake: Balance = 1_000_000;
+	/// Registration lease that must remain for the registration to be considered active.
+	pub const RelayerStakeLease: BlockNumber = 8;
+	/// Part of the stake that is slashed when registered relayer misbehaves.
+	pub const RelayerSlashFraction: Perbill = Perbill::one();
+}
+
 impl pallet_bridge_relayers::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Reward = Balance;
 	type PaymentProcedure =
-		bp_relayers::PayLaneRewardFromAccount<pallet_balances::Pallet<Runtime>, AccountId>;
+		bp_relayers::PayRewardFromAccount<pallet_balances::Pallet<Runtime>, AccountId>;
+	type StakeAndSlash = pallet_bridge_relayers::StakeAndSlashNamed<
+		AccountId,
+		BlockNumber,
+		Balances,
+		RelayerStakeReserveId,
+		RelayerStake,
+		RelayerStakeLease,
+	>;
+	type SlashFraction = RelayerSlashFraction;
+	// slashed funds are sent to the rewards account of the lane
+	type SlashDestination = ();
 	type WeightInfo = ();
 }
 
@@ -444,6 +466,11 @@ parameter_types! {
 /// Instance of the messages pallet used to relay messages to/from Rialto chain.
 pub type WithRialtoMessagesInstance = ();
 
+/// Reward that is paid to the relayer for delivering single message to the bridged chain.
+pub type MessageDeliveryReward = frame_support::traits::ConstU64<100_000>;
+/// Reward that is paid to the relayer for confirming delivery of single message.
+pub type MessageConfirmationReward = frame_support::traits::ConstU64<10_000>;
+
 impl pallet_bridge_messages::Config<WithRialtoMessagesInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_bridge_messages::weights::BridgeWeight<Runtime>;
@@ -462,8 +489,9 @@ impl pallet_bridge_messages::Config<WithRialtoMessagesInstance> for Runtime {
 	type LaneMessageVerifier = crate::rialto_messages::ToRialtoMessageVerifier;
 	type DeliveryConfirmationPayments = pallet_bridge_relayers::DeliveryConfirmationPaymentsAdapter<
 		Runtime,
-		frame_support::traits::ConstU64<100_000>,
-		frame_support::traits::ConstU64<10_000>,
+		RialtoChainId,
+		MessageDeliveryReward,
+		MessageConfirmationReward,
 	>;
 
 	type SourceHeaderChain = crate::rialto_messages::RialtoAsSourceHeaderChain;
@@ -493,8 +521,9 @@ impl pallet_bridge_messages::Config<WithRialtoParachainMessagesInstance> for Run
 	type LaneMessageVerifier = crate::rialto_parachain_messages::ToRialtoParachainMessageVerifier;
 	type DeliveryConfirmationPayments = pallet_bridge_relayers::DeliveryConfirmationPaymentsAdapter<
 		Runtime,
-		frame_support::traits::ConstU64<100_000>,
-		frame_support::traits::ConstU64<10_000>,
+		RialtoParachainChainId,
+		MessageDeliveryReward,
+		MessageConfirmationReward,
 	>;
 
 	type SourceHeaderChain = crate::rialto_parachain_messages::RialtoParachainAsSourceHeaderChain;
@@ -503,7 +532,11 @@ impl pallet_bridge_messages::Config<WithRialtoParachainMessagesInstance> for Run
 }
 
 parameter_types! {
-	pub const RialtoParachainMessagesLane: bp_messages::LaneId = rialto_parachain_messages::XCM_LANE;
+	pub RialtoMessagesLanes: &'static [bp_messages::LaneId] = &[rialto_messages::XCM_LANE];
+	pub RialtoParachainMessagesLanes: &'static [bp_messages::LaneId] = &[rialto_parachain_messages::XCM_LANE];
+	/// Priority boost that is given to message delivery transaction of registered relayer for
+	/// every delivered message.
+	pub const PriorityBoostPerMessage: TransactionPriority = 1_000_000;
 	pub const RialtoParachainId: u32 = bp_rialto_parachain::RIALTO_PARACHAIN_ID;
 	pub const RialtoParasPalletName: &'static str = bp_rialto::PARAS_PALLET_NAME;
 	pub const WestendParasPalletName: &'static str = bp_westend::PARAS_PALLET_NAME;
@@ -601,6 +634,18 @@ generate_bridge_reject_obsolete_headers_and_messages! {
 	BridgeRialtoMessages, BridgeRialtoParachainMessages
 }
 
+/// Signed extension that refunds relayers that are delivering messages from the Rialto chain.
+pub type BridgeRefundRialtoRelayers =
+	bridge_runtime_common::refund_relayer_extension::RefundRelayerForMessagesFromGrandpaChain<
+		Runtime,
+		RialtoGrandpaInstance,
+		WithRialtoMessagesInstance,
+		BridgeRejectObsoleteHeadersAndMessages,
+		RialtoMessagesLanes,
+		Runtime,
+		PriorityBoostPerMessage,
+	>;
+
 /// Signed extension that refunds relayers that are delivering messages from the Rialto parachain.
 pub type BridgeRefundRialtoParachainRelayers =
 	bridge_runtime_common::refund_relayer_extension::RefundRelayerForMessagesFromParachain<
@@ -610,8 +655,9 @@ pub type BridgeRefundRialtoParachainRelayers =
 		WithRialtoParachainMessagesInstance,
 		BridgeRejectObsoleteHeadersAndMessages,
 		RialtoParachainId,
-		RialtoParachainMessagesLane,
+		RialtoParachainMessagesLanes,
 		Runtime,
+		PriorityBoostPerMessage,
 	>;
 
 /// The address format for describing accounts.
@@ -635,6 +681,7 @@ pub type SignedExtra = (
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 	BridgeRejectObsoleteHeadersAndMessages,
+	BridgeRefundRialtoRelayers,
 	BridgeRefundRialtoParachainRelayers,
 );
 /// The payload being signed in transactions.
@@ -651,8 +698,14 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Runtime storage migrations.
+pub type Migrations = (
+	pallet_bridge_relayers::migration::v1::MigrateToV1<Runtime, pallet_balances::Pallet<Runtime>>,
+);
+
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
 		fn version() -> RuntimeVersion {
@@ -1013,7 +1066,16 @@ impl_runtime_apis! {
 				}
 
 				fn is_relayer_rewarded(relayer: &Self::AccountId) -> bool {
-					pallet_bridge_relayers::Pallet::<Runtime>::relayer_reward(relayer, &Self::bench_lane_id()).is_some()
+					let lane = <Self as MessagesConfig<WithRialtoMessagesInstance>>::bench_lane_id();
+					let bridged_chain_id = bp_runtime::RIALTO_CHAIN_ID;
+					pallet_bridge_relayers::Pallet::<Runtime>::relayer_reward(
+						relayer,
+						bp_relayers::RewardsAccountParams::new(
+							lane,
+							bridged_chain_id,
+							bp_relayers::RewardsAccountOwner::BridgedChain
+						)
+					).is_some()
 				}
 			}
 
@@ -1043,15 +1105,20 @@ impl_runtime_apis! {
 
 			impl RelayersConfig for Runtime {
 				fn prepare_environment(
-					lane: bp_messages::LaneId,
+					account_params: bp_relayers::RewardsAccountParams,
 					reward: Balance,
 				) {
 					use frame_support::traits::fungible::Mutate;
-					let lane_rewards_account = bp_relayers::PayLaneRewardFromAccount::<
+					let rewards_account = bp_relayers::PayRewardFromAccount::<
 						Balances,
 						AccountId
-					>::lane_rewards_account(lane);
-					Balances::mint_into(&lane_rewards_account, reward).unwrap();
+					>::rewards_account(account_params);
+					Balances::mint_into(&rewards_account, reward).unwrap();
+				}
+
+				fn deposit_account(account: AccountId, balance: Balance) {
+					use frame_support::traits::fungible::Mutate;
+					Balances::mint_into(&account, balance.saturating_add(ExistentialDeposit::get())).unwrap();
 				}
 			}
 
@@ -124,8 +124,14 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Runtime storage migrations.
+pub type Migrations = (
+	pallet_bridge_relayers::migration::v1::MigrateToV1<Runtime, pallet_balances::Pallet<Runtime>>,
+);
+
 impl_opaque_keys! {
 	pub struct SessionKeys {
 		pub aura: Aura,
@@ -517,11 +523,33 @@ impl pallet_aura::Config for Runtime {
 	type MaxAuthorities = MaxAuthorities;
 }
 
+parameter_types! {
+	/// Identifier of the named reserve, used to hold stake of registered relayers.
+	pub const RelayerStakeReserveId: [u8; 8] = *b"brdgrlrs";
+	/// Stake that is reserved from the account of every registered relayer.
+	pub const RelayerStake: Balance = 1_000_000;
+	/// Registration lease that must remain for the registration to be considered active.
+	pub const RelayerStakeLease: BlockNumber = 8;
+	/// Part of the stake that is slashed when registered relayer misbehaves.
+	pub const RelayerSlashFraction: Perbill = Perbill::one();
+}
+
 impl pallet_bridge_relayers::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Reward = Balance;
 	type PaymentProcedure =
-		bp_relayers::PayLaneRewardFromAccount<pallet_balances::Pallet<Runtime>, AccountId>;
+		bp_relayers::PayRewardFromAccount<pallet_balances::Pallet<Runtime>, AccountId>;
+	type StakeAndSlash = pallet_bridge_relayers::StakeAndSlashNamed<
+		AccountId,
+		BlockNumber,
+		Balances,
+		RelayerStakeReserveId,
+		RelayerStake,
+		RelayerStakeLease,
+	>;
+	type SlashFraction = RelayerSlashFraction;
+	// slashed funds are sent to the rewards account of the lane
+	type SlashDestination = ();
 	type WeightInfo = ();
 }
 
@@ -563,6 +591,11 @@ parameter_types! {
 /// Instance of the messages pallet used to relay messages to/from Millau chain.
 pub type WithMillauMessagesInstance = ();
 
+/// Reward that is paid to the relayer for delivering single message to the bridged chain.
+pub type MessageDeliveryReward = frame_support::traits::ConstU128<100_000>;
+/// Reward that is paid to the relayer for confirming delivery of single message.
+pub type MessageConfirmationReward = frame_support::traits::ConstU128<100_000>;
+
 impl pallet_bridge_messages::Config<WithMillauMessagesInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_bridge_messages::weights::BridgeWeight<Runtime>;
@@ -581,8 +614,9 @@ impl pallet_bridge_messages::Config<WithMillauMessagesInstance> for Runtime {
 	type LaneMessageVerifier = crate::millau_messages::ToMillauMessageVerifier;
 	type DeliveryConfirmationPayments = pallet_bridge_relayers::DeliveryConfirmationPaymentsAdapter<
 		Runtime,
-		frame_support::traits::ConstU128<100_000>,
-		frame_support::traits::ConstU128<100_000>,
+		BridgedChainId,
+		MessageDeliveryReward,
+		MessageConfirmationReward,
 	>;
 
 	type SourceHeaderChain = crate::millau_messages::MillauAsSourceHeaderChain;
@@ -73,6 +73,7 @@ pub use pallet_sudo::Call as SudoCall;
 pub use pallet_timestamp::Call as TimestampCall;
 pub use pallet_xcm::Call as XcmCall;
 
+use bridge_runtime_common::generate_bridge_reject_obsolete_headers_and_messages;
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
@@ -389,11 +390,33 @@ impl pallet_authority_discovery::Config for Runtime {
 	type MaxAuthorities = ConstU32<10>;
 }
 
+parameter_types! {
+	/// Identifier of the named reserve, used to hold stake of registered relayers.
+	pub const RelayerStakeReserveId: [u8; 8] = *b"brdgrlrs";
+	/// Stake that is reserved from the account of every registered relayer.
+	pub const RelayerStake: Balance = 1_000_000;
+	/// Registration lease that must remain for the registration to be considered active.
+	pub const RelayerStakeLease: BlockNumber = 8;
+	/// Part of the stake that is slashed when registered relayer misbehaves.
+	pub const RelayerSlashFraction: Perbill = Perbill::one();
+}
+
 impl pallet_bridge_relayers::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Reward = Balance;
 	type PaymentProcedure =
-		bp_relayers::PayLaneRewardFromAccount<pallet_balances::Pallet<Runtime>, AccountId>;
+		bp_relayers::PayRewardFromAccount<pallet_balances::Pallet<Runtime>, AccountId>;
+	type StakeAndSlash = pallet_bridge_relayers::StakeAndSlashNamed<
+		AccountId,
+		BlockNumber,
+		Balances,
+		RelayerStakeReserveId,
+		RelayerStake,
+		RelayerStakeLease,
+	>;
+	type SlashFraction = RelayerSlashFraction;
+	// slashed funds are sent to the rewards account of the lane
+	type SlashDestination = ();
 	type WeightInfo = ();
 }
 
@@ -437,6 +460,11 @@ parameter_types! {
 /// Instance of the messages pallet used to relay messages to/from Millau chain.
 pub type WithMillauMessagesInstance = ();
 
+/// Reward that is paid to the relayer for delivering single message to the bridged chain.
+pub type MessageDeliveryReward = frame_support::traits::ConstU128<100_000>;
+/// Reward that is paid to the relayer for confirming delivery of single message.
+pub type MessageConfirmationReward = frame_support::traits::ConstU128<100_000>;
+
 impl pallet_bridge_messages::Config<WithMillauMessagesInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_bridge_messages::weights::BridgeWeight<Runtime>;
@@ -455,8 +483,9 @@ impl pallet_bridge_messages::Config<WithMillauMessagesInstance> for Runtime {
 	type LaneMessageVerifier = crate::millau_messages::ToMillauMessageVerifier;
 	type DeliveryConfirmationPayments = pallet_bridge_relayers::DeliveryConfirmationPaymentsAdapter<
 		Runtime,
-		frame_support::traits::ConstU128<100_000>,
-		frame_support::traits::ConstU128<100_000>,
+		BridgedChainId,
+		MessageDeliveryReward,
+		MessageConfirmationReward,
 	>;
 
 	type SourceHeaderChain = crate::millau_messages::MillauAsSourceHeaderChain;
@@ -464,11 +493,28 @@ impl pallet_bridge_messages::Config<WithMillauMessagesInstance> for Runtime {
 	type BridgedChainId = BridgedChainId;
 }
 
+parameter_types! {
+	/// BABE randomness from one epoch ago is known in advance, so commitment claims are completed
+	/// using randomness of the block that is more than two epochs after the claim.
+	pub const MillauBeefyClaimDelay: bp_rialto::BlockNumber =
+		2 * bp_rialto::EPOCH_DURATION_IN_SLOTS + 1;
+	/// Commitment claims may be completed during a single epoch.
+	pub const MillauBeefyClaimLifetime: bp_rialto::BlockNumber = bp_rialto::EPOCH_DURATION_IN_SLOTS;
+}
+
 pub type MillauBeefyInstance = ();
 impl pallet_bridge_beefy::Config<MillauBeefyInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type MaxRequests = frame_support::traits::ConstU32<16>;
 	type CommitmentsToKeep = frame_support::traits::ConstU32<8>;
+	type HeadersToKeep = frame_support::traits::ConstU32<8>;
+	type MinSignaturesInSubset = frame_support::traits::ConstU32<8>;
+	type MaxBridgedAuthorities = MaxAuthoritiesAtMillau;
+	type Randomness = pallet_babe::RandomnessFromOneEpochAgo<Runtime>;
+	type ClaimDelay = MillauBeefyClaimDelay;
+	type ClaimLifetime = MillauBeefyClaimLifetime;
 	type BridgedChain = bp_millau::Millau;
+	type WeightInfo = pallet_bridge_beefy::weights::BridgeWeight<Runtime>;
 }
 
 construct_runtime!(
@@ -504,7 +550,7 @@ construct_runtime!(
 		BridgeMillauMessages: pallet_bridge_messages::{Pallet, Call, Storage, Event<T>, Config<T>},
 
 		// Millau bridge modules (BEEFY based).
-		BridgeMillauBeefy: pallet_bridge_beefy::{Pallet, Call, Storage},
+		BridgeMillauBeefy: pallet_bridge_beefy::{Pallet, Call, Storage, Event<T>},
 
 		// Parachain modules.
 		ParachainsOrigin: polkadot_runtime_parachains::origin::{Pallet, Origin},
@@ -533,6 +579,16 @@ construct_runtime!(
 	}
 );
 
+generate_bridge_reject_obsolete_headers_and_messages! {
+	RuntimeCall, AccountId,
+	// Grandpa
+	BridgeMillauGrandpa,
+	// BEEFY
+	BridgeMillauBeefy,
+	// Messages
+	BridgeMillauMessages
+}
+
 /// The address format for describing accounts.
 pub type Address = sp_runtime::MultiAddress<AccountId, ()>;
 /// Block header type as expected by this runtime.
@@ -553,6 +609,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	BridgeRejectObsoleteHeadersAndMessages,
 );
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
@@ -568,8 +625,14 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Runtime storage migrations.
+pub type Migrations = (
+	pallet_bridge_relayers::migration::v1::MigrateToV1<Runtime, pallet_balances::Pallet<Runtime>>,
+);
+
 /// MMR helper types.
 mod mmr {
 	use super::Runtime;
@@ -950,6 +1013,51 @@ impl_runtime_apis! {
 			>(lane, messages)
 		}
 	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	impl frame_benchmarking::Benchmark<Block> for Runtime {
+		fn benchmark_metadata(extra: bool) -> (
+			Vec<frame_benchmarking::BenchmarkList>,
+			Vec<frame_support::traits::StorageInfo>,
+		) {
+			use frame_benchmarking::{list_benchmark, Benchmarking, BenchmarkList};
+			use frame_support::traits::StorageInfoTrait;
+
+			let mut list = Vec::<BenchmarkList>::new();
+
+			list_benchmark!(list, extra, pallet_bridge_beefy, BridgeMillauBeefy);
+
+			let storage_info = AllPalletsWithSystem::storage_info();
+
+			return (list, storage_info)
+		}
+
+		fn dispatch_benchmark(
+			config: frame_benchmarking::BenchmarkConfig,
+		) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+			use frame_benchmarking::{Benchmarking, BenchmarkBatch, TrackedStorageKey, add_benchmark};
+
+			let whitelist: Vec<TrackedStorageKey> = vec![
+				// Block Number
+				hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef702a5c1b19ab7a04f536c519aca4983ac").to_vec().into(),
+				// Execution Phase
+				hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef7ff553b5a9862a516939d82b3d3d8661a").to_vec().into(),
+				// Event Count
+				hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef70a98fdbe9ce6c55837576c60c7af3850").to_vec().into(),
+				// System Events
+				hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef780d41e5e16056765bc8461851072c9d7").to_vec().into(),
+				// Caller 0 Account
+				hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef7b99d880ec681799c0cf30e8886371da946c154ffd9992e395af90b5b13cc6f295c77033fce8a9045824a6690bbf99c6db269502f0a8d1d2a008542d5690a0749").to_vec().into(),
+			];
+
+			let mut batches = Vec::<BenchmarkBatch>::new();
+			let params = (&config, &whitelist);
+
+			add_benchmark!(params, batches, pallet_bridge_beefy, BridgeMillauBeefy);
+
+			Ok(batches)
+		}
+	}
 }
 
 #[cfg(test)]
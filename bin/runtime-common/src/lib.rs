@@ -53,6 +53,15 @@ where
 	}
 }
 
+impl<Call, T, I> BridgeRuntimeFilterCall<Call> for pallet_bridge_beefy::Pallet<T, I>
+where
+	pallet_bridge_beefy::Pallet<T, I>: FilterCall<Call>,
+{
+	fn validate(call: &Call) -> TransactionValidity {
+		<pallet_bridge_beefy::Pallet<T, I> as FilterCall<Call>>::validate(call)
+	}
+}
+
 impl<Call, T, I> BridgeRuntimeFilterCall<Call> for pallet_bridge_parachains::Pallet<T, I>
 where
 	pallet_bridge_parachains::Pallet<T, I>: FilterCall<Call>,
@@ -70,6 +79,7 @@ where
 /// generate_bridge_reject_obsolete_headers_and_messages!{
 ///     Call, AccountId
 ///     BridgeRialtoGrandpa, BridgeWestendGrandpa,
+///     BridgeRialtoBeefy,
 ///     BridgeRialtoParachains
 /// }
 /// ```
@@ -31,7 +31,11 @@ use bp_messages::{
 use bp_runtime::{messages::MessageDispatchResult, Chain, ChainId, Size, StorageProofChecker};
 pub use bp_runtime::{UnderlyingChainOf, UnderlyingChainProvider};
 use codec::{Decode, DecodeLimit, Encode};
-use frame_support::{traits::Get, weights::Weight, RuntimeDebug};
+use frame_support::{
+	traits::{ConstU32, Get},
+	weights::Weight,
+	BoundedVec, RuntimeDebug,
+};
 use hash_db::Hasher;
 use scale_info::TypeInfo;
 use sp_std::{convert::TryFrom, fmt::Debug, marker::PhantomData, vec::Vec};
@@ -421,9 +425,10 @@ pub mod target {
 	/// Messages proof from bridged chain:
 	///
 	/// - hash of finalized header;
-	/// - storage proof of messages and (optionally) outbound lane state;
+	/// - storage proof of messages and (optionally) outbound lane states;
 	/// - lane id;
-	/// - nonces (inclusive range) of messages which are included in this proof.
+	/// - nonces (inclusive range) of messages which are included in this proof;
+	/// - (optionally) ids and nonces ranges of other lanes, covered by the same storage proof.
 	#[derive(Clone, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 	pub struct FromBridgedChainMessagesProof<BridgedHeaderHash> {
 		/// Hash of the finalized bridged header the proof is for.
@@ -436,6 +441,61 @@ pub mod target {
 		pub nonces_start: MessageNonce,
 		/// Nonce of the last message being delivered.
 		pub nonces_end: MessageNonce,
+		/// Messages of other lanes, that are also included in this proof.
+		pub additional_lanes: AdditionalLanesMessages,
+	}
+
+	/// Maximal number of lanes (including the primary lane), that may be covered by the single
+	/// messages proof.
+	pub const MAX_LANES_IN_PROOF: u32 = 4;
+
+	/// Maximal number of lanes (including the primary lane), that may be covered by the single
+	/// messages proof.
+	pub type MaxLanesInProof = ConstU32<MAX_LANES_IN_PROOF>;
+
+	/// Messages of additional (non-primary) lanes, included in the messages proof.
+	pub type AdditionalLanesMessages =
+		BoundedVec<FromBridgedChainLaneMessages, ConstU32<{ MAX_LANES_IN_PROOF - 1 }>>;
+
+	impl<BridgedHeaderHash> FromBridgedChainMessagesProof<BridgedHeaderHash> {
+		/// Returns number of lanes, included in this proof.
+		pub fn lanes_count(&self) -> u32 {
+			1 + self.additional_lanes.len() as u32
+		}
+
+		/// Returns messages of all lanes, included in this proof. The primary lane comes first.
+		pub fn lanes(&self) -> impl Iterator<Item = FromBridgedChainLaneMessages> + '_ {
+			sp_std::iter::once(FromBridgedChainLaneMessages {
+				lane: self.lane,
+				nonces_start: self.nonces_start,
+				nonces_end: self.nonces_end,
+			})
+			.chain(self.additional_lanes.iter().cloned())
+		}
+
+		/// Returns total number of messages at all lanes, included in this proof.
+		pub fn messages_count(&self) -> MessageNonce {
+			// receiving proofs where end < begin is ok (if proof includes outbound lane state)
+			self.lanes().fold(0, |total, lane_messages| {
+				let lane_messages_count = lane_messages
+					.nonces_end
+					.checked_sub(lane_messages.nonces_start)
+					.map(|nonces_difference| nonces_difference.saturating_add(1))
+					.unwrap_or(0);
+				total.saturating_add(lane_messages_count)
+			})
+		}
+	}
+
+	/// Lane id and nonces (inclusive range) of messages, included in the messages proof.
+	#[derive(Clone, Copy, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct FromBridgedChainLaneMessages {
+		/// Messages are sent over this lane.
+		pub lane: LaneId,
+		/// Nonce of the first message being delivered.
+		pub nonces_start: MessageNonce,
+		/// Nonce of the last message being delivered.
+		pub nonces_end: MessageNonce,
 	}
 
 	impl<BridgedHeaderHash> Size for FromBridgedChainMessagesProof<BridgedHeaderHash> {
@@ -584,6 +644,10 @@ pub mod target {
 		) -> Result<ProvedMessages<Message>, Self::Error> {
 			verify_messages_proof::<B>(proof, messages_count).map_err(Into::into)
 		}
+
+		fn proof_lanes_count(proof: &Self::MessagesProof) -> u32 {
+			proof.lanes_count()
+		}
 	}
 
 	/// Verify proof of Bridged -> This chain messages.
@@ -593,18 +657,19 @@ pub mod target {
 	///
 	/// The `messages_count` argument verification (sane limits) is supposed to be made
 	/// outside of this function. This function only verifies that the proof declares exactly
-	/// `messages_count` messages.
+	/// `messages_count` messages, summed over all lanes of the proof.
 	pub fn verify_messages_proof<B: MessageBridge>(
 		proof: FromBridgedChainMessagesProof<HashOf<BridgedChain<B>>>,
 		messages_count: u32,
 	) -> Result<ProvedMessages<Message>, MessageProofError> {
-		let FromBridgedChainMessagesProof {
-			bridged_header_hash,
-			storage_proof,
-			lane,
-			nonces_start,
-			nonces_end,
-		} = proof;
+		// let's check that the user (relayer) has passed correct `messages_count`
+		// (this bounds maximal capacity of messages vecs below)
+		if proof.messages_count() != MessageNonce::from(messages_count) {
+			return Err(MessageProofError::MessagesCountMismatch)
+		}
+
+		let lanes = proof.lanes().collect::<Vec<_>>();
+		let FromBridgedChainMessagesProof { bridged_header_hash, storage_proof, .. } = proof;
 
 		B::BridgedHeaderChain::parse_finalized_storage_proof(
 			bridged_header_hash,
@@ -613,64 +678,68 @@ pub mod target {
 				let parser =
 					StorageProofCheckerAdapter::<_, B> { storage, _dummy: Default::default() };
 
-				// receiving proofs where end < begin is ok (if proof includes outbound lane state)
-				let messages_in_the_proof =
-					if let Some(nonces_difference) = nonces_end.checked_sub(nonces_start) {
-						// let's check that the user (relayer) has passed correct `messages_count`
-						// (this bounds maximal capacity of messages vec below)
-						let messages_in_the_proof = nonces_difference.saturating_add(1);
-						if messages_in_the_proof != MessageNonce::from(messages_count) {
-							return Err(MessageProofError::MessagesCountMismatch)
-						}
-
-						messages_in_the_proof
-					} else {
-						0
-					};
-
-				// Read messages first. All messages that are claimed to be in the proof must
-				// be in the proof. So any error in `read_value`, or even missing value is fatal.
-				//
-				// Mind that we allow proofs with no messages if outbound lane state is proved.
-				let mut messages = Vec::with_capacity(messages_in_the_proof as _);
-				for nonce in nonces_start..=nonces_end {
-					let message_key = MessageKey { lane_id: lane, nonce };
-					let raw_message_data = parser
-						.read_raw_message(&message_key)
-						.ok_or(MessageProofError::MissingRequiredMessage)?;
-					let payload = MessagePayload::decode(&mut &raw_message_data[..])
-						.map_err(|_| MessageProofError::FailedToDecodeMessage)?;
-					messages.push(Message { key: message_key, payload });
-				}
-
-				// Now let's check if proof contains outbound lane state proof. It is optional, so
-				// we simply ignore `read_value` errors and missing value.
-				let mut proved_lane_messages = ProvedLaneMessages { lane_state: None, messages };
-				let raw_outbound_lane_data = parser.read_raw_outbound_lane_data(&lane);
-				if let Some(raw_outbound_lane_data) = raw_outbound_lane_data {
-					proved_lane_messages.lane_state = Some(
-						OutboundLaneData::decode(&mut &raw_outbound_lane_data[..])
-							.map_err(|_| MessageProofError::FailedToDecodeOutboundLaneState)?,
-					);
-				}
+				let mut proved_messages = ProvedMessages::new();
+				for FromBridgedChainLaneMessages { lane, nonces_start, nonces_end } in lanes {
+					if proved_messages.contains_key(&lane) {
+						return Err(MessageProofError::DuplicateLane)
+					}
 
-				// Now we may actually check if the proof is empty or not.
-				if proved_lane_messages.lane_state.is_none() &&
-					proved_lane_messages.messages.is_empty()
-				{
-					return Err(MessageProofError::Empty)
+					let proved_lane_messages =
+						verify_lane_messages(&parser, lane, nonces_start, nonces_end)?;
+					proved_messages.insert(lane, proved_lane_messages);
 				}
 
-				// We only support single lane messages in this generated_schema
-				let mut proved_messages = ProvedMessages::new();
-				proved_messages.insert(lane, proved_lane_messages);
-
 				Ok(proved_messages)
 			},
 		)
 		.map_err(MessageProofError::HeaderChain)?
 	}
 
+	/// Read and verify messages of single lane from the storage proof.
+	fn verify_lane_messages<H: Hasher, B: MessageBridge>(
+		parser: &StorageProofCheckerAdapter<H, B>,
+		lane: LaneId,
+		nonces_start: MessageNonce,
+		nonces_end: MessageNonce,
+	) -> Result<ProvedLaneMessages<Message>, MessageProofError> {
+		// Read messages first. All messages that are claimed to be in the proof must
+		// be in the proof. So any error in `read_value`, or even missing value is fatal.
+		//
+		// Mind that we allow proofs with no messages if outbound lane state is proved.
+		let messages_in_the_proof = nonces_end
+			.checked_sub(nonces_start)
+			.map(|nonces_difference| nonces_difference.saturating_add(1))
+			.unwrap_or(0);
+		let mut messages = Vec::with_capacity(messages_in_the_proof as _);
+		for nonce in nonces_start..=nonces_end {
+			let message_key = MessageKey { lane_id: lane, nonce };
+			let raw_message_data = parser
+				.read_raw_message(&message_key)
+				.ok_or(MessageProofError::MissingRequiredMessage)?;
+			let payload = MessagePayload::decode(&mut &raw_message_data[..])
+				.map_err(|_| MessageProofError::FailedToDecodeMessage)?;
+			messages.push(Message { key: message_key, payload });
+		}
+
+		// Now let's check if proof contains outbound lane state proof. It is optional, so
+		// we simply ignore `read_value` errors and missing value.
+		let mut proved_lane_messages = ProvedLaneMessages { lane_state: None, messages };
+		let raw_outbound_lane_data = parser.read_raw_outbound_lane_data(&lane);
+		if let Some(raw_outbound_lane_data) = raw_outbound_lane_data {
+			proved_lane_messages.lane_state = Some(
+				OutboundLaneData::decode(&mut &raw_outbound_lane_data[..])
+					.map_err(|_| MessageProofError::FailedToDecodeOutboundLaneState)?,
+			);
+		}
+
+		// Now we may actually check if the proof of this lane is empty or not.
+		if proved_lane_messages.lane_state.is_none() && proved_lane_messages.messages.is_empty() {
+			return Err(MessageProofError::Empty)
+		}
+
+		Ok(proved_lane_messages)
+	}
+
 	/// Error that happens during message proof verification.
 	#[derive(Debug, PartialEq, Eq)]
 	pub enum MessageProofError {
@@ -680,6 +749,8 @@ pub mod target {
 		Empty,
 		/// Declared messages count doesn't match actual value.
 		MessagesCountMismatch,
+		/// The same lane is included in the proof more than once.
+		DuplicateLane,
 		/// Message is missing from the proof.
 		MissingRequiredMessage,
 		/// Failed to decode message from the proof.
@@ -695,6 +766,7 @@ pub mod target {
 				MessageProofError::Empty => "Messages proof is empty",
 				MessageProofError::MessagesCountMismatch =>
 					"Declared messages count doesn't match actual value",
+				MessageProofError::DuplicateLane => "Lane is included in the proof more than once",
 				MessageProofError::MissingRequiredMessage => "Message is missing from the proof",
 				MessageProofError::FailedToDecodeMessage =>
 					"Failed to decode message from the proof",
@@ -741,7 +813,8 @@ mod tests {
 	use super::*;
 	use crate::{
 		messages_generation::{
-			encode_all_messages, encode_lane_data, prepare_messages_storage_proof,
+			encode_all_messages, encode_lane_data, prepare_lanes_messages_storage_proof,
+			prepare_messages_storage_proof,
 		},
 		mock::*,
 	};
@@ -848,6 +921,53 @@ mod tests {
 			encode_outbound_lane_data,
 		);
 
+		using_bridged_header(state_root, move |bridged_header_hash| {
+			test(target::FromBridgedChainMessagesProof {
+				bridged_header_hash,
+				storage_proof,
+				lane: TEST_LANE_ID,
+				nonces_start: 1,
+				nonces_end,
+				additional_lanes: Default::default(),
+			})
+		})
+	}
+
+	fn using_lanes_messages_proof<R>(
+		lanes: Vec<(LaneId, MessageNonce)>,
+		test: impl Fn(target::FromBridgedChainMessagesProof<H256>) -> R,
+	) -> R {
+		let (state_root, storage_proof) = prepare_lanes_messages_storage_proof::<OnThisChainBridge>(
+			lanes.iter().map(|(lane, nonces_end)| (*lane, 1..=*nonces_end, None)).collect(),
+			bp_runtime::StorageProofSize::Minimal(0),
+			vec![42],
+			encode_all_messages,
+			encode_lane_data,
+		);
+
+		using_bridged_header(state_root, move |bridged_header_hash| {
+			let (lane, nonces_end) = lanes[0];
+			test(target::FromBridgedChainMessagesProof {
+				bridged_header_hash,
+				storage_proof,
+				lane,
+				nonces_start: 1,
+				nonces_end,
+				additional_lanes: lanes[1..]
+					.iter()
+					.map(|(lane, nonces_end)| target::FromBridgedChainLaneMessages {
+						lane: *lane,
+						nonces_start: 1,
+						nonces_end: *nonces_end,
+					})
+					.collect::<Vec<_>>()
+					.try_into()
+					.expect("tests are using no more than `MAX_LANES_IN_PROOF` lanes"),
+			})
+		})
+	}
+
+	fn using_bridged_header<R>(state_root: H256, test: impl FnOnce(H256) -> R) -> R {
 		sp_io::TestExternalities::new(Default::default()).execute_with(move || {
 			let bridged_header = BridgedChainHeader::new(
 				0,
@@ -866,13 +986,7 @@ mod tests {
 				bridged_header_hash,
 				bridged_header.build(),
 			);
-			test(target::FromBridgedChainMessagesProof {
-				bridged_header_hash,
-				storage_proof,
-				lane: TEST_LANE_ID,
-				nonces_start: 1,
-				nonces_end,
-			})
+			test(bridged_header_hash)
 		})
 	}
 
@@ -1071,4 +1185,100 @@ mod tests {
 			Err(target::MessageProofError::MessagesCountMismatch),
 		);
 	}
+
+	#[test]
+	fn multi_lane_message_proof_is_accepted() {
+		let lane2 = LaneId([0, 0, 0, 1]);
+		assert_eq!(
+			using_lanes_messages_proof(vec![(TEST_LANE_ID, 1), (lane2, 2)], |proof| {
+				target::verify_messages_proof::<OnThisChainBridge>(proof, 3)
+			}),
+			Ok(vec![
+				(
+					TEST_LANE_ID,
+					ProvedLaneMessages {
+						lane_state: None,
+						messages: vec![Message {
+							key: MessageKey { lane_id: TEST_LANE_ID, nonce: 1 },
+							payload: vec![42],
+						}],
+					},
+				),
+				(
+					lane2,
+					ProvedLaneMessages {
+						lane_state: None,
+						messages: vec![
+							Message {
+								key: MessageKey { lane_id: lane2, nonce: 1 },
+								payload: vec![42],
+							},
+							Message {
+								key: MessageKey { lane_id: lane2, nonce: 2 },
+								payload: vec![42],
+							},
+						],
+					},
+				),
+			]
+			.into_iter()
+			.collect()),
+		);
+	}
+
+	#[test]
+	fn multi_lane_message_proof_is_rejected_if_declared_messages_of_single_lane() {
+		assert_eq!(
+			using_lanes_messages_proof(
+				vec![(TEST_LANE_ID, 1), (LaneId([0, 0, 0, 1]), 2)],
+				|proof| target::verify_messages_proof::<OnThisChainBridge>(proof, 2),
+			),
+			Err(target::MessageProofError::MessagesCountMismatch),
+		);
+	}
+
+	#[test]
+	fn multi_lane_message_proof_is_rejected_if_lane_is_duplicated() {
+		assert_eq!(
+			using_lanes_messages_proof(vec![(TEST_LANE_ID, 1), (TEST_LANE_ID, 1)], |proof| {
+				target::verify_messages_proof::<OnThisChainBridge>(proof, 2)
+			}),
+			Err(target::MessageProofError::DuplicateLane),
+		);
+	}
+
+	#[test]
+	fn multi_lane_message_proof_is_rejected_if_message_of_additional_lane_is_missing() {
+		assert_eq!(
+			using_lanes_messages_proof(
+				vec![(TEST_LANE_ID, 1), (LaneId([0, 0, 0, 1]), 2)],
+				|mut proof| {
+					proof.additional_lanes[0].nonces_end = 3;
+					target::verify_messages_proof::<OnThisChainBridge>(proof, 4)
+				},
+			),
+			Err(target::MessageProofError::MissingRequiredMessage),
+		);
+	}
+
+	#[test]
+	fn multi_lane_message_proof_with_too_many_lanes_is_not_decoded() {
+		let lane_messages = target::FromBridgedChainLaneMessages {
+			lane: TEST_LANE_ID,
+			nonces_start: 1,
+			nonces_end: 1,
+		};
+		let encoded_proof = (
+			H256::default(),
+			Vec::<Vec<u8>>::new(),
+			TEST_LANE_ID,
+			1u64,
+			1u64,
+			vec![lane_messages; target::MAX_LANES_IN_PROOF as usize],
+		)
+			.encode();
+		assert!(
+			target::FromBridgedChainMessagesProof::<H256>::decode(&mut &encoded_proof[..]).is_err()
+		);
+	}
 }
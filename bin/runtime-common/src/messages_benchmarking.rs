@@ -79,6 +79,7 @@ where
 			lane: params.lane,
 			nonces_start: *params.message_nonces.start(),
 			nonces_end: *params.message_nonces.end(),
+			additional_lanes: Default::default(),
 		},
 		Weight::zero(),
 	)
@@ -126,6 +127,7 @@ where
 			lane: params.lane,
 			nonces_start: *params.message_nonces.start(),
 			nonces_end: *params.message_nonces.end(),
+			additional_lanes: Default::default(),
 		},
 		Weight::zero(),
 	)
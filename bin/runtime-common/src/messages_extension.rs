@@ -50,16 +50,20 @@ impl<
 				ref proof,
 				..
 			}) => {
-				let inbound_lane_data =
-					pallet_bridge_messages::InboundLanes::<T, I>::get(proof.lane);
-				if proof.nonces_end <= inbound_lane_data.last_delivered_nonce() {
+				// the transaction is obsolete only if it brings no new messages at all lanes
+				let is_obsolete = proof.lanes().all(|lane_messages| {
+					let inbound_lane_data =
+						pallet_bridge_messages::InboundLanes::<T, I>::get(lane_messages.lane);
+					lane_messages.nonces_end <= inbound_lane_data.last_delivered_nonce()
+				});
+				if is_obsolete {
 					log::trace!(
 						target: pallet_bridge_messages::LOG_TARGET,
 						"Rejecting obsolete messages delivery transaction: \
-                            lane {:?}, bundled {:?}, best {:?}",
+							lane {:?}, bundled {:?}, additional lanes {:?}",
 						proof.lane,
 						proof.nonces_end,
-						inbound_lane_data.last_delivered_nonce(),
+						proof.additional_lanes,
 					);
 
 					return sp_runtime::transaction_validity::InvalidTransaction::Stale.into()
@@ -78,7 +82,7 @@ impl<
 					log::trace!(
 						target: pallet_bridge_messages::LOG_TARGET,
 						"Rejecting obsolete messages confirmation transaction: \
-                            lane {:?}, bundled {:?}, best {:?}",
+							lane {:?}, bundled {:?}, best {:?}",
 						proof.lane,
 						latest_delivered_nonce,
 						outbound_lane_data.latest_received_nonce,
@@ -98,7 +102,8 @@ impl<
 mod tests {
 	use crate::{
 		messages::{
-			source::FromBridgedChainMessagesDeliveryProof, target::FromBridgedChainMessagesProof,
+			source::FromBridgedChainMessagesDeliveryProof,
+			target::{FromBridgedChainLaneMessages, FromBridgedChainMessagesProof},
 		},
 		mock::{TestRuntime, ThisChainRuntimeCall},
 		BridgeRuntimeFilterCall,
@@ -128,6 +133,37 @@ mod tests {
 						lane: bp_messages::LaneId([0, 0, 0, 0]),
 						nonces_start,
 						nonces_end,
+						additional_lanes: Default::default(),
+					},
+				},
+			),
+		)
+		.is_ok()
+	}
+
+	fn validate_multi_lane_message_delivery(
+		nonces_end: bp_messages::MessageNonce,
+		additional_lane_nonces_end: bp_messages::MessageNonce,
+	) -> bool {
+		pallet_bridge_messages::Pallet::<TestRuntime>::validate(
+			&ThisChainRuntimeCall::BridgeMessages(
+				pallet_bridge_messages::Call::<TestRuntime, ()>::receive_messages_proof {
+					relayer_id_at_bridged_chain: 42,
+					messages_count: (nonces_end + additional_lane_nonces_end) as u32,
+					dispatch_weight: frame_support::weights::Weight::zero(),
+					proof: FromBridgedChainMessagesProof {
+						bridged_header_hash: Default::default(),
+						storage_proof: vec![],
+						lane: bp_messages::LaneId([0, 0, 0, 0]),
+						nonces_start: 1,
+						nonces_end,
+						additional_lanes: vec![FromBridgedChainLaneMessages {
+							lane: bp_messages::LaneId([0, 0, 0, 1]),
+							nonces_start: 1,
+							nonces_end: additional_lane_nonces_end,
+						}]
+						.try_into()
+						.expect("single additional lane is allowed"),
 					},
 				},
 			),
@@ -165,6 +201,26 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn extension_rejects_multi_lane_delivery_if_all_lanes_are_obsolete() {
+		sp_io::TestExternalities::new(Default::default()).execute_with(|| {
+			// when current best delivered is message#10 at the first lane and nothing is
+			// delivered at the second lane, proof that brings no new messages is rejected
+			deliver_message_10();
+			assert!(!validate_multi_lane_message_delivery(10, 0));
+		});
+	}
+
+	#[test]
+	fn extension_accepts_multi_lane_delivery_if_some_lane_brings_new_messages() {
+		sp_io::TestExternalities::new(Default::default()).execute_with(|| {
+			// when current best delivered is message#10 at the first lane, proof that brings
+			// new messages of the second lane is accepted
+			deliver_message_10();
+			assert!(validate_multi_lane_message_delivery(10, 5));
+		});
+	}
+
 	fn confirm_message_10() {
 		pallet_bridge_messages::OutboundLanes::<TestRuntime>::insert(
 			bp_messages::LaneId([0, 0, 0, 0]),
@@ -55,43 +55,68 @@ where
 	B: MessageBridge,
 	HashOf<BridgedChain<B>>: Copy + Default,
 {
-	// prepare Bridged chain storage with messages and (optionally) outbound lane state
-	let message_count = message_nonces.end().saturating_sub(*message_nonces.start()) + 1;
-	let mut storage_keys = Vec::with_capacity(message_count as usize + 1);
+	prepare_lanes_messages_storage_proof::<B>(
+		vec![(lane, message_nonces, outbound_lane_data)],
+		size,
+		message_payload,
+		encode_message,
+		encode_outbound_lane_data,
+	)
+}
+
+/// Prepare storage proof of given messages, sent over several lanes.
+///
+/// Returns state trie root and nodes with prepared messages.
+pub(crate) fn prepare_lanes_messages_storage_proof<B>(
+	lanes: Vec<(LaneId, RangeInclusive<MessageNonce>, Option<OutboundLaneData>)>,
+	size: StorageProofSize,
+	message_payload: MessagePayload,
+	encode_message: impl Fn(MessageNonce, &MessagePayload) -> Option<Vec<u8>>,
+	encode_outbound_lane_data: impl Fn(&OutboundLaneData) -> Vec<u8>,
+) -> (HashOf<BridgedChain<B>>, RawStorageProof)
+where
+	B: MessageBridge,
+	HashOf<BridgedChain<B>>: Copy + Default,
+{
+	// prepare Bridged chain storage with messages and (optionally) outbound lane states
+	let mut storage_keys = Vec::new();
 	let mut root = Default::default();
 	let mut mdb = MemoryDB::default();
 	{
 		let mut trie =
 			TrieDBMutBuilderV1::<HasherOf<BridgedChain<B>>>::new(&mut mdb, &mut root).build();
 
-		// insert messages
-		for nonce in message_nonces {
-			let message_key = MessageKey { lane_id: lane, nonce };
-			let message_payload = match encode_message(nonce, &message_payload) {
-				Some(message_payload) => message_payload,
-				None => continue,
-			};
-			let storage_key = storage_keys::message_key(
-				B::BRIDGED_MESSAGES_PALLET_NAME,
-				&message_key.lane_id,
-				message_key.nonce,
-			)
-			.0;
-			trie.insert(&storage_key, &message_payload)
-				.map_err(|_| "TrieMut::insert has failed")
-				.expect("TrieMut::insert should not fail in benchmarks");
-			storage_keys.push(storage_key);
-		}
-
-		// insert outbound lane state
-		if let Some(outbound_lane_data) = outbound_lane_data.as_ref().map(encode_outbound_lane_data)
-		{
-			let storage_key =
-				storage_keys::outbound_lane_data_key(B::BRIDGED_MESSAGES_PALLET_NAME, &lane).0;
-			trie.insert(&storage_key, &outbound_lane_data)
-				.map_err(|_| "TrieMut::insert has failed")
-				.expect("TrieMut::insert should not fail in benchmarks");
-			storage_keys.push(storage_key);
+		for (lane, message_nonces, outbound_lane_data) in lanes {
+			// insert messages
+			for nonce in message_nonces {
+				let message_key = MessageKey { lane_id: lane, nonce };
+				let message_payload = match encode_message(nonce, &message_payload) {
+					Some(message_payload) => message_payload,
+					None => continue,
+				};
+				let storage_key = storage_keys::message_key(
+					B::BRIDGED_MESSAGES_PALLET_NAME,
+					&message_key.lane_id,
+					message_key.nonce,
+				)
+				.0;
+				trie.insert(&storage_key, &message_payload)
+					.map_err(|_| "TrieMut::insert has failed")
+					.expect("TrieMut::insert should not fail in benchmarks");
+				storage_keys.push(storage_key);
+			}
+
+			// insert outbound lane state
+			if let Some(outbound_lane_data) =
+				outbound_lane_data.as_ref().map(&encode_outbound_lane_data)
+			{
+				let storage_key =
+					storage_keys::outbound_lane_data_key(B::BRIDGED_MESSAGES_PALLET_NAME, &lane).0;
+				trie.insert(&storage_key, &outbound_lane_data)
+					.map_err(|_| "TrieMut::insert has failed")
+					.expect("TrieMut::insert should not fail in benchmarks");
+				storage_keys.push(storage_key);
+			}
 		}
 	}
 	root = grow_trie(root, &mut mdb, size);
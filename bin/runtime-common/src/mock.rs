@@ -32,6 +32,7 @@ use crate::messages::{
 	BridgedChainWithMessages, HashOf, MessageBridge, ThisChainWithMessages,
 };
 
+use bp_beefy::{BeefyEcdsaToEthereum, ChainWithBeefy, EcdsaValidatorId};
 use bp_header_chain::HeaderChain;
 use bp_messages::{target_chain::ForbidInboundMessages, LaneId, MessageNonce};
 use bp_parachains::SingleParaStoredHeaderDataBuilder;
@@ -39,13 +40,14 @@ use bp_runtime::{Chain, ChainId, Parachain, UnderlyingChainProvider};
 use codec::{Decode, Encode};
 use frame_support::{
 	parameter_types,
+	traits::Randomness,
 	weights::{ConstantMultiplier, IdentityFee, RuntimeDbWeight, Weight},
 };
 use pallet_transaction_payment::Multiplier;
 use sp_runtime::{
 	testing::H256,
-	traits::{BlakeTwo256, ConstU32, ConstU64, ConstU8, IdentityLookup},
-	FixedPointNumber, Perquintill,
+	traits::{BlakeTwo256, ConstU32, ConstU64, ConstU8, IdentityLookup, Keccak256},
+	FixedPointNumber, Perbill, Perquintill,
 };
 
 /// Account identifier at `ThisChain`.
@@ -106,6 +108,7 @@ frame_support::construct_runtime! {
 		TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>},
 		BridgeRelayers: pallet_bridge_relayers::{Pallet, Call, Storage, Event<T>},
 		BridgeGrandpa: pallet_bridge_grandpa::{Pallet, Call, Storage},
+		BridgeBeefy: pallet_bridge_beefy::{Pallet, Call, Storage, Event<T>},
 		BridgeParachains: pallet_bridge_parachains::{Pallet, Call, Storage, Event<T>},
 		BridgeMessages: pallet_bridge_messages::{Pallet, Call, Storage, Event<T>, Config<T>},
 	}
@@ -113,7 +116,7 @@ frame_support::construct_runtime! {
 
 crate::generate_bridge_reject_obsolete_headers_and_messages! {
 	ThisChainRuntimeCall, ThisChainAccountId,
-	BridgeGrandpa, BridgeParachains, BridgeMessages
+	BridgeGrandpa, BridgeBeefy, BridgeParachains, BridgeMessages
 }
 
 parameter_types! {
@@ -128,6 +131,10 @@ parameter_types! {
 	pub AdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(3, 100_000);
 	pub MinimumMultiplier: Multiplier = Multiplier::saturating_from_rational(1, 1_000_000u128);
 	pub MaximumMultiplier: Multiplier = sp_runtime::traits::Bounded::max_value();
+	pub const ReserveId: [u8; 8] = *b"brdgrlrs";
+	pub const Stake: ThisChainBalance = 1_000;
+	pub const Lease: ThisChainBlockNumber = 8;
+	pub const SlashFraction: Perbill = Perbill::one();
 }
 
 impl frame_system::Config for TestRuntime {
@@ -199,6 +206,20 @@ impl pallet_bridge_grandpa::Config for TestRuntime {
 	type WeightInfo = pallet_bridge_grandpa::weights::BridgeWeight<TestRuntime>;
 }
 
+impl pallet_bridge_beefy::Config for TestRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxRequests = ConstU32<50>;
+	type BridgedChain = BridgedUnderlyingChain;
+	type CommitmentsToKeep = ConstU32<8>;
+	type HeadersToKeep = ConstU32<8>;
+	type MinSignaturesInSubset = ConstU32<4>;
+	type MaxBridgedAuthorities = ConstU32<1024>;
+	type Randomness = ThisChainRandomness;
+	type ClaimDelay = ConstU32<2>;
+	type ClaimLifetime = ConstU32<4>;
+	type WeightInfo = pallet_bridge_beefy::weights::BridgeWeight<TestRuntime>;
+}
+
 impl pallet_bridge_parachains::Config for TestRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type BridgesGrandpaPalletInstance = ();
@@ -228,6 +249,7 @@ impl pallet_bridge_messages::Config for TestRuntime {
 	type LaneMessageVerifier = FromThisChainMessageVerifier<OnThisChainBridge>;
 	type DeliveryConfirmationPayments = pallet_bridge_relayers::DeliveryConfirmationPaymentsAdapter<
 		TestRuntime,
+		BridgedChainId,
 		frame_support::traits::ConstU64<100_000>,
 		frame_support::traits::ConstU64<10_000>,
 	>;
@@ -242,9 +264,28 @@ impl pallet_bridge_relayers::Config for TestRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type Reward = ThisChainBalance;
 	type PaymentProcedure = ();
+	type StakeAndSlash = pallet_bridge_relayers::StakeAndSlashNamed<
+		ThisChainAccountId,
+		ThisChainBlockNumber,
+		Balances,
+		ReserveId,
+		Stake,
+		Lease,
+	>;
+	type SlashFraction = SlashFraction;
+	type SlashDestination = ();
 	type WeightInfo = ();
 }
 
+/// Randomness at `ThisChain`.
+pub struct ThisChainRandomness;
+
+impl Randomness<ThisChainHash, ThisChainBlockNumber> for ThisChainRandomness {
+	fn random(_subject: &[u8]) -> (ThisChainHash, ThisChainBlockNumber) {
+		(Default::default(), frame_system::Pallet::<TestRuntime>::block_number())
+	}
+}
+
 /// Bridge that is deployed on `ThisChain` and allows sending/receiving messages to/from
 /// `BridgedChain`.
 #[derive(Debug, PartialEq, Eq)]
@@ -372,6 +413,15 @@ impl Chain for BridgedUnderlyingChain {
 	}
 }
 
+impl ChainWithBeefy for BridgedUnderlyingChain {
+	type CommitmentHasher = Keccak256;
+	type MmrHashing = Keccak256;
+	type MmrHash = <Keccak256 as sp_runtime::traits::Hash>::Output;
+	type BeefyMmrLeafExtra = ();
+	type AuthorityId = EcdsaValidatorId;
+	type AuthorityIdToMerkleLeaf = BeefyEcdsaToEthereum;
+}
+
 impl Chain for BridgedUnderlyingParachain {
 	type BlockNumber = BridgedChainBlockNumber;
 	type Hash = BridgedChainHash;
@@ -14,27 +14,43 @@
 // You should have received a copy of the GNU General Public License
 // along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
 
-//! Signed extension that refunds relayer if he has delivered some new messages.
-//! It also refunds transaction cost if the transaction is an `utility.batchAll()`
-//! with calls that are: delivering new messsage and all necessary underlying headers
-//! (parachain or relay chain).
-
-use crate::messages::target::FromBridgedChainMessagesProof;
+//! Signed extensions that refund relayer if he has delivered some new messages or
+//! delivery confirmations. They also refund transaction cost if the transaction is an
+//! `utility.batchAll()` with calls that are: delivering new messsages (or confirmations)
+//! and all necessary underlying headers (parachain or relay chain).
+//!
+//! There's single generic extension here (`RefundRelayerForMessages`), which is parameterized
+//! by the extractor of finality calls that may be batched with messages calls. There are two
+//! specializations of this extension: `RefundRelayerForMessagesFromParachain` is used to refund
+//! relayers that are delivering messages from the parachain and
+//! `RefundRelayerForMessagesFromGrandpaChain` is used to refund relayers that are delivering
+//! messages from the chain with GRANDPA finality (e.g. from relay chain or from some standalone
+//! chain).
+
+use crate::messages::{
+	source::FromBridgedChainMessagesDeliveryProof,
+	target::{FromBridgedChainLaneMessages, FromBridgedChainMessagesProof, MAX_LANES_IN_PROOF},
+};
 
-use bp_messages::{target_chain::SourceHeaderChain, LaneId, MessageNonce};
+use bp_messages::{
+	source_chain::TargetHeaderChain, target_chain::SourceHeaderChain, LaneId, MessageNonce,
+};
 use bp_polkadot_core::parachains::ParaId;
-use bp_runtime::{Chain, HashOf};
+use bp_relayers::{RewardsAccountOwner, RewardsAccountParams};
+use bp_runtime::{compute_priority_boost, Chain, HashOf};
 use codec::{Decode, Encode};
 use frame_support::{
 	dispatch::{CallableCallFor, DispatchInfo, Dispatchable, PostDispatchInfo},
-	traits::IsSubType,
-	CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+	traits::{ConstU32, IsSubType},
+	BoundedVec, CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebug,
+	RuntimeDebugNoBound,
 };
 use pallet_bridge_grandpa::{
-	BridgedChain, Call as GrandpaCall, Config as GrandpaConfig, Pallet as GrandpaPallet,
+	BridgedBlockNumber, BridgedChain, Call as GrandpaCall, Config as GrandpaConfig,
+	Pallet as GrandpaPallet,
 };
 use pallet_bridge_messages::{
-	Call as MessagesCall, Config as MessagesConfig, Pallet as MessagesPallet,
+	Call as MessagesCall, Config as MessagesConfig, OutboundLanes, Pallet as MessagesPallet,
 };
 use pallet_bridge_parachains::{
 	Call as ParachainsCall, Config as ParachainsConfig, Pallet as ParachainsPallet, RelayBlockHash,
@@ -46,10 +62,12 @@ use pallet_utility::{Call as UtilityCall, Config as UtilityConfig, Pallet as Uti
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{DispatchInfoOf, Get, Header as HeaderT, PostDispatchInfoOf, SignedExtension, Zero},
-	transaction_validity::{TransactionValidity, TransactionValidityError, ValidTransaction},
+	transaction_validity::{
+		TransactionPriority, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
 	DispatchResult, FixedPointOperand,
 };
-use sp_std::marker::PhantomData;
+use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
 
 // TODO (https://github.com/paritytech/parity-bridges-common/issues/1667):
 // support multiple bridges in this extension
@@ -81,14 +99,128 @@ where
 		pallet_transaction_payment::Pallet::<R>::compute_actual_fee(len as _, info, post_info, tip)
 	}
 }
-/// Signed extension that refunds relayer for new messages coming from the parachain.
+/// Extracts expected post-dispatch state of finality pallets from the finality calls, that may
+/// be batched (`utility.batchAll`) with the message delivery or delivery confirmation call.
+///
+/// Finality calls are refunded by the `RefundRelayerForMessages` extension, if they have updated
+/// the state of finality pallets and the messages call has delivered (or confirmed) some new
+/// messages.
+pub trait FinalityCallExtractor<R: frame_system::Config> {
+	/// Unique identifier of the signed extension that is using this extractor.
+	const IDENTIFIER: &'static str;
+
+	/// Hash of the bridged chain header that is used in messages (and delivery) proofs.
+	type BridgedHeaderHash;
+	/// Expected post-dispatch state of finality pallets.
+	type ExpectedState: Clone + PartialEq + Debug;
+
+	/// Extracts expected post-dispatch state from finality calls that precede the messages call
+	/// in the batch. Returns `None` if calls are not supported.
+	fn extract_expected_state(calls: &[CallOf<R>]) -> Option<Self::ExpectedState>;
+
+	/// Returns true if finality pallets have reached the expected state.
+	///
+	/// Must be called after the call is dispatched.
+	fn is_expected_state_reached(expected_state: &Self::ExpectedState) -> bool;
+}
+
+/// Finality call of the chain with GRANDPA finality (e.g. relay chain or some standalone
+/// chain): single `submit_finality_proof` call of the GRANDPA pallet instance `GI`.
+pub struct GrandpaFinalityCall<GI>(PhantomData<GI>);
+
+impl<R, GI> FinalityCallExtractor<R> for GrandpaFinalityCall<GI>
+where
+	R: GrandpaConfig<GI>,
+	GI: 'static,
+	CallOf<R>: IsSubType<CallableCallFor<GrandpaPallet<R, GI>, R>>,
+{
+	const IDENTIFIER: &'static str = "RefundRelayerForMessagesFromGrandpaChain";
+
+	type BridgedHeaderHash = HashOf<BridgedChain<R, GI>>;
+	type ExpectedState = ExpectedGrandpaChainState<BridgedBlockNumber<R, GI>>;
+
+	fn extract_expected_state(calls: &[CallOf<R>]) -> Option<Self::ExpectedState> {
+		match *calls {
+			[ref grandpa_call] => extract_expected_grandpa_chain_state::<R, GI>(grandpa_call),
+			_ => None,
+		}
+	}
+
+	fn is_expected_state_reached(expected_state: &Self::ExpectedState) -> bool {
+		grandpa_chain_state::<R, GI>().as_ref() == Some(expected_state)
+	}
+}
+
+/// Finality calls of the parachain: optional `submit_finality_proof` call of the relay chain
+/// GRANDPA pallet instance `GI`, followed by the `submit_parachain_heads` call of the
+/// parachains pallet instance `PI`, which is updating head of the parachain `PID`.
+pub struct ParachainFinalityCalls<GI, PI, PID>(PhantomData<(GI, PI, PID)>);
+
+impl<R, GI, PI, PID> FinalityCallExtractor<R> for ParachainFinalityCalls<GI, PI, PID>
+where
+	R: GrandpaConfig<GI> + ParachainsConfig<PI, BridgesGrandpaPalletInstance = GI>,
+	GI: 'static,
+	PI: 'static,
+	PID: Get<u32>,
+	<R as GrandpaConfig<GI>>::BridgedChain:
+		Chain<BlockNumber = RelayBlockNumber, Hash = RelayBlockHash, Hasher = RelayBlockHasher>,
+	CallOf<R>: IsSubType<CallableCallFor<GrandpaPallet<R, GI>, R>>
+		+ IsSubType<CallableCallFor<ParachainsPallet<R, PI>, R>>,
+{
+	const IDENTIFIER: &'static str = "RefundRelayerForMessagesFromParachain";
+
+	type BridgedHeaderHash = HashOf<BridgedChain<R, GI>>;
+	type ExpectedState = ExpectedParachainFinalityState;
+
+	fn extract_expected_state(calls: &[CallOf<R>]) -> Option<Self::ExpectedState> {
+		match *calls {
+			[ref relay_chain_call, ref parachain_call] => Some(ExpectedParachainFinalityState {
+				relay_chain: Some(extract_expected_grandpa_chain_state::<R, GI>(relay_chain_call)?),
+				parachain: extract_expected_parachain_state::<R, GI, PI, PID>(parachain_call)?,
+			}),
+			[ref parachain_call] => Some(ExpectedParachainFinalityState {
+				relay_chain: None,
+				parachain: extract_expected_parachain_state::<R, GI, PI, PID>(parachain_call)?,
+			}),
+			_ => None,
+		}
+	}
+
+	fn is_expected_state_reached(expected_state: &Self::ExpectedState) -> bool {
+		// check if relay chain state has been updated
+		if let Some(expected_relay_chain_state) = expected_state.relay_chain {
+			if grandpa_chain_state::<R, GI>() != Some(expected_relay_chain_state) {
+				return false
+			}
+		}
+
+		// check if parachain state has been updated
+		parachain_state::<R, PI, PID>() == Some(expected_state.parachain)
+	}
+}
+
+/// Signed extension that refunds relayer for new messages coming from the bridged chain.
+///
+/// Also refunds relayer for delivering new confirmations of messages, sent to the bridged chain.
 ///
 /// Also refunds relayer for successful finality delivery if it comes in batch (`utility.batchAll`)
-/// with message delivery transaction. Batch may deliver either both relay chain header and
-/// parachain head, or just parachain head. Corresponding headers must be used in messages
+/// with message delivery or delivery confirmation transaction. Supported finality calls are
+/// defined by the `FC` extractor. Corresponding headers must be used in messages (or delivery)
 /// proof verification.
 ///
+/// Only transactions that are delivering messages (or confirmations) over lanes from the `LIDS`
+/// set are refunded. The reward is registered for the primary lane of the delivered proof. If
+/// the messages proof covers several lanes, the transaction is refunded only if it has delivered
+/// new messages over every lane.
+///
+/// Message delivery transactions of registered relayers get priority boost, which is
+/// proportional to the number of delivered messages (see `PRI`).
+///
 /// Extension does not refund transaction tip due to security reasons.
+///
+/// If registered relayer (see `pallet_bridge_relayers::Pallet::register`) submits a bridge
+/// transaction, which hasn't updated the state of all bridge pallets it was expected to update,
+/// its stake is slashed and sent to the rewards account that is used to refund relayers.
 #[derive(
 	CloneNoBound,
 	Decode,
@@ -99,51 +231,65 @@ where
 	RuntimeDebugNoBound,
 	TypeInfo,
 )]
-#[scale_info(skip_type_params(RT, GI, PI, MI, BE, PID, LID, FEE))]
-#[allow(clippy::type_complexity)] // TODO: get rid of that in https://github.com/paritytech/parity-bridges-common/issues/1666
-pub struct RefundRelayerForMessagesFromParachain<RT, GI, PI, MI, BE, PID, LID, FEE>(
-	PhantomData<(RT, GI, PI, MI, BE, PID, LID, FEE)>,
+#[scale_info(skip_type_params(RT, FC, MI, BE, LIDS, FEE, PRI))]
+pub struct RefundRelayerForMessages<RT, FC, MI, BE, LIDS, FEE, PRI>(
+	PhantomData<(RT, FC, MI, BE, LIDS, FEE, PRI)>,
 );
 
+/// Signed extension that refunds relayer for new messages coming from the parachain.
+///
+/// Batch may deliver either both relay chain header and parachain head, or just parachain head
+/// (see `ParachainFinalityCalls`).
+pub type RefundRelayerForMessagesFromParachain<RT, GI, PI, MI, BE, PID, LIDS, FEE, PRI> =
+	RefundRelayerForMessages<RT, ParachainFinalityCalls<GI, PI, PID>, MI, BE, LIDS, FEE, PRI>;
+
+/// Signed extension that refunds relayer for new messages coming from the chain with GRANDPA
+/// finality.
+///
+/// Batch may deliver the GRANDPA chain header (see `GrandpaFinalityCall`).
+pub type RefundRelayerForMessagesFromGrandpaChain<RT, GI, MI, BE, LIDS, FEE, PRI> =
+	RefundRelayerForMessages<RT, GrandpaFinalityCall<GI>, MI, BE, LIDS, FEE, PRI>;
+
 /// Data that is crafted in `pre_dispatch` method and used at `post_dispatch`.
 #[derive(PartialEq)]
 #[cfg_attr(test, derive(Debug))]
-pub struct PreDispatchData<AccountId> {
+pub struct PreDispatchData<AccountId, ExpectedState> {
 	/// Transaction submitter (relayer) account.
 	pub relayer: AccountId,
 	/// Type of the call.
-	pub call_type: CallType,
+	pub call_type: CallType<ExpectedState>,
 }
 
 /// Type of the call that the extension recognizes.
-#[derive(Clone, Copy, PartialEq, RuntimeDebugNoBound)]
-pub enum CallType {
-	/// Relay chain finality + parachain finality + message delivery calls.
-	AllFinalityAndDelivery(ExpectedRelayChainState, ExpectedParachainState, MessagesState),
-	/// Parachain finality + message delivery calls.
-	ParachainFinalityAndDelivery(ExpectedParachainState, MessagesState),
-	/// Standalone message delivery call.
-	Delivery(MessagesState),
+#[derive(Clone, PartialEq, RuntimeDebug)]
+pub enum CallType<ExpectedState> {
+	/// Finality calls + message delivery (or confirmation) call. Contains expected post-dispatch
+	/// state of finality pallets.
+	FinalityAndMsgs(ExpectedState, MessagesCallInfo),
+	/// Standalone message delivery (or confirmation) call.
+	Msgs(MessagesCallInfo),
 }
 
-impl CallType {
-	/// Returns the pre-dispatch messages pallet state.
-	fn pre_dispatch_messages_state(&self) -> MessagesState {
+impl<ExpectedState> CallType<ExpectedState> {
+	/// Returns the messages call info.
+	fn messages_call_info(&self) -> &MessagesCallInfo {
 		match *self {
-			Self::AllFinalityAndDelivery(_, _, messages_state) => messages_state,
-			Self::ParachainFinalityAndDelivery(_, messages_state) => messages_state,
-			Self::Delivery(messages_state) => messages_state,
+			Self::FinalityAndMsgs(_, ref messages_call_info) => messages_call_info,
+			Self::Msgs(ref messages_call_info) => messages_call_info,
 		}
 	}
 }
 
-/// Expected post-dispatch state of the relay chain pallet.
-#[derive(Clone, Copy, PartialEq, RuntimeDebugNoBound)]
-pub struct ExpectedRelayChainState {
-	/// Best known relay chain block number.
-	pub best_block_number: RelayBlockNumber,
+/// Expected post-dispatch state of the GRANDPA pallet.
+#[derive(Clone, Copy, PartialEq, RuntimeDebug)]
+pub struct ExpectedGrandpaChainState<BlockNumber> {
+	/// Best known bridged chain block number.
+	pub best_block_number: BlockNumber,
 }
 
+/// Expected post-dispatch state of the relay chain pallet.
+pub type ExpectedRelayChainState = ExpectedGrandpaChainState<RelayBlockNumber>;
+
 /// Expected post-dispatch state of the parachain pallet.
 #[derive(Clone, Copy, PartialEq, RuntimeDebugNoBound)]
 pub struct ExpectedParachainState {
@@ -151,63 +297,197 @@ pub struct ExpectedParachainState {
 	pub at_relay_block_number: RelayBlockNumber,
 }
 
+/// Expected post-dispatch state of the relay chain and parachain pallets.
+#[derive(Clone, Copy, PartialEq, RuntimeDebugNoBound)]
+pub struct ExpectedParachainFinalityState {
+	/// Expected state of the relay chain pallet, if relay chain header is delivered in the
+	/// same batch.
+	pub relay_chain: Option<ExpectedRelayChainState>,
+	/// Expected state of the parachain pallet.
+	pub parachain: ExpectedParachainState,
+}
+
+/// Messages call that the extensions recognize.
+#[derive(Clone, PartialEq, RuntimeDebugNoBound)]
+pub enum MessagesCallInfo {
+	/// Messages delivery call (`receive_messages_proof`) with pre-dispatch states of the primary
+	/// and additional inbound lanes of the proof and the total number of new messages that the
+	/// call is going to deliver over these lanes.
+	ReceiveMessagesProof(MessagesState, AdditionalLanesMessagesState, MessageNonce),
+	/// Messages delivery confirmation call (`receive_messages_delivery_proof`) with pre-dispatch
+	/// state of the outbound lane.
+	ReceiveMessagesDeliveryProof(MessagesState),
+}
+
+impl MessagesCallInfo {
+	/// Returns the pre-dispatch messages pallet state of the primary lane.
+	fn messages_state(&self) -> MessagesState {
+		match *self {
+			Self::ReceiveMessagesProof(messages_state, _, _) => messages_state,
+			Self::ReceiveMessagesDeliveryProof(messages_state) => messages_state,
+		}
+	}
+
+	/// Returns owner of the rewards account, that is paying refund for this call.
+	///
+	/// Message delivery transactions are refunded from the account of this chain (messages are
+	/// sent by the bridged chain and delivered to this chain). Message delivery confirmation
+	/// transactions are refunded from the account of the bridged chain (messages are sent by
+	/// this chain and delivered to the bridged chain).
+	fn rewards_account_owner(&self) -> RewardsAccountOwner {
+		match *self {
+			Self::ReceiveMessagesProof(..) => RewardsAccountOwner::ThisChain,
+			Self::ReceiveMessagesDeliveryProof(_) => RewardsAccountOwner::BridgedChain,
+		}
+	}
+}
+
 /// Pre-dispatch state of messages pallet.
 ///
 /// This struct is for pre-dispatch state of the pallet, not the expected post-dispatch state.
-/// That's because message delivery transaction may deliver some of messages that it brings.
-/// If this happens, we consider it "helpful" and refund its cost. If transaction fails to
-/// deliver at least one message, it is considered wrong and is not refunded.
+/// That's because message delivery transaction may deliver some of messages that it brings
+/// (and delivery confirmation transaction may confirm some of messages). If this happens, we
+/// consider it "helpful" and refund its cost. If transaction fails to deliver (confirm) at least
+/// one message, it is considered wrong and is not refunded.
 #[derive(Clone, Copy, PartialEq, RuntimeDebugNoBound)]
 pub struct MessagesState {
-	/// Best delivered message nonce.
+	/// Lane that the call is delivering messages (or confirmations) over.
+	pub lane: LaneId,
+	/// Best delivered message nonce for the inbound lane, or best confirmed message nonce
+	/// (`latest_received_nonce`) for the outbound lane.
 	pub best_nonce: MessageNonce,
 }
 
+/// Pre-dispatch states of additional (non-primary) inbound lanes of the messages proof.
+pub type AdditionalLanesMessagesState =
+	BoundedVec<MessagesState, ConstU32<{ MAX_LANES_IN_PROOF - 1 }>>;
+
 // without this typedef rustfmt fails with internal err
 type BalanceOf<R> =
 	<<R as TransactionPaymentConfig>::OnChargeTransaction as OnChargeTransaction<R>>::Balance;
 type CallOf<R> = <R as frame_system::Config>::RuntimeCall;
 
-impl<R, GI, PI, MI, BE, PID, LID, FEE> SignedExtension
-	for RefundRelayerForMessagesFromParachain<R, GI, PI, MI, BE, PID, LID, FEE>
+impl<R, FC, MI, BE, LIDS, FEE, PRI> RefundRelayerForMessages<R, FC, MI, BE, LIDS, FEE, PRI>
 where
 	R: 'static
 		+ Send
 		+ Sync
 		+ frame_system::Config
 		+ UtilityConfig<RuntimeCall = CallOf<R>>
-		+ GrandpaConfig<GI>
-		+ ParachainsConfig<PI, BridgesGrandpaPalletInstance = GI>
 		+ MessagesConfig<MI>
 		+ RelayersConfig,
-	GI: 'static + Send + Sync,
-	PI: 'static + Send + Sync,
+	FC: 'static + Send + Sync + FinalityCallExtractor<R>,
 	MI: 'static + Send + Sync,
 	BE: 'static
 		+ Send
 		+ Sync
 		+ Default
 		+ SignedExtension<AccountId = R::AccountId, Call = CallOf<R>>,
-	PID: 'static + Send + Sync + Get<u32>,
-	LID: 'static + Send + Sync + Get<LaneId>,
+	LIDS: 'static + Send + Sync + Get<&'static [LaneId]>,
 	FEE: 'static + Send + Sync + TransactionFeeCalculation<<R as RelayersConfig>::Reward>,
+	PRI: 'static + Send + Sync + Get<TransactionPriority>,
 	<R as frame_system::Config>::RuntimeCall:
 		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
 	CallOf<R>: IsSubType<CallableCallFor<UtilityPallet<R>, R>>
-		+ IsSubType<CallableCallFor<GrandpaPallet<R, GI>, R>>
-		+ IsSubType<CallableCallFor<ParachainsPallet<R, PI>, R>>
 		+ IsSubType<CallableCallFor<MessagesPallet<R, MI>, R>>,
-	<R as GrandpaConfig<GI>>::BridgedChain:
-		Chain<BlockNumber = RelayBlockNumber, Hash = RelayBlockHash, Hasher = RelayBlockHasher>,
-	<R as MessagesConfig<MI>>::SourceHeaderChain: SourceHeaderChain<
-		MessagesProof = FromBridgedChainMessagesProof<HashOf<BridgedChain<R, GI>>>,
+	<R as MessagesConfig<MI>>::SourceHeaderChain:
+		SourceHeaderChain<MessagesProof = FromBridgedChainMessagesProof<FC::BridgedHeaderHash>>,
+	<R as MessagesConfig<MI>>::TargetHeaderChain: TargetHeaderChain<
+		<R as MessagesConfig<MI>>::OutboundPayload,
+		<R as frame_system::Config>::AccountId,
+		MessagesDeliveryProof = FromBridgedChainMessagesDeliveryProof<FC::BridgedHeaderHash>,
 	>,
 {
-	const IDENTIFIER: &'static str = "RefundRelayerForMessagesFromParachain";
+	/// Returns type of the bridge transaction, or `None` if the transaction is not supported by
+	/// the extension.
+	fn parse_call_type(call: &CallOf<R>) -> Option<CallType<FC::ExpectedState>> {
+		if let Some(UtilityCall::<R>::batch_all { ref calls }) = call.is_sub_type() {
+			let (messages_call, finality_calls) = calls.split_last()?;
+			return Some(CallType::FinalityAndMsgs(
+				FC::extract_expected_state(finality_calls)?,
+				extract_messages_call_info::<R, FC::BridgedHeaderHash, MI, LIDS>(messages_call)?,
+			))
+		}
+
+		Some(CallType::Msgs(extract_messages_call_info::<R, FC::BridgedHeaderHash, MI, LIDS>(
+			call,
+		)?))
+	}
+
+	/// Returns true if the transaction has updated all bridge pallets state, that it was
+	/// expected to update.
+	///
+	/// The transaction is considered unhelpful if it has failed, if its finality calls haven't
+	/// updated the state of corresponding pallets, or if it hasn't delivered any new messages.
+	fn is_helpful_transaction(
+		call_type: &CallType<FC::ExpectedState>,
+		result: &DispatchResult,
+	) -> bool {
+		// failed transaction is never helpful
+		if result.is_err() {
+			return false
+		}
+
+		// check if finality pallets state has been updated
+		if let CallType::FinalityAndMsgs(ref expected_state, _) = *call_type {
+			if !FC::is_expected_state_reached(expected_state) {
+				return false
+			}
+
+			// there's a conflict between how bridge GRANDPA pallet works and the batch
+			// transaction. If relay chain header is mandatory, the GRANDPA pallet returns
+			// `Pays::No`, because such transaction is mandatory for operating the bridge. But
+			// `utility.batchAll` transaction always requires payment. But in both cases we'll
+			// refund relayer - either explicitly here, or using `Pays::No` if he's choosing to
+			// submit dedicated transaction.
+		}
+
+		// check if messages have been delivered (or confirmed)
+		if !is_messages_call_helpful::<R, MI>(call_type.messages_call_info()) {
+			return false
+		}
+
+		true
+	}
+}
+
+impl<R, FC, MI, BE, LIDS, FEE, PRI> SignedExtension
+	for RefundRelayerForMessages<R, FC, MI, BE, LIDS, FEE, PRI>
+where
+	R: 'static
+		+ Send
+		+ Sync
+		+ frame_system::Config
+		+ UtilityConfig<RuntimeCall = CallOf<R>>
+		+ MessagesConfig<MI>
+		+ RelayersConfig,
+	FC: 'static + Send + Sync + FinalityCallExtractor<R>,
+	MI: 'static + Send + Sync,
+	BE: 'static
+		+ Send
+		+ Sync
+		+ Default
+		+ SignedExtension<AccountId = R::AccountId, Call = CallOf<R>>,
+	LIDS: 'static + Send + Sync + Get<&'static [LaneId]>,
+	FEE: 'static + Send + Sync + TransactionFeeCalculation<<R as RelayersConfig>::Reward>,
+	PRI: 'static + Send + Sync + Get<TransactionPriority>,
+	<R as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	CallOf<R>: IsSubType<CallableCallFor<UtilityPallet<R>, R>>
+		+ IsSubType<CallableCallFor<MessagesPallet<R, MI>, R>>,
+	<R as MessagesConfig<MI>>::SourceHeaderChain:
+		SourceHeaderChain<MessagesProof = FromBridgedChainMessagesProof<FC::BridgedHeaderHash>>,
+	<R as MessagesConfig<MI>>::TargetHeaderChain: TargetHeaderChain<
+		<R as MessagesConfig<MI>>::OutboundPayload,
+		<R as frame_system::Config>::AccountId,
+		MessagesDeliveryProof = FromBridgedChainMessagesDeliveryProof<FC::BridgedHeaderHash>,
+	>,
+{
+	const IDENTIFIER: &'static str = FC::IDENTIFIER;
 	type AccountId = R::AccountId;
 	type Call = CallOf<R>;
 	type AdditionalSigned = ();
-	type Pre = Option<PreDispatchData<R::AccountId>>;
+	type Pre = Option<PreDispatchData<R::AccountId, FC::ExpectedState>>;
 
 	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
 		Ok(())
@@ -228,7 +508,12 @@ where
 			}
 		}
 
-		Ok(ValidTransaction::default())
+		// boost priority of message delivery transactions of registered relayers
+		let priority = Self::parse_call_type(call)
+			.map(|call_type| priority_boost::<R, PRI>(who, call_type.messages_call_info()))
+			.unwrap_or(0);
+
+		Ok(ValidTransaction { priority, ..Default::default() })
 	}
 
 	fn pre_dispatch(
@@ -242,39 +527,16 @@ where
 		self.validate(who, call, info, len).map(drop)?;
 
 		// now try to check if tx matches one of types we support
-		let parse_call_type = || {
-			if let Some(UtilityCall::<R>::batch_all { ref calls }) = call.is_sub_type() {
-				if calls.len() == 3 {
-					return Some(CallType::AllFinalityAndDelivery(
-						extract_expected_relay_chain_state::<R, GI>(&calls[0])?,
-						extract_expected_parachain_state::<R, GI, PI, PID>(&calls[1])?,
-						extract_messages_state::<R, GI, MI, LID>(&calls[2])?,
-					))
-				}
-				if calls.len() == 2 {
-					return Some(CallType::ParachainFinalityAndDelivery(
-						extract_expected_parachain_state::<R, GI, PI, PID>(&calls[0])?,
-						extract_messages_state::<R, GI, MI, LID>(&calls[1])?,
-					))
-				}
-				return None
-			}
-
-			Some(CallType::Delivery(extract_messages_state::<R, GI, MI, LID>(call)?))
-		};
-
-		Ok(parse_call_type()
-			.map(|call_type| {
-				log::trace!(
-					target: "runtime::bridge",
-					"RefundRelayerForMessagesFromParachain from parachain {} via {:?} parsed bridge transaction in pre-dispatch: {:?}",
-					PID::get(),
-					LID::get(),
-					call_type,
-				);
-				PreDispatchData { relayer: who.clone(), call_type }
-			})
-		)
+		Ok(Self::parse_call_type(call).map(|call_type| {
+			log::trace!(
+				target: "runtime::bridge",
+				"{} via {:?} parsed bridge transaction in pre-dispatch: {:?}",
+				FC::IDENTIFIER,
+				call_type.messages_call_info().messages_state().lane,
+				call_type,
+			);
+			PreDispatchData { relayer: who.clone(), call_type }
+		}))
 	}
 
 	fn post_dispatch(
@@ -291,86 +553,125 @@ where
 			_ => return Ok(()),
 		};
 
-		// we never refund anything if transaction has failed
-		if result.is_err() {
-			return Ok(())
-		}
+		// refund or slash the relayer
+		let is_helpful_transaction = Self::is_helpful_transaction(&call_type, result);
+		let messages_call_info = call_type.messages_call_info();
+		let lane_id = messages_call_info.messages_state().lane;
+		let reward = match refund_or_slash_relayer::<R, MI, FEE>(
+			&relayer,
+			messages_call_info,
+			is_helpful_transaction,
+			info,
+			post_info,
+			len,
+		) {
+			Some(reward) => reward,
+			None => return Ok(()),
+		};
 
-		// check if relay chain state has been updated
-		if let CallType::AllFinalityAndDelivery(expected_relay_chain_state, _, _) = call_type {
-			let actual_relay_chain_state = relay_chain_state::<R, GI>();
-			if actual_relay_chain_state != Some(expected_relay_chain_state) {
-				// we only refund relayer if all calls have updated chain state
-				return Ok(())
-			}
+		log::trace!(
+			target: "runtime::bridge",
+			"{} via {:?} has registered {:?} reward: {:?}",
+			FC::IDENTIFIER,
+			lane_id,
+			relayer,
+			reward,
+		);
 
-			// there's a conflict between how bridge GRANDPA pallet works and the
-			// `AllFinalityAndDelivery` transaction. If relay chain header is mandatory, the GRANDPA
-			// pallet returns `Pays::No`, because such transaction is mandatory for operating the
-			// bridge. But `utility.batchAll` transaction always requires payment. But in both cases
-			// we'll refund relayer - either explicitly here, or using `Pays::No` if he's choosing
-			// to submit dedicated transaction.
-		}
+		Ok(())
+	}
+}
 
-		// check if parachain state has been updated
-		match call_type {
-			CallType::AllFinalityAndDelivery(_, expected_parachain_state, _) |
-			CallType::ParachainFinalityAndDelivery(expected_parachain_state, _) => {
-				let actual_parachain_state = parachain_state::<R, PI, PID>();
-				if actual_parachain_state != Some(expected_parachain_state) {
-					// we only refund relayer if all calls have updated chain state
-					return Ok(())
-				}
-			},
-			_ => (),
+/// Refunds relayer for helpful bridge transaction, or slashes registered relayer if the
+/// transaction was not helpful.
+///
+/// Returns registered relayer reward if the relayer has been refunded.
+fn refund_or_slash_relayer<R, MI, FEE>(
+	relayer: &R::AccountId,
+	messages_call_info: &MessagesCallInfo,
+	is_helpful_transaction: bool,
+	info: &DispatchInfo,
+	post_info: &PostDispatchInfo,
+	len: usize,
+) -> Option<<R as RelayersConfig>::Reward>
+where
+	R: MessagesConfig<MI> + RelayersConfig,
+	MI: 'static,
+	FEE: TransactionFeeCalculation<<R as RelayersConfig>::Reward>,
+{
+	// rewards are paid from the account, associated with the bridged chain and the lane. The
+	// account owner depends on the direction of messages that the call is dealing with
+	let rewards_account_params = RewardsAccountParams::new(
+		messages_call_info.messages_state().lane,
+		<R as MessagesConfig<MI>>::BridgedChainId::get(),
+		messages_call_info.rewards_account_owner(),
+	);
+
+	// if the transaction hasn't done anything useful, we slash registered relayer. Slashed funds
+	// are sent to the rewards account of the lane, unless the relayers pallet is configured to
+	// send them elsewhere
+	if !is_helpful_transaction {
+		if RelayersPallet::<R>::is_registration_active(relayer) {
+			RelayersPallet::<R>::slash_and_deregister(relayer, rewards_account_params);
 		}
 
-		// check if messages have been delivered
-		let actual_messages_state = messages_state::<R, MI, LID>();
-		let pre_dispatch_messages_state = call_type.pre_dispatch_messages_state();
-		if actual_messages_state == Some(pre_dispatch_messages_state) {
-			// we only refund relayer if all calls have updated chain state
-			return Ok(())
-		}
+		return None
+	}
 
-		// regarding the tip - refund that happens here (at this side of the bridge) isn't the whole
-		// relayer compensation. He'll receive some amount at the other side of the bridge. It shall
-		// (in theory) cover the tip here. Otherwise, if we'll be compensating tip here, some
-		// malicious relayer may use huge tips, effectively depleting account that pay rewards. The
-		// cost of this attack is nothing. Hence we use zero as tip here.
-		let tip = Zero::zero();
+	// regarding the tip - refund that happens here (at this side of the bridge) isn't the whole
+	// relayer compensation. He'll receive some amount at the other side of the bridge. It shall
+	// (in theory) cover the tip here. Otherwise, if we'll be compensating tip here, some
+	// malicious relayer may use huge tips, effectively depleting account that pay rewards. The
+	// cost of this attack is nothing. Hence we use zero as tip here.
+	let tip = Zero::zero();
 
-		// compute the relayer reward
-		let reward = FEE::compute_fee(info, post_info, len, tip);
+	// compute the relayer reward
+	let reward = FEE::compute_fee(info, post_info, len, tip);
 
-		// finally - register reward in relayers pallet
-		RelayersPallet::<R>::register_relayer_reward(LID::get(), &relayer, reward);
+	// finally - register reward in relayers pallet
+	RelayersPallet::<R>::register_relayer_reward(rewards_account_params, relayer, reward);
 
-		log::trace!(
-			target: "runtime::bridge",
-			"RefundRelayerForMessagesFromParachain from parachain {} via {:?} has registered {:?} reward: {:?}",
-			PID::get(),
-			LID::get(),
-			relayer,
-			reward,
-		);
+	Some(reward)
+}
 
-		Ok(())
+/// Returns priority boost for the message delivery transaction, submitted by the registered
+/// relayer.
+///
+/// The boost is proportional to the number of new messages that the transaction brings (see
+/// `extract_messages_call_info`). If relayer is not registered, or the transaction is not a
+/// message delivery transaction, the boost is zero.
+fn priority_boost<R, PRI>(
+	relayer: &R::AccountId,
+	messages_call_info: &MessagesCallInfo,
+) -> TransactionPriority
+where
+	R: RelayersConfig,
+	PRI: Get<TransactionPriority>,
+{
+	if !RelayersPallet::<R>::is_registration_active(relayer) {
+		return 0
+	}
+
+	match *messages_call_info {
+		MessagesCallInfo::ReceiveMessagesProof(_, _, new_messages) =>
+			compute_priority_boost::<PRI>(new_messages),
+		MessagesCallInfo::ReceiveMessagesDeliveryProof(_) => 0,
 	}
 }
 
-/// Extracts expected relay chain state from the call.
-fn extract_expected_relay_chain_state<R, GI>(call: &CallOf<R>) -> Option<ExpectedRelayChainState>
+/// Extracts expected GRANDPA chain state from the call.
+fn extract_expected_grandpa_chain_state<R, GI>(
+	call: &CallOf<R>,
+) -> Option<ExpectedGrandpaChainState<BridgedBlockNumber<R, GI>>>
 where
 	R: GrandpaConfig<GI>,
 	GI: 'static,
-	<R as GrandpaConfig<GI>>::BridgedChain: Chain<BlockNumber = RelayBlockNumber>,
 	CallOf<R>: IsSubType<CallableCallFor<GrandpaPallet<R, GI>, R>>,
 {
 	if let Some(GrandpaCall::<R, GI>::submit_finality_proof { ref finality_target, .. }) =
 		call.is_sub_type()
 	{
-		return Some(ExpectedRelayChainState { best_block_number: *finality_target.number() })
+		return Some(ExpectedGrandpaChainState { best_block_number: *finality_target.number() })
 	}
 	None
 }
@@ -403,42 +704,90 @@ where
 	None
 }
 
-/// Extracts messages state from the call.
-fn extract_messages_state<R, GI, MI, LID>(call: &CallOf<R>) -> Option<MessagesState>
+/// Extracts messages call info from the call.
+///
+/// If the messages proof covers several lanes, all of them must be in the `LIDS` set. The state
+/// of every lane is tracked, so relayer is refunded only if messages of all lanes are delivered.
+///
+/// The number of new messages of the delivery call is the sum of new messages over all lanes
+/// of the proof. It may not exceed the number of messages, declared by the relayer
+/// (`messages_count`), and the number of new messages at every lane may not exceed the
+/// `MaxUnconfirmedMessagesAtInboundLane` limit.
+fn extract_messages_call_info<R, H, MI, LIDS>(call: &CallOf<R>) -> Option<MessagesCallInfo>
 where
-	R: GrandpaConfig<GI> + MessagesConfig<MI>,
-	GI: 'static,
+	R: MessagesConfig<MI>,
 	MI: 'static,
-	LID: Get<LaneId>,
+	LIDS: Get<&'static [LaneId]>,
 	CallOf<R>: IsSubType<CallableCallFor<MessagesPallet<R, MI>, R>>,
-	<R as MessagesConfig<MI>>::SourceHeaderChain: SourceHeaderChain<
-		MessagesProof = FromBridgedChainMessagesProof<HashOf<BridgedChain<R, GI>>>,
+	<R as MessagesConfig<MI>>::SourceHeaderChain:
+		SourceHeaderChain<MessagesProof = FromBridgedChainMessagesProof<H>>,
+	<R as MessagesConfig<MI>>::TargetHeaderChain: TargetHeaderChain<
+		<R as MessagesConfig<MI>>::OutboundPayload,
+		<R as frame_system::Config>::AccountId,
+		MessagesDeliveryProof = FromBridgedChainMessagesDeliveryProof<H>,
 	>,
 {
-	if let Some(MessagesCall::<R, MI>::receive_messages_proof { ref proof, .. }) =
+	if let Some(MessagesCall::<R, MI>::receive_messages_proof {
+		ref proof,
+		ref messages_count,
+		..
+	}) = call.is_sub_type()
+	{
+		if !proof.lanes().all(|lane_messages| LIDS::get().contains(&lane_messages.lane)) {
+			return None
+		}
+
+		let max_unconfirmed_messages =
+			<R as MessagesConfig<MI>>::MaxUnconfirmedMessagesAtInboundLane::get();
+		let mut new_messages: MessageNonce = 0;
+		let mut lane_state = |lane_messages: FromBridgedChainLaneMessages| {
+			let best_nonce = MessagesPallet::<R, MI>::inbound_lane_data(lane_messages.lane)
+				.last_delivered_nonce();
+			new_messages = new_messages.saturating_add(
+				lane_messages
+					.nonces_end
+					.saturating_sub(best_nonce)
+					.min(max_unconfirmed_messages),
+			);
+			MessagesState { lane: lane_messages.lane, best_nonce }
+		};
+
+		let mut lanes = proof.lanes();
+		let messages_state = lane_state(lanes.next()?);
+		let additional_lanes_state =
+			AdditionalLanesMessagesState::try_from(lanes.map(&mut lane_state).collect::<Vec<_>>())
+				.ok()?;
+		return Some(MessagesCallInfo::ReceiveMessagesProof(
+			messages_state,
+			additional_lanes_state,
+			new_messages.min(MessageNonce::from(*messages_count)),
+		))
+	}
+
+	if let Some(MessagesCall::<R, MI>::receive_messages_delivery_proof { ref proof, .. }) =
 		call.is_sub_type()
 	{
-		if LID::get() != proof.lane {
+		if !LIDS::get().contains(&proof.lane) {
 			return None
 		}
 
-		return Some(MessagesState {
-			best_nonce: MessagesPallet::<R, MI>::inbound_lane_data(proof.lane)
-				.last_delivered_nonce(),
-		})
+		return Some(MessagesCallInfo::ReceiveMessagesDeliveryProof(MessagesState {
+			lane: proof.lane,
+			best_nonce: OutboundLanes::<R, MI>::get(proof.lane).latest_received_nonce,
+		}))
 	}
+
 	None
 }
 
-/// Returns relay chain state that we are interested in.
-fn relay_chain_state<R, GI>() -> Option<ExpectedRelayChainState>
+/// Returns GRANDPA chain state that we are interested in.
+fn grandpa_chain_state<R, GI>() -> Option<ExpectedGrandpaChainState<BridgedBlockNumber<R, GI>>>
 where
 	R: GrandpaConfig<GI>,
 	GI: 'static,
-	<R as GrandpaConfig<GI>>::BridgedChain: Chain<BlockNumber = RelayBlockNumber>,
 {
 	GrandpaPallet::<R, GI>::best_finalized_number()
-		.map(|best_block_number| ExpectedRelayChainState { best_block_number })
+		.map(|best_block_number| ExpectedGrandpaChainState { best_block_number })
 }
 
 /// Returns parachain state that we are interested in.
@@ -455,35 +804,60 @@ where
 	})
 }
 
-/// Returns messages state that we are interested in.
-fn messages_state<R, MI, LID>() -> Option<MessagesState>
+/// Returns true if the messages call has delivered (or confirmed) at least one new message.
+///
+/// The delivery call must deliver at least one new message over every lane of the proof.
+///
+/// Must be called after the call is dispatched.
+fn is_messages_call_helpful<R, MI>(messages_call_info: &MessagesCallInfo) -> bool
 where
 	R: MessagesConfig<MI>,
 	MI: 'static,
-	LID: Get<LaneId>,
 {
-	Some(MessagesState {
-		best_nonce: MessagesPallet::<R, MI>::inbound_lane_data(LID::get()).last_delivered_nonce(),
-	})
+	match *messages_call_info {
+		MessagesCallInfo::ReceiveMessagesProof(
+			pre_dispatch_state,
+			ref additional_lanes_pre_dispatch_state,
+			_,
+		) => sp_std::iter::once(&pre_dispatch_state)
+			.chain(additional_lanes_pre_dispatch_state.iter())
+			.all(|pre_dispatch_state| {
+				let best_nonce =
+					MessagesPallet::<R, MI>::inbound_lane_data(pre_dispatch_state.lane)
+						.last_delivered_nonce();
+				best_nonce > pre_dispatch_state.best_nonce
+			}),
+		MessagesCallInfo::ReceiveMessagesDeliveryProof(pre_dispatch_state) => {
+			let best_nonce =
+				OutboundLanes::<R, MI>::get(pre_dispatch_state.lane).latest_received_nonce;
+			best_nonce > pre_dispatch_state.best_nonce
+		},
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::{messages::target::FromBridgedChainMessagesProof, mock::*};
-	use bp_messages::InboundLaneData;
+	use crate::mock::*;
+	use bp_messages::{InboundLaneData, OutboundLaneData, UnrewardedRelayersState};
 	use bp_parachains::{BestParaHeadHash, ParaInfo};
 	use bp_polkadot_core::parachains::ParaHeadsProof;
 	use bp_runtime::HeaderId;
 	use bp_test_utils::make_default_justification;
-	use frame_support::{assert_storage_noop, parameter_types, weights::Weight};
+	use frame_support::{
+		assert_ok, assert_storage_noop, parameter_types, traits::fungible::Mutate, weights::Weight,
+	};
 	use sp_runtime::{transaction_validity::InvalidTransaction, DispatchError};
 
 	parameter_types! {
 		pub TestParachain: u32 = 1000;
 		pub TestLaneId: LaneId = TEST_LANE_ID;
+		pub TestLaneIds: &'static [LaneId] = &[TEST_LANE_ID, TEST_LANE_ID_2];
+		pub TestPriorityBoostPerMessage: TransactionPriority = 1_000;
 	}
 
+	const TEST_LANE_ID_2: LaneId = LaneId([1, 2, 3, 4]);
+
 	type TestExtension = RefundRelayerForMessagesFromParachain<
 		TestRuntime,
 		(),
@@ -491,8 +865,19 @@ mod tests {
 		(),
 		BridgeRejectObsoleteHeadersAndMessages,
 		TestParachain,
-		TestLaneId,
+		TestLaneIds,
+		TestRuntime,
+		TestPriorityBoostPerMessage,
+	>;
+
+	type TestGrandpaExtension = RefundRelayerForMessagesFromGrandpaChain<
+		TestRuntime,
+		(),
+		(),
+		BridgeRejectObsoleteHeadersAndMessages,
+		TestLaneIds,
 		TestRuntime,
+		TestPriorityBoostPerMessage,
 	>;
 
 	fn relayer_account_at_this_chain() -> ThisChainAccountId {
@@ -525,6 +910,12 @@ mod tests {
 		let lane_data =
 			InboundLaneData { last_confirmed_nonce: best_delivered_message, ..Default::default() };
 		pallet_bridge_messages::InboundLanes::<TestRuntime>::insert(lane_id, lane_data);
+
+		let outbound_lane_data = OutboundLaneData {
+			latest_received_nonce: best_delivered_message,
+			..Default::default()
+		};
+		pallet_bridge_messages::OutboundLanes::<TestRuntime>::insert(lane_id, outbound_lane_data);
 	}
 
 	fn submit_relay_header_call(relay_header_number: RelayBlockNumber) -> RuntimeCall {
@@ -554,20 +945,71 @@ mod tests {
 	}
 
 	fn message_delivery_call(best_message: MessageNonce) -> RuntimeCall {
+		message_delivery_call_at_lane(TestLaneId::get(), best_message)
+	}
+
+	fn message_delivery_call_at_lane(lane: LaneId, best_message: MessageNonce) -> RuntimeCall {
+		message_delivery_call_with_count(lane, best_message, 1)
+	}
+
+	fn message_delivery_call_with_count(
+		lane: LaneId,
+		best_message: MessageNonce,
+		messages_count: u32,
+	) -> RuntimeCall {
 		RuntimeCall::BridgeMessages(MessagesCall::receive_messages_proof {
 			relayer_id_at_bridged_chain: relayer_account_at_bridged_chain(),
 			proof: FromBridgedChainMessagesProof {
 				bridged_header_hash: Default::default(),
 				storage_proof: vec![],
-				lane: TestLaneId::get(),
+				lane,
 				nonces_start: best_message,
 				nonces_end: best_message,
+				additional_lanes: Default::default(),
 			},
-			messages_count: 1,
+			messages_count,
 			dispatch_weight: Weight::zero(),
 		})
 	}
 
+	fn multi_lane_message_delivery_call(
+		best_message: MessageNonce,
+		additional_lane: LaneId,
+		additional_lane_best_message: MessageNonce,
+		messages_count: u32,
+	) -> RuntimeCall {
+		let mut call =
+			message_delivery_call_with_count(TestLaneId::get(), best_message, messages_count);
+		if let RuntimeCall::BridgeMessages(MessagesCall::receive_messages_proof {
+			ref mut proof,
+			..
+		}) = call
+		{
+			proof.additional_lanes = vec![FromBridgedChainLaneMessages {
+				lane: additional_lane,
+				nonces_start: additional_lane_best_message,
+				nonces_end: additional_lane_best_message,
+			}]
+			.try_into()
+			.expect("single additional lane fits the proof; qed");
+		}
+		call
+	}
+
+	fn message_confirmation_call(best_message: MessageNonce) -> RuntimeCall {
+		RuntimeCall::BridgeMessages(MessagesCall::receive_messages_delivery_proof {
+			proof: FromBridgedChainMessagesDeliveryProof {
+				bridged_header_hash: Default::default(),
+				storage_proof: vec![],
+				lane: TestLaneId::get(),
+			},
+			relayers_state: UnrewardedRelayersState {
+				last_delivered_nonce: best_message,
+				..Default::default()
+			},
+		})
+	}
+
 	fn parachain_finality_and_delivery_batch_call(
 		parachain_head_at_relay_header_number: RelayBlockNumber,
 		best_message: MessageNonce,
@@ -580,6 +1022,18 @@ mod tests {
 		})
 	}
 
+	fn parachain_finality_and_confirmation_batch_call(
+		parachain_head_at_relay_header_number: RelayBlockNumber,
+		best_message: MessageNonce,
+	) -> RuntimeCall {
+		RuntimeCall::Utility(UtilityCall::batch_all {
+			calls: vec![
+				submit_parachain_head_call(parachain_head_at_relay_header_number),
+				message_confirmation_call(best_message),
+			],
+		})
+	}
+
 	fn all_finality_and_delivery_batch_call(
 		relay_header_number: RelayBlockNumber,
 		parachain_head_at_relay_header_number: RelayBlockNumber,
@@ -594,47 +1048,258 @@ mod tests {
 		})
 	}
 
-	fn all_finality_pre_dispatch_data() -> PreDispatchData<ThisChainAccountId> {
+	fn all_finality_and_confirmation_batch_call(
+		relay_header_number: RelayBlockNumber,
+		parachain_head_at_relay_header_number: RelayBlockNumber,
+		best_message: MessageNonce,
+	) -> RuntimeCall {
+		RuntimeCall::Utility(UtilityCall::batch_all {
+			calls: vec![
+				submit_relay_header_call(relay_header_number),
+				submit_parachain_head_call(parachain_head_at_relay_header_number),
+				message_confirmation_call(best_message),
+			],
+		})
+	}
+
+	fn grandpa_finality_and_delivery_batch_call(
+		relay_header_number: RelayBlockNumber,
+		best_message: MessageNonce,
+	) -> RuntimeCall {
+		RuntimeCall::Utility(UtilityCall::batch_all {
+			calls: vec![
+				submit_relay_header_call(relay_header_number),
+				message_delivery_call(best_message),
+			],
+		})
+	}
+
+	fn grandpa_finality_and_confirmation_batch_call(
+		relay_header_number: RelayBlockNumber,
+		best_message: MessageNonce,
+	) -> RuntimeCall {
+		RuntimeCall::Utility(UtilityCall::batch_all {
+			calls: vec![
+				submit_relay_header_call(relay_header_number),
+				message_confirmation_call(best_message),
+			],
+		})
+	}
+
+	fn all_finality_pre_dispatch_data(
+	) -> PreDispatchData<ThisChainAccountId, ExpectedParachainFinalityState> {
 		PreDispatchData {
 			relayer: relayer_account_at_this_chain(),
-			call_type: CallType::AllFinalityAndDelivery(
-				ExpectedRelayChainState { best_block_number: 200 },
-				ExpectedParachainState { at_relay_block_number: 200 },
-				MessagesState { best_nonce: 100 },
+			call_type: CallType::FinalityAndMsgs(
+				ExpectedParachainFinalityState {
+					relay_chain: Some(ExpectedRelayChainState { best_block_number: 200 }),
+					parachain: ExpectedParachainState { at_relay_block_number: 200 },
+				},
+				MessagesCallInfo::ReceiveMessagesProof(
+					MessagesState { lane: TestLaneId::get(), best_nonce: 100 },
+					Default::default(),
+					1,
+				),
 			),
 		}
 	}
 
-	fn parachain_finality_pre_dispatch_data() -> PreDispatchData<ThisChainAccountId> {
+	fn all_finality_confirmation_pre_dispatch_data(
+	) -> PreDispatchData<ThisChainAccountId, ExpectedParachainFinalityState> {
 		PreDispatchData {
 			relayer: relayer_account_at_this_chain(),
-			call_type: CallType::ParachainFinalityAndDelivery(
-				ExpectedParachainState { at_relay_block_number: 200 },
-				MessagesState { best_nonce: 100 },
+			call_type: CallType::FinalityAndMsgs(
+				ExpectedParachainFinalityState {
+					relay_chain: Some(ExpectedRelayChainState { best_block_number: 200 }),
+					parachain: ExpectedParachainState { at_relay_block_number: 200 },
+				},
+				MessagesCallInfo::ReceiveMessagesDeliveryProof(MessagesState {
+					lane: TestLaneId::get(),
+					best_nonce: 100,
+				}),
 			),
 		}
 	}
 
-	fn delivery_pre_dispatch_data() -> PreDispatchData<ThisChainAccountId> {
+	fn parachain_finality_pre_dispatch_data(
+	) -> PreDispatchData<ThisChainAccountId, ExpectedParachainFinalityState> {
 		PreDispatchData {
 			relayer: relayer_account_at_this_chain(),
-			call_type: CallType::Delivery(MessagesState { best_nonce: 100 }),
+			call_type: CallType::FinalityAndMsgs(
+				ExpectedParachainFinalityState {
+					relay_chain: None,
+					parachain: ExpectedParachainState { at_relay_block_number: 200 },
+				},
+				MessagesCallInfo::ReceiveMessagesProof(
+					MessagesState { lane: TestLaneId::get(), best_nonce: 100 },
+					Default::default(),
+					1,
+				),
+			),
 		}
 	}
 
-	fn run_test(test: impl FnOnce()) {
-		sp_io::TestExternalities::new(Default::default()).execute_with(test)
+	fn parachain_finality_confirmation_pre_dispatch_data(
+	) -> PreDispatchData<ThisChainAccountId, ExpectedParachainFinalityState> {
+		PreDispatchData {
+			relayer: relayer_account_at_this_chain(),
+			call_type: CallType::FinalityAndMsgs(
+				ExpectedParachainFinalityState {
+					relay_chain: None,
+					parachain: ExpectedParachainState { at_relay_block_number: 200 },
+				},
+				MessagesCallInfo::ReceiveMessagesDeliveryProof(MessagesState {
+					lane: TestLaneId::get(),
+					best_nonce: 100,
+				}),
+			),
+		}
+	}
+
+	fn delivery_pre_dispatch_data(
+	) -> PreDispatchData<ThisChainAccountId, ExpectedParachainFinalityState> {
+		PreDispatchData {
+			relayer: relayer_account_at_this_chain(),
+			call_type: CallType::Msgs(MessagesCallInfo::ReceiveMessagesProof(
+				MessagesState { lane: TestLaneId::get(), best_nonce: 100 },
+				Default::default(),
+				1,
+			)),
+		}
+	}
+
+	fn multi_lane_delivery_pre_dispatch_data(
+	) -> PreDispatchData<ThisChainAccountId, ExpectedParachainFinalityState> {
+		PreDispatchData {
+			relayer: relayer_account_at_this_chain(),
+			call_type: CallType::Msgs(MessagesCallInfo::ReceiveMessagesProof(
+				MessagesState { lane: TestLaneId::get(), best_nonce: 100 },
+				vec![MessagesState { lane: TEST_LANE_ID_2, best_nonce: 0 }].try_into().unwrap(),
+				1,
+			)),
+		}
+	}
+
+	fn confirmation_pre_dispatch_data(
+	) -> PreDispatchData<ThisChainAccountId, ExpectedParachainFinalityState> {
+		PreDispatchData {
+			relayer: relayer_account_at_this_chain(),
+			call_type: CallType::Msgs(MessagesCallInfo::ReceiveMessagesDeliveryProof(
+				MessagesState { lane: TestLaneId::get(), best_nonce: 100 },
+			)),
+		}
+	}
+
+	fn grandpa_finality_pre_dispatch_data(
+	) -> PreDispatchData<ThisChainAccountId, ExpectedRelayChainState> {
+		PreDispatchData {
+			relayer: relayer_account_at_this_chain(),
+			call_type: CallType::FinalityAndMsgs(
+				ExpectedGrandpaChainState { best_block_number: 200 },
+				MessagesCallInfo::ReceiveMessagesProof(
+					MessagesState { lane: TestLaneId::get(), best_nonce: 100 },
+					Default::default(),
+					1,
+				),
+			),
+		}
+	}
+
+	fn grandpa_finality_confirmation_pre_dispatch_data(
+	) -> PreDispatchData<ThisChainAccountId, ExpectedRelayChainState> {
+		PreDispatchData {
+			relayer: relayer_account_at_this_chain(),
+			call_type: CallType::FinalityAndMsgs(
+				ExpectedGrandpaChainState { best_block_number: 200 },
+				MessagesCallInfo::ReceiveMessagesDeliveryProof(MessagesState {
+					lane: TestLaneId::get(),
+					best_nonce: 100,
+				}),
+			),
+		}
+	}
+
+	fn grandpa_delivery_pre_dispatch_data(
+	) -> PreDispatchData<ThisChainAccountId, ExpectedRelayChainState> {
+		PreDispatchData {
+			relayer: relayer_account_at_this_chain(),
+			call_type: CallType::Msgs(MessagesCallInfo::ReceiveMessagesProof(
+				MessagesState { lane: TestLaneId::get(), best_nonce: 100 },
+				Default::default(),
+				1,
+			)),
+		}
+	}
+
+	fn run_test(test: impl FnOnce()) {
+		sp_io::TestExternalities::new(Default::default()).execute_with(test)
+	}
+
+	fn register_relayer() {
+		let relayer = relayer_account_at_this_chain();
+		Balances::mint_into(&relayer, ExistentialDeposit::get() + Stake::get()).unwrap();
+		assert_ok!(RelayersPallet::<TestRuntime>::register(RuntimeOrigin::signed(relayer), 1000));
+	}
+
+	fn test_rewards_account_params() -> RewardsAccountParams {
+		rewards_account_params_at_lane(TestLaneId::get())
+	}
+
+	fn rewards_account_params_at_lane(lane: LaneId) -> RewardsAccountParams {
+		RewardsAccountParams::new(lane, BridgedChainId::get(), RewardsAccountOwner::ThisChain)
+	}
+
+	fn confirmation_rewards_account_params() -> RewardsAccountParams {
+		RewardsAccountParams::new(
+			TestLaneId::get(),
+			BridgedChainId::get(),
+			RewardsAccountOwner::BridgedChain,
+		)
+	}
+
+	fn confirmation_rewards_account() -> ThisChainAccountId {
+		let account = bp_relayers::PayRewardFromAccount::<(), ThisChainAccountId>::rewards_account(
+			confirmation_rewards_account_params(),
+		);
+		Balances::mint_into(&account, ExistentialDeposit::get()).unwrap();
+		account
+	}
+
+	fn rewards_account() -> ThisChainAccountId {
+		let account = bp_relayers::PayRewardFromAccount::<(), ThisChainAccountId>::rewards_account(
+			test_rewards_account_params(),
+		);
+		Balances::mint_into(&account, ExistentialDeposit::get()).unwrap();
+		account
 	}
 
 	fn run_validate(call: RuntimeCall) -> TransactionValidity {
-		let extension: TestExtension = RefundRelayerForMessagesFromParachain(PhantomData);
+		let extension: TestExtension = RefundRelayerForMessages(PhantomData);
 		extension.validate(&relayer_account_at_this_chain(), &call, &DispatchInfo::default(), 0)
 	}
 
 	fn run_pre_dispatch(
 		call: RuntimeCall,
-	) -> Result<Option<PreDispatchData<ThisChainAccountId>>, TransactionValidityError> {
-		let extension: TestExtension = RefundRelayerForMessagesFromParachain(PhantomData);
+	) -> Result<
+		Option<PreDispatchData<ThisChainAccountId, ExpectedParachainFinalityState>>,
+		TransactionValidityError,
+	> {
+		let extension: TestExtension = RefundRelayerForMessages(PhantomData);
+		extension.pre_dispatch(&relayer_account_at_this_chain(), &call, &DispatchInfo::default(), 0)
+	}
+
+	fn run_grandpa_validate(call: RuntimeCall) -> TransactionValidity {
+		let extension: TestGrandpaExtension = RefundRelayerForMessages(PhantomData);
+		extension.validate(&relayer_account_at_this_chain(), &call, &DispatchInfo::default(), 0)
+	}
+
+	fn run_grandpa_pre_dispatch(
+		call: RuntimeCall,
+	) -> Result<
+		Option<PreDispatchData<ThisChainAccountId, ExpectedRelayChainState>>,
+		TransactionValidityError,
+	> {
+		let extension: TestGrandpaExtension = RefundRelayerForMessages(PhantomData);
 		extension.pre_dispatch(&relayer_account_at_this_chain(), &call, &DispatchInfo::default(), 0)
 	}
 
@@ -653,7 +1318,9 @@ mod tests {
 	}
 
 	fn run_post_dispatch(
-		pre_dispatch_data: Option<PreDispatchData<ThisChainAccountId>>,
+		pre_dispatch_data: Option<
+			PreDispatchData<ThisChainAccountId, ExpectedParachainFinalityState>,
+		>,
 		dispatch_result: DispatchResult,
 	) {
 		let post_dispatch_result = TestExtension::post_dispatch(
@@ -666,6 +1333,20 @@ mod tests {
 		assert_eq!(post_dispatch_result, Ok(()));
 	}
 
+	fn run_grandpa_post_dispatch(
+		pre_dispatch_data: Option<PreDispatchData<ThisChainAccountId, ExpectedRelayChainState>>,
+		dispatch_result: DispatchResult,
+	) {
+		let post_dispatch_result = TestGrandpaExtension::post_dispatch(
+			Some(pre_dispatch_data),
+			&dispatch_info(),
+			&post_dispatch_info(),
+			1024,
+			&dispatch_result,
+		);
+		assert_eq!(post_dispatch_result, Ok(()));
+	}
+
 	fn expected_reward() -> ThisChainBalance {
 		pallet_transaction_payment::Pallet::<TestRuntime>::compute_actual_fee(
 			1024,
@@ -694,6 +1375,101 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn validate_boosts_priority_of_registered_relayer_message_delivery() {
+		run_test(|| {
+			initialize_environment(100, 100, 100);
+			register_relayer();
+
+			// all calls are declaring single message
+			let expected_priority = TestPriorityBoostPerMessage::get();
+			assert_eq!(
+				run_validate(message_delivery_call(200)).map(|v| v.priority),
+				Ok(expected_priority),
+			);
+			assert_eq!(
+				run_validate(parachain_finality_and_delivery_batch_call(200, 200))
+					.map(|v| v.priority),
+				Ok(expected_priority),
+			);
+			assert_eq!(
+				run_validate(all_finality_and_delivery_batch_call(200, 200, 200))
+					.map(|v| v.priority),
+				Ok(expected_priority),
+			);
+			assert_eq!(
+				run_grandpa_validate(grandpa_finality_and_delivery_batch_call(200, 200))
+					.map(|v| v.priority),
+				Ok(expected_priority),
+			);
+
+			// confirmation transactions are not boosted
+			assert_eq!(run_validate(message_confirmation_call(200)).map(|v| v.priority), Ok(0));
+		});
+	}
+
+	#[test]
+	fn validate_limits_priority_boost() {
+		run_test(|| {
+			initialize_environment(100, 100, 100);
+			register_relayer();
+
+			// boost is limited by the number of messages, declared by the relayer
+			assert_eq!(
+				run_validate(message_delivery_call_with_count(TestLaneId::get(), 200, 10))
+					.map(|v| v.priority),
+				Ok(TestPriorityBoostPerMessage::get() * 10),
+			);
+			// boost is limited by the number of new messages in the proof
+			assert_eq!(
+				run_validate(message_delivery_call_with_count(TestLaneId::get(), 105, 10))
+					.map(|v| v.priority),
+				Ok(TestPriorityBoostPerMessage::get() * 5),
+			);
+			// boost is limited by the `MaxUnconfirmedMessagesAtInboundLane`
+			let max_messages =
+				<TestRuntime as MessagesConfig<()>>::MaxUnconfirmedMessagesAtInboundLane::get();
+			assert_eq!(
+				run_validate(message_delivery_call_with_count(TestLaneId::get(), 200, 100))
+					.map(|v| v.priority),
+				Ok(TestPriorityBoostPerMessage::get() * max_messages),
+			);
+		});
+	}
+
+	#[test]
+	fn validate_does_not_boost_priority_of_unsupported_transactions() {
+		run_test(|| {
+			initialize_environment(100, 100, 100);
+			register_relayer();
+
+			// delivery at unknown lane
+			assert_eq!(
+				run_validate(message_delivery_call_at_lane(LaneId([0, 0, 0, 42]), 200))
+					.map(|v| v.priority),
+				Ok(0),
+			);
+			// batch that is not recognized by the extension
+			assert_eq!(
+				run_grandpa_validate(RuntimeCall::Utility(UtilityCall::batch_all {
+					calls: vec![message_confirmation_call(200), message_delivery_call(200)],
+				}))
+				.map(|v| v.priority),
+				Ok(0),
+			);
+		});
+	}
+
+	#[test]
+	fn validate_does_not_boost_priority_of_unregistered_relayer() {
+		run_test(|| {
+			initialize_environment(100, 100, 100);
+
+			assert_eq!(run_validate(message_delivery_call(200)).map(|v| v.priority), Ok(0));
+			assert_eq!(run_grandpa_validate(message_delivery_call(200)).map(|v| v.priority), Ok(0),);
+		});
+	}
+
 	#[test]
 	fn ext_rejects_batch_with_obsolete_relay_chain_header() {
 		run_test(|| {
@@ -765,6 +1541,54 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn ext_rejects_batch_with_obsolete_beefy_commitment() {
+		run_test(|| {
+			initialize_environment(100, 100, 100);
+			pallet_bridge_beefy::ImportedCommitmentsInfo::<TestRuntime>::put(
+				bp_beefy::ImportedCommitmentsInfoData {
+					best_block_number: 100,
+					next_block_number_index: 0,
+				},
+			);
+
+			let beefy_commitment_claim_call = |block_number| {
+				RuntimeCall::BridgeBeefy(pallet_bridge_beefy::Call::submit_commitment_claim {
+					commitment: bp_beefy::Commitment {
+						payload: bp_beefy::BeefyPayload::from_single_entry(
+							bp_beefy::MMR_ROOT_PAYLOAD_ID,
+							vec![],
+						),
+						block_number,
+						validator_set_id: 0,
+					},
+					signers: vec![],
+				})
+			};
+
+			assert_eq!(
+				run_pre_dispatch(RuntimeCall::Utility(UtilityCall::batch_all {
+					calls: vec![beefy_commitment_claim_call(100), message_delivery_call(200)],
+				})),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Stale)),
+			);
+
+			assert_eq!(
+				run_validate(RuntimeCall::Utility(UtilityCall::batch_all {
+					calls: vec![beefy_commitment_claim_call(100), message_delivery_call(200)],
+				})),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Stale)),
+			);
+
+			assert_eq!(
+				run_validate(RuntimeCall::Utility(UtilityCall::batch_all {
+					calls: vec![beefy_commitment_claim_call(101), message_delivery_call(200)],
+				})),
+				Ok(ValidTransaction::default()),
+			);
+		});
+	}
+
 	#[test]
 	fn pre_dispatch_parses_batch_with_relay_chain_and_parachain_headers() {
 		run_test(|| {
@@ -824,6 +1648,178 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn pre_dispatch_parses_confirmation_transactions() {
+		run_test(|| {
+			initialize_environment(100, 100, 100);
+
+			assert_eq!(
+				run_pre_dispatch(all_finality_and_confirmation_batch_call(200, 200, 200)),
+				Ok(Some(all_finality_confirmation_pre_dispatch_data())),
+			);
+			assert_eq!(
+				run_pre_dispatch(parachain_finality_and_confirmation_batch_call(200, 200)),
+				Ok(Some(parachain_finality_confirmation_pre_dispatch_data())),
+			);
+			assert_eq!(
+				run_pre_dispatch(message_confirmation_call(200)),
+				Ok(Some(confirmation_pre_dispatch_data())),
+			);
+		});
+	}
+
+	#[test]
+	fn ext_rejects_obsolete_confirmations() {
+		run_test(|| {
+			initialize_environment(100, 100, 100);
+
+			assert_eq!(
+				run_validate(all_finality_and_confirmation_batch_call(200, 200, 100)),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Stale)),
+			);
+			assert_eq!(
+				run_pre_dispatch(parachain_finality_and_confirmation_batch_call(200, 100)),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Stale)),
+			);
+		});
+	}
+
+	#[test]
+	fn pre_dispatch_ignores_transactions_at_unknown_lanes() {
+		run_test(|| {
+			initialize_environment(100, 100, 100);
+
+			assert_eq!(
+				run_pre_dispatch(message_delivery_call_at_lane(LaneId([9, 9, 9, 9]), 200)),
+				Ok(None),
+			);
+			assert_eq!(
+				run_grandpa_pre_dispatch(message_delivery_call_at_lane(LaneId([9, 9, 9, 9]), 200)),
+				Ok(None),
+			);
+		});
+	}
+
+	#[test]
+	fn pre_dispatch_ignores_multi_lane_transactions_with_unknown_lanes() {
+		run_test(|| {
+			initialize_environment(100, 100, 100);
+
+			assert_eq!(
+				run_pre_dispatch(multi_lane_message_delivery_call(200, LaneId([9, 9, 9, 9]), 1, 1)),
+				Ok(None),
+			);
+			assert_eq!(
+				run_grandpa_pre_dispatch(multi_lane_message_delivery_call(
+					200,
+					LaneId([9, 9, 9, 9]),
+					1,
+					1
+				)),
+				Ok(None),
+			);
+			assert_eq!(
+				run_pre_dispatch(multi_lane_message_delivery_call(200, TEST_LANE_ID_2, 1, 1)),
+				Ok(Some(multi_lane_delivery_pre_dispatch_data())),
+			);
+		});
+	}
+
+	#[test]
+	fn validate_boosts_priority_by_new_messages_at_all_lanes() {
+		run_test(|| {
+			initialize_environment(100, 100, 100);
+			register_relayer();
+
+			// 5 new messages at the primary lane and 3 new messages at the additional lane
+			assert_eq!(
+				run_validate(multi_lane_message_delivery_call(105, TEST_LANE_ID_2, 3, 10))
+					.map(|v| v.priority),
+				Ok(TestPriorityBoostPerMessage::get() * 8),
+			);
+			// boost is still limited by the number of messages, declared by the relayer
+			assert_eq!(
+				run_validate(multi_lane_message_delivery_call(105, TEST_LANE_ID_2, 3, 6))
+					.map(|v| v.priority),
+				Ok(TestPriorityBoostPerMessage::get() * 6),
+			);
+		});
+	}
+
+	#[test]
+	fn post_dispatch_refunds_relayer_only_if_messages_at_all_lanes_are_delivered() {
+		run_test(|| {
+			initialize_environment(100, 100, 100);
+
+			// only messages at the primary lane are delivered => no refund
+			let lane_data = InboundLaneData { last_confirmed_nonce: 200, ..Default::default() };
+			pallet_bridge_messages::InboundLanes::<TestRuntime>::insert(
+				TestLaneId::get(),
+				lane_data,
+			);
+			run_post_dispatch(Some(multi_lane_delivery_pre_dispatch_data()), Ok(()));
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::relayer_reward(
+					relayer_account_at_this_chain(),
+					test_rewards_account_params(),
+				),
+				None,
+			);
+
+			// messages at both lanes are delivered => reward is registered at the primary lane
+			let lane_data = InboundLaneData { last_confirmed_nonce: 200, ..Default::default() };
+			pallet_bridge_messages::InboundLanes::<TestRuntime>::insert(TEST_LANE_ID_2, lane_data);
+			run_post_dispatch(Some(multi_lane_delivery_pre_dispatch_data()), Ok(()));
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::relayer_reward(
+					relayer_account_at_this_chain(),
+					test_rewards_account_params(),
+				),
+				Some(expected_reward()),
+			);
+		});
+	}
+
+	#[test]
+	fn post_dispatch_refunds_relayer_at_the_delivery_lane() {
+		run_test(|| {
+			initialize_environment(100, 100, 100);
+
+			let pre_dispatch_data =
+				run_pre_dispatch(message_delivery_call_at_lane(TEST_LANE_ID_2, 200)).unwrap();
+			assert_eq!(
+				pre_dispatch_data,
+				Some(PreDispatchData {
+					relayer: relayer_account_at_this_chain(),
+					call_type: CallType::Msgs(MessagesCallInfo::ReceiveMessagesProof(
+						MessagesState { lane: TEST_LANE_ID_2, best_nonce: 0 },
+						Default::default(),
+						1
+					)),
+				}),
+			);
+
+			let lane_data = InboundLaneData { last_confirmed_nonce: 200, ..Default::default() };
+			pallet_bridge_messages::InboundLanes::<TestRuntime>::insert(TEST_LANE_ID_2, lane_data);
+
+			run_post_dispatch(pre_dispatch_data, Ok(()));
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::relayer_reward(
+					relayer_account_at_this_chain(),
+					rewards_account_params_at_lane(TEST_LANE_ID_2),
+				),
+				Some(expected_reward()),
+			);
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::relayer_reward(
+					relayer_account_at_this_chain(),
+					test_rewards_account_params(),
+				),
+				None,
+			);
+		});
+	}
+
 	#[test]
 	fn post_dispatch_ignores_unknown_transaction() {
 		run_test(|| {
@@ -877,6 +1873,23 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn post_dispatch_ignores_transaction_that_has_not_confirmed_any_messages() {
+		run_test(|| {
+			initialize_environment(200, 200, 100);
+
+			assert_storage_noop!(run_post_dispatch(
+				Some(all_finality_confirmation_pre_dispatch_data()),
+				Ok(())
+			));
+			assert_storage_noop!(run_post_dispatch(
+				Some(parachain_finality_confirmation_pre_dispatch_data()),
+				Ok(())
+			));
+			assert_storage_noop!(run_post_dispatch(Some(confirmation_pre_dispatch_data()), Ok(())));
+		});
+	}
+
 	#[test]
 	fn post_dispatch_refunds_relayer_in_all_finality_batch() {
 		run_test(|| {
@@ -886,7 +1899,7 @@ mod tests {
 			assert_eq!(
 				RelayersPallet::<TestRuntime>::relayer_reward(
 					relayer_account_at_this_chain(),
-					TestLaneId::get()
+					test_rewards_account_params()
 				),
 				Some(expected_reward()),
 			);
@@ -902,7 +1915,7 @@ mod tests {
 			assert_eq!(
 				RelayersPallet::<TestRuntime>::relayer_reward(
 					relayer_account_at_this_chain(),
-					TestLaneId::get()
+					test_rewards_account_params()
 				),
 				Some(expected_reward()),
 			);
@@ -918,10 +1931,312 @@ mod tests {
 			assert_eq!(
 				RelayersPallet::<TestRuntime>::relayer_reward(
 					relayer_account_at_this_chain(),
-					TestLaneId::get()
+					test_rewards_account_params()
+				),
+				Some(expected_reward()),
+			);
+		});
+	}
+
+	#[test]
+	fn post_dispatch_refunds_relayer_in_confirmation_transactions() {
+		run_test(|| {
+			initialize_environment(200, 200, 200);
+
+			run_post_dispatch(Some(all_finality_confirmation_pre_dispatch_data()), Ok(()));
+			run_post_dispatch(Some(parachain_finality_confirmation_pre_dispatch_data()), Ok(()));
+			run_post_dispatch(Some(confirmation_pre_dispatch_data()), Ok(()));
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::relayer_reward(
+					relayer_account_at_this_chain(),
+					confirmation_rewards_account_params()
+				),
+				Some(expected_reward() * 3),
+			);
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::relayer_reward(
+					relayer_account_at_this_chain(),
+					test_rewards_account_params()
+				),
+				None,
+			);
+		});
+	}
+
+	#[test]
+	fn post_dispatch_slashes_registered_relayer_to_confirmation_rewards_account() {
+		run_test(|| {
+			initialize_environment(200, 200, 100);
+			register_relayer();
+			let delivery_rewards_account = rewards_account();
+			let confirmation_rewards_account = confirmation_rewards_account();
+
+			run_post_dispatch(Some(confirmation_pre_dispatch_data()), Ok(()));
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::registered_relayer(relayer_account_at_this_chain()),
+				None,
+			);
+			assert_eq!(
+				Balances::free_balance(confirmation_rewards_account),
+				ExistentialDeposit::get() + Stake::get(),
+			);
+			assert_eq!(Balances::free_balance(delivery_rewards_account), ExistentialDeposit::get());
+		});
+	}
+
+	#[test]
+	fn post_dispatch_slashes_registered_relayer_if_transaction_has_failed() {
+		run_test(|| {
+			initialize_environment(200, 200, 200);
+			register_relayer();
+			let rewards_account = rewards_account();
+
+			run_post_dispatch(
+				Some(all_finality_pre_dispatch_data()),
+				Err(DispatchError::BadOrigin),
+			);
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::registered_relayer(relayer_account_at_this_chain()),
+				None,
+			);
+			assert_eq!(Balances::reserved_balance(relayer_account_at_this_chain()), 0);
+			assert_eq!(
+				Balances::free_balance(rewards_account),
+				ExistentialDeposit::get() + Stake::get(),
+			);
+		});
+	}
+
+	#[test]
+	fn post_dispatch_slashes_registered_relayer_if_transaction_has_not_updated_parachain_state() {
+		run_test(|| {
+			initialize_environment(200, 100, 200);
+			register_relayer();
+			let rewards_account = rewards_account();
+
+			run_post_dispatch(Some(parachain_finality_pre_dispatch_data()), Ok(()));
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::registered_relayer(relayer_account_at_this_chain()),
+				None,
+			);
+			assert_eq!(
+				Balances::free_balance(rewards_account),
+				ExistentialDeposit::get() + Stake::get(),
+			);
+		});
+	}
+
+	#[test]
+	fn post_dispatch_slashes_registered_relayer_if_transaction_has_not_delivered_any_messages() {
+		run_test(|| {
+			initialize_environment(200, 200, 100);
+			register_relayer();
+			let rewards_account = rewards_account();
+
+			run_post_dispatch(Some(delivery_pre_dispatch_data()), Ok(()));
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::registered_relayer(relayer_account_at_this_chain()),
+				None,
+			);
+			assert_eq!(
+				Balances::free_balance(rewards_account),
+				ExistentialDeposit::get() + Stake::get(),
+			);
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::relayer_reward(
+					relayer_account_at_this_chain(),
+					test_rewards_account_params()
+				),
+				None,
+			);
+		});
+	}
+
+	#[test]
+	fn post_dispatch_does_not_slash_registered_relayer_if_transaction_is_helpful() {
+		run_test(|| {
+			initialize_environment(200, 200, 200);
+			register_relayer();
+
+			run_post_dispatch(Some(delivery_pre_dispatch_data()), Ok(()));
+			assert!(RelayersPallet::<TestRuntime>::registered_relayer(
+				relayer_account_at_this_chain()
+			)
+			.is_some());
+			assert_eq!(Balances::reserved_balance(relayer_account_at_this_chain()), Stake::get());
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::relayer_reward(
+					relayer_account_at_this_chain(),
+					test_rewards_account_params()
 				),
 				Some(expected_reward()),
 			);
 		});
 	}
+
+	#[test]
+	fn grandpa_ext_validate_rejects_obsolete_transactions() {
+		run_test(|| {
+			initialize_environment(100, 100, 100);
+
+			assert_eq!(
+				run_grandpa_validate(grandpa_finality_and_delivery_batch_call(200, 200)),
+				Ok(ValidTransaction::default()),
+			);
+
+			assert_eq!(
+				run_grandpa_validate(grandpa_finality_and_delivery_batch_call(100, 200)),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Stale)),
+			);
+
+			assert_eq!(
+				run_grandpa_pre_dispatch(grandpa_finality_and_delivery_batch_call(200, 100)),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Stale)),
+			);
+		});
+	}
+
+	#[test]
+	fn grandpa_ext_pre_dispatch_parses_supported_transactions() {
+		run_test(|| {
+			initialize_environment(100, 100, 100);
+
+			assert_eq!(
+				run_grandpa_pre_dispatch(grandpa_finality_and_delivery_batch_call(200, 200)),
+				Ok(Some(grandpa_finality_pre_dispatch_data())),
+			);
+
+			assert_eq!(
+				run_grandpa_pre_dispatch(message_delivery_call(200)),
+				Ok(Some(grandpa_delivery_pre_dispatch_data())),
+			);
+
+			// batches that are including parachain heads are not supported by this extension
+			assert_eq!(
+				run_grandpa_pre_dispatch(all_finality_and_delivery_batch_call(200, 200, 200)),
+				Ok(None),
+			);
+			assert_eq!(
+				run_grandpa_pre_dispatch(parachain_finality_and_delivery_batch_call(200, 200)),
+				Ok(None),
+			);
+		});
+	}
+
+	#[test]
+	fn grandpa_ext_pre_dispatch_parses_confirmation_transactions() {
+		run_test(|| {
+			initialize_environment(100, 100, 100);
+
+			assert_eq!(
+				run_grandpa_pre_dispatch(grandpa_finality_and_confirmation_batch_call(200, 200)),
+				Ok(Some(grandpa_finality_confirmation_pre_dispatch_data())),
+			);
+		});
+	}
+
+	#[test]
+	fn grandpa_ext_post_dispatch_refunds_relayer_only_if_messages_are_confirmed() {
+		run_test(|| {
+			initialize_environment(200, 100, 100);
+
+			assert_storage_noop!(run_grandpa_post_dispatch(
+				Some(grandpa_finality_confirmation_pre_dispatch_data()),
+				Ok(())
+			));
+		});
+
+		run_test(|| {
+			initialize_environment(200, 100, 200);
+
+			run_grandpa_post_dispatch(
+				Some(grandpa_finality_confirmation_pre_dispatch_data()),
+				Ok(()),
+			);
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::relayer_reward(
+					relayer_account_at_this_chain(),
+					confirmation_rewards_account_params()
+				),
+				Some(expected_reward()),
+			);
+		});
+	}
+
+	#[test]
+	fn grandpa_ext_post_dispatch_ignores_unhelpful_transactions() {
+		run_test(|| {
+			initialize_environment(100, 100, 200);
+
+			// GRANDPA chain state has not been updated
+			assert_storage_noop!(run_grandpa_post_dispatch(
+				Some(grandpa_finality_pre_dispatch_data()),
+				Ok(())
+			));
+
+			// transaction has failed
+			assert_storage_noop!(run_grandpa_post_dispatch(
+				Some(grandpa_delivery_pre_dispatch_data()),
+				Err(DispatchError::BadOrigin)
+			));
+		});
+
+		run_test(|| {
+			initialize_environment(200, 100, 100);
+
+			// no messages have been delivered
+			assert_storage_noop!(run_grandpa_post_dispatch(
+				Some(grandpa_finality_pre_dispatch_data()),
+				Ok(())
+			));
+			assert_storage_noop!(run_grandpa_post_dispatch(
+				Some(grandpa_delivery_pre_dispatch_data()),
+				Ok(())
+			));
+		});
+	}
+
+	#[test]
+	fn grandpa_ext_post_dispatch_refunds_relayer() {
+		run_test(|| {
+			initialize_environment(200, 100, 200);
+
+			run_grandpa_post_dispatch(Some(grandpa_finality_pre_dispatch_data()), Ok(()));
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::relayer_reward(
+					relayer_account_at_this_chain(),
+					test_rewards_account_params()
+				),
+				Some(expected_reward()),
+			);
+
+			run_grandpa_post_dispatch(Some(grandpa_delivery_pre_dispatch_data()), Ok(()));
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::relayer_reward(
+					relayer_account_at_this_chain(),
+					test_rewards_account_params()
+				),
+				Some(expected_reward() * 2),
+			);
+		});
+	}
+
+	#[test]
+	fn grandpa_ext_post_dispatch_slashes_registered_relayer_if_grandpa_state_is_not_updated() {
+		run_test(|| {
+			initialize_environment(100, 100, 200);
+			register_relayer();
+			let rewards_account = rewards_account();
+
+			run_grandpa_post_dispatch(Some(grandpa_finality_pre_dispatch_data()), Ok(()));
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::registered_relayer(relayer_account_at_this_chain()),
+				None,
+			);
+			assert_eq!(
+				Balances::free_balance(rewards_account),
+				ExistentialDeposit::get() + Stake::get(),
+			);
+		});
+	}
 }
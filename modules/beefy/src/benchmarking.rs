@@ -0,0 +1,418 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks for the BEEFY Pallet.
+//!
+//! The main dispatchable of the BEEFY pallet is `submit_commitment`, so these benchmarks are
+//! based around that. There are three main factors which affect commitment verification:
+//!
+//! 1. The number of validators in the BEEFY authority set. We need to compute merkle root of all
+//!    validator keys to verify the set;
+//! 2. The number of signatures that we need to verify. The `submit_commitment` benchmark assumes
+//!    that only `2/3+1` signatures are verified and the `verify_signature` benchmark computes cost
+//!    of every additional signature verification;
+//! 3. The number of items in the MMR leaf proof.
+//!
+//! The `submit_commitment_claim` call only verifies the signers bitfield, so its weight only
+//! depends on the bitfield size.
+//!
+//! The `submit_commitment_with_signatures_subset` call doesn't need the full validator set, but
+//! every provided signature comes with the merkle proof of the validator key. So its weight
+//! depends on the number of provided signatures, the number of items in the MMR leaf proof,
+//! the number of items in the validator key merkle proof and the signers bitfield size.
+//!
+//! The `submit_header` call only verifies the MMR leaf proof, so its weight only depends on the
+//! number of items in the proof.
+
+use crate::{
+	utils::{
+		commitment_claim_hash, commitment_claim_seed, get_authorities_mmr_root, signatures_required,
+	},
+	*,
+};
+
+use bp_beefy::{
+	max_signers_bitfield_len, max_validator_proof_len, merkle_proof, select_signers,
+	signers_to_bitfield, BeefyAuthorityId, BeefyPayload, Commitment, EcdsaValidatorId,
+	EcdsaValidatorSignature, SignaturesSubset, ValidatorSet, ValidatorSignatureWithProof,
+	MMR_ROOT_PAYLOAD_ID,
+};
+use bp_runtime::BasicOperatingMode;
+use codec::Encode;
+use frame_benchmarking::{benchmarks_instance_pallet, whitelisted_caller};
+use frame_system::RawOrigin;
+use sp_core::crypto::Wraps;
+use sp_runtime::{
+	traits::{Convert, Hash, Header as HeaderT, Keccak256, One, Saturating, Zero},
+	RuntimeAppPublic,
+};
+use sp_std::prelude::*;
+
+/// Maximal number of validators in the BEEFY authority set that we are using in benchmarks.
+///
+/// Real chains may have much more validators, but we are building linear function here, so
+/// let's just select some limited range for benchmarking.
+const MAX_VALIDATORS: u32 = 128;
+
+/// Maximal number of items in the MMR leaf proof.
+///
+/// We are generating proof for the last leaf of the perfect MMR, so the MMR will have
+/// `2^MAX_MMR_PROOF_ITEMS` leafs.
+const MAX_MMR_PROOF_ITEMS: u32 = 32;
+
+/// Maximal number of signatures in the subset, provided to the
+/// `submit_commitment_with_signatures_subset` call.
+const MAX_SIGNATURES_IN_SUBSET: u32 = 64;
+
+/// Minimal number of signatures in the subset that is accepted by the pallet.
+fn min_signatures_in_subset<T: Config<I>, I: 'static>() -> u32 {
+	T::MinSignaturesInSubset::get().clamp(1, MAX_SIGNATURES_IN_SUBSET)
+}
+
+/// Maximal number of validators that is accepted by the `submit_commitment_claim` and
+/// `submit_commitment_with_signatures_subset` calls.
+fn max_validators<T: Config<I>, I: 'static>() -> u32 {
+	T::MaxBridgedAuthorities::get().clamp(2, MAX_VALIDATORS)
+}
+
+/// Generate `count` BEEFY validator keys.
+fn validator_ids(count: u32) -> Vec<EcdsaValidatorId> {
+	(0..count).map(|_| EcdsaValidatorId::generate_pair(None)).collect()
+}
+
+/// Sign given commitment by all validators.
+fn sign_commitment<BlockNumber: Encode>(
+	commitment: &Commitment<BlockNumber>,
+	validators: &[EcdsaValidatorId],
+) -> Vec<Option<EcdsaValidatorSignature>> {
+	let commitment_hash = sp_io::hashing::keccak_256(&commitment.encode());
+	validators
+		.iter()
+		.map(|id| {
+			sp_io::crypto::ecdsa_sign_prehashed(
+				EcdsaValidatorId::ID,
+				id.as_inner_ref(),
+				&commitment_hash,
+			)
+			.map(Into::into)
+		})
+		.collect()
+}
+
+/// Generate MMR proof for the last leaf of the perfect MMR with `2^proof_items` leafs.
+///
+/// Returns computed MMR root and the proof.
+fn generate_mmr_proof<T: Config<I>, I: 'static>(
+	leaf: &BridgedBeefyMmrLeaf<T, I>,
+	proof_items: u32,
+) -> (BridgedMmrHash<T, I>, BridgedMmrProof<T, I>) {
+	// the leaf that we're proving is always the right child, so its parent is computed as
+	// `hash(left_sibling || leaf)`
+	let items = (0..proof_items)
+		.map(|index| BridgedMmrHashing::<T, I>::hash(&index.encode()))
+		.collect::<Vec<_>>();
+	let leaf_hash = BridgedMmrHashing::<T, I>::hash(&leaf.encode());
+	let root = items.iter().fold(leaf_hash, |node, sibling| {
+		let mut concat = sibling.as_ref().to_vec();
+		concat.extend_from_slice(node.as_ref());
+		BridgedMmrHashing::<T, I>::hash(&concat)
+	});
+
+	let leaf_count = 1u64 << proof_items;
+	(root, BridgedMmrProof::<T, I> { leaf_indices: vec![leaf_count - 1], leaf_count, items })
+}
+
+/// Initialize pallet and prepare arguments of the `submit_commitment` call.
+fn prepare_benchmark_data<T: Config<I>, I: 'static>(
+	validators_count: u32,
+	proof_items: u32,
+) -> (
+	BridgedBeefySignedCommitment<T, I>,
+	BridgedBeefyAuthoritySet<T, I>,
+	BridgedBeefyMmrLeaf<T, I>,
+	BridgedMmrProof<T, I>,
+)
+where
+	BridgedChain<T, I>:
+		ChainWithBeefy<AuthorityId = EcdsaValidatorId, CommitmentHasher = Keccak256>,
+	BridgedBeefyMmrLeafExtra<T, I>: Default,
+{
+	let validators = validator_ids(validators_count);
+	let authority_set = bp_beefy::BeefyAuthoritySet {
+		id: 0,
+		len: validators_count,
+		root: get_authorities_mmr_root::<T, I, _>(validators.iter()),
+	};
+
+	initialize::<T, I>(InitializationData {
+		operating_mode: BasicOperatingMode::Normal,
+		best_block_number: Zero::zero(),
+		authority_set: authority_set.clone(),
+	})
+	.expect("benchmarks are correct");
+
+	let leaf = BridgedBeefyMmrLeaf::<T, I> {
+		version: bp_beefy::MmrLeafVersion::new(1, 0),
+		parent_number_and_hash: (Zero::zero(), Default::default()),
+		beefy_next_authority_set: authority_set,
+		leaf_extra: Default::default(),
+	};
+	let (mmr_root, mmr_proof) = generate_mmr_proof::<T, I>(&leaf, proof_items);
+
+	let commitment = Commitment {
+		payload: BeefyPayload::from_single_entry(MMR_ROOT_PAYLOAD_ID, mmr_root.encode()),
+		block_number: One::one(),
+		validator_set_id: 0,
+	};
+	// every validator signs the commitment, but we drop signatures that are not required to
+	// finalize the block
+	let mut signatures = sign_commitment(&commitment, &validators);
+	signatures
+		.iter_mut()
+		.skip(signatures_required(validators_count as usize))
+		.for_each(|signature| *signature = None);
+
+	(
+		BridgedBeefySignedCommitment::<T, I> { commitment, signatures },
+		ValidatorSet::new(validators, 0).expect("validators set is not empty; qed"),
+		leaf,
+		mmr_proof,
+	)
+}
+
+/// Returns indices of validators that have signed the commitment.
+fn commitment_signers<T: Config<I>, I: 'static>(
+	signed_commitment: &BridgedBeefySignedCommitment<T, I>,
+) -> Vec<u32> {
+	signed_commitment
+		.signatures
+		.iter()
+		.enumerate()
+		.filter(|(_, signature)| signature.is_some())
+		.map(|(index, _)| index as u32)
+		.collect()
+}
+
+/// Initialize pallet and prepare arguments of the `submit_commitment_claim` call.
+///
+/// The number of validators is selected so that the signers bitfield has `signers_bitfield_len`
+/// bytes.
+fn prepare_commitment_claim_benchmark_data<T: Config<I>, I: 'static>(
+	signers_bitfield_len: u32,
+) -> (BridgedBeefyCommitment<T, I>, Vec<u8>)
+where
+	BridgedChain<T, I>:
+		ChainWithBeefy<AuthorityId = EcdsaValidatorId, CommitmentHasher = Keccak256>,
+	BridgedBeefyMmrLeafExtra<T, I>: Default,
+{
+	let validators_count =
+		(8 * signers_bitfield_len.saturating_sub(1) + 1).min(max_validators::<T, I>());
+	let (signed_commitment, _, _, _) = prepare_benchmark_data::<T, I>(validators_count, 0);
+	let signers =
+		signers_to_bitfield(commitment_signers::<T, I>(&signed_commitment), validators_count);
+	(signed_commitment.commitment, signers)
+}
+
+/// Initialize pallet, store commitment claim of the `submitter` and prepare arguments of the
+/// `submit_commitment_with_signatures_subset` call.
+///
+/// The number of validators is selected so that there are at least `signatures_count` signers,
+/// every validator key merkle proof has at least `validator_proof_len` items and the signers
+/// bitfield has at least `signers_bitfield_len` bytes.
+fn prepare_signatures_subset_benchmark_data<T: Config<I>, I: 'static>(
+	submitter: &T::AccountId,
+	signatures_count: u32,
+	proof_items: u32,
+	validator_proof_len: u32,
+	signers_bitfield_len: u32,
+) -> (
+	BridgedBeefyCommitment<T, I>,
+	BridgedBeefySignaturesSubset<T, I>,
+	BridgedBeefyMmrLeaf<T, I>,
+	BridgedMmrProof<T, I>,
+)
+where
+	BridgedChain<T, I>:
+		ChainWithBeefy<AuthorityId = EcdsaValidatorId, CommitmentHasher = Keccak256>,
+	BridgedBeefyMmrLeafExtra<T, I>: Default,
+{
+	let validators_count = [
+		(1 << validator_proof_len.saturating_sub(1)) + 1,
+		8 * signers_bitfield_len.saturating_sub(1) + 1,
+		signatures_count * 3 / 2 + 1,
+	]
+	.into_iter()
+	.max()
+	.unwrap_or_default()
+	.min(max_validators::<T, I>());
+	let (signed_commitment, validator_set, leaf, mmr_proof) =
+		prepare_benchmark_data::<T, I>(validators_count, proof_items);
+	let commitment = signed_commitment.commitment.clone();
+	let signers = commitment_signers::<T, I>(&signed_commitment);
+	let signers_bitfield = signers_to_bitfield(signers.clone(), validators_count);
+
+	// the claim is stored at the current block and the randomness, that is used to complete
+	// it, is recorded, so the claim may be completed right now
+	let claimed_at = frame_system::Pallet::<T>::block_number();
+	let randomness_at = claimed_at.saturating_add(T::ClaimDelay::get());
+	frame_system::Pallet::<T>::set_block_number(randomness_at);
+	ClaimsRandomness::<T, I>::insert(randomness_at, Some((Default::default(), randomness_at)));
+	let claim = CommitmentClaimOf::<T> {
+		claim_hash: commitment_claim_hash::<T, I>(&commitment, &signers_bitfield),
+		claimed_at,
+	};
+	CommitmentClaims::<T, I>::insert(submitter, claim.clone());
+	let seed = commitment_claim_seed::<T, I>(&claim).expect("randomness has been updated; qed");
+	let selected_signers = select_signers::<Keccak256>(seed, signers, signatures_count);
+
+	let merkle_leafs = validator_set
+		.validators()
+		.iter()
+		.cloned()
+		.map(bp_beefy::BeefyAuthorityIdToMerkleLeafOf::<BridgedChain<T, I>>::convert)
+		.collect::<Vec<_>>();
+	let signatures = selected_signers
+		.into_iter()
+		.map(|index| ValidatorSignatureWithProof {
+			validator_index: index,
+			validator_id: validator_set.validators()[index as usize].clone(),
+			signature: signed_commitment.signatures[index as usize]
+				.clone()
+				.expect("selected from signers; qed"),
+			proof: merkle_proof::<BridgedMmrHashing<T, I>, _, _>(
+				merkle_leafs.clone(),
+				index as usize,
+			)
+			.proof,
+		})
+		.collect();
+
+	(commitment, SignaturesSubset { signers: signers_bitfield, signatures }, leaf, mmr_proof)
+}
+
+/// Prepare imported commitment and arguments of the `submit_header` call.
+fn prepare_header_benchmark_data<T: Config<I>, I: 'static>(
+	proof_items: u32,
+) -> (BridgedBlockNumber<T, I>, BridgedHeader<T, I>, BridgedBeefyMmrLeaf<T, I>, BridgedMmrProof<T, I>)
+where
+	BridgedBeefyMmrLeafExtra<T, I>: Default,
+{
+	let header = BridgedHeader::<T, I>::new(
+		One::one(),
+		Default::default(),
+		Default::default(),
+		Default::default(),
+		Default::default(),
+	);
+	let leaf = BridgedBeefyMmrLeaf::<T, I> {
+		version: bp_beefy::MmrLeafVersion::new(1, 0),
+		parent_number_and_hash: (*header.number(), header.hash()),
+		beefy_next_authority_set: Default::default(),
+		leaf_extra: Default::default(),
+	};
+	let (mmr_root, mmr_proof) = generate_mmr_proof::<T, I>(&leaf, proof_items);
+
+	let commitment_block_number = *header.number() + One::one();
+	ImportedCommitments::<T, I>::insert(
+		commitment_block_number,
+		ImportedCommitment::<T, I> {
+			parent_number_and_hash: leaf.parent_number_and_hash,
+			mmr_root,
+		},
+	);
+
+	(commitment_block_number, header, leaf, mmr_proof)
+}
+
+benchmarks_instance_pallet! {
+	where_clause {
+		where
+			BridgedChain<T, I>: ChainWithBeefy<
+				AuthorityId = EcdsaValidatorId,
+				CommitmentHasher = Keccak256,
+			>,
+			BridgedBeefyMmrLeafExtra<T, I>: Default,
+			BridgedMmrHashing<T, I>: 'static + Send + Sync,
+			BridgedBeefySignedCommitment<T, I>: Clone,
+	}
+
+	// This is the "gold standard" benchmark for this extrinsic, and it's what should be used to
+	// annotate the weight in the pallet.
+	submit_commitment {
+		let v in 1..MAX_VALIDATORS;
+		let p in 0..MAX_MMR_PROOF_ITEMS;
+		let caller: T::AccountId = whitelisted_caller();
+		let (commitment, validator_set, leaf, proof) = prepare_benchmark_data::<T, I>(v, p);
+	}: submit_commitment(RawOrigin::Signed(caller), commitment, validator_set, Box::new(leaf), proof)
+	verify {
+		let commitments_info = ImportedCommitmentsInfo::<T, I>::get().unwrap();
+		assert_eq!(commitments_info.best_block_number, One::one());
+		assert!(ImportedCommitments::<T, I>::contains_key(commitments_info.best_block_number));
+	}
+
+	// Benchmark `submit_commitment_claim` extrinsic with different sizes of the signers bitfield.
+	submit_commitment_claim {
+		let b in 1..max_signers_bitfield_len(max_validators::<T, I>());
+		let caller: T::AccountId = whitelisted_caller();
+		let (commitment, signers) = prepare_commitment_claim_benchmark_data::<T, I>(b);
+	}: submit_commitment_claim(RawOrigin::Signed(caller.clone()), commitment, signers)
+	verify {
+		assert!(CommitmentClaims::<T, I>::contains_key(&caller));
+	}
+
+	// Benchmark `submit_commitment_with_signatures_subset` extrinsic with different number of
+	// signatures, MMR proof sizes, validator key proof sizes and signers bitfield sizes.
+	submit_commitment_with_signatures_subset {
+		let s in min_signatures_in_subset::<T, I>()..MAX_SIGNATURES_IN_SUBSET;
+		let p in 0..MAX_MMR_PROOF_ITEMS;
+		let l in 1..max_validator_proof_len(max_validators::<T, I>());
+		let b in 1..max_signers_bitfield_len(max_validators::<T, I>());
+		let caller: T::AccountId = whitelisted_caller();
+		let (commitment, signatures, leaf, proof) =
+			prepare_signatures_subset_benchmark_data::<T, I>(&caller, s, p, l, b);
+	}: submit_commitment_with_signatures_subset(RawOrigin::Signed(caller.clone()), commitment, signatures, Box::new(leaf), proof)
+	verify {
+		let commitments_info = ImportedCommitmentsInfo::<T, I>::get().unwrap();
+		assert_eq!(commitments_info.best_block_number, One::one());
+		assert!(ImportedCommitments::<T, I>::contains_key(commitments_info.best_block_number));
+		assert!(!CommitmentClaims::<T, I>::contains_key(&caller));
+	}
+
+	// Benchmark `submit_header` extrinsic with different MMR proof sizes.
+	submit_header {
+		let p in 0..MAX_MMR_PROOF_ITEMS;
+		let caller: T::AccountId = whitelisted_caller();
+		let (commitment_block_number, header, leaf, proof) = prepare_header_benchmark_data::<T, I>(p);
+		let header_hash = header.hash();
+	}: submit_header(RawOrigin::Signed(caller), commitment_block_number, Box::new(header), Box::new(leaf), proof)
+	verify {
+		assert!(ImportedHeaders::<T, I>::contains_key(header_hash));
+	}
+
+	// Benchmark verification of single additional commitment signature.
+	verify_signature {
+		let validators = validator_ids(1);
+		let commitment = Commitment {
+			payload: BeefyPayload::from_single_entry(MMR_ROOT_PAYLOAD_ID, vec![]),
+			block_number: BridgedBlockNumber::<T, I>::one(),
+			validator_set_id: 0,
+		};
+		let signature = sign_commitment(&commitment, &validators)[0].clone().unwrap();
+		let message = commitment.encode();
+	}: {
+		assert!(BeefyAuthorityId::<Keccak256>::verify(&validators[0], &signature, &message));
+	}
+}
@@ -0,0 +1,226 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{BridgedBlockNumber, BridgedMmrHashing, Config, Pallet};
+use bp_runtime::FilterCall;
+use frame_support::{dispatch::CallableCallFor, traits::IsSubType};
+use sp_runtime::{
+	traits::Header,
+	transaction_validity::{InvalidTransaction, TransactionValidity, ValidTransaction},
+};
+
+/// Validate BEEFY commitments and proved headers in order to avoid "mining" transactions that
+/// provide outdated bridged chain data. Without this validation, even honest relayers may lose
+/// their funds if there are multiple relays running and submitting the same information.
+impl<
+		Call: IsSubType<CallableCallFor<Pallet<T, I>, T>>,
+		T: frame_system::Config<RuntimeCall = Call> + Config<I>,
+		I: 'static,
+	> FilterCall<Call> for Pallet<T, I>
+where
+	BridgedMmrHashing<T, I>: 'static + Send + Sync,
+{
+	fn validate(call: &<T as frame_system::Config>::RuntimeCall) -> TransactionValidity {
+		match call.is_sub_type() {
+			Some(crate::Call::<T, I>::submit_commitment { ref commitment, .. }) =>
+				Self::validate_bundled_block_number(commitment.commitment.block_number),
+			Some(crate::Call::<T, I>::submit_commitment_claim { ref commitment, .. }) =>
+				Self::validate_bundled_block_number(commitment.block_number),
+			Some(crate::Call::<T, I>::submit_commitment_with_signatures_subset {
+				ref commitment,
+				..
+			}) => Self::validate_bundled_block_number(commitment.block_number),
+			Some(crate::Call::<T, I>::submit_header { ref header, .. }) => {
+				let header_hash = header.hash();
+				if crate::ImportedHeaders::<T, I>::contains_key(header_hash) {
+					log::trace!(
+						target: crate::LOG_TARGET,
+						"Rejecting already proved bridged header: {:?}",
+						header_hash,
+					);
+
+					return InvalidTransaction::Stale.into()
+				}
+			},
+			_ => (),
+		}
+
+		Ok(ValidTransaction::default())
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Reject transaction if it brings commitment that is not better than the best known.
+	fn validate_bundled_block_number(
+		bundled_block_number: BridgedBlockNumber<T, I>,
+	) -> TransactionValidity {
+		let best_block_number = match crate::ImportedCommitmentsInfo::<T, I>::get() {
+			Some(commitments_info) => commitments_info.best_block_number,
+			None => return InvalidTransaction::Call.into(),
+		};
+
+		if best_block_number >= bundled_block_number {
+			log::trace!(
+				target: crate::LOG_TARGET,
+				"Rejecting obsolete BEEFY commitment: bundled {:?}, best {:?}",
+				bundled_block_number,
+				best_block_number,
+			);
+
+			return InvalidTransaction::Stale.into()
+		}
+
+		Ok(ValidTransaction::default())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::FilterCall;
+	use crate::{
+		mock::{run_test, run_test_with_initialize, validator_pairs, RuntimeCall, TestRuntime},
+		mock_chain::ChainBuilder,
+		ImportedCommitmentsInfo, ImportedHeaders,
+	};
+	use bp_header_chain::StoredHeaderDataBuilder;
+	use sp_runtime::traits::Header;
+
+	fn validate_commitment_submit(num: u64) -> bool {
+		let mut header = ChainBuilder::new(1).append_finalized_header().to_header();
+		header.customize_commitment(
+			|commitment| commitment.block_number = num,
+			&validator_pairs(0, 1),
+			1,
+		);
+
+		crate::Pallet::<TestRuntime>::validate(&RuntimeCall::Beefy(
+			crate::Call::<TestRuntime, ()>::submit_commitment {
+				commitment: header.commitment.unwrap(),
+				validator_set: header.validator_set,
+				mmr_leaf: Box::new(header.leaf),
+				mmr_proof: header.leaf_proof,
+			},
+		))
+		.is_ok()
+	}
+
+	fn sync_to_block_10() {
+		ImportedCommitmentsInfo::<TestRuntime, ()>::mutate(|info| {
+			info.as_mut().expect("pallet is initialized").best_block_number = 10;
+		});
+	}
+
+	#[test]
+	fn extension_rejects_commitment_if_pallet_is_not_initialized() {
+		run_test(|| {
+			assert!(!validate_commitment_submit(15));
+		});
+	}
+
+	#[test]
+	fn extension_rejects_obsolete_commitment() {
+		run_test_with_initialize(1, || {
+			// when current best block is #10 and we're trying to import commitment for #5 => tx is
+			// rejected
+			sync_to_block_10();
+			assert!(!validate_commitment_submit(5));
+		});
+	}
+
+	#[test]
+	fn extension_rejects_same_commitment() {
+		run_test_with_initialize(1, || {
+			// when current best block is #10 and we're trying to import commitment for #10 => tx
+			// is rejected
+			sync_to_block_10();
+			assert!(!validate_commitment_submit(10));
+		});
+	}
+
+	#[test]
+	fn extension_accepts_new_commitment() {
+		run_test_with_initialize(1, || {
+			// when current best block is #10 and we're trying to import commitment for #15 => tx
+			// is accepted
+			sync_to_block_10();
+			assert!(validate_commitment_submit(15));
+		});
+	}
+
+	#[test]
+	fn extension_rejects_obsolete_commitment_claim() {
+		run_test_with_initialize(1, || {
+			let header = ChainBuilder::new(1).append_finalized_header().to_header();
+			let signatures = header.signatures_subset(Default::default(), 1);
+			let call =
+				RuntimeCall::Beefy(crate::Call::<TestRuntime, ()>::submit_commitment_claim {
+					commitment: header.commitment.unwrap().commitment,
+					signers: signatures.signers,
+				});
+
+			// when commitment is better than the best known => tx is accepted
+			assert!(crate::Pallet::<TestRuntime>::validate(&call).is_ok());
+
+			// when commitment is not better than the best known => tx is rejected
+			sync_to_block_10();
+			assert!(crate::Pallet::<TestRuntime>::validate(&call).is_err());
+		});
+	}
+
+	#[test]
+	fn extension_rejects_obsolete_commitment_with_signatures_subset() {
+		run_test_with_initialize(1, || {
+			let header = ChainBuilder::new(1).append_finalized_header().to_header();
+			let signatures = header.signatures_subset(Default::default(), 1);
+			let call = RuntimeCall::Beefy(
+				crate::Call::<TestRuntime, ()>::submit_commitment_with_signatures_subset {
+					commitment: header.commitment.unwrap().commitment,
+					signatures,
+					mmr_leaf: Box::new(header.leaf),
+					mmr_proof: header.leaf_proof,
+				},
+			);
+
+			// when commitment is better than the best known => tx is accepted
+			assert!(crate::Pallet::<TestRuntime>::validate(&call).is_ok());
+
+			// when commitment is not better than the best known => tx is rejected
+			sync_to_block_10();
+			assert!(crate::Pallet::<TestRuntime>::validate(&call).is_err());
+		});
+	}
+
+	#[test]
+	fn extension_rejects_already_proved_header() {
+		run_test_with_initialize(1, || {
+			let chain = ChainBuilder::new(1).append_finalized_headers(2);
+			let header = chain.header(1).header;
+			let call = RuntimeCall::Beefy(crate::Call::<TestRuntime, ()>::submit_header {
+				commitment_block_number: 2,
+				header: Box::new(header.clone()),
+				mmr_leaf: Box::new(chain.header(2).leaf),
+				mmr_proof: chain.header(2).leaf_proof,
+			});
+
+			// when header is not yet proved => tx is accepted
+			assert!(crate::Pallet::<TestRuntime>::validate(&call).is_ok());
+
+			// when header is already proved => tx is rejected
+			ImportedHeaders::<TestRuntime, ()>::insert(header.hash(), header.build());
+			assert!(crate::Pallet::<TestRuntime>::validate(&call).is_err());
+		});
+	}
+}
@@ -27,16 +27,24 @@
 //! - extra data of MMR leafs
 //!
 //! Given the header hash, other pallets are able to verify header-based proofs
-//! (e.g. storage proofs, transaction inclusion proofs, etc.).
+//! (e.g. storage proofs, transaction inclusion proofs, etc.). To verify storage proofs, the
+//! header itself must be proved first using the `submit_header` call. The pallet then stores
+//! the header state root and implements the `HeaderChain` trait.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use bp_beefy::{ChainWithBeefy, InitializationData};
+use bp_beefy::{ChainWithBeefy, ImportedCommitmentsInfoData, InitializationData};
+use bp_header_chain::{HeaderChain, StoredHeaderData, StoredHeaderDataBuilder};
+use bp_runtime::HashOf;
+use sp_runtime::traits::Header as HeaderT;
 use sp_std::{boxed::Box, prelude::*};
 
 // Re-export in crate namespace for `construct_runtime!`
 pub use pallet::*;
+pub use weights::WeightInfo;
+pub use weights_ext::WeightInfoExt;
 
+mod extension;
 mod utils;
 
 #[cfg(test)]
@@ -44,8 +52,17 @@ mod mock;
 #[cfg(test)]
 mod mock_chain;
 
+/// Module, containing weights for this pallet.
+pub mod weights;
+pub mod weights_ext;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
 /// The target that will be used when publishing logs related to this pallet.
 pub const LOG_TARGET: &str = "runtime::bridge-beefy";
+/// The subject that is used to read on-chain randomness for commitment claims.
+const CLAIM_RANDOMNESS_SUBJECT: &[u8] = b"bridge-beefy-claim";
 
 /// Configured bridged chain.
 pub type BridgedChain<T, I> = <T as Config<I>>::BridgedChain;
@@ -53,6 +70,11 @@ pub type BridgedChain<T, I> = <T as Config<I>>::BridgedChain;
 pub type BridgedBlockNumber<T, I> = bp_runtime::BlockNumberOf<BridgedChain<T, I>>;
 /// Block hash, used by configured bridged chain.
 pub type BridgedBlockHash<T, I> = bp_runtime::HashOf<BridgedChain<T, I>>;
+/// Header of the configured bridged chain.
+pub type BridgedHeader<T, I> = bp_runtime::HeaderOf<BridgedChain<T, I>>;
+/// Header data of the bridged chain, that is stored by the pallet.
+pub type BridgedStoredHeaderData<T, I> =
+	StoredHeaderData<BridgedBlockNumber<T, I>, BridgedBlockHash<T, I>>;
 
 /// Pallet initialization data.
 pub type InitializationDataOf<T, I> =
@@ -65,6 +87,13 @@ pub type BridgedBeefyAuthorityId<T, I> = bp_beefy::BeefyAuthorityIdOf<BridgedCha
 pub type BridgedBeefyAuthoritySet<T, I> = bp_beefy::BeefyAuthoritySetOf<BridgedChain<T, I>>;
 /// BEEFY authority set, used by configured bridged chain.
 pub type BridgedBeefyAuthoritySetInfo<T, I> = bp_beefy::BeefyAuthoritySetInfoOf<BridgedChain<T, I>>;
+/// BEEFY commitment, used by configured bridged chain.
+pub type BridgedBeefyCommitment<T, I> = bp_beefy::BeefyCommitmentOf<BridgedChain<T, I>>;
+/// BEEFY commitment signatures of the validators subset, used by configured bridged chain.
+pub type BridgedBeefySignaturesSubset<T, I> = bp_beefy::BeefySignaturesSubsetOf<BridgedChain<T, I>>;
+/// Output of the hasher, used to compute the digest of the BEEFY commitment.
+pub type BridgedBeefyCommitmentHash<T, I> =
+	<BridgedBeefyCommitmentHasher<T, I> as sp_runtime::traits::Hash>::Output;
 /// BEEFY signed commitment, used by configured bridged chain.
 pub type BridgedBeefySignedCommitment<T, I> = bp_beefy::BeefySignedCommitmentOf<BridgedChain<T, I>>;
 /// MMR hashing algorithm, used by configured bridged chain.
@@ -83,26 +112,29 @@ pub type ImportedCommitment<T, I> = bp_beefy::ImportedCommitment<
 	BridgedBlockHash<T, I>,
 	BridgedMmrHash<T, I>,
 >;
-
-/// Some high level info about the imported commitments.
-#[derive(codec::Encode, codec::Decode, scale_info::TypeInfo)]
-pub struct ImportedCommitmentsInfoData<BlockNumber> {
-	/// Best known block number, provided in a BEEFY commitment. However this is not
-	/// the best proven block. The best proven block is this block's parent.
-	best_block_number: BlockNumber,
-	/// The head of the `ImportedBlockNumbers` ring buffer.
-	next_block_number_index: u32,
-}
+/// Claim of the commitment signers, stored by the pallet.
+pub type CommitmentClaimOf<T> = bp_beefy::CommitmentClaim<
+	<T as frame_system::Config>::Hash,
+	<T as frame_system::Config>::BlockNumber,
+>;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use bp_runtime::{BasicOperatingMode, OwnedBridgeModule};
-	use frame_support::pallet_prelude::*;
+	use frame_support::{dispatch::PostDispatchInfo, pallet_prelude::*, traits::Randomness};
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{CheckedSub, One, Saturating},
+		SaturatedConversion,
+	};
 
 	#[pallet::config]
 	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
 		/// The upper bound on the number of requests allowed by the pallet.
 		///
 		/// A request refers to an action which writes a header to storage.
@@ -120,8 +152,55 @@ pub mod pallet {
 		#[pallet::constant]
 		type CommitmentsToKeep: Get<u32>;
 
+		/// Maximal number of proved headers to keep in the storage.
+		///
+		/// Headers are proved using the `submit_header` call. Once this number of headers is
+		/// stored, proving next header will prune the oldest one.
+		#[pallet::constant]
+		type HeadersToKeep: Get<u32>;
+
+		/// Minimal number of signatures that must be provided when commitment is submitted
+		/// using the `submit_commitment_with_signatures_subset` call.
+		///
+		/// If there are less validators that have signed the commitment, then signatures of all
+		/// of them must be provided. The submitter may provide more signatures than required.
+		#[pallet::constant]
+		type MinSignaturesInSubset: Get<u32>;
+
+		/// Max number of authorities at the bridged chain.
+		///
+		/// It limits the size of the signers bitfield and the length of validator key merkle
+		/// proofs, that are provided when commitment is submitted using the
+		/// `submit_commitment_claim` and `submit_commitment_with_signatures_subset` calls.
+		#[pallet::constant]
+		type MaxBridgedAuthorities: Get<u32>;
+
+		/// Source of the on-chain randomness, that is used to select validators whose signatures
+		/// must be provided to the `submit_commitment_with_signatures_subset` call.
+		///
+		/// The randomness must not be known in advance and must not be influenced by the
+		/// transaction submitter.
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+
+		/// Number of blocks between the commitment claim and the block, whose randomness is
+		/// used to select signers of the claimed commitment.
+		///
+		/// The randomness of that block must be unknown at the moment of claim, so the delay
+		/// must be large enough. E.g. if `pallet_babe::RandomnessFromOneEpochAgo` is used, the
+		/// delay must be larger than two epochs. The delay must be at least one block.
+		#[pallet::constant]
+		type ClaimDelay: Get<Self::BlockNumber>;
+
+		/// Number of blocks after the randomness block, during which the commitment claim may
+		/// be completed. Claims that are not completed within this period are expired.
+		#[pallet::constant]
+		type ClaimLifetime: Get<Self::BlockNumber>;
+
 		/// The chain we are bridging to here.
 		type BridgedChain: ChainWithBeefy;
+
+		/// Weights gathered through benchmarking.
+		type WeightInfo: WeightInfoExt;
 	}
 
 	#[pallet::pallet]
@@ -130,12 +209,32 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
-		fn on_initialize(_n: T::BlockNumber) -> frame_support::weights::Weight {
+		fn on_initialize(n: T::BlockNumber) -> frame_support::weights::Weight {
 			<RequestCount<T, I>>::mutate(|count| *count = count.saturating_sub(1));
+			let mut reads = 2;
+			let mut writes = 1;
+
+			// remember randomness, that will be used to select signers of commitment claims
+			if ClaimsRandomness::<T, I>::contains_key(n) {
+				ClaimsRandomness::<T, I>::insert(
+					n,
+					Some(T::Randomness::random(CLAIM_RANDOMNESS_SUBJECT)),
+				);
+				reads += 1;
+				writes += 1;
+			}
+
+			// forget randomness, that may only be used by expired claims
+			if let Some(expired_at) =
+				n.checked_sub(&T::ClaimLifetime::get().saturating_add(One::one()))
+			{
+				ClaimsRandomness::<T, I>::remove(expired_at);
+				writes += 1;
+			}
 
 			Weight::from_ref_time(0)
-				.saturating_add(T::DbWeight::get().reads(1))
-				.saturating_add(T::DbWeight::get().writes(1))
+				.saturating_add(T::DbWeight::get().reads(reads))
+				.saturating_add(T::DbWeight::get().writes(writes))
 		}
 	}
 
@@ -197,77 +296,238 @@ pub mod pallet {
 		/// If successful in verification, it will update the underlying storage with the data
 		/// provided in the newly submitted commitment.
 		#[pallet::call_index(3)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::submit_commitment_weight(
+			validator_set.len().saturated_into(),
+			commitment.signatures.iter().filter(|s| s.is_some()).count().saturated_into(),
+			mmr_proof.items.len().saturated_into(),
+		))]
 		pub fn submit_commitment(
 			origin: OriginFor<T>,
 			commitment: BridgedBeefySignedCommitment<T, I>,
 			validator_set: BridgedBeefyAuthoritySet<T, I>,
 			mmr_leaf: Box<BridgedBeefyMmrLeaf<T, I>>,
 			mmr_proof: BridgedMmrProof<T, I>,
-		) -> DispatchResult
+		) -> DispatchResultWithPostInfo
 		where
 			BridgedBeefySignedCommitment<T, I>: Clone,
 		{
 			Self::ensure_not_halted().map_err(Error::<T, I>::BridgeModule)?;
 			ensure_signed(origin)?;
 
-			ensure!(Self::request_count() < T::MaxRequests::get(), <Error<T, I>>::TooManyRequests);
-
-			// Ensure that the commitment is for a better block.
 			let commitments_info =
-				ImportedCommitmentsInfo::<T, I>::get().ok_or(Error::<T, I>::NotInitialized)?;
-			ensure!(
-				commitment.commitment.block_number > commitments_info.best_block_number,
-				Error::<T, I>::OldCommitment
-			);
+				ensure_better_commitment::<T, I>(commitment.commitment.block_number)?;
 
-			// Verify commitment and mmr leaf.
+			// Verify commitment.
 			let current_authority_set_info = CurrentAuthoritySetInfo::<T, I>::get();
-			let mmr_root = utils::verify_commitment::<T, I>(
+			let (mmr_root, verified_signatures) = utils::verify_commitment::<T, I>(
 				&commitment,
 				&current_authority_set_info,
 				&validator_set,
 			)?;
-			utils::verify_beefy_mmr_leaf::<T, I>(&mmr_leaf, mmr_proof, mmr_root)?;
 
-			// Update request count.
-			RequestCount::<T, I>::mutate(|count| *count += 1);
-			// Update authority set if needed.
-			if mmr_leaf.beefy_next_authority_set.id > current_authority_set_info.id {
-				CurrentAuthoritySetInfo::<T, I>::put(mmr_leaf.beefy_next_authority_set);
+			// Verify mmr leaf and import commitment.
+			let mmr_proof_items = mmr_proof.items.len();
+			let is_authority_set_changed = import_verified_commitment::<T, I>(
+				commitments_info,
+				current_authority_set_info,
+				commitment.commitment.block_number,
+				mmr_root,
+				*mmr_leaf,
+				mmr_proof,
+			)?;
+
+			// commitment that hands off to the next authority set is mandatory - the pallet
+			// can't import any further commitments without it. We don't want to charge extra
+			// costs for mandatory operations, so relayer is not paying fee for such commitments
+			let pays_fee = if is_authority_set_changed { Pays::No } else { Pays::Yes };
+
+			// the call weight assumes that all provided signatures are verified. But we stop
+			// verification once we have `2/3+1` correct signatures, so we may refund the rest
+			let actual_weight = T::WeightInfo::submit_commitment_weight(
+				validator_set.len().saturated_into(),
+				verified_signatures,
+				mmr_proof_items.saturated_into(),
+			);
+
+			Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee })
+		}
+
+		/// Claim that the commitment has been signed by given subset of BEEFY authority set.
+		///
+		/// This is the first phase of the commitment submission, that doesn't require the full
+		/// validator set and all signatures. The submitter declares which validators have
+		/// signed the commitment. The claim is stored by the pallet and may be completed using
+		/// the `submit_commitment_with_signatures_subset` call, once the on-chain randomness of
+		/// the block `T::ClaimDelay` blocks after the claim, becomes available. The claim expires
+		/// `T::ClaimLifetime` blocks after that block.
+		///
+		/// Every submitter may only have one active claim. The new claim overwrites the
+		/// previous one.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::submit_commitment_claim(signers.len().saturated_into()))]
+		pub fn submit_commitment_claim(
+			origin: OriginFor<T>,
+			commitment: BridgedBeefyCommitment<T, I>,
+			signers: Vec<u8>,
+		) -> DispatchResult {
+			Self::ensure_not_halted().map_err(Error::<T, I>::BridgeModule)?;
+			let submitter = ensure_signed(origin)?;
+
+			ensure_better_commitment::<T, I>(commitment.block_number)?;
+
+			// Verify claimed signers.
+			let current_authority_set_info = CurrentAuthoritySetInfo::<T, I>::get();
+			utils::verify_commitment_signers::<T, I>(
+				&commitment,
+				&signers,
+				&current_authority_set_info,
+			)?;
+
+			// Remember the claim and ask to record randomness that will be used to complete it.
+			let claim_hash = utils::commitment_claim_hash::<T, I>(&commitment, &signers);
+			let claimed_at = frame_system::Pallet::<T>::block_number();
+			let randomness_at = claimed_at.saturating_add(T::ClaimDelay::get());
+			if !ClaimsRandomness::<T, I>::contains_key(randomness_at) {
+				ClaimsRandomness::<T, I>::insert(randomness_at, None);
 			}
+			CommitmentClaims::<T, I>::insert(
+				&submitter,
+				CommitmentClaimOf::<T> { claim_hash, claimed_at },
+			);
 
-			// Import commitment.
-			let block_number_index = commitments_info.next_block_number_index;
-			let to_prune = ImportedBlockNumbers::<T, I>::try_get(block_number_index);
-			ImportedCommitments::<T, I>::insert(
-				commitment.commitment.block_number,
-				ImportedCommitment::<T, I> {
-					parent_number_and_hash: mmr_leaf.parent_number_and_hash,
-					mmr_root,
-				},
+			log::trace!(
+				target: LOG_TARGET,
+				"Accepted claim of commitment for block {:?} from {:?}",
+				commitment.block_number,
+				submitter,
 			);
-			ImportedBlockNumbers::<T, I>::insert(
-				block_number_index,
-				commitment.commitment.block_number,
+
+			Ok(())
+		}
+
+		/// Submit a commitment, signed by a subset of BEEFY authority set.
+		///
+		/// This is the second phase of the commitment submission. The commitment and the signers
+		/// bitfield must match the claim, previously submitted by the same account using the
+		/// `submit_commitment_claim` call. The submitter provides signatures of a pseudo-random
+		/// subset of claimed signers. The subset is selected using the on-chain randomness of
+		/// the fixed block after the claim, that has been unknown when the claim has been
+		/// submitted. Every signature comes with the merkle proof of the validator key membership
+		/// in the current authority set.
+		///
+		/// If successful in verification, it will update the underlying storage with the data
+		/// provided in the newly submitted commitment.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::submit_commitment_with_signatures_subset(
+			signatures.signatures.len().saturated_into(),
+			mmr_proof.items.len().saturated_into(),
+			signatures
+				.signatures
+				.iter()
+				.map(|signature| signature.proof.len())
+				.max()
+				.unwrap_or(0)
+				.saturated_into(),
+			signatures.signers.len().saturated_into(),
+		))]
+		pub fn submit_commitment_with_signatures_subset(
+			origin: OriginFor<T>,
+			commitment: BridgedBeefyCommitment<T, I>,
+			signatures: BridgedBeefySignaturesSubset<T, I>,
+			mmr_leaf: Box<BridgedBeefyMmrLeaf<T, I>>,
+			mmr_proof: BridgedMmrProof<T, I>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_not_halted().map_err(Error::<T, I>::BridgeModule)?;
+			let submitter = ensure_signed(origin)?;
+
+			let commitments_info = ensure_better_commitment::<T, I>(commitment.block_number)?;
+
+			// Verify commitment.
+			let claim = CommitmentClaims::<T, I>::take(&submitter)
+				.ok_or(Error::<T, I>::UnknownCommitmentClaim)?;
+			let current_authority_set_info = CurrentAuthoritySetInfo::<T, I>::get();
+			let mmr_root = utils::verify_commitment_with_signatures_subset::<T, I>(
+				&commitment,
+				&signatures,
+				&claim,
+				&current_authority_set_info,
+			)?;
+
+			// Verify mmr leaf and import commitment.
+			let is_authority_set_changed = import_verified_commitment::<T, I>(
+				commitments_info,
+				current_authority_set_info,
+				commitment.block_number,
+				mmr_root,
+				*mmr_leaf,
+				mmr_proof,
+			)?;
+
+			// see comment in `submit_commitment`
+			let pays_fee = if is_authority_set_changed { Pays::No } else { Pays::Yes };
+
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee })
+		}
+
+		/// Prove that the header is a part of the bridged chain, finalized by BEEFY.
+		///
+		/// The header is proved using MMR leaf of its child block and the MMR proof of this
+		/// leaf, built against MMR root of the commitment, that has been previously imported
+		/// by the pallet. Only headers that are better than the best proved header are accepted.
+		///
+		/// If successful, the header state root is stored, so the header may be used to verify
+		/// storage proofs.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::submit_header(mmr_proof.items.len().saturated_into()))]
+		pub fn submit_header(
+			origin: OriginFor<T>,
+			commitment_block_number: BridgedBlockNumber<T, I>,
+			header: Box<BridgedHeader<T, I>>,
+			mmr_leaf: Box<BridgedBeefyMmrLeaf<T, I>>,
+			mmr_proof: BridgedMmrProof<T, I>,
+		) -> DispatchResult {
+			Self::ensure_not_halted().map_err(Error::<T, I>::BridgeModule)?;
+			ensure_signed(origin)?;
+			ensure!(Self::request_count() < T::MaxRequests::get(), <Error<T, I>>::TooManyRequests);
+
+			let header_hash = header.hash();
+			ensure!(
+				!ImportedHeaders::<T, I>::contains_key(header_hash),
+				Error::<T, I>::HeaderAlreadyImported
 			);
-			ImportedCommitmentsInfo::<T, I>::put(ImportedCommitmentsInfoData {
-				best_block_number: commitment.commitment.block_number,
-				next_block_number_index: (block_number_index + 1) % T::CommitmentsToKeep::get(),
-			});
-			if let Ok(old_block_number) = to_prune {
-				log::debug!(
-					target: LOG_TARGET,
-					"Pruning commitment for old block: {:?}.",
-					old_block_number
-				);
-				ImportedCommitments::<T, I>::remove(old_block_number);
-			}
+
+			// MMR covers the whole history of the bridged chain, so without this check anyone
+			// could evict useful headers from the ring buffer by proving old headers
+			ensure!(
+				BestImportedHeaderNumber::<T, I>::get()
+					.map(|best_number| *header.number() > best_number)
+					.unwrap_or(true),
+				Error::<T, I>::OldHeader
+			);
+
+			// MMR leaf of the block references its parent, so the leaf of header child must
+			// reference the header
+			ensure!(
+				mmr_leaf.parent_number_and_hash == (*header.number(), header_hash),
+				Error::<T, I>::HeaderMismatch
+			);
+
+			let imported_commitment = ImportedCommitments::<T, I>::get(commitment_block_number)
+				.ok_or(Error::<T, I>::UnknownCommitment)?;
+			utils::verify_beefy_mmr_leaf::<T, I>(
+				&mmr_leaf,
+				mmr_proof,
+				imported_commitment.mmr_root,
+			)?;
+
+			insert_header::<T, I>(&header, header_hash);
+			RequestCount::<T, I>::mutate(|count| *count += 1);
 
 			log::info!(
 				target: LOG_TARGET,
-				"Successfully imported commitment for block {:?}",
-				commitment.commitment.block_number,
+				"Successfully imported header {:?} using commitment for block {:?}",
+				header_hash,
+				commitment_block_number,
 			);
 
 			Ok(())
@@ -305,6 +565,42 @@ pub mod pallet {
 	pub type ImportedCommitments<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, BridgedBlockNumber<T, I>, ImportedCommitment<T, I>>;
 
+	/// A ring buffer of hashes of proved headers. Ordered by the insertion time.
+	#[pallet::storage]
+	pub(super) type ImportedHeaderHashes<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, u32, BridgedBlockHash<T, I>>;
+
+	/// Current position in the `ImportedHeaderHashes` ring buffer.
+	#[pallet::storage]
+	pub(super) type ImportedHeaderHashesPointer<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery>;
+
+	/// Number of the best header, proved using the `submit_header` call.
+	#[pallet::storage]
+	pub type BestImportedHeaderNumber<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BridgedBlockNumber<T, I>>;
+
+	/// Relevant fields of headers, proved using the `submit_header` call.
+	#[pallet::storage]
+	pub type ImportedHeaders<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, BridgedBlockHash<T, I>, BridgedStoredHeaderData<T, I>>;
+
+	/// Commitment claims, submitted using the `submit_commitment_claim` call and not yet
+	/// completed by the `submit_commitment_with_signatures_subset` call.
+	#[pallet::storage]
+	pub type CommitmentClaims<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, CommitmentClaimOf<T>>;
+
+	/// On-chain randomness (and the number of block where it has become known), that is used to
+	/// select signers of commitment claims.
+	///
+	/// When the claim is submitted at block `N`, the `None` value is inserted under the
+	/// `N + T::ClaimDelay` key. The randomness is recorded at the beginning of that block and is
+	/// removed once all claims, that are using it, are expired.
+	#[pallet::storage]
+	pub type ClaimsRandomness<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, Option<(T::Hash, T::BlockNumber)>, ValueQuery>;
+
 	/// The current BEEFY authority set at the bridged chain.
 	#[pallet::storage]
 	pub type CurrentAuthoritySetInfo<T: Config<I>, I: 'static = ()> =
@@ -360,6 +656,23 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// Commitment for given bridged chain block has been imported.
+		CommitmentImported {
+			/// Number of the bridged chain block, finalized by the commitment.
+			block_number: BridgedBlockNumber<T, I>,
+			/// MMR root from the commitment payload.
+			mmr_root: BridgedMmrHash<T, I>,
+		},
+		/// BEEFY authority set at the bridged chain has been changed.
+		AuthoritySetChanged {
+			/// New BEEFY authority set.
+			authority_set: BridgedBeefyAuthoritySetInfo<T, I>,
+		},
+	}
+
 	#[pallet::error]
 	pub enum Error<T, I = ()> {
 		/// The pallet has not been initialized yet.
@@ -388,6 +701,38 @@ pub mod pallet {
 		MmrProofVerificationFailed,
 		/// The validators are not matching the merkle tree root of the authority set.
 		InvalidValidatorSetRoot,
+		/// The bitfield of validators that have signed the commitment is invalid.
+		InvalidSignersBitfield,
+		/// The bitfield of validators that have signed the commitment is larger than allowed by
+		/// the `MaxBridgedAuthorities` constant.
+		TooLargeSignersBitfield,
+		/// The submitter has no active commitment claim.
+		UnknownCommitmentClaim,
+		/// The commitment or the signers bitfield don't match the submitter claim.
+		InvalidCommitmentClaim,
+		/// The randomness, that is used to select signers, is not yet available. The submitter
+		/// shall retry later.
+		CommitmentClaimIsTooRecent,
+		/// The commitment claim has not been completed in time and is expired.
+		CommitmentClaimIsExpired,
+		/// The randomness, that is used to select signers, has been known at the moment of
+		/// claim. It means that the `ClaimDelay` is too small.
+		CommitmentClaimRandomnessIsKnown,
+		/// The signature of unexpected validator is provided in the signatures subset.
+		UnexpectedCommitmentSigner,
+		/// The merkle proof of validator key membership in the authority set is invalid.
+		InvalidValidatorProof,
+		/// The merkle proof of validator key membership in the authority set has more items than
+		/// allowed by the `MaxBridgedAuthorities` constant.
+		TooLongValidatorProof,
+		/// The commitment that is used to prove the header is unknown to the pallet.
+		UnknownCommitment,
+		/// The MMR leaf doesn't reference the header that is being proved.
+		HeaderMismatch,
+		/// The header has already been proved.
+		HeaderAlreadyImported,
+		/// The header is not better than the best header, proved by the pallet.
+		OldHeader,
 		/// Error generated by the `OwnedBridgeModule` trait.
 		BridgeModule(bp_runtime::OwnedBridgeModuleError),
 	}
@@ -409,6 +754,109 @@ pub mod pallet {
 
 		Ok(())
 	}
+
+	/// Ensure that the commitment for given block may be imported.
+	///
+	/// Returns info about imported commitments.
+	pub(super) fn ensure_better_commitment<T: Config<I>, I: 'static>(
+		block_number: BridgedBlockNumber<T, I>,
+	) -> Result<ImportedCommitmentsInfoData<BridgedBlockNumber<T, I>>, Error<T, I>> {
+		ensure!(
+			Pallet::<T, I>::request_count() < T::MaxRequests::get(),
+			<Error<T, I>>::TooManyRequests
+		);
+
+		// Ensure that the commitment is for a better block.
+		let commitments_info =
+			ImportedCommitmentsInfo::<T, I>::get().ok_or(Error::<T, I>::NotInitialized)?;
+		ensure!(block_number > commitments_info.best_block_number, Error::<T, I>::OldCommitment);
+
+		Ok(commitments_info)
+	}
+
+	/// Verify MMR leaf of the verified commitment and import the commitment.
+	///
+	/// Returns true if the commitment has changed the current authority set.
+	pub(super) fn import_verified_commitment<T: Config<I>, I: 'static>(
+		commitments_info: ImportedCommitmentsInfoData<BridgedBlockNumber<T, I>>,
+		current_authority_set_info: BridgedBeefyAuthoritySetInfo<T, I>,
+		block_number: BridgedBlockNumber<T, I>,
+		mmr_root: BridgedMmrHash<T, I>,
+		mmr_leaf: BridgedBeefyMmrLeaf<T, I>,
+		mmr_proof: BridgedMmrProof<T, I>,
+	) -> Result<bool, Error<T, I>> {
+		utils::verify_beefy_mmr_leaf::<T, I>(&mmr_leaf, mmr_proof, mmr_root)?;
+
+		// Update request count.
+		RequestCount::<T, I>::mutate(|count| *count += 1);
+		// Update authority set if needed.
+		let is_authority_set_changed =
+			mmr_leaf.beefy_next_authority_set.id > current_authority_set_info.id;
+		if is_authority_set_changed {
+			CurrentAuthoritySetInfo::<T, I>::put(mmr_leaf.beefy_next_authority_set.clone());
+			Pallet::<T, I>::deposit_event(Event::AuthoritySetChanged {
+				authority_set: mmr_leaf.beefy_next_authority_set,
+			});
+		}
+
+		// Import commitment.
+		let block_number_index = commitments_info.next_block_number_index;
+		let to_prune = ImportedBlockNumbers::<T, I>::try_get(block_number_index);
+		ImportedCommitments::<T, I>::insert(
+			block_number,
+			ImportedCommitment::<T, I> {
+				parent_number_and_hash: mmr_leaf.parent_number_and_hash,
+				mmr_root,
+			},
+		);
+		ImportedBlockNumbers::<T, I>::insert(block_number_index, block_number);
+		ImportedCommitmentsInfo::<T, I>::put(ImportedCommitmentsInfoData {
+			best_block_number: block_number,
+			next_block_number_index: (block_number_index + 1) % T::CommitmentsToKeep::get(),
+		});
+		if let Ok(old_block_number) = to_prune {
+			log::debug!(
+				target: LOG_TARGET,
+				"Pruning commitment for old block: {:?}.",
+				old_block_number
+			);
+			ImportedCommitments::<T, I>::remove(old_block_number);
+		}
+
+		log::info!(target: LOG_TARGET, "Successfully imported commitment for block {:?}", block_number);
+		Pallet::<T, I>::deposit_event(Event::CommitmentImported { block_number, mmr_root });
+
+		Ok(is_authority_set_changed)
+	}
+
+	/// Insert proved header data into the storage, pruning the oldest header if required.
+	pub(super) fn insert_header<T: Config<I>, I: 'static>(
+		header: &BridgedHeader<T, I>,
+		hash: BridgedBlockHash<T, I>,
+	) {
+		let index = ImportedHeaderHashesPointer::<T, I>::get();
+		let to_prune = ImportedHeaderHashes::<T, I>::try_get(index);
+		ImportedHeaders::<T, I>::insert(hash, header.build());
+		ImportedHeaderHashes::<T, I>::insert(index, hash);
+		BestImportedHeaderNumber::<T, I>::put(*header.number());
+
+		ImportedHeaderHashesPointer::<T, I>::put((index + 1) % T::HeadersToKeep::get());
+		if let Ok(old_hash) = to_prune {
+			log::debug!(target: LOG_TARGET, "Pruning old header: {:?}.", old_hash);
+			ImportedHeaders::<T, I>::remove(old_hash);
+		}
+	}
+}
+
+/// Bridge BEEFY pallet as header chain.
+pub type BeefyChainHeaders<T, I> = Pallet<T, I>;
+
+impl<T: Config<I>, I: 'static> HeaderChain<BridgedChain<T, I>> for BeefyChainHeaders<T, I> {
+	fn finalized_header_state_root(
+		header_hash: HashOf<BridgedChain<T, I>>,
+	) -> Option<HashOf<BridgedChain<T, I>>> {
+		ImportedHeaders::<T, I>::get(header_hash).map(|h| h.state_root)
+	}
 }
 
 #[cfg(test)]
@@ -416,18 +864,27 @@ mod tests {
 	use super::*;
 	use bp_runtime::{BasicOperatingMode, OwnedBridgeModuleError};
 	use bp_test_utils::generate_owned_bridge_module_tests;
-	use frame_support::{assert_noop, assert_ok, traits::Get};
+	use frame_support::{
+		assert_noop, assert_ok,
+		traits::{Get, Randomness},
+	};
 	use mock::*;
 	use mock_chain::*;
 	use sp_beefy::mmr::BeefyAuthoritySet;
-	use sp_runtime::DispatchError;
+	use sp_runtime::{traits::Hash, DispatchError};
 
 	fn next_block() {
 		use frame_support::traits::OnInitialize;
 
-		let current_number = frame_system::Pallet::<TestRuntime>::block_number();
-		frame_system::Pallet::<TestRuntime>::set_block_number(current_number + 1);
-		let _ = Pallet::<TestRuntime>::on_initialize(current_number);
+		let next_number = frame_system::Pallet::<TestRuntime>::block_number() + 1;
+		frame_system::Pallet::<TestRuntime>::set_block_number(next_number);
+		let _ = Pallet::<TestRuntime>::on_initialize(next_number);
+	}
+
+	fn advance_blocks(count: u64) {
+		for _ in 0..count {
+			next_block();
+		}
 	}
 
 	fn import_header_chain(headers: Vec<HeaderAndCommitment>) {
@@ -564,6 +1021,76 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn submit_commitment_refunds_weight_of_unverified_signatures() {
+		run_test_with_initialize(16, || {
+			// all validators have signed the commitment, but we only need `2/3+1` signatures
+			let mut header = ChainBuilder::new(16).append_finalized_header().to_header();
+			header.customize_commitment(|_| {}, &validator_pairs(0, 16), 16);
+
+			let pre_dispatch_weight = <TestRuntime as Config>::WeightInfo::submit_commitment_weight(
+				16,
+				16,
+				header.leaf_proof.items.len() as u32,
+			);
+			let expected_actual_weight = <TestRuntime as Config>::WeightInfo::submit_commitment(
+				16,
+				header.leaf_proof.items.len() as u32,
+			);
+
+			let result = import_commitment(header);
+			assert_ok!(result);
+			let actual_weight = result.unwrap().actual_weight.unwrap();
+			assert_eq!(actual_weight, expected_actual_weight);
+			assert!(actual_weight.ref_time() < pre_dispatch_weight.ref_time());
+		})
+	}
+
+	#[test]
+	fn submit_commitment_is_free_for_authority_set_handoffs() {
+		run_test_with_initialize(3, || {
+			let chain = ChainBuilder::new(3)
+				.append_finalized_header() // 1
+				.append_handoff_header(5); // 2
+
+			next_block();
+			let result = import_commitment(chain.header(1));
+			assert_ok!(result);
+			assert_eq!(result.unwrap().pays_fee, frame_support::dispatch::Pays::Yes);
+			assert_eq!(
+				frame_system::Pallet::<TestRuntime>::events()
+					.into_iter()
+					.map(|r| r.event)
+					.collect::<Vec<_>>(),
+				vec![TestEvent::Beefy(Event::CommitmentImported {
+					block_number: 1,
+					mmr_root: chain.header(1).mmr_root,
+				})],
+			);
+
+			next_block();
+			frame_system::Pallet::<TestRuntime>::reset_events();
+			let result = import_commitment(chain.header(2));
+			assert_ok!(result);
+			assert_eq!(result.unwrap().pays_fee, frame_support::dispatch::Pays::No);
+			assert_eq!(
+				frame_system::Pallet::<TestRuntime>::events()
+					.into_iter()
+					.map(|r| r.event)
+					.collect::<Vec<_>>(),
+				vec![
+					TestEvent::Beefy(Event::AuthoritySetChanged {
+						authority_set: chain.header(2).leaf.beefy_next_authority_set,
+					}),
+					TestEvent::Beefy(Event::CommitmentImported {
+						block_number: 2,
+						mmr_root: chain.header(2).mmr_root,
+					}),
+				],
+			);
+		})
+	}
+
 	#[test]
 	fn commitment_pruning_works() {
 		run_test_with_initialize(3, || {
@@ -644,5 +1171,441 @@ mod tests {
 		});
 	}
 
+	/// Claim commitment signers and return signatures of validators, selected using the
+	/// randomness that has become known after the claim.
+	fn claim_and_select_signatures(
+		header: &HeaderAndCommitment,
+		count: u32,
+	) -> TestBridgedSignaturesSubset {
+		let signers = header.signatures_subset(Default::default(), count).signers;
+		assert_ok!(claim_commitment(header, signers));
+		advance_blocks(<TestRuntime as Config>::ClaimDelay::get());
+		header.signatures_subset(claimed_signers_seed(), count)
+	}
+
+	#[test]
+	fn submit_commitment_with_signatures_subset_works() {
+		run_test_with_initialize(16, || {
+			let chain = ChainBuilder::new(16)
+				.append_finalized_header() // 1
+				.append_handoff_header(8) // 2
+				.append_finalized_header(); // 3
+
+			for number in 1..=3 {
+				next_block();
+				let header = chain.header(number);
+				let signatures = claim_and_select_signatures(&header, 4);
+				assert_eq!(signatures.signatures.len(), 4);
+				assert_ok!(import_commitment_with_signatures_subset(header, signatures));
+				assert!(!CommitmentClaims::<TestRuntime>::contains_key(1));
+			}
+
+			assert_eq!(ImportedCommitmentsInfo::<TestRuntime>::get().unwrap().best_block_number, 3);
+			assert_eq!(CurrentAuthoritySetInfo::<TestRuntime>::get().id, 1);
+			assert_eq!(CurrentAuthoritySetInfo::<TestRuntime>::get().len, 8);
+		})
+	}
+
+	#[test]
+	fn submit_commitment_claim_fails_if_signers_bitfield_is_invalid() {
+		run_test_with_initialize(16, || {
+			let header = ChainBuilder::new(16).append_finalized_header().to_header();
+			let mut signers = header.signatures_subset(Default::default(), 4).signers;
+			signers.push(0);
+
+			assert_noop!(
+				claim_commitment(&header, signers),
+				Error::<TestRuntime, ()>::InvalidSignersBitfield,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_commitment_claim_fails_if_signers_bitfield_is_too_large() {
+		run_test_with_initialize(16, || {
+			let header = ChainBuilder::new(16).append_finalized_header().to_header();
+			let max_bitfield_len = bp_beefy::max_signers_bitfield_len(
+				<TestRuntime as Config>::MaxBridgedAuthorities::get(),
+			);
+
+			assert_noop!(
+				claim_commitment(&header, vec![0xFF; max_bitfield_len as usize + 1]),
+				Error::<TestRuntime, ()>::TooLargeSignersBitfield,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_commitment_claim_fails_if_not_enough_signers() {
+		run_test_with_initialize(16, || {
+			let header = ChainBuilder::new(16).append_finalized_header().to_header();
+
+			assert_noop!(
+				claim_commitment(&header, bp_beefy::signers_to_bitfield(0..10, 16)),
+				Error::<TestRuntime, ()>::NotEnoughCorrectSignatures,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_commitment_with_signatures_subset_fails_if_there_is_no_claim() {
+		run_test_with_initialize(16, || {
+			let header = ChainBuilder::new(16).append_finalized_header().to_header();
+			let signatures = header.signatures_subset(Default::default(), 4);
+
+			assert_noop!(
+				import_commitment_with_signatures_subset(header, signatures),
+				Error::<TestRuntime, ()>::UnknownCommitmentClaim,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_commitment_with_signatures_subset_fails_if_signers_are_not_claimed() {
+		run_test_with_initialize(16, || {
+			let header = ChainBuilder::new(16).append_finalized_header().to_header();
+			let mut signatures = claim_and_select_signatures(&header, 4);
+			signatures.signers[0] ^= 1;
+
+			assert_noop!(
+				import_commitment_with_signatures_subset(header, signatures),
+				Error::<TestRuntime, ()>::InvalidCommitmentClaim,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_commitment_with_signatures_subset_fails_if_randomness_is_not_yet_known() {
+		run_test_with_initialize(16, || {
+			let header = ChainBuilder::new(16).append_finalized_header().to_header();
+			let signatures = header.signatures_subset(Default::default(), 4);
+			assert_ok!(claim_commitment(&header, signatures.signers.clone()));
+			advance_blocks(<TestRuntime as Config>::ClaimDelay::get() - 1);
+
+			assert_noop!(
+				import_commitment_with_signatures_subset(header, signatures),
+				Error::<TestRuntime, ()>::CommitmentClaimIsTooRecent,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_commitment_with_signatures_subset_fails_if_claim_is_expired() {
+		run_test_with_initialize(16, || {
+			let header = ChainBuilder::new(16).append_finalized_header().to_header();
+			let signatures = claim_and_select_signatures(&header, 4);
+			advance_blocks(<TestRuntime as Config>::ClaimLifetime::get() + 1);
+
+			assert_noop!(
+				import_commitment_with_signatures_subset(header, signatures),
+				Error::<TestRuntime, ()>::CommitmentClaimIsExpired,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_commitment_with_signatures_subset_uses_randomness_of_fixed_block() {
+		run_test_with_initialize(16, || {
+			let header = ChainBuilder::new(16).append_finalized_header().to_header();
+			let signatures = claim_and_select_signatures(&header, 4);
+			let seed = claimed_signers_seed();
+
+			// the randomness is updated at every block, but the claim may only be completed using
+			// the randomness of the fixed block
+			next_block();
+			assert_eq!(claimed_signers_seed(), seed);
+
+			// so the submitter can't select signers using the current randomness
+			let claim = CommitmentClaims::<TestRuntime>::get(1).unwrap();
+			let (current_randomness, _) = TestRandomness::random(CLAIM_RANDOMNESS_SUBJECT);
+			let current_seed = BridgedBeefyCommitmentHasher::<TestRuntime, ()>::hash_of(&(
+				current_randomness,
+				claim.claim_hash,
+			));
+			let current_signatures = header.signatures_subset(current_seed, 4);
+			assert_ne!(current_signatures.signatures, signatures.signatures);
+			assert_noop!(
+				import_commitment_with_signatures_subset(header.clone(), current_signatures),
+				Error::<TestRuntime, ()>::UnexpectedCommitmentSigner,
+			);
+
+			assert_ok!(import_commitment_with_signatures_subset(header, signatures));
+		})
+	}
+
+	#[test]
+	fn claims_randomness_is_pruned_when_claims_are_expired() {
+		run_test_with_initialize(16, || {
+			let header = ChainBuilder::new(16).append_finalized_header().to_header();
+			let signers = header.signatures_subset(Default::default(), 4).signers;
+			assert_ok!(claim_commitment(&header, signers));
+
+			let randomness_at = <TestRuntime as Config>::ClaimDelay::get();
+			assert_eq!(ClaimsRandomness::<TestRuntime>::get(randomness_at), None);
+			assert!(ClaimsRandomness::<TestRuntime>::contains_key(randomness_at));
+
+			advance_blocks(randomness_at);
+			assert!(ClaimsRandomness::<TestRuntime>::get(randomness_at).is_some());
+
+			advance_blocks(<TestRuntime as Config>::ClaimLifetime::get() + 1);
+			assert!(!ClaimsRandomness::<TestRuntime>::contains_key(randomness_at));
+		})
+	}
+
+	#[test]
+	fn submit_commitment_with_signatures_subset_fails_if_too_few_signatures_are_provided() {
+		run_test_with_initialize(16, || {
+			let header = ChainBuilder::new(16).append_finalized_header().to_header();
+			claim_and_select_signatures(&header, 4);
+			let signatures = header.signatures_subset(claimed_signers_seed(), 3);
+			assert_eq!(signatures.signatures.len(), 3);
+
+			assert_noop!(
+				import_commitment_with_signatures_subset(header, signatures),
+				Error::<TestRuntime, ()>::InvalidCommitmentSignaturesLen,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_commitment_with_signatures_subset_fails_if_unexpected_signer_is_provided() {
+		run_test_with_initialize(16, || {
+			let header = ChainBuilder::new(16).append_finalized_header().to_header();
+			let mut signatures = claim_and_select_signatures(&header, 4);
+			signatures.signatures.swap(0, 1);
+
+			assert_noop!(
+				import_commitment_with_signatures_subset(header, signatures),
+				Error::<TestRuntime, ()>::UnexpectedCommitmentSigner,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_commitment_with_signatures_subset_fails_if_validator_proof_is_invalid() {
+		run_test_with_initialize(16, || {
+			let header = ChainBuilder::new(16).append_finalized_header().to_header();
+			let mut signatures = claim_and_select_signatures(&header, 4);
+			signatures.signatures[0].proof.pop();
+
+			assert_noop!(
+				import_commitment_with_signatures_subset(header, signatures),
+				Error::<TestRuntime, ()>::InvalidValidatorProof,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_commitment_with_signatures_subset_fails_if_validator_proof_is_too_long() {
+		run_test_with_initialize(16, || {
+			let header = ChainBuilder::new(16).append_finalized_header().to_header();
+			let mut signatures = claim_and_select_signatures(&header, 4);
+			let max_proof_len = bp_beefy::max_validator_proof_len(
+				<TestRuntime as Config>::MaxBridgedAuthorities::get(),
+			);
+			let proof = &mut signatures.signatures[0].proof;
+			proof.resize(max_proof_len as usize + 1, proof[0]);
+
+			assert_noop!(
+				import_commitment_with_signatures_subset(header, signatures),
+				Error::<TestRuntime, ()>::TooLongValidatorProof,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_commitment_with_signatures_subset_fails_if_signature_is_invalid() {
+		run_test_with_initialize(16, || {
+			let header = ChainBuilder::new(16).append_finalized_header().to_header();
+			let mut signatures = claim_and_select_signatures(&header, 4);
+			let other_signature = signatures.signatures[1].signature.clone();
+			signatures.signatures[0].signature = other_signature;
+
+			assert_noop!(
+				import_commitment_with_signatures_subset(header, signatures),
+				Error::<TestRuntime, ()>::NotEnoughCorrectSignatures,
+			);
+		})
+	}
+
+	fn submit_header(
+		commitment_block_number: TestBridgedBlockNumber,
+		header: TestBridgedHeader,
+		leaf_header: HeaderAndCommitment,
+	) -> sp_runtime::DispatchResult {
+		Pallet::<TestRuntime>::submit_header(
+			RuntimeOrigin::signed(1),
+			commitment_block_number,
+			Box::new(header),
+			Box::new(leaf_header.leaf),
+			leaf_header.leaf_proof,
+		)
+	}
+
+	#[test]
+	fn submit_header_works() {
+		run_test_with_initialize(3, || {
+			let chain = ChainBuilder::new(3).append_finalized_headers(3);
+			assert_ok!(import_commitment(chain.header(3)));
+
+			// leaf of header#3 references header#2
+			let header = chain.header(2).header;
+			assert_ok!(submit_header(3, header.clone(), chain.header(3)));
+
+			assert_eq!(
+				BeefyChainHeaders::<TestRuntime, ()>::finalized_header_state_root(header.hash()),
+				Some(*header.state_root()),
+			);
+		})
+	}
+
+	#[test]
+	fn submit_header_fails_if_commitment_is_unknown() {
+		run_test_with_initialize(3, || {
+			let chain = ChainBuilder::new(3).append_finalized_headers(3);
+			assert_ok!(import_commitment(chain.header(3)));
+
+			assert_noop!(
+				submit_header(2, chain.header(2).header, chain.header(3)),
+				Error::<TestRuntime, ()>::UnknownCommitment,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_header_fails_if_leaf_references_other_header() {
+		run_test_with_initialize(3, || {
+			let chain = ChainBuilder::new(3).append_finalized_headers(3);
+			assert_ok!(import_commitment(chain.header(3)));
+
+			assert_noop!(
+				submit_header(3, chain.header(1).header, chain.header(3)),
+				Error::<TestRuntime, ()>::HeaderMismatch,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_header_fails_if_mmr_proof_is_invalid() {
+		run_test_with_initialize(3, || {
+			let chain = ChainBuilder::new(3).append_finalized_headers(3);
+			assert_ok!(import_commitment(chain.header(3)));
+
+			// proof of leaf#2 is built against MMR root at header#2
+			assert_noop!(
+				submit_header(3, chain.header(1).header, chain.header(2)),
+				Error::<TestRuntime, ()>::MmrProofVerificationFailed,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_header_fails_if_header_is_already_imported() {
+		run_test_with_initialize(3, || {
+			let chain = ChainBuilder::new(3).append_finalized_headers(3);
+			assert_ok!(import_commitment(chain.header(3)));
+			assert_ok!(submit_header(3, chain.header(2).header, chain.header(3)));
+
+			assert_noop!(
+				submit_header(3, chain.header(2).header, chain.header(3)),
+				Error::<TestRuntime, ()>::HeaderAlreadyImported,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_header_fails_if_header_is_not_better_than_best_header() {
+		run_test_with_initialize(3, || {
+			let chain = ChainBuilder::new(3).append_finalized_headers(3);
+			assert_ok!(import_commitment(chain.header(2)));
+			next_block();
+			assert_ok!(import_commitment(chain.header(3)));
+			assert_ok!(submit_header(3, chain.header(2).header, chain.header(3)));
+			assert_eq!(BestImportedHeaderNumber::<TestRuntime>::get(), Some(2));
+
+			// leaf#2 is proved against the commitment#2, but header#1 is older than the best
+			// proved header
+			assert_noop!(
+				submit_header(2, chain.header(1).header, chain.header(2)),
+				Error::<TestRuntime, ()>::OldHeader,
+			);
+		})
+	}
+
+	#[test]
+	fn submit_header_fails_if_there_are_too_many_requests() {
+		run_test_with_initialize(3, || {
+			let chain = ChainBuilder::new(3).append_finalized_headers(3);
+			assert_ok!(import_commitment(chain.header(3)));
+			RequestCount::<TestRuntime>::put(<TestRuntime as Config>::MaxRequests::get());
+
+			assert_noop!(
+				submit_header(3, chain.header(2).header, chain.header(3)),
+				Error::<TestRuntime, ()>::TooManyRequests,
+			);
+		})
+	}
+
+	#[test]
+	fn old_headers_can_not_evict_proved_headers() {
+		run_test_with_initialize(3, || {
+			let headers_to_keep = <TestRuntime as Config<()>>::HeadersToKeep::get();
+			let best_number = headers_to_keep as TestBridgedBlockNumber;
+			let chain = ChainBuilder::new(3).append_finalized_headers(best_number as usize + 1);
+			for number in 2..best_number + 2 {
+				next_block();
+				assert_ok!(import_commitment(chain.header(number)));
+			}
+			assert_ok!(submit_header(
+				best_number + 1,
+				chain.header(best_number).header,
+				chain.header(best_number + 1)
+			));
+			let pointer = ImportedHeaderHashesPointer::<TestRuntime>::get();
+
+			// every historic header has a valid MMR proof, but it is rejected and doesn't occupy
+			// a slot in the ring buffer
+			for number in 1..best_number {
+				assert_noop!(
+					submit_header(
+						number + 1,
+						chain.header(number).header,
+						chain.header(number + 1)
+					),
+					Error::<TestRuntime, ()>::OldHeader,
+				);
+			}
+			assert_eq!(ImportedHeaderHashesPointer::<TestRuntime>::get(), pointer);
+			assert!(ImportedHeaders::<TestRuntime>::contains_key(
+				chain.header(best_number).header.hash()
+			));
+		})
+	}
+
+	#[test]
+	fn proved_headers_are_pruned() {
+		run_test_with_initialize(3, || {
+			let headers_to_keep = <TestRuntime as Config<()>>::HeadersToKeep::get();
+			let chain = ChainBuilder::new(3).append_finalized_headers(headers_to_keep as usize + 2);
+			for number in 1..headers_to_keep as TestBridgedBlockNumber + 2 {
+				advance_blocks(2);
+				assert_ok!(import_commitment(chain.header(number + 1)));
+				assert_ok!(submit_header(
+					number + 1,
+					chain.header(number).header,
+					chain.header(number + 1)
+				));
+			}
+
+			// header#1 has been pruned
+			assert!(!ImportedHeaders::<TestRuntime>::contains_key(chain.header(1).header.hash()));
+			for number in 2..headers_to_keep as TestBridgedBlockNumber + 2 {
+				assert!(ImportedHeaders::<TestRuntime>::contains_key(
+					chain.header(number).header.hash()
+				));
+			}
+		})
+	}
+
 	generate_owned_bridge_module_tests!(BasicOperatingMode::Normal, BasicOperatingMode::Halted);
 }
@@ -16,15 +16,21 @@
 
 use crate as beefy;
 use crate::{
-	utils::get_authorities_mmr_root, BridgedBeefyAuthoritySet, BridgedBeefyAuthoritySetInfo,
-	BridgedBeefyCommitmentHasher, BridgedBeefyMmrLeafExtra, BridgedBeefySignedCommitment,
-	BridgedMmrHash, BridgedMmrHashing, BridgedMmrProof,
+	utils::{commitment_claim_seed, get_authorities_mmr_root},
+	BridgedBeefyAuthoritySet, BridgedBeefyAuthoritySetInfo, BridgedBeefyCommitmentHash,
+	BridgedBeefyCommitmentHasher, BridgedBeefyMmrLeafExtra, BridgedBeefySignaturesSubset,
+	BridgedBeefySignedCommitment, BridgedMmrHash, BridgedMmrHashing, BridgedMmrProof,
+	CommitmentClaims,
 };
 
 use bp_beefy::{BeefyValidatorSignatureOf, ChainWithBeefy, Commitment, MmrDataOrHash};
 use bp_runtime::{BasicOperatingMode, Chain};
 use codec::Encode;
-use frame_support::{construct_runtime, parameter_types, traits::ConstU64, weights::Weight};
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU64, Randomness},
+	weights::Weight,
+};
 use sp_core::{sr25519::Signature, Pair};
 use sp_runtime::{
 	testing::{Header, H256},
@@ -37,14 +43,17 @@ use sp_core::crypto::Wraps;
 use sp_runtime::traits::Keccak256;
 
 pub type TestAccountId = u64;
+pub type TestEvent = RuntimeEvent;
 pub type TestBridgedBlockNumber = u64;
 pub type TestBridgedBlockHash = H256;
 pub type TestBridgedHeader = Header;
 pub type TestBridgedAuthoritySetInfo = BridgedBeefyAuthoritySetInfo<TestRuntime, ()>;
 pub type TestBridgedValidatorSet = BridgedBeefyAuthoritySet<TestRuntime, ()>;
 pub type TestBridgedCommitment = BridgedBeefySignedCommitment<TestRuntime, ()>;
+pub type TestBridgedSignaturesSubset = BridgedBeefySignaturesSubset<TestRuntime, ()>;
 pub type TestBridgedValidatorSignature = BeefyValidatorSignatureOf<TestBridgedChain>;
 pub type TestBridgedCommitmentHasher = BridgedBeefyCommitmentHasher<TestRuntime, ()>;
+pub type TestBridgedCommitmentHash = BridgedBeefyCommitmentHash<TestRuntime, ()>;
 pub type TestBridgedMmrHashing = BridgedMmrHashing<TestRuntime, ()>;
 pub type TestBridgedMmrHash = BridgedMmrHash<TestRuntime, ()>;
 pub type TestBridgedBeefyMmrLeafExtra = BridgedBeefyMmrLeafExtra<TestRuntime, ()>;
@@ -67,7 +76,7 @@ construct_runtime! {
 		UncheckedExtrinsic = TestUncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		Beefy: beefy::{Pallet},
+		Beefy: beefy::{Pallet, Call, Event<T>},
 	}
 }
 
@@ -87,7 +96,7 @@ impl frame_system::Config for TestRuntime {
 	type AccountId = TestAccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type Header = Header;
-	type RuntimeEvent = ();
+	type RuntimeEvent = RuntimeEvent;
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
@@ -105,9 +114,27 @@ impl frame_system::Config for TestRuntime {
 }
 
 impl beefy::Config for TestRuntime {
+	type RuntimeEvent = RuntimeEvent;
 	type MaxRequests = frame_support::traits::ConstU32<16>;
 	type BridgedChain = TestBridgedChain;
 	type CommitmentsToKeep = frame_support::traits::ConstU32<16>;
+	type HeadersToKeep = frame_support::traits::ConstU32<16>;
+	type MinSignaturesInSubset = frame_support::traits::ConstU32<4>;
+	type MaxBridgedAuthorities = frame_support::traits::ConstU32<32>;
+	type Randomness = TestRandomness;
+	type ClaimDelay = ConstU64<2>;
+	type ClaimLifetime = ConstU64<4>;
+	type WeightInfo = ();
+}
+
+/// Randomness, that is updated at the beginning of every block.
+pub struct TestRandomness;
+
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		let block_number = frame_system::Pallet::<TestRuntime>::block_number();
+		(BlakeTwo256::hash_of(&(subject, block_number)), block_number)
+	}
 }
 
 #[derive(Debug)]
@@ -166,10 +193,50 @@ pub fn run_test_with_initialize<T>(initial_validators_count: u32, test: impl FnO
 	})
 }
 
+/// Claim that the commitment has been signed by given signers.
+pub fn claim_commitment(
+	header: &crate::mock_chain::HeaderAndCommitment,
+	signers: Vec<u8>,
+) -> sp_runtime::DispatchResult {
+	crate::Pallet::<TestRuntime>::submit_commitment_claim(
+		RuntimeOrigin::signed(1),
+		header
+			.commitment
+			.as_ref()
+			.expect("thou shall not call claim_commitment on header without commitment")
+			.commitment
+			.clone(),
+		signers,
+	)
+}
+
+/// Returns seed that is used to select signers of the active commitment claim.
+pub fn claimed_signers_seed() -> TestBridgedCommitmentHash {
+	let claim = CommitmentClaims::<TestRuntime>::get(1).expect("claim has been submitted");
+	commitment_claim_seed::<TestRuntime, ()>(&claim).expect("randomness is known")
+}
+
+/// Import given commitment, using the signatures subset.
+pub fn import_commitment_with_signatures_subset(
+	header: crate::mock_chain::HeaderAndCommitment,
+	signatures: TestBridgedSignaturesSubset,
+) -> frame_support::dispatch::DispatchResultWithPostInfo {
+	crate::Pallet::<TestRuntime>::submit_commitment_with_signatures_subset(
+		RuntimeOrigin::signed(1),
+		header
+			.commitment
+			.expect("thou shall not call import_commitment on header without commitment")
+			.commitment,
+		signatures,
+		Box::new(header.leaf),
+		header.leaf_proof,
+	)
+}
+
 /// Import given commitment.
 pub fn import_commitment(
 	header: crate::mock_chain::HeaderAndCommitment,
-) -> sp_runtime::DispatchResult {
+) -> frame_support::dispatch::DispatchResultWithPostInfo {
 	crate::Pallet::<TestRuntime>::submit_commitment(
 		RuntimeOrigin::signed(1),
 		header
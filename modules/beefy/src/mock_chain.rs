@@ -19,20 +19,24 @@
 use crate::{
 	mock::{
 		sign_commitment, validator_pairs, BeefyPair, TestBridgedBlockNumber, TestBridgedCommitment,
-		TestBridgedHeader, TestBridgedMmrHash, TestBridgedMmrHashing, TestBridgedMmrNode,
-		TestBridgedMmrProof, TestBridgedRawMmrLeaf, TestBridgedValidatorSet,
+		TestBridgedCommitmentHash, TestBridgedCommitmentHasher, TestBridgedHeader,
+		TestBridgedMmrHash, TestBridgedMmrHashing, TestBridgedMmrNode, TestBridgedMmrProof,
+		TestBridgedRawMmrLeaf, TestBridgedSignaturesSubset, TestBridgedValidatorSet,
 		TestBridgedValidatorSignature, TestRuntime,
 	},
 	utils::get_authorities_mmr_root,
 };
 
-use bp_beefy::{BeefyPayload, Commitment, ValidatorSetId, MMR_ROOT_PAYLOAD_ID};
+use bp_beefy::{
+	merkle_proof, select_signers, signers_to_bitfield, BeefyPayload, Commitment, SignaturesSubset,
+	ValidatorSetId, ValidatorSignatureWithProof, MMR_ROOT_PAYLOAD_ID,
+};
 use codec::Encode;
 use pallet_mmr::NodeIndex;
 use rand::Rng;
 use sp_beefy::mmr::{BeefyNextAuthoritySet, MmrLeafVersion};
 use sp_core::Pair;
-use sp_runtime::traits::{Hash, Header as HeaderT};
+use sp_runtime::traits::{Convert, Hash, Header as HeaderT};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
@@ -55,6 +59,48 @@ impl HeaderAndCommitment {
 		}
 	}
 
+	/// Returns signatures of `count` validators, selected from the commitment signers using
+	/// given seed.
+	pub fn signatures_subset(
+		&self,
+		seed: TestBridgedCommitmentHash,
+		count: u32,
+	) -> TestBridgedSignaturesSubset {
+		let signed_commitment = self.commitment.as_ref().expect("header has commitment");
+		let signers = signed_commitment
+			.signatures
+			.iter()
+			.enumerate()
+			.filter(|(_, signature)| signature.is_some())
+			.map(|(index, _)| index as u32)
+			.collect::<Vec<_>>();
+		let selected_signers =
+			select_signers::<TestBridgedCommitmentHasher>(seed, signers.clone(), count);
+
+		let validators = self.validator_set.validators();
+		let merkle_leafs = validators
+			.iter()
+			.cloned()
+			.map(pallet_beefy_mmr::BeefyEcdsaToEthereum::convert)
+			.collect::<Vec<_>>();
+		SignaturesSubset {
+			signers: signers_to_bitfield(signers, validators.len() as u32),
+			signatures: selected_signers
+				.into_iter()
+				.map(|index| ValidatorSignatureWithProof {
+					validator_index: index,
+					validator_id: validators[index as usize].clone(),
+					signature: signed_commitment.signatures[index as usize].clone().unwrap(),
+					proof: merkle_proof::<TestBridgedMmrHashing, _, _>(
+						merkle_leafs.clone(),
+						index as usize,
+					)
+					.proof,
+				})
+				.collect(),
+		}
+	}
+
 	pub fn customize_commitment(
 		&mut self,
 		f: impl FnOnce(&mut Commitment<TestBridgedBlockNumber>),
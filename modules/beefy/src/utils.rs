@@ -1,12 +1,18 @@
 use crate::{
 	BridgedBeefyAuthorityId, BridgedBeefyAuthoritySet, BridgedBeefyAuthoritySetInfo,
-	BridgedBeefyMmrLeaf, BridgedBeefySignedCommitment, BridgedChain, BridgedMmrHash,
-	BridgedMmrHashing, BridgedMmrProof, Config, Error, LOG_TARGET,
+	BridgedBeefyCommitment, BridgedBeefyCommitmentHash, BridgedBeefyCommitmentHasher,
+	BridgedBeefyMmrLeaf, BridgedBeefySignaturesSubset, BridgedBeefySignedCommitment, BridgedChain,
+	BridgedMmrHash, BridgedMmrHashing, BridgedMmrProof, ClaimsRandomness, CommitmentClaimOf,
+	Config, Error, LOG_TARGET,
+};
+use bp_beefy::{
+	max_signers_bitfield_len, max_validator_proof_len, merkle_root, select_signers,
+	signers_from_bitfield, verify_merkle_proof, verify_mmr_leaves_proof, BeefyAuthorityId,
+	MmrDataOrHash,
 };
-use bp_beefy::{merkle_root, verify_mmr_leaves_proof, BeefyAuthorityId, MmrDataOrHash};
 use codec::Encode;
-use frame_support::ensure;
-use sp_runtime::traits::{Convert, Hash};
+use frame_support::{ensure, traits::Get};
+use sp_runtime::traits::{Convert, Hash, Saturating};
 use sp_std::{vec, vec::Vec};
 
 type BridgedMmrDataOrHash<T, I> = MmrDataOrHash<BridgedMmrHashing<T, I>, BridgedBeefyMmrLeaf<T, I>>;
@@ -52,14 +58,17 @@ fn verify_authority_set<T: Config<I>, I: 'static>(
 ///
 /// We're using 'conservative' approach here, where signatures of `2/3+1` validators are
 /// required..
-pub(crate) fn signatures_required<T: Config<I>, I: 'static>(validators_len: usize) -> usize {
+pub(crate) fn signatures_required(validators_len: usize) -> usize {
 	validators_len - validators_len.saturating_sub(1) / 3
 }
 
+/// Verify commitment signatures.
+///
+/// Returns number of signatures that have been verified.
 fn verify_signatures<T: Config<I>, I: 'static>(
 	commitment: &BridgedBeefySignedCommitment<T, I>,
 	authority_set: &BridgedBeefyAuthoritySet<T, I>,
-) -> Result<(), Error<T, I>> {
+) -> Result<u32, Error<T, I>> {
 	ensure!(
 		commitment.signatures.len() == authority_set.len(),
 		Error::<T, I>::InvalidCommitmentSignaturesLen
@@ -67,11 +76,13 @@ fn verify_signatures<T: Config<I>, I: 'static>(
 
 	// Ensure that the commitment was signed by enough authorities.
 	let msg = commitment.commitment.encode();
-	let mut missing_signatures = signatures_required::<T, I>(authority_set.len());
+	let mut missing_signatures = signatures_required(authority_set.len());
+	let mut verified_signatures = 0u32;
 	for (idx, (authority, maybe_sig)) in
 		authority_set.validators().iter().zip(commitment.signatures.iter()).enumerate()
 	{
 		if let Some(sig) = maybe_sig {
+			verified_signatures += 1;
 			if authority.verify(sig, &msg) {
 				missing_signatures = missing_signatures.saturating_sub(1);
 				if missing_signatures == 0 {
@@ -90,25 +101,26 @@ fn verify_signatures<T: Config<I>, I: 'static>(
 	}
 	ensure!(missing_signatures == 0, Error::<T, I>::NotEnoughCorrectSignatures);
 
-	Ok(())
+	Ok(verified_signatures)
 }
 
 /// Extract MMR root from commitment payload.
 fn extract_mmr_root<T: Config<I>, I: 'static>(
-	commitment: &BridgedBeefySignedCommitment<T, I>,
+	commitment: &BridgedBeefyCommitment<T, I>,
 ) -> Result<BridgedMmrHash<T, I>, Error<T, I>> {
 	commitment
-		.commitment
 		.payload
 		.get_decoded(&bp_beefy::MMR_ROOT_PAYLOAD_ID)
 		.ok_or(Error::MmrRootMissingFromCommitment)
 }
 
+/// Verify commitment and return MMR root from its payload, along with the number of signatures
+/// that have been verified.
 pub(crate) fn verify_commitment<T: Config<I>, I: 'static>(
 	commitment: &BridgedBeefySignedCommitment<T, I>,
 	authority_set_info: &BridgedBeefyAuthoritySetInfo<T, I>,
 	authority_set: &BridgedBeefyAuthoritySet<T, I>,
-) -> Result<BridgedMmrHash<T, I>, Error<T, I>> {
+) -> Result<(BridgedMmrHash<T, I>, u32), Error<T, I>> {
 	// Ensure that the commitment is signed by the best known BEEFY validator set.
 	ensure!(
 		commitment.commitment.validator_set_id == authority_set_info.id,
@@ -120,7 +132,142 @@ pub(crate) fn verify_commitment<T: Config<I>, I: 'static>(
 	);
 
 	verify_authority_set(authority_set_info, authority_set)?;
-	verify_signatures(commitment, authority_set)?;
+	let verified_signatures = verify_signatures(commitment, authority_set)?;
+
+	extract_mmr_root(&commitment.commitment).map(|mmr_root| (mmr_root, verified_signatures))
+}
+
+/// Verify claimed signers of the commitment and return indices of signers.
+///
+/// The signers bitfield must be valid for the current authority set and there must be enough
+/// signers to finalize the commitment.
+pub(crate) fn verify_commitment_signers<T: Config<I>, I: 'static>(
+	commitment: &BridgedBeefyCommitment<T, I>,
+	signers: &[u8],
+	authority_set_info: &BridgedBeefyAuthoritySetInfo<T, I>,
+) -> Result<Vec<u32>, Error<T, I>> {
+	// Ensure that the commitment is signed by the best known BEEFY validator set.
+	ensure!(
+		commitment.validator_set_id == authority_set_info.id,
+		Error::<T, I>::InvalidCommitmentValidatorSetId
+	);
+
+	// Ensure that enough validators are claimed to sign the commitment.
+	ensure!(
+		signers.len() <= max_signers_bitfield_len(T::MaxBridgedAuthorities::get()) as usize,
+		Error::<T, I>::TooLargeSignersBitfield
+	);
+	let signers = signers_from_bitfield(signers, authority_set_info.len)
+		.ok_or(Error::<T, I>::InvalidSignersBitfield)?;
+	ensure!(
+		signers.len() >= signatures_required(authority_set_info.len as usize),
+		Error::<T, I>::NotEnoughCorrectSignatures
+	);
+
+	Ok(signers)
+}
+
+/// Returns hash of the commitment signers claim.
+pub(crate) fn commitment_claim_hash<T: Config<I>, I: 'static>(
+	commitment: &BridgedBeefyCommitment<T, I>,
+	signers: &[u8],
+) -> T::Hash {
+	T::Hashing::hash_of(&(commitment, signers))
+}
+
+/// Returns seed that is used to select signers, whose signatures must be provided to complete
+/// the commitment claim.
+///
+/// The seed is derived from the on-chain randomness of the block `T::ClaimDelay` blocks after
+/// the claim. This randomness has been unknown when the claim has been submitted and it can't
+/// be chosen by the submitter.
+pub(crate) fn commitment_claim_seed<T: Config<I>, I: 'static>(
+	claim: &CommitmentClaimOf<T>,
+) -> Result<BridgedBeefyCommitmentHash<T, I>, Error<T, I>> {
+	let randomness_at = claim.claimed_at.saturating_add(T::ClaimDelay::get());
+	let now = frame_system::Pallet::<T>::block_number();
+	ensure!(now >= randomness_at, Error::<T, I>::CommitmentClaimIsTooRecent);
+	ensure!(
+		now <= randomness_at.saturating_add(T::ClaimLifetime::get()),
+		Error::<T, I>::CommitmentClaimIsExpired
+	);
+
+	let (randomness, known_since) = ClaimsRandomness::<T, I>::get(randomness_at)
+		.ok_or(Error::<T, I>::CommitmentClaimIsExpired)?;
+	ensure!(known_since > claim.claimed_at, Error::<T, I>::CommitmentClaimRandomnessIsKnown);
+
+	Ok(BridgedBeefyCommitmentHasher::<T, I>::hash_of(&(randomness, claim.claim_hash)))
+}
+
+/// Verify commitment, signed by the subset of validators and return MMR root from its payload.
+///
+/// Unlike `verify_commitment`, it doesn't need the full validator set. Instead, every provided
+/// signature comes with the merkle proof of the validator key membership in the authority set.
+/// The commitment and signers must match the claim, that has been previously submitted.
+pub(crate) fn verify_commitment_with_signatures_subset<T: Config<I>, I: 'static>(
+	commitment: &BridgedBeefyCommitment<T, I>,
+	signatures: &BridgedBeefySignaturesSubset<T, I>,
+	claim: &CommitmentClaimOf<T>,
+	authority_set_info: &BridgedBeefyAuthoritySetInfo<T, I>,
+) -> Result<BridgedMmrHash<T, I>, Error<T, I>> {
+	// Ensure that the commitment and signers are the same that have been claimed.
+	ensure!(
+		commitment_claim_hash::<T, I>(commitment, &signatures.signers) == claim.claim_hash,
+		Error::<T, I>::InvalidCommitmentClaim
+	);
+	let signers = verify_commitment_signers(commitment, &signatures.signers, authority_set_info)?;
+
+	// Ensure that the submitter has provided signatures of all selected validators.
+	let signatures_count = sp_std::cmp::max(
+		signatures.signatures.len() as u32,
+		sp_std::cmp::min(T::MinSignaturesInSubset::get(), signers.len() as u32),
+	);
+	let seed = commitment_claim_seed::<T, I>(claim)?;
+	let selected_signers =
+		select_signers::<BridgedBeefyCommitmentHasher<T, I>>(seed, signers, signatures_count);
+	ensure!(
+		signatures.signatures.len() == selected_signers.len(),
+		Error::<T, I>::InvalidCommitmentSignaturesLen
+	);
+
+	// Ensure that all selected validators are members of the authority set and have signed
+	// the commitment.
+	let max_validator_proof_len = max_validator_proof_len(T::MaxBridgedAuthorities::get());
+	let msg = commitment.encode();
+	for (selected_signer, signature) in selected_signers.into_iter().zip(&signatures.signatures) {
+		ensure!(
+			signature.validator_index == selected_signer,
+			Error::<T, I>::UnexpectedCommitmentSigner
+		);
+		ensure!(
+			signature.proof.len() <= max_validator_proof_len as usize,
+			Error::<T, I>::TooLongValidatorProof
+		);
+
+		let merkle_leaf =
+			BridgedBeefyAuthorityIdToMerkleLeaf::<T, I>::convert(signature.validator_id.clone());
+		ensure!(
+			verify_merkle_proof::<BridgedMmrHashing<T, I>, _, _>(
+				&authority_set_info.root,
+				signature.proof.iter().cloned(),
+				authority_set_info.len as usize,
+				signature.validator_index as usize,
+				&merkle_leaf,
+			),
+			Error::<T, I>::InvalidValidatorProof
+		);
+
+		if !signature.validator_id.verify(&signature.signature, &msg) {
+			log::debug!(
+				target: LOG_TARGET,
+				"Signed commitment contains incorrect signature of validator {} ({:?}): {:?}",
+				signature.validator_index,
+				signature.validator_id,
+				signature.signature,
+			);
+			return Err(Error::<T, I>::NotEnoughCorrectSignatures)
+		}
+	}
 
 	extract_mmr_root(commitment)
 }
@@ -0,0 +1,424 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for pallet_bridge_beefy
+//!
+//! The pallet benchmarks are registered in the Rialto runtime, but the values below have not
+//! been produced by the benchmark CLI yet. They follow the layout of the generated file and
+//! list the same storage accesses that the benchmarks touch, so they must be replaced with the
+//! output of the following command, once it is run on the reference machine.
+
+// Command:
+// target/release/rialto-bridge-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_bridge_beefy
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=Compiled
+// --heap-pages=4096
+// --output=./modules/beefy/src/weights.rs
+// --template=./.maintain/millau-weight-template.hbs
+
+#![allow(clippy::all)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_bridge_beefy.
+pub trait WeightInfo {
+	fn submit_commitment(v: u32, p: u32) -> Weight;
+	fn verify_signature() -> Weight;
+	fn submit_header(p: u32) -> Weight;
+	fn submit_commitment_claim(b: u32) -> Weight;
+	fn submit_commitment_with_signatures_subset(s: u32, p: u32, l: u32, b: u32) -> Weight;
+}
+
+/// Weights for `pallet_bridge_beefy` that are estimated for the Bridge testnets.
+///
+/// Those weights are test only and must never be used in production.
+pub struct BridgeWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
+	/// Storage: BridgeMillauBeefy PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy PalletOperatingMode (max_values: Some(1), max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy RequestCount (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy RequestCount (max_values: Some(1), max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitmentsInfo (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitmentsInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy CurrentAuthoritySetInfo (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy CurrentAuthoritySetInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedBlockNumbers (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedBlockNumbers (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitments (r:0 w:2)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitments (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// The range of component `v` is `[1, 128]`.
+	///
+	/// The range of component `p` is `[0, 32]`.
+	fn submit_commitment(v: u32, p: u32) -> Weight {
+		Weight::from_parts(52_816_204, 5008)
+			.saturating_add(Weight::from_ref_time(36_897_085).saturating_mul(v.into()))
+			.saturating_add(Weight::from_ref_time(1_362_418).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn verify_signature() -> Weight {
+		Weight::from_parts(53_302_000, 0)
+	}
+	/// Storage: BridgeMillauBeefy PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy PalletOperatingMode (max_values: Some(1), max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy RequestCount (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy RequestCount (max_values: Some(1), max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedHeaders (r:1 w:2)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedHeaders (max_values: None, max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitments (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitments (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedHeaderHashesPointer (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedHeaderHashesPointer (max_values: Some(1),
+	/// max_size: None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedHeaderHashes (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedHeaderHashes (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy BestImportedHeaderNumber (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy BestImportedHeaderNumber (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// The range of component `p` is `[0, 32]`.
+	fn submit_header(p: u32) -> Weight {
+		Weight::from_parts(50_624_885, 7355)
+			.saturating_add(Weight::from_ref_time(1_362_418).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: BridgeMillauBeefy PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy PalletOperatingMode (max_values: Some(1), max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy RequestCount (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy RequestCount (max_values: Some(1), max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitmentsInfo (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitmentsInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy CurrentAuthoritySetInfo (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy CurrentAuthoritySetInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedBlockNumbers (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedBlockNumbers (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy CommitmentClaims (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy CommitmentClaims (max_values: None, max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ClaimsRandomness (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ClaimsRandomness (max_values: None, max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitments (r:0 w:2)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitments (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// The range of component `s` is `[1, 64]`.
+	///
+	/// The range of component `p` is `[0, 32]`.
+	///
+	/// The range of component `l` is `[1, 7]`.
+	///
+	/// The range of component `b` is `[1, 16]`.
+	fn submit_commitment_with_signatures_subset(s: u32, p: u32, l: u32, b: u32) -> Weight {
+		Weight::from_parts(52_816_204, 5008)
+			.saturating_add(Weight::from_ref_time(54_664_418).saturating_mul(s.into()))
+			.saturating_add(Weight::from_ref_time(1_362_418).saturating_mul(p.into()))
+			.saturating_add(Weight::from_ref_time(87_194_752).saturating_mul(l.into()))
+			.saturating_add(Weight::from_ref_time(231_562).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: BridgeMillauBeefy PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy PalletOperatingMode (max_values: Some(1), max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy RequestCount (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy RequestCount (max_values: Some(1), max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitmentsInfo (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitmentsInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy CurrentAuthoritySetInfo (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy CurrentAuthoritySetInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ClaimsRandomness (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ClaimsRandomness (max_values: None, max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy CommitmentClaims (r:0 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy CommitmentClaims (max_values: None, max_size: None, mode:
+	/// Measured)
+	///
+	/// The range of component `b` is `[1, 16]`.
+	fn submit_commitment_claim(b: u32) -> Weight {
+		Weight::from_parts(32_870_416, 3504)
+			.saturating_add(Weight::from_ref_time(231_562).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: BridgeMillauBeefy PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy PalletOperatingMode (max_values: Some(1), max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy RequestCount (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy RequestCount (max_values: Some(1), max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitmentsInfo (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitmentsInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy CurrentAuthoritySetInfo (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy CurrentAuthoritySetInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedBlockNumbers (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedBlockNumbers (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitments (r:0 w:2)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitments (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// The range of component `v` is `[1, 128]`.
+	///
+	/// The range of component `p` is `[0, 32]`.
+	fn submit_commitment(v: u32, p: u32) -> Weight {
+		Weight::from_parts(52_816_204, 5008)
+			.saturating_add(Weight::from_ref_time(36_897_085).saturating_mul(v.into()))
+			.saturating_add(Weight::from_ref_time(1_362_418).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn verify_signature() -> Weight {
+		Weight::from_parts(53_302_000, 0)
+	}
+	/// Storage: BridgeMillauBeefy PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy PalletOperatingMode (max_values: Some(1), max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy RequestCount (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy RequestCount (max_values: Some(1), max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedHeaders (r:1 w:2)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedHeaders (max_values: None, max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitments (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitments (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedHeaderHashesPointer (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedHeaderHashesPointer (max_values: Some(1),
+	/// max_size: None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedHeaderHashes (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedHeaderHashes (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy BestImportedHeaderNumber (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy BestImportedHeaderNumber (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// The range of component `p` is `[0, 32]`.
+	fn submit_header(p: u32) -> Weight {
+		Weight::from_parts(50_624_885, 7355)
+			.saturating_add(Weight::from_ref_time(1_362_418).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: BridgeMillauBeefy PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy PalletOperatingMode (max_values: Some(1), max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy RequestCount (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy RequestCount (max_values: Some(1), max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitmentsInfo (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitmentsInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy CurrentAuthoritySetInfo (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy CurrentAuthoritySetInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedBlockNumbers (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedBlockNumbers (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy CommitmentClaims (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy CommitmentClaims (max_values: None, max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ClaimsRandomness (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ClaimsRandomness (max_values: None, max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitments (r:0 w:2)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitments (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// The range of component `s` is `[1, 64]`.
+	///
+	/// The range of component `p` is `[0, 32]`.
+	///
+	/// The range of component `l` is `[1, 7]`.
+	///
+	/// The range of component `b` is `[1, 16]`.
+	fn submit_commitment_with_signatures_subset(s: u32, p: u32, l: u32, b: u32) -> Weight {
+		Weight::from_parts(52_816_204, 5008)
+			.saturating_add(Weight::from_ref_time(54_664_418).saturating_mul(s.into()))
+			.saturating_add(Weight::from_ref_time(1_362_418).saturating_mul(p.into()))
+			.saturating_add(Weight::from_ref_time(87_194_752).saturating_mul(l.into()))
+			.saturating_add(Weight::from_ref_time(231_562).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: BridgeMillauBeefy PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy PalletOperatingMode (max_values: Some(1), max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy RequestCount (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy RequestCount (max_values: Some(1), max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitmentsInfo (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitmentsInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy CurrentAuthoritySetInfo (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy CurrentAuthoritySetInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ClaimsRandomness (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ClaimsRandomness (max_values: None, max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy CommitmentClaims (r:0 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy CommitmentClaims (max_values: None, max_size: None, mode:
+	/// Measured)
+	///
+	/// The range of component `b` is `[1, 16]`.
+	fn submit_commitment_claim(b: u32) -> Weight {
+		Weight::from_parts(32_870_416, 3504)
+			.saturating_add(Weight::from_ref_time(231_562).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
@@ -0,0 +1,42 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weight-related utilities.
+
+use crate::{utils::signatures_required, weights::WeightInfo};
+
+use frame_support::weights::Weight;
+
+/// Extended weight info.
+pub trait WeightInfoExt: WeightInfo {
+	/// Weight of the commitment submission extrinsic.
+	///
+	/// The `submit_commitment` benchmark assumes that exactly `2/3+1` signatures are verified.
+	/// If we need to verify more signatures (because some of them are invalid), every extra
+	/// signature adds `verify_signature` weight.
+	fn submit_commitment_weight(
+		validators_len: u32,
+		verified_signatures: u32,
+		mmr_proof_items: u32,
+	) -> Weight {
+		let base_weight = Self::submit_commitment(validators_len, mmr_proof_items);
+		let extra_signatures =
+			verified_signatures.saturating_sub(signatures_required(validators_len as usize) as u32);
+		base_weight.saturating_add(Self::verify_signature().saturating_mul(extra_signatures as u64))
+	}
+}
+
+impl<T: WeightInfo> WeightInfoExt for T {}
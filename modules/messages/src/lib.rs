@@ -250,7 +250,12 @@ pub mod pallet {
 		/// state update. Because of that, the submitter (relayer) has no benefit of not including
 		/// this data in the transaction, so reward confirmations lags should be minimal.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::receive_messages_proof_weight(proof, *messages_count, *dispatch_weight))]
+		#[pallet::weight(T::WeightInfo::receive_messages_proof_weight(
+			proof,
+			T::SourceHeaderChain::proof_lanes_count(proof),
+			*messages_count,
+			*dispatch_weight,
+		))]
 		pub fn receive_messages_proof(
 			origin: OriginFor<T>,
 			relayer_id_at_bridged_chain: T::InboundRelayer,
@@ -261,7 +266,8 @@ pub mod pallet {
 			Self::ensure_not_halted().map_err(Error::<T, I>::BridgeModule)?;
 			let relayer_id_at_this_chain = ensure_signed(origin)?;
 
-			// reject transactions that are declaring too many messages
+			// reject transactions that are declaring too many messages. If the proof covers several
+			// lanes, the limit applies to the total number of messages at all lanes
 			ensure!(
 				MessageNonce::from(messages_count) <= T::MaxUnconfirmedMessagesAtInboundLane::get(),
 				Error::<T, I>::TooManyMessagesInTheProof
@@ -279,6 +285,7 @@ pub mod pallet {
 			// to get pre-computed value (and it has been already computed by the executive).
 			let declared_weight = T::WeightInfo::receive_messages_proof_weight(
 				&proof,
+				T::SourceHeaderChain::proof_lanes_count(&proof),
 				messages_count,
 				dispatch_weight,
 			);
@@ -892,8 +899,9 @@ mod tests {
 		message, message_payload, run_test, unrewarded_relayer, AccountId, DbWeight,
 		RuntimeEvent as TestEvent, RuntimeOrigin, TestDeliveryConfirmationPayments,
 		TestDeliveryPayments, TestMessagesDeliveryProof, TestMessagesProof, TestRuntime,
-		MAX_OUTBOUND_PAYLOAD_SIZE, PAYLOAD_REJECTED_BY_TARGET_CHAIN, REGULAR_PAYLOAD, TEST_LANE_ID,
-		TEST_LANE_ID_2, TEST_LANE_ID_3, TEST_RELAYER_A, TEST_RELAYER_B,
+		TestSourceHeaderChain, MAX_OUTBOUND_PAYLOAD_SIZE, PAYLOAD_REJECTED_BY_TARGET_CHAIN,
+		REGULAR_PAYLOAD, TEST_LANE_ID, TEST_LANE_ID_2, TEST_LANE_ID_3, TEST_RELAYER_A,
+		TEST_RELAYER_B,
 	};
 	use bp_messages::{BridgeMessagesCall, UnrewardedRelayer, UnrewardedRelayersState};
 	use bp_test_utils::generate_owned_bridge_module_tests;
@@ -1182,6 +1190,26 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn receive_messages_proof_weight_depends_on_lanes_count() {
+		let mut message_at_lane_2 = message(1, REGULAR_PAYLOAD);
+		message_at_lane_2.key.lane_id = TEST_LANE_ID_2;
+		let single_lane_proof: TestMessagesProof =
+			Ok(vec![message(1, REGULAR_PAYLOAD), message(2, REGULAR_PAYLOAD)]).into();
+		let two_lanes_proof: TestMessagesProof =
+			Ok(vec![message(1, REGULAR_PAYLOAD), message_at_lane_2]).into();
+
+		let proof_weight = |proof: &TestMessagesProof| {
+			<TestRuntime as Config>::WeightInfo::receive_messages_proof_weight(
+				proof,
+				TestSourceHeaderChain::proof_lanes_count(proof),
+				2,
+				REGULAR_PAYLOAD.declared_weight,
+			)
+		};
+		assert!(proof_weight(&two_lanes_proof).all_gt(proof_weight(&single_lane_proof)));
+	}
+
 	#[test]
 	fn receive_messages_proof_updates_confirmed_message_nonce() {
 		run_test(|| {
@@ -1541,6 +1569,7 @@ mod tests {
 				let pre_dispatch_weight =
 					<TestRuntime as Config>::WeightInfo::receive_messages_proof_weight(
 						&proof,
+						1,
 						messages_count,
 						REGULAR_PAYLOAD.declared_weight,
 					);
@@ -361,6 +361,10 @@ impl SourceHeaderChain for TestSourceHeaderChain {
 	) -> Result<ProvedMessages<Message>, Self::Error> {
 		proof.result.map(|proof| proof.into_iter().collect()).map_err(|_| TEST_ERROR)
 	}
+
+	fn proof_lanes_count(proof: &Self::MessagesProof) -> u32 {
+		proof.result.as_ref().map(|lanes| lanes.len() as u32).unwrap_or(1)
+	}
 }
 
 /// Source header chain that is used in tests.
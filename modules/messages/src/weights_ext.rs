@@ -40,6 +40,7 @@ pub fn ensure_weights_are_correct<W: WeightInfoExt>() {
 	assert_ne!(W::receive_messages_proof_overhead(), Weight::zero());
 	assert_ne!(W::receive_messages_proof_messages_overhead(1), Weight::zero());
 	assert_ne!(W::receive_messages_proof_outbound_lane_state_overhead(), Weight::zero());
+	assert_ne!(W::receive_messages_proof_additional_lanes_overhead(1), Weight::zero());
 	assert_ne!(W::storage_proof_size_overhead(1), Weight::zero());
 
 	// verify `receive_messages_delivery_proof` weight components
@@ -48,7 +49,7 @@ pub fn ensure_weights_are_correct<W: WeightInfoExt>() {
 
 	// verify `receive_message_proof` weight
 	let receive_messages_proof_weight =
-		W::receive_messages_proof_weight(&PreComputedSize(1), 10, Weight::zero());
+		W::receive_messages_proof_weight(&PreComputedSize(1), 1, 10, Weight::zero());
 	assert_ne!(receive_messages_proof_weight.ref_time(), 0);
 	assert_ne!(receive_messages_proof_weight.proof_size(), 0);
 
@@ -82,6 +83,7 @@ pub fn ensure_able_to_receive_message<W: WeightInfoExt>(
 			(max_incoming_message_proof_size + W::expected_extra_storage_proof_size()) as usize,
 		),
 		1,
+		1,
 		max_incoming_message_dispatch_weight,
 	);
 	assert!(
@@ -135,8 +137,11 @@ pub trait WeightInfoExt: WeightInfo {
 	// Functions that are directly mapped to extrinsics weights.
 
 	/// Weight of message delivery extrinsic.
+	///
+	/// The `lanes_count` is the number of lanes, covered by the messages proof.
 	fn receive_messages_proof_weight(
 		proof: &impl Size,
+		lanes_count: u32,
 		messages_count: u32,
 		dispatch_weight: Weight,
 	) -> Weight {
@@ -144,6 +149,8 @@ pub trait WeightInfoExt: WeightInfo {
 		let transaction_overhead = Self::receive_messages_proof_overhead();
 		let outbound_state_delivery_weight =
 			Self::receive_messages_proof_outbound_lane_state_overhead();
+		let additional_lanes_weight =
+			Self::receive_messages_proof_additional_lanes_overhead(lanes_count.saturating_sub(1));
 		let messages_delivery_weight =
 			Self::receive_messages_proof_messages_overhead(MessageNonce::from(messages_count));
 		let messages_dispatch_weight = dispatch_weight;
@@ -159,6 +166,7 @@ pub trait WeightInfoExt: WeightInfo {
 
 		transaction_overhead
 			.saturating_add(outbound_state_delivery_weight)
+			.saturating_add(additional_lanes_weight)
 			.saturating_add(messages_delivery_weight)
 			.saturating_add(messages_dispatch_weight)
 			.saturating_add(proof_size_overhead)
@@ -220,6 +228,18 @@ pub trait WeightInfoExt: WeightInfo {
 		weight_of_single_message_and_lane_state.saturating_sub(weight_of_single_message)
 	}
 
+	/// Returns weight that needs to be accounted when message delivery transaction
+	/// (`receive_messages_proof`) is delivering messages of additional lanes.
+	///
+	/// There's no dedicated benchmark for that, so every additional lane is charged as the
+	/// overhead of separate delivery transaction (which includes reading and writing the
+	/// inbound lane state) with outbound lane state proof.
+	fn receive_messages_proof_additional_lanes_overhead(additional_lanes: u32) -> Weight {
+		Self::receive_messages_proof_overhead()
+			.saturating_add(Self::receive_messages_proof_outbound_lane_state_overhead())
+			.saturating_mul(additional_lanes as _)
+	}
+
 	/// Returns weight overhead of delivery confirmation transaction
 	/// (`receive_messages_delivery_proof`).
 	fn receive_messages_delivery_proof_overhead() -> Weight {
@@ -116,6 +116,13 @@ pub mod pallet {
 		},
 		/// Parachain head has been updated.
 		UpdatedParachainHead { parachain: ParaId, parachain_head_hash: ParaHash },
+		/// The caller has proved that the parachain has been deregistered at the bridged relay
+		/// chain. All its data is scheduled for pruning.
+		ParachainDeregistered { parachain: ParaId },
+		/// The caller has provided head of parachain, which data is currently being pruned.
+		RejectedHeadOfPrunedParachain { parachain: ParaId, parachain_head_hash: ParaHash },
+		/// All stored data of deregistered parachain has been pruned.
+		DeregisteredParachainPruned { parachain: ParaId },
 	}
 
 	#[pallet::error]
@@ -255,6 +262,21 @@ pub mod pallet {
 		MaxValues = MaybeMaxTotalParachainHashes<T, I>,
 	>;
 
+	/// Parachains that have been deregistered at the bridged relay chain and which stored data is
+	/// being pruned.
+	///
+	/// The value is the next position of the `ImportedParaHashes` ring buffer that needs to be
+	/// pruned.
+	#[pallet::storage]
+	pub type DeregisteredParachains<T: Config<I>, I: 'static = ()> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = ParaId,
+		Value = u32,
+		QueryKind = OptionQuery,
+		OnEmpty = GetDefault,
+		MaxValues = MaybeMaxParachains<T, I>,
+	>;
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
@@ -266,6 +288,13 @@ pub mod pallet {
 		type OperatingModeStorage = PalletOperatingMode<T, I>;
 	}
 
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_idle(_block: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::prune_deregistered_parachain(remaining_weight)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I>
 	where
@@ -321,22 +350,42 @@ pub mod pallet {
 				move |storage| {
 					for (parachain, parachain_head_hash) in parachains {
 						let parachain_head = match Pallet::<T, I>::read_parachain_head(&storage, parachain) {
-							Ok(Some(parachain_head)) => parachain_head,
+							Ok(Some(parachain_head)) => {
+								// parachain is registered => refund deregistration weight
+								actual_weight = actual_weight
+									.saturating_sub(WeightInfoOf::<T, I>::parachain_deregistration_weight(T::DbWeight::get()));
+								parachain_head
+							},
 							Ok(None) => {
+								let is_deregistered = Pallet::<T, I>::schedule_deregistered_parachain_pruning(
+									parachain,
+									relay_block_number,
+								);
+								// if parachain has not been deregistered, we have only read its best head
+								if !is_deregistered {
+									actual_weight = actual_weight.saturating_sub(
+										WeightInfoOf::<T, I>::parachain_deregistration_weight(T::DbWeight::get())
+											.saturating_sub(T::DbWeight::get().reads(1)),
+									);
+								}
 								log::trace!(
 									target: LOG_TARGET,
 									"The head of parachain {:?} is None. {}",
 									parachain,
-									if ParasInfo::<T, I>::contains_key(parachain) {
-										"Looks like it is not yet registered at the source relay chain"
-									} else {
+									if is_deregistered {
 										"Looks like it has been deregistered from the source relay chain"
+									} else {
+										"Looks like it is not yet registered at the source relay chain"
 									},
 								);
 								Self::deposit_event(Event::MissingParachainHead { parachain });
 								continue;
 							},
 							Err(e) => {
+								// we have failed to read parachain head => refund deregistration weight
+								actual_weight = actual_weight
+									.saturating_sub(WeightInfoOf::<T, I>::parachain_deregistration_weight(T::DbWeight::get()));
+
 								log::trace!(
 									target: LOG_TARGET,
 									"The read of head of parachain {:?} has failed: {:?}",
@@ -381,6 +430,20 @@ pub mod pallet {
 							},
 						};
 
+						// we can't import heads of the parachain until all its previous data is pruned
+						if DeregisteredParachains::<T, I>::contains_key(parachain) {
+							log::trace!(
+								target: LOG_TARGET,
+								"The head of parachain {:?} has been provided, but its old data is being pruned",
+								parachain,
+							);
+							Self::deposit_event(Event::RejectedHeadOfPrunedParachain {
+								parachain,
+								parachain_head_hash,
+							});
+							continue;
+						}
+
 						let update_result: Result<_, ()> = ParasInfo::<T, I>::try_mutate(parachain, |stored_best_head| {
 							let artifacts = Pallet::<T, I>::update_parachain_head(
 								parachain,
@@ -531,6 +594,93 @@ pub mod pallet {
 			true
 		}
 
+		/// Schedule pruning of all stored data of the parachain that has been deregistered at the
+		/// bridged relay chain.
+		///
+		/// The parachain is considered deregistered if its head is missing from the storage proof,
+		/// crafted at the relay chain block that is better than the block where we have seen its
+		/// best head. Returns `true` if the parachain has been scheduled for pruning.
+		pub(super) fn schedule_deregistered_parachain_pruning(
+			parachain: ParaId,
+			at_relay_block_number: RelayBlockNumber,
+		) -> bool {
+			let stored_best_head = match ParasInfo::<T, I>::get(parachain) {
+				Some(stored_best_head) => stored_best_head,
+				None => return false,
+			};
+			if stored_best_head.best_head_hash.at_relay_block_number >= at_relay_block_number {
+				// the parachain may be missing at older relay block, which isn't a proof of
+				// deregistration
+				return false
+			}
+
+			log::trace!(
+				target: LOG_TARGET,
+				"Parachain {:?} has been deregistered at relay block {}. Scheduling its data pruning",
+				parachain,
+				at_relay_block_number,
+			);
+
+			// the best head is removed immediately, so the parachain is no longer known to the
+			// pallet. Other heads are pruned later, in the `on_idle`
+			ParasInfo::<T, I>::remove(parachain);
+			DeregisteredParachains::<T, I>::insert(parachain, 0);
+			Self::deposit_event(Event::ParachainDeregistered { parachain });
+
+			true
+		}
+
+		/// Prune stored heads of deregistered parachain, using at most `remaining_weight`.
+		///
+		/// Returns weight that has been used.
+		pub(super) fn prune_deregistered_parachain(remaining_weight: Weight) -> Weight {
+			// we'll need at least to read and update the pruning state and to prune single head
+			let db_weight = T::DbWeight::get();
+			let head_pruning_weight = db_weight.reads_writes(1, 2);
+			if !remaining_weight.all_gte(db_weight.reads_writes(1, 1) + head_pruning_weight) {
+				return Weight::zero()
+			}
+
+			// first db read - pruning state
+			let mut used_weight = db_weight.reads(1);
+			let (parachain, mut next_position) = match DeregisteredParachains::<T, I>::iter().next()
+			{
+				Some(pruning_state) => pruning_state,
+				None => return used_weight,
+			};
+
+			// reserve weight for the final pruning state write
+			used_weight += db_weight.writes(1);
+
+			// prune heads, one by one
+			let heads_to_keep = T::HeadsToKeep::get();
+			while next_position < heads_to_keep &&
+				remaining_weight.all_gte(used_weight + head_pruning_weight)
+			{
+				if let Some(head_hash) = ImportedParaHashes::<T, I>::take(parachain, next_position)
+				{
+					ImportedParaHeads::<T, I>::remove(parachain, head_hash);
+				}
+				used_weight += head_pruning_weight;
+				next_position += 1;
+			}
+
+			if next_position < heads_to_keep {
+				DeregisteredParachains::<T, I>::insert(parachain, next_position);
+			} else {
+				log::trace!(
+					target: LOG_TARGET,
+					"All data of deregistered parachain {:?} has been pruned",
+					parachain,
+				);
+
+				DeregisteredParachains::<T, I>::remove(parachain);
+				Self::deposit_event(Event::DeregisteredParachainPruned { parachain });
+			}
+
+			used_weight
+		}
+
 		/// Try to update parachain head.
 		pub(super) fn update_parachain_head(
 			parachain: ParaId,
@@ -737,7 +887,7 @@ mod tests {
 		assert_noop, assert_ok,
 		dispatch::DispatchResultWithPostInfo,
 		storage::generator::{StorageDoubleMap, StorageMap},
-		traits::{Get, OnInitialize},
+		traits::{Get, OnIdle, OnInitialize},
 		weights::Weight,
 	};
 	use frame_system::{EventRecord, Pallet as System, Phase};
@@ -882,6 +1032,9 @@ mod tests {
 			} else {
 				WeightInfoOf::<TestRuntime, ()>::parachain_head_pruning_weight(db_weight)
 			})
+			.saturating_sub(WeightInfoOf::<TestRuntime, ()>::parachain_deregistration_weight(
+				db_weight,
+			))
 	}
 
 	#[test]
@@ -924,7 +1077,11 @@ mod tests {
 
 			// we're trying to update heads of parachains 1, 2 and 3
 			let expected_weight =
-				WeightInfo::submit_parachain_heads_weight(DbWeight::get(), &proof, 2);
+				WeightInfo::submit_parachain_heads_weight(DbWeight::get(), &proof, 2)
+					.saturating_sub(
+						WeightInfo::parachain_deregistration_weight(DbWeight::get())
+							.saturating_mul(2),
+					);
 			let result = Pallet::<TestRuntime>::submit_parachain_heads(
 				RuntimeOrigin::signed(1),
 				(0, test_relay_header(0, state_root).hash()),
@@ -1095,9 +1252,13 @@ mod tests {
 			// parachain
 			let expected_weight =
 				WeightInfo::submit_parachain_heads_weight(DbWeight::get(), &proof, 3)
-					.saturating_sub(WeightInfo::parachain_head_storage_write_weight(
-						DbWeight::get(),
-					));
+					.saturating_sub(
+						WeightInfo::parachain_head_storage_write_weight(DbWeight::get()),
+					)
+					.saturating_sub(
+						WeightInfo::parachain_deregistration_weight(DbWeight::get())
+							.saturating_mul(3),
+					);
 			initialize(state_root);
 			let result = Pallet::<TestRuntime>::submit_parachain_heads(
 				RuntimeOrigin::signed(1),
@@ -1508,6 +1669,187 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn schedules_pruning_of_deregistered_parachain() {
+		let (state_root_0, proof_0, parachains_0) =
+			prepare_parachain_heads_proof(vec![(1, head_data(1, 0))]);
+		let (state_root_1, proof_1, _) = prepare_parachain_heads_proof(vec![(2, head_data(2, 0))]);
+		run_test(|| {
+			initialize(state_root_0);
+			assert_ok!(import_parachain_1_head(0, state_root_0, parachains_0, proof_0));
+
+			// head of parachain#1 is missing at relay block#1 => it has been deregistered
+			proceed(1, state_root_1);
+			System::<TestRuntime>::reset_events();
+			let db_weight = <TestRuntime as frame_system::Config>::DbWeight::get();
+			let expected_weight = WeightInfoOf::<TestRuntime, ()>::submit_parachain_heads_weight(
+				db_weight, &proof_1, 1,
+			);
+			let result = import_parachain_1_head(
+				1,
+				state_root_1,
+				vec![(ParaId(1), head_hash(1, 1))],
+				proof_1,
+			);
+			assert_ok!(result);
+			assert_eq!(result.expect("checked above").actual_weight, Some(expected_weight));
+
+			assert_eq!(ParasInfo::<TestRuntime>::get(ParaId(1)), None);
+			assert_eq!(DeregisteredParachains::<TestRuntime>::get(ParaId(1)), Some(0));
+			assert!(ImportedParaHeads::<TestRuntime>::contains_key(ParaId(1), head_hash(1, 0)));
+			assert_eq!(
+				System::<TestRuntime>::events(),
+				vec![
+					EventRecord {
+						phase: Phase::Initialization,
+						event: TestEvent::Parachains(Event::ParachainDeregistered {
+							parachain: ParaId(1),
+						}),
+						topics: vec![],
+					},
+					EventRecord {
+						phase: Phase::Initialization,
+						event: TestEvent::Parachains(Event::MissingParachainHead {
+							parachain: ParaId(1),
+						}),
+						topics: vec![],
+					}
+				],
+			);
+		});
+	}
+
+	#[test]
+	fn does_not_schedule_pruning_if_parachain_is_missing_at_obsolete_relay_block() {
+		let (state_root_0, proof_0, _) = prepare_parachain_heads_proof(vec![(2, head_data(2, 0))]);
+		let (state_root_1, proof_1, parachains_1) =
+			prepare_parachain_heads_proof(vec![(1, head_data(1, 1))]);
+		run_test(|| {
+			initialize(state_root_0);
+			proceed(1, state_root_1);
+			assert_ok!(import_parachain_1_head(1, state_root_1, parachains_1, proof_1));
+
+			// head of parachain#1 is missing at relay block#0, but we have already seen it at
+			// relay block#1
+			let db_weight = <TestRuntime as frame_system::Config>::DbWeight::get();
+			let expected_weight = WeightInfoOf::<TestRuntime, ()>::submit_parachain_heads_weight(
+				db_weight, &proof_0, 1,
+			)
+			.saturating_sub(WeightInfoOf::<TestRuntime, ()>::parachain_deregistration_weight(
+				db_weight,
+			))
+			.saturating_add(db_weight.reads(1));
+			let result = import_parachain_1_head(
+				0,
+				state_root_0,
+				vec![(ParaId(1), head_hash(1, 0))],
+				proof_0,
+			);
+			assert_ok!(result);
+			assert_eq!(result.expect("checked above").actual_weight, Some(expected_weight));
+
+			assert!(ParasInfo::<TestRuntime>::contains_key(ParaId(1)));
+			assert!(!DeregisteredParachains::<TestRuntime>::contains_key(ParaId(1)));
+		});
+	}
+
+	#[test]
+	fn prunes_deregistered_parachain_data_incrementally() {
+		run_test(|| {
+			let heads_to_keep = crate::mock::HeadsToKeep::get();
+			let db_weight = DbWeight::get();
+
+			// import exactly `HeadsToKeep` headers
+			for i in 0..heads_to_keep {
+				let (state_root, proof, parachains) =
+					prepare_parachain_heads_proof(vec![(1, head_data(1, i))]);
+				if i == 0 {
+					initialize(state_root);
+				} else {
+					proceed(i, state_root);
+				}
+				assert_ok!(import_parachain_1_head(i, state_root, parachains, proof));
+			}
+
+			// and then prove that the parachain has been deregistered
+			let (state_root, proof, _) = prepare_parachain_heads_proof(vec![(2, head_data(2, 0))]);
+			proceed(heads_to_keep, state_root);
+			assert_ok!(import_parachain_1_head(
+				heads_to_keep,
+				state_root,
+				vec![(ParaId(1), head_hash(1, heads_to_keep))],
+				proof,
+			));
+
+			// if passed weight is too low to do anything
+			assert_eq!(
+				Pallet::<TestRuntime>::on_idle(0, db_weight.reads_writes(2, 2)),
+				Weight::zero(),
+			);
+			assert_eq!(DeregisteredParachains::<TestRuntime>::get(ParaId(1)), Some(0));
+
+			// if passed weight is enough to prune single head
+			assert_eq!(
+				Pallet::<TestRuntime>::on_idle(0, db_weight.reads_writes(2, 3)),
+				db_weight.reads_writes(2, 3),
+			);
+			assert_eq!(DeregisteredParachains::<TestRuntime>::get(ParaId(1)), Some(1));
+			assert!(!ImportedParaHeads::<TestRuntime>::contains_key(ParaId(1), head_hash(1, 0)));
+			assert!(ImportedParaHeads::<TestRuntime>::contains_key(ParaId(1), head_hash(1, 1)));
+
+			// heads of the parachain are rejected while it is being pruned
+			let (state_root, proof, parachains) =
+				prepare_parachain_heads_proof(vec![(1, head_data(1, 100))]);
+			proceed(heads_to_keep + 1, state_root);
+			System::<TestRuntime>::reset_events();
+			assert_ok!(import_parachain_1_head(heads_to_keep + 1, state_root, parachains, proof));
+			assert_eq!(ParasInfo::<TestRuntime>::get(ParaId(1)), None);
+			assert_eq!(
+				System::<TestRuntime>::events(),
+				vec![EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Parachains(Event::RejectedHeadOfPrunedParachain {
+						parachain: ParaId(1),
+						parachain_head_hash: head_hash(1, 100),
+					}),
+					topics: vec![],
+				}],
+			);
+
+			// if passed weight is enough to prune all remaining heads
+			let remaining_heads = heads_to_keep as u64 - 1;
+			System::<TestRuntime>::reset_events();
+			assert_eq!(
+				Pallet::<TestRuntime>::on_idle(0, db_weight.reads_writes(100, 100)),
+				db_weight.reads_writes(1 + remaining_heads, 1 + 2 * remaining_heads),
+			);
+			assert_eq!(DeregisteredParachains::<TestRuntime>::get(ParaId(1)), None);
+			for i in 0..heads_to_keep {
+				assert!(!ImportedParaHeads::<TestRuntime>::contains_key(
+					ParaId(1),
+					head_hash(1, i)
+				));
+				assert!(!ImportedParaHashes::<TestRuntime>::contains_key(ParaId(1), i));
+			}
+			assert_eq!(
+				System::<TestRuntime>::events(),
+				vec![EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Parachains(Event::DeregisteredParachainPruned {
+						parachain: ParaId(1),
+					}),
+					topics: vec![],
+				}],
+			);
+
+			// nothing to prune anymore
+			assert_eq!(
+				Pallet::<TestRuntime>::on_idle(0, db_weight.reads_writes(100, 100)),
+				db_weight.reads(1),
+			);
+		});
+	}
+
 	#[test]
 	fn ignores_parachain_head_if_parachain_head_hash_is_wrong() {
 		let (state_root, proof, _) = prepare_parachain_heads_proof(vec![(1, head_data(1, 0))]);
@@ -65,7 +65,14 @@ pub trait WeightInfoExt: WeightInfo {
 		let pruning_weight =
 			Self::parachain_head_pruning_weight(db_weight).saturating_mul(parachains_count as u64);
 
-		base_weight.saturating_add(proof_size_overhead).saturating_add(pruning_weight)
+		// potential deregistration weight (refunded if hasn't happened)
+		let deregistration_weight = Self::parachain_deregistration_weight(db_weight)
+			.saturating_mul(parachains_count as u64);
+
+		base_weight
+			.saturating_add(proof_size_overhead)
+			.saturating_add(pruning_weight)
+			.saturating_add(deregistration_weight)
 	}
 
 	/// Returns weight of single parachain head storage update.
@@ -85,6 +92,15 @@ pub trait WeightInfoExt: WeightInfo {
 		db_weight.writes(1)
 	}
 
+	/// Returns weight of single parachain deregistration.
+	///
+	/// Parachain is deregistered if its head is missing from the storage proof.
+	fn parachain_deregistration_weight(db_weight: RuntimeDbWeight) -> Weight {
+		// we need to read and remove the best head (`ParasInfo`), to schedule pruning of other
+		// heads (`DeregisteredParachains`) and to deposit an event
+		db_weight.reads_writes(1, 3)
+	}
+
 	/// Returns weight that needs to be accounted when storage proof of given size is received.
 	fn storage_proof_size_overhead(extra_proof_bytes: u32) -> Weight {
 		let extra_byte_weight = (Self::submit_parachain_heads_with_16kb_proof() -
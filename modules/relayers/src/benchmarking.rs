@@ -20,8 +20,11 @@
 
 use crate::*;
 
+use bp_messages::LaneId;
+use bp_relayers::RewardsAccountOwner;
 use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_system::RawOrigin;
+use sp_runtime::traits::One;
 
 /// Reward amount that is (hopefully) is larger than existential deposit across all chains.
 const REWARD_AMOUNT: u32 = u32::MAX;
@@ -32,22 +35,77 @@ pub struct Pallet<T: Config>(crate::Pallet<T>);
 /// Trait that must be implemented by runtime.
 pub trait Config: crate::Config {
 	/// Prepare environment for paying given reward for serving given lane.
-	fn prepare_environment(lane: LaneId, reward: Self::Reward);
+	fn prepare_environment(account_params: RewardsAccountParams, reward: Self::Reward);
+	/// Give enough balance to given account.
+	fn deposit_account(account: Self::AccountId, balance: Self::Reward);
 }
 
 benchmarks! {
 	// Benchmark `claim_rewards` call.
 	claim_rewards {
 		let lane = LaneId([0, 0, 0, 0]);
+		let account_params =
+			RewardsAccountParams::new(lane, *b"test", RewardsAccountOwner::ThisChain);
 		let relayer: T::AccountId = whitelisted_caller();
 		let reward = T::Reward::from(REWARD_AMOUNT);
 
-		T::prepare_environment(lane, reward);
-		RelayerRewards::<T>::insert(&relayer, lane, reward);
-	}: _(RawOrigin::Signed(relayer), lane)
+		T::prepare_environment(account_params, reward);
+		RelayerRewards::<T>::insert(&relayer, account_params, reward);
+	}: _(RawOrigin::Signed(relayer), account_params)
 	verify {
 		// we can't check anything here, because `PaymentProcedure` is responsible for
 		// payment logic, so we assume that if call has succeeded, the procedure has
 		// also completed successfully
 	}
+
+	// Benchmark `register` call.
+	register {
+		let relayer: T::AccountId = whitelisted_caller();
+		let valid_till = frame_system::Pallet::<T>::block_number()
+			.saturating_add(crate::Pallet::<T>::required_registration_lease())
+			.saturating_add(One::one())
+			.saturating_add(One::one());
+
+		T::deposit_account(relayer.clone(), crate::Pallet::<T>::required_stake());
+	}: _(RawOrigin::Signed(relayer.clone()), valid_till)
+	verify {
+		assert!(crate::Pallet::<T>::is_registration_active(&relayer));
+	}
+
+	// Benchmark `extend_registration` call.
+	extend_registration {
+		let relayer: T::AccountId = whitelisted_caller();
+		let valid_till = frame_system::Pallet::<T>::block_number()
+			.saturating_add(crate::Pallet::<T>::required_registration_lease())
+			.saturating_add(One::one())
+			.saturating_add(One::one());
+
+		T::deposit_account(relayer.clone(), crate::Pallet::<T>::required_stake());
+		crate::Pallet::<T>::register(RawOrigin::Signed(relayer.clone()).into(), valid_till).unwrap();
+
+		let new_valid_till = valid_till.saturating_add(One::one());
+	}: _(RawOrigin::Signed(relayer.clone()), new_valid_till)
+	verify {
+		assert_eq!(
+			crate::Pallet::<T>::registered_relayer(&relayer).map(|r| r.valid_till),
+			Some(new_valid_till),
+		);
+	}
+
+	// Benchmark `deregister` call.
+	deregister {
+		let relayer: T::AccountId = whitelisted_caller();
+		let valid_till = frame_system::Pallet::<T>::block_number()
+			.saturating_add(crate::Pallet::<T>::required_registration_lease())
+			.saturating_add(One::one())
+			.saturating_add(One::one());
+
+		T::deposit_account(relayer.clone(), crate::Pallet::<T>::required_stake());
+		crate::Pallet::<T>::register(RawOrigin::Signed(relayer.clone()).into(), valid_till).unwrap();
+
+		frame_system::Pallet::<T>::set_block_number(valid_till.saturating_add(One::one()));
+	}: _(RawOrigin::Signed(relayer.clone()))
+	verify {
+		assert!(!crate::Pallet::<T>::is_registration_active(&relayer));
+	}
 }
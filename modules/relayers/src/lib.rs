@@ -14,27 +14,33 @@
 // You should have received a copy of the GNU General Public License
 // along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
 
-//! Runtime module that is used to store relayer rewards and (in the future) to
+//! Runtime module that is used to store relayer rewards, registrations and (in the future) to
 //! coordinate relations between relayers.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 
-use bp_messages::LaneId;
-use bp_relayers::{PaymentProcedure, RelayerRewardsKeyProvider};
+use bp_relayers::{
+	ExplicitOrAccountParams, PaymentProcedure, Registration, RelayerRewardsKeyProvider,
+	RewardsAccountParams, StakeAndSlash,
+};
 use bp_runtime::StorageDoubleMapKeyProvider;
-use frame_support::sp_runtime::Saturating;
+use frame_support::{fail, sp_runtime::Saturating};
 use sp_arithmetic::traits::{AtLeast32BitUnsigned, Zero};
+use sp_runtime::{traits::CheckedSub, PerThing, Perbill};
 use sp_std::marker::PhantomData;
 use weights::WeightInfo;
 
 pub use pallet::*;
 pub use payment_adapter::DeliveryConfirmationPaymentsAdapter;
+pub use stake_adapter::StakeAndSlashNamed;
 
 pub mod benchmarking;
+pub mod migration;
 
 mod mock;
 mod payment_adapter;
+mod stake_adapter;
 
 pub mod weights;
 
@@ -59,12 +65,21 @@ pub mod pallet {
 		type Reward: AtLeast32BitUnsigned + Copy + Parameter + MaxEncodedLen;
 		/// Pay rewards adapter.
 		type PaymentProcedure: PaymentProcedure<Self::AccountId, Self::Reward>;
+		/// Stake and slash scheme.
+		type StakeAndSlash: StakeAndSlash<Self::AccountId, Self::BlockNumber, Self::Reward>;
+		/// Part of the relayer stake that is slashed when the relayer misbehaves. The rest of the
+		/// stake is returned to the relayer when it is `deregistered`.
+		type SlashFraction: Get<Perbill>;
+		/// Account that receives slashed funds. If it is `None`, slashed funds are sent to the
+		/// rewards account, selected by the caller of the `slash_and_deregister`.
+		type SlashDestination: Get<Option<Self::AccountId>>;
 		/// Pallet call weights.
 		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(migration::v1::STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::call]
@@ -72,56 +87,369 @@ pub mod pallet {
 		/// Claim accumulated rewards.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::claim_rewards())]
-		pub fn claim_rewards(origin: OriginFor<T>, lane_id: LaneId) -> DispatchResult {
+		pub fn claim_rewards(
+			origin: OriginFor<T>,
+			rewards_account_params: RewardsAccountParams,
+		) -> DispatchResult {
 			let relayer = ensure_signed(origin)?;
 
 			RelayerRewards::<T>::try_mutate_exists(
 				&relayer,
-				lane_id,
+				rewards_account_params,
 				|maybe_reward| -> DispatchResult {
 					let reward = maybe_reward.take().ok_or(Error::<T>::NoRewardForRelayer)?;
-					T::PaymentProcedure::pay_reward(&relayer, lane_id, reward).map_err(|e| {
-						log::trace!(
-							target: LOG_TARGET,
-							"Failed to pay {:?} rewards to {:?}: {:?}",
-							lane_id,
-							relayer,
-							e,
-						);
-						Error::<T>::FailedToPayReward
-					})?;
+					T::PaymentProcedure::pay_reward(&relayer, rewards_account_params, reward)
+						.map_err(|e| {
+							log::trace!(
+								target: LOG_TARGET,
+								"Failed to pay {:?} rewards to {:?}: {:?}",
+								rewards_account_params,
+								relayer,
+								e,
+							);
+							Error::<T>::FailedToPayReward
+						})?;
 
 					Self::deposit_event(Event::<T>::RewardPaid {
 						relayer: relayer.clone(),
-						lane_id,
+						rewards_account_params,
 						reward,
 					});
 					Ok(())
 				},
 			)
 		}
+
+		/// Register relayer.
+		///
+		/// Registration allows relayer to get priority boost for its bridge transactions. The
+		/// `RequiredStake` is reserved from the relayer account. The registration is active
+		/// until the `valid_till` block.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::register())]
+		pub fn register(origin: OriginFor<T>, valid_till: T::BlockNumber) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+
+			RegisteredRelayers::<T>::try_mutate(&relayer, |maybe_registration| -> DispatchResult {
+				ensure!(maybe_registration.is_none(), Error::<T>::AlreadyRegistered);
+				Self::ensure_valid_registration_lease(valid_till)?;
+
+				let required_stake = Self::required_stake();
+				T::StakeAndSlash::reserve(&relayer, required_stake).map_err(|e| {
+					log::trace!(
+						target: LOG_TARGET,
+						"Failed to reserve {:?} on relayer {:?} account: {:?}",
+						required_stake,
+						relayer,
+						e,
+					);
+
+					Error::<T>::FailedToReserve
+				})?;
+
+				let registration = Registration { valid_till, stake: required_stake };
+				log::trace!(target: LOG_TARGET, "Successfully registered relayer: {:?}", relayer);
+				Self::deposit_event(Event::<T>::RegistrationUpdated {
+					relayer: relayer.clone(),
+					registration,
+				});
+
+				*maybe_registration = Some(registration);
+
+				Ok(())
+			})
+		}
+
+		/// Extend relayer registration.
+		///
+		/// The new `valid_till` must not be less than the current one. If the `RequiredStake`
+		/// has been changed since the relayer has been registered, the difference is reserved
+		/// from (or returned to) the relayer account.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::extend_registration())]
+		pub fn extend_registration(
+			origin: OriginFor<T>,
+			valid_till: T::BlockNumber,
+		) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+
+			RegisteredRelayers::<T>::try_mutate(&relayer, |maybe_registration| -> DispatchResult {
+				let mut registration = match *maybe_registration {
+					Some(registration) => registration,
+					None => fail!(Error::<T>::NotRegistered),
+				};
+
+				// new `valid_till` must be larger (or equal) than the old one
+				ensure!(
+					valid_till >= registration.valid_till,
+					Error::<T>::CannotReduceRegistrationLease,
+				);
+				Self::ensure_valid_registration_lease(valid_till)?;
+				registration.valid_till = valid_till;
+
+				// regarding stake, there are three options:
+				// - if relayer stake is larger than required stake, we may do unreserve
+				// - if relayer stake equals to required stake, we do nothing
+				// - if relayer stake is smaller than required stake, we do additional reserve
+				let required_stake = Self::required_stake();
+				if let Some(to_unreserve) = registration.stake.checked_sub(&required_stake) {
+					Self::do_unreserve(&relayer, to_unreserve)?;
+				} else if let Some(to_reserve) = required_stake.checked_sub(&registration.stake) {
+					T::StakeAndSlash::reserve(&relayer, to_reserve).map_err(|e| {
+						log::trace!(
+							target: LOG_TARGET,
+							"Failed to reserve {:?} on relayer {:?} account: {:?}",
+							to_reserve,
+							relayer,
+							e,
+						);
+
+						Error::<T>::FailedToReserve
+					})?;
+				}
+				registration.stake = required_stake;
+
+				log::trace!(
+					target: LOG_TARGET,
+					"Successfully extended relayer registration: {:?}",
+					relayer,
+				);
+				Self::deposit_event(Event::<T>::RegistrationUpdated {
+					relayer: relayer.clone(),
+					registration,
+				});
+
+				*maybe_registration = Some(registration);
+
+				Ok(())
+			})
+		}
+
+		/// `Deregister` relayer.
+		///
+		/// After this call, bridge transactions of the relayer won't get any priority boost.
+		/// The relayer may only deregister after its registration has ended.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::deregister())]
+		pub fn deregister(origin: OriginFor<T>) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+
+			RegisteredRelayers::<T>::try_mutate(&relayer, |maybe_registration| -> DispatchResult {
+				let registration = match maybe_registration.take() {
+					Some(registration) => registration,
+					None => fail!(Error::<T>::NotRegistered),
+				};
+
+				// we can't deregister until `valid_till + 1`
+				ensure!(
+					registration.valid_till < frame_system::Pallet::<T>::block_number(),
+					Error::<T>::RegistrationIsStillActive,
+				);
+
+				// if stake is non-zero, we should do unreserve
+				if !registration.stake.is_zero() {
+					Self::do_unreserve(&relayer, registration.stake)?;
+				}
+
+				log::trace!(target: LOG_TARGET, "Successfully deregistered relayer: {:?}", relayer);
+				Self::deposit_event(Event::<T>::Deregistered { relayer: relayer.clone() });
+
+				*maybe_registration = None;
+
+				Ok(())
+			})
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
-		/// Register reward for given relayer.
-		pub fn register_relayer_reward(lane_id: LaneId, relayer: &T::AccountId, reward: T::Reward) {
-			if reward.is_zero() {
-				return
+		/// Returns true if given relayer registration is active at current block.
+		///
+		/// This call respects both `RequiredStake` and `RequiredRegistrationLease`, meaning that
+		/// it'll return false if registered stake is lower than required or if remaining lease
+		/// is less than required.
+		pub fn is_registration_active(relayer: &T::AccountId) -> bool {
+			let registration = match Self::registered_relayer(relayer) {
+				Some(registration) => registration,
+				None => return false,
+			};
+
+			// registration is inactive if relayer stake is less than required
+			if registration.stake < Self::required_stake() {
+				return false
+			}
+
+			// registration is inactive if it ends soon
+			let remaining_lease = registration
+				.valid_till
+				.saturating_sub(frame_system::Pallet::<T>::block_number());
+			if remaining_lease <= Self::required_registration_lease() {
+				return false
+			}
+
+			true
+		}
+
+		/// Return required registration lease.
+		pub(crate) fn required_registration_lease() -> T::BlockNumber {
+			<T::StakeAndSlash as StakeAndSlash<
+				T::AccountId,
+				T::BlockNumber,
+				T::Reward,
+			>>::RequiredRegistrationLease::get()
+		}
+
+		/// Return required stake.
+		pub(crate) fn required_stake() -> T::Reward {
+			<T::StakeAndSlash as StakeAndSlash<
+				T::AccountId,
+				T::BlockNumber,
+				T::Reward,
+			>>::RequiredStake::get()
+		}
+
+		/// Ensure that the registration with given `valid_till` has a lease that is larger than
+		/// the `RequiredRegistrationLease`.
+		fn ensure_valid_registration_lease(valid_till: T::BlockNumber) -> DispatchResult {
+			let lease = valid_till.saturating_sub(frame_system::Pallet::<T>::block_number());
+			ensure!(
+				lease > Self::required_registration_lease(),
+				Error::<T>::InvalidRegistrationLease
+			);
+			Ok(())
+		}
+
+		/// `Unreserve` given amount on relayer account.
+		fn do_unreserve(relayer: &T::AccountId, amount: T::Reward) -> DispatchResult {
+			let failed_to_unreserve = T::StakeAndSlash::unreserve(relayer, amount);
+			if !failed_to_unreserve.is_zero() {
+				log::trace!(
+					target: LOG_TARGET,
+					"Failed to unreserve {:?}/{:?} on relayer {:?} account",
+					failed_to_unreserve,
+					amount,
+					relayer,
+				);
+
+				fail!(Error::<T>::FailedToUnreserve)
 			}
 
-			RelayerRewards::<T>::mutate(relayer, lane_id, |old_reward: &mut Option<T::Reward>| {
-				let new_reward = old_reward.unwrap_or_else(Zero::zero).saturating_add(reward);
-				*old_reward = Some(new_reward);
+			Ok(())
+		}
+
+		/// Slash and `deregister` relayer. This function slashes `T::SlashFraction` of the staked
+		/// balance and returns the rest of the stake to the relayer.
+		///
+		/// Slashed funds are sent to the `T::SlashDestination` account or, if it is not
+		/// configured, to the rewards account, identified by the `slash_destination`. It may fail
+		/// inside, but error is swallowed and we only log it.
+		pub fn slash_and_deregister(
+			relayer: &T::AccountId,
+			slash_destination: RewardsAccountParams,
+		) {
+			let registration = match RegisteredRelayers::<T>::take(relayer) {
+				Some(registration) => registration,
+				None => {
+					log::trace!(
+						target: crate::LOG_TARGET,
+						"Cannot slash unregistered relayer {:?}",
+						relayer,
+					);
+
+					return
+				},
+			};
+
+			let slash_destination = match T::SlashDestination::get() {
+				Some(account) => ExplicitOrAccountParams::Explicit(account),
+				None => ExplicitOrAccountParams::Params(slash_destination),
+			};
+			let slash_amount = T::SlashFraction::get().mul_floor(registration.stake);
+			match T::StakeAndSlash::repatriate_reserved(
+				relayer,
+				slash_destination.clone(),
+				slash_amount,
+			) {
+				Ok(failed_to_slash) if failed_to_slash.is_zero() => {
+					log::trace!(
+						target: crate::LOG_TARGET,
+						"Relayer account {:?} has been slashed for {:?}. Funds were deposited to {:?}",
+						relayer,
+						slash_amount,
+						slash_destination,
+					);
+				},
+				Ok(failed_to_slash) => {
+					log::trace!(
+						target: crate::LOG_TARGET,
+						"Relayer account {:?} has been partially slashed for {:?}. Funds were deposited to {:?}. \
+						Failed to slash: {:?}",
+						relayer,
+						slash_amount,
+						slash_destination,
+						failed_to_slash,
+					);
+				},
+				Err(e) => {
+					// it may fail if there's no beneficiary account. For us it means that this
+					// account must exist before we'll deploy the bridge
+					log::debug!(
+						target: crate::LOG_TARGET,
+						"Failed to slash relayer account {:?}: {:?}. Maybe beneficiary account doesn't exist? \
+						Beneficiary: {:?}, amount: {:?}",
+						relayer,
+						e,
+						slash_destination,
+						slash_amount,
+					);
+				},
+			}
 
+			// the rest of the stake is returned to the relayer
+			let remaining_stake = registration.stake.saturating_sub(slash_amount);
+			let failed_to_unreserve = T::StakeAndSlash::unreserve(relayer, remaining_stake);
+			if !failed_to_unreserve.is_zero() {
 				log::trace!(
 					target: crate::LOG_TARGET,
-					"Relayer {:?} can now claim reward for serving lane {:?}: {:?}",
+					"Failed to unreserve {:?}/{:?} of remaining stake of slashed relayer {:?}",
+					failed_to_unreserve,
+					remaining_stake,
 					relayer,
-					lane_id,
-					new_reward,
 				);
+			}
+
+			Self::deposit_event(Event::<T>::SlashedAndDeregistered {
+				relayer: relayer.clone(),
+				registration,
 			});
 		}
+
+		/// Register reward for given relayer.
+		pub fn register_relayer_reward(
+			rewards_account_params: RewardsAccountParams,
+			relayer: &T::AccountId,
+			reward: T::Reward,
+		) {
+			if reward.is_zero() {
+				return
+			}
+
+			RelayerRewards::<T>::mutate(
+				relayer,
+				rewards_account_params,
+				|old_reward: &mut Option<T::Reward>| {
+					let new_reward = old_reward.unwrap_or_else(Zero::zero).saturating_add(reward);
+					*old_reward = Some(new_reward);
+
+					log::trace!(
+						target: crate::LOG_TARGET,
+						"Relayer {:?} can now claim reward for serving payer {:?}: {:?}",
+						relayer,
+						rewards_account_params,
+						new_reward,
+					);
+				},
+			);
+		}
 	}
 
 	#[pallet::event]
@@ -131,11 +459,30 @@ pub mod pallet {
 		RewardPaid {
 			/// Relayer account that has been rewarded.
 			relayer: T::AccountId,
-			/// Relayer has received reward for serving this lane.
-			lane_id: LaneId,
+			/// Relayer has received reward from this account.
+			rewards_account_params: RewardsAccountParams,
 			/// Reward amount.
 			reward: T::Reward,
 		},
+		/// Relayer registration has been added or updated.
+		RegistrationUpdated {
+			/// Relayer account that has been registered.
+			relayer: T::AccountId,
+			/// Relayer registration.
+			registration: Registration<T::BlockNumber, T::Reward>,
+		},
+		/// Relayer has been `deregistered`.
+		Deregistered {
+			/// Relayer account that has been `deregistered`.
+			relayer: T::AccountId,
+		},
+		/// Relayer has been slashed and `deregistered`.
+		SlashedAndDeregistered {
+			/// Relayer account that has been `deregistered`.
+			relayer: T::AccountId,
+			/// Registration that was removed.
+			registration: Registration<T::BlockNumber, T::Reward>,
+		},
 	}
 
 	#[pallet::error]
@@ -144,6 +491,22 @@ pub mod pallet {
 		NoRewardForRelayer,
 		/// Reward payment procedure has failed.
 		FailedToPayReward,
+		/// The relayer has tried to register for past block or registration lease
+		/// is too short.
+		InvalidRegistrationLease,
+		/// New registration lease is less than the previous one.
+		CannotReduceRegistrationLease,
+		/// Failed to reserve enough funds on relayer account.
+		FailedToReserve,
+		/// Failed to `unreserve` enough funds on relayer account.
+		FailedToUnreserve,
+		/// The relayer is already registered. Use `extend_registration` to update its
+		/// registration.
+		AlreadyRegistered,
+		/// Cannot `deregister` if not registered.
+		NotRegistered,
+		/// Failed to `deregister` relayer, because lease is still active.
+		RegistrationIsStillActive,
 	}
 
 	/// Map of the relayer => accumulated reward.
@@ -158,6 +521,21 @@ pub mod pallet {
 		<RelayerRewardsKeyProviderOf<T> as StorageDoubleMapKeyProvider>::Value,
 		OptionQuery,
 	>;
+
+	/// Relayers that have reserved some of their balance to get free priority boost
+	/// for their bridge transactions.
+	///
+	/// Other relayers may submit transactions as well, but they will have default
+	/// priority.
+	#[pallet::storage]
+	#[pallet::getter(fn registered_relayer)]
+	pub type RegisteredRelayers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Registration<T::BlockNumber, T::Reward>,
+		OptionQuery,
+	>;
 }
 
 #[cfg(test)]
@@ -166,6 +544,8 @@ mod tests {
 	use mock::{RuntimeEvent as TestEvent, *};
 
 	use crate::Event::RewardPaid;
+	use bp_messages::LaneId;
+	use bp_relayers::RewardsAccountOwner;
 	use frame_support::{
 		assert_noop, assert_ok,
 		traits::fungible::{Inspect, Mutate},
@@ -182,7 +562,10 @@ mod tests {
 	fn root_cant_claim_anything() {
 		run_test(|| {
 			assert_noop!(
-				Pallet::<TestRuntime>::claim_rewards(RuntimeOrigin::root(), TEST_LANE_ID),
+				Pallet::<TestRuntime>::claim_rewards(
+					RuntimeOrigin::root(),
+					TEST_REWARDS_ACCOUNT_PARAMS
+				),
 				DispatchError::BadOrigin,
 			);
 		});
@@ -194,7 +577,7 @@ mod tests {
 			assert_noop!(
 				Pallet::<TestRuntime>::claim_rewards(
 					RuntimeOrigin::signed(REGULAR_RELAYER),
-					TEST_LANE_ID
+					TEST_REWARDS_ACCOUNT_PARAMS
 				),
 				Error::<TestRuntime>::NoRewardForRelayer,
 			);
@@ -204,11 +587,15 @@ mod tests {
 	#[test]
 	fn relayer_cant_claim_if_payment_procedure_fails() {
 		run_test(|| {
-			RelayerRewards::<TestRuntime>::insert(FAILING_RELAYER, TEST_LANE_ID, 100);
+			RelayerRewards::<TestRuntime>::insert(
+				FAILING_RELAYER,
+				TEST_REWARDS_ACCOUNT_PARAMS,
+				100,
+			);
 			assert_noop!(
 				Pallet::<TestRuntime>::claim_rewards(
 					RuntimeOrigin::signed(FAILING_RELAYER),
-					TEST_LANE_ID
+					TEST_REWARDS_ACCOUNT_PARAMS
 				),
 				Error::<TestRuntime>::FailedToPayReward,
 			);
@@ -220,12 +607,19 @@ mod tests {
 		run_test(|| {
 			get_ready_for_events();
 
-			RelayerRewards::<TestRuntime>::insert(REGULAR_RELAYER, TEST_LANE_ID, 100);
+			RelayerRewards::<TestRuntime>::insert(
+				REGULAR_RELAYER,
+				TEST_REWARDS_ACCOUNT_PARAMS,
+				100,
+			);
 			assert_ok!(Pallet::<TestRuntime>::claim_rewards(
 				RuntimeOrigin::signed(REGULAR_RELAYER),
-				TEST_LANE_ID
+				TEST_REWARDS_ACCOUNT_PARAMS
 			));
-			assert_eq!(RelayerRewards::<TestRuntime>::get(REGULAR_RELAYER, TEST_LANE_ID), None);
+			assert_eq!(
+				RelayerRewards::<TestRuntime>::get(REGULAR_RELAYER, TEST_REWARDS_ACCOUNT_PARAMS),
+				None
+			);
 
 			//Check if the `RewardPaid` event was emitted.
 			assert_eq!(
@@ -234,7 +628,7 @@ mod tests {
 					phase: Phase::Initialization,
 					event: TestEvent::Relayers(RewardPaid {
 						relayer: REGULAR_RELAYER,
-						lane_id: TEST_LANE_ID,
+						rewards_account_params: TEST_REWARDS_ACCOUNT_PARAMS,
 						reward: 100
 					}),
 					topics: vec![],
@@ -244,30 +638,443 @@ mod tests {
 	}
 
 	#[test]
-	fn pay_lane_reward_from_account_actually_pays_reward() {
+	fn register_fails_if_valid_till_is_a_past_block() {
+		run_test(|| {
+			System::<TestRuntime>::set_block_number(100);
+
+			assert_noop!(
+				Pallet::<TestRuntime>::register(RuntimeOrigin::signed(REGISTER_RELAYER), 50),
+				Error::<TestRuntime>::InvalidRegistrationLease,
+			);
+		});
+	}
+
+	#[test]
+	fn register_fails_if_valid_till_lease_is_less_than_required() {
+		run_test(|| {
+			System::<TestRuntime>::set_block_number(100);
+
+			assert_noop!(
+				Pallet::<TestRuntime>::register(
+					RuntimeOrigin::signed(REGISTER_RELAYER),
+					99 + Lease::get()
+				),
+				Error::<TestRuntime>::InvalidRegistrationLease,
+			);
+		});
+	}
+
+	#[test]
+	fn register_works() {
+		run_test(|| {
+			get_ready_for_events();
+
+			assert_ok!(Pallet::<TestRuntime>::register(
+				RuntimeOrigin::signed(REGISTER_RELAYER),
+				150
+			));
+			assert_eq!(Balances::reserved_balance(REGISTER_RELAYER), Stake::get());
+			assert_eq!(
+				Pallet::<TestRuntime>::registered_relayer(REGISTER_RELAYER),
+				Some(Registration { valid_till: 150, stake: Stake::get() }),
+			);
+
+			assert_eq!(
+				System::<TestRuntime>::events().last(),
+				Some(&EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Relayers(Event::RegistrationUpdated {
+						relayer: REGISTER_RELAYER,
+						registration: Registration { valid_till: 150, stake: Stake::get() },
+					}),
+					topics: vec![],
+				}),
+			);
+		});
+	}
+
+	#[test]
+	fn register_fails_if_relayer_is_already_registered() {
+		run_test(|| {
+			assert_ok!(Pallet::<TestRuntime>::register(
+				RuntimeOrigin::signed(REGISTER_RELAYER),
+				150
+			));
+
+			assert_noop!(
+				Pallet::<TestRuntime>::register(RuntimeOrigin::signed(REGISTER_RELAYER), 200),
+				Error::<TestRuntime>::AlreadyRegistered,
+			);
+		});
+	}
+
+	#[test]
+	fn register_fails_if_it_cant_reserve_stake() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime>::register(RuntimeOrigin::signed(REGULAR_RELAYER), 150),
+				Error::<TestRuntime>::FailedToReserve,
+			);
+		});
+	}
+
+	#[test]
+	fn extend_registration_fails_if_relayer_is_not_registered() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime>::extend_registration(
+					RuntimeOrigin::signed(REGISTER_RELAYER),
+					150
+				),
+				Error::<TestRuntime>::NotRegistered,
+			);
+		});
+	}
+
+	#[test]
+	fn extend_registration_fails_if_new_valid_till_is_lesser_than_previous() {
+		run_test(|| {
+			assert_ok!(Pallet::<TestRuntime>::register(
+				RuntimeOrigin::signed(REGISTER_RELAYER),
+				150
+			));
+
+			assert_noop!(
+				Pallet::<TestRuntime>::extend_registration(
+					RuntimeOrigin::signed(REGISTER_RELAYER),
+					125
+				),
+				Error::<TestRuntime>::CannotReduceRegistrationLease,
+			);
+		});
+	}
+
+	#[test]
+	fn extend_registration_works() {
+		run_test(|| {
+			assert_ok!(Pallet::<TestRuntime>::register(
+				RuntimeOrigin::signed(REGISTER_RELAYER),
+				150
+			));
+
+			get_ready_for_events();
+			assert_ok!(Pallet::<TestRuntime>::extend_registration(
+				RuntimeOrigin::signed(REGISTER_RELAYER),
+				200
+			));
+			assert_eq!(Balances::reserved_balance(REGISTER_RELAYER), Stake::get());
+			assert_eq!(
+				Pallet::<TestRuntime>::registered_relayer(REGISTER_RELAYER),
+				Some(Registration { valid_till: 200, stake: Stake::get() }),
+			);
+
+			assert_eq!(
+				System::<TestRuntime>::events().last(),
+				Some(&EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Relayers(Event::RegistrationUpdated {
+						relayer: REGISTER_RELAYER,
+						registration: Registration { valid_till: 200, stake: Stake::get() },
+					}),
+					topics: vec![],
+				}),
+			);
+		});
+	}
+
+	#[test]
+	fn extend_registration_reserves_more_if_required_stake_has_been_increased() {
+		run_test(|| {
+			RegisteredRelayers::<TestRuntime>::insert(
+				REGISTER_RELAYER,
+				Registration { valid_till: 150, stake: Stake::get() - 1 },
+			);
+			TestStakeAndSlash::reserve(&REGISTER_RELAYER, Stake::get() - 1).unwrap();
+
+			assert_ok!(Pallet::<TestRuntime>::extend_registration(
+				RuntimeOrigin::signed(REGISTER_RELAYER),
+				150
+			));
+			assert_eq!(Balances::reserved_balance(REGISTER_RELAYER), Stake::get());
+			assert_eq!(
+				Pallet::<TestRuntime>::registered_relayer(REGISTER_RELAYER),
+				Some(Registration { valid_till: 150, stake: Stake::get() }),
+			);
+		});
+	}
+
+	#[test]
+	fn extend_registration_unreserves_excess_if_required_stake_has_been_decreased() {
+		run_test(|| {
+			RegisteredRelayers::<TestRuntime>::insert(
+				REGISTER_RELAYER,
+				Registration { valid_till: 150, stake: Stake::get() + 1 },
+			);
+			TestStakeAndSlash::reserve(&REGISTER_RELAYER, Stake::get() + 1).unwrap();
+
+			assert_ok!(Pallet::<TestRuntime>::extend_registration(
+				RuntimeOrigin::signed(REGISTER_RELAYER),
+				150
+			));
+			assert_eq!(Balances::reserved_balance(REGISTER_RELAYER), Stake::get());
+			assert_eq!(
+				Pallet::<TestRuntime>::registered_relayer(REGISTER_RELAYER),
+				Some(Registration { valid_till: 150, stake: Stake::get() }),
+			);
+		});
+	}
+
+	#[test]
+	fn deregister_fails_if_not_registered() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime>::deregister(RuntimeOrigin::signed(REGISTER_RELAYER)),
+				Error::<TestRuntime>::NotRegistered,
+			);
+		});
+	}
+
+	#[test]
+	fn deregister_fails_if_registration_is_still_active() {
+		run_test(|| {
+			assert_ok!(Pallet::<TestRuntime>::register(
+				RuntimeOrigin::signed(REGISTER_RELAYER),
+				150
+			));
+
+			System::<TestRuntime>::set_block_number(100);
+
+			assert_noop!(
+				Pallet::<TestRuntime>::deregister(RuntimeOrigin::signed(REGISTER_RELAYER)),
+				Error::<TestRuntime>::RegistrationIsStillActive,
+			);
+		});
+	}
+
+	#[test]
+	fn deregister_works() {
+		run_test(|| {
+			get_ready_for_events();
+
+			assert_ok!(Pallet::<TestRuntime>::register(
+				RuntimeOrigin::signed(REGISTER_RELAYER),
+				150
+			));
+
+			System::<TestRuntime>::set_block_number(151);
+
+			let free_balance = Balances::free_balance(REGISTER_RELAYER);
+			assert_ok!(Pallet::<TestRuntime>::deregister(RuntimeOrigin::signed(REGISTER_RELAYER)));
+			assert_eq!(Balances::reserved_balance(REGISTER_RELAYER), 0);
+			assert_eq!(Balances::free_balance(REGISTER_RELAYER), free_balance + Stake::get());
+			assert_eq!(Pallet::<TestRuntime>::registered_relayer(REGISTER_RELAYER), None);
+
+			assert_eq!(
+				System::<TestRuntime>::events().last(),
+				Some(&EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Relayers(Event::Deregistered { relayer: REGISTER_RELAYER }),
+					topics: vec![],
+				}),
+			);
+		});
+	}
+
+	#[test]
+	fn is_registration_active_is_false_for_unregistered_relayer() {
+		run_test(|| {
+			assert!(!Pallet::<TestRuntime>::is_registration_active(&REGISTER_RELAYER));
+		});
+	}
+
+	#[test]
+	fn is_registration_active_is_false_when_stake_is_too_low() {
+		run_test(|| {
+			RegisteredRelayers::<TestRuntime>::insert(
+				REGISTER_RELAYER,
+				Registration { valid_till: 150, stake: Stake::get() - 1 },
+			);
+			assert!(!Pallet::<TestRuntime>::is_registration_active(&REGISTER_RELAYER));
+		});
+	}
+
+	#[test]
+	fn is_registration_active_is_false_when_remaining_lease_is_too_low() {
+		run_test(|| {
+			System::<TestRuntime>::set_block_number(150 - Lease::get());
+
+			RegisteredRelayers::<TestRuntime>::insert(
+				REGISTER_RELAYER,
+				Registration { valid_till: 150, stake: Stake::get() },
+			);
+			assert!(!Pallet::<TestRuntime>::is_registration_active(&REGISTER_RELAYER));
+		});
+	}
+
+	#[test]
+	fn is_registration_active_is_true_when_relayer_is_properly_registered() {
+		run_test(|| {
+			System::<TestRuntime>::set_block_number(150 - Lease::get());
+
+			RegisteredRelayers::<TestRuntime>::insert(
+				REGISTER_RELAYER,
+				Registration { valid_till: 151, stake: Stake::get() },
+			);
+			assert!(Pallet::<TestRuntime>::is_registration_active(&REGISTER_RELAYER));
+		});
+	}
+
+	#[test]
+	fn slash_and_deregister_works() {
+		run_test(|| {
+			get_ready_for_events();
+
+			let beneficiary_account =
+				TestPaymentProcedure::rewards_account(TEST_REWARDS_ACCOUNT_PARAMS);
+			Balances::mint_into(&beneficiary_account, ExistentialDeposit::get()).unwrap();
+
+			assert_ok!(Pallet::<TestRuntime>::register(
+				RuntimeOrigin::signed(REGISTER_RELAYER),
+				150
+			));
+			let free_balance = Balances::free_balance(REGISTER_RELAYER);
+
+			Pallet::<TestRuntime>::slash_and_deregister(
+				&REGISTER_RELAYER,
+				TEST_REWARDS_ACCOUNT_PARAMS,
+			);
+			assert_eq!(Pallet::<TestRuntime>::registered_relayer(REGISTER_RELAYER), None);
+			assert_eq!(Balances::reserved_balance(REGISTER_RELAYER), 0);
+			assert_eq!(Balances::free_balance(REGISTER_RELAYER), free_balance);
+			assert_eq!(
+				Balances::free_balance(beneficiary_account),
+				ExistentialDeposit::get() + Stake::get()
+			);
+
+			assert_eq!(
+				System::<TestRuntime>::events().last(),
+				Some(&EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Relayers(Event::SlashedAndDeregistered {
+						relayer: REGISTER_RELAYER,
+						registration: Registration { valid_till: 150, stake: Stake::get() },
+					}),
+					topics: vec![],
+				}),
+			);
+		});
+	}
+
+	#[test]
+	fn slash_and_deregister_returns_remaining_stake_to_relayer() {
+		run_test(|| {
+			TestSlashFraction::set(Perbill::from_percent(30));
+
+			let beneficiary_account =
+				TestPaymentProcedure::rewards_account(TEST_REWARDS_ACCOUNT_PARAMS);
+			Balances::mint_into(&beneficiary_account, ExistentialDeposit::get()).unwrap();
+
+			assert_ok!(Pallet::<TestRuntime>::register(
+				RuntimeOrigin::signed(REGISTER_RELAYER),
+				150
+			));
+			let free_balance = Balances::free_balance(REGISTER_RELAYER);
+
+			Pallet::<TestRuntime>::slash_and_deregister(
+				&REGISTER_RELAYER,
+				TEST_REWARDS_ACCOUNT_PARAMS,
+			);
+			let slashed = Perbill::from_percent(30) * Stake::get();
+			assert_eq!(Pallet::<TestRuntime>::registered_relayer(REGISTER_RELAYER), None);
+			assert_eq!(Balances::reserved_balance(REGISTER_RELAYER), 0);
+			assert_eq!(
+				Balances::free_balance(REGISTER_RELAYER),
+				free_balance + Stake::get() - slashed
+			);
+			assert_eq!(
+				Balances::free_balance(beneficiary_account),
+				ExistentialDeposit::get() + slashed
+			);
+		});
+	}
+
+	#[test]
+	fn slash_and_deregister_sends_slashed_funds_to_configured_account() {
+		run_test(|| {
+			let slash_destination = 42;
+			TestSlashDestination::set(Some(slash_destination));
+			Balances::mint_into(&slash_destination, ExistentialDeposit::get()).unwrap();
+
+			assert_ok!(Pallet::<TestRuntime>::register(
+				RuntimeOrigin::signed(REGISTER_RELAYER),
+				150
+			));
+
+			Pallet::<TestRuntime>::slash_and_deregister(
+				&REGISTER_RELAYER,
+				TEST_REWARDS_ACCOUNT_PARAMS,
+			);
+			assert_eq!(Balances::reserved_balance(REGISTER_RELAYER), 0);
+			assert_eq!(
+				Balances::free_balance(slash_destination),
+				ExistentialDeposit::get() + Stake::get()
+			);
+			assert_eq!(
+				Balances::free_balance(TestPaymentProcedure::rewards_account(
+					TEST_REWARDS_ACCOUNT_PARAMS
+				)),
+				0
+			);
+		});
+	}
+
+	#[test]
+	fn slash_and_deregister_does_nothing_if_relayer_is_not_registered() {
+		run_test(|| {
+			get_ready_for_events();
+
+			Pallet::<TestRuntime>::slash_and_deregister(
+				&REGISTER_RELAYER,
+				TEST_REWARDS_ACCOUNT_PARAMS,
+			);
+			assert!(System::<TestRuntime>::events().is_empty());
+		});
+	}
+
+	#[test]
+	fn pay_reward_from_account_actually_pays_reward() {
 		type Balances = pallet_balances::Pallet<TestRuntime>;
-		type PayLaneRewardFromAccount = bp_relayers::PayLaneRewardFromAccount<Balances, AccountId>;
+		type PayRewardFromAccount = bp_relayers::PayRewardFromAccount<Balances, AccountId>;
 
 		run_test(|| {
-			let lane0_rewards_account =
-				PayLaneRewardFromAccount::lane_rewards_account(LaneId([0, 0, 0, 0]));
-			let lane1_rewards_account =
-				PayLaneRewardFromAccount::lane_rewards_account(LaneId([0, 0, 0, 1]));
-
-			Balances::mint_into(&lane0_rewards_account, 100).unwrap();
-			Balances::mint_into(&lane1_rewards_account, 100).unwrap();
-			assert_eq!(Balances::balance(&lane0_rewards_account), 100);
-			assert_eq!(Balances::balance(&lane1_rewards_account), 100);
+			let in_lane_0 = RewardsAccountParams::new(
+				LaneId([0, 0, 0, 0]),
+				*b"test",
+				RewardsAccountOwner::ThisChain,
+			);
+			let out_lane_1 = RewardsAccountParams::new(
+				LaneId([0, 0, 0, 1]),
+				*b"test",
+				RewardsAccountOwner::BridgedChain,
+			);
+
+			let in_lane0_rewards_account = PayRewardFromAccount::rewards_account(in_lane_0);
+			let out_lane1_rewards_account = PayRewardFromAccount::rewards_account(out_lane_1);
+
+			Balances::mint_into(&in_lane0_rewards_account, 100).unwrap();
+			Balances::mint_into(&out_lane1_rewards_account, 100).unwrap();
+			assert_eq!(Balances::balance(&in_lane0_rewards_account), 100);
+			assert_eq!(Balances::balance(&out_lane1_rewards_account), 100);
 			assert_eq!(Balances::balance(&1), 0);
 
-			PayLaneRewardFromAccount::pay_reward(&1, LaneId([0, 0, 0, 0]), 100).unwrap();
-			assert_eq!(Balances::balance(&lane0_rewards_account), 0);
-			assert_eq!(Balances::balance(&lane1_rewards_account), 100);
+			PayRewardFromAccount::pay_reward(&1, in_lane_0, 100).unwrap();
+			assert_eq!(Balances::balance(&in_lane0_rewards_account), 0);
+			assert_eq!(Balances::balance(&out_lane1_rewards_account), 100);
 			assert_eq!(Balances::balance(&1), 100);
 
-			PayLaneRewardFromAccount::pay_reward(&1, LaneId([0, 0, 0, 1]), 100).unwrap();
-			assert_eq!(Balances::balance(&lane0_rewards_account), 0);
-			assert_eq!(Balances::balance(&lane1_rewards_account), 0);
+			PayRewardFromAccount::pay_reward(&1, out_lane_1, 100).unwrap();
+			assert_eq!(Balances::balance(&in_lane0_rewards_account), 0);
+			assert_eq!(Balances::balance(&out_lane1_rewards_account), 0);
 			assert_eq!(Balances::balance(&1), 200);
 		});
 	}
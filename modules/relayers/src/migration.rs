@@ -0,0 +1,181 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations of the relayers pallet.
+
+use crate::{Config, Pallet, LOG_TARGET};
+
+use bp_messages::LaneId;
+use codec::{Decode, Encode};
+use frame_support::{
+	traits::{fungible::Transfer, Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_runtime::traits::AccountIdConversion;
+use sp_std::marker::PhantomData;
+
+/// Storage of the pallet before rewards have been keyed by the rewards account parameters.
+pub mod v0 {
+	use super::*;
+	use frame_support::{Blake2_128Concat, Identity};
+
+	/// Map of the relayer => accumulated reward, where reward is keyed by the lane that the
+	/// relayer has served.
+	#[frame_support::storage_alias]
+	pub type RelayerRewards<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Identity,
+		LaneId,
+		<T as Config>::Reward,
+	>;
+
+	/// Returns account that has been paying rewards for serving given lane.
+	pub fn lane_rewards_account<AccountId: Decode + Encode>(lane_id: LaneId) -> AccountId {
+		lane_id.into_sub_account_truncating(b"bridge-lane")
+	}
+}
+
+/// Migration to the storage, where rewards are keyed by the rewards account parameters.
+pub mod v1 {
+	use super::*;
+
+	/// Pallet storage version after the migration.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Migrates the `RelayerRewards` map from the lane keys to the rewards account parameters.
+	///
+	/// Funds that are used to pay old rewards are stored at the lane rewards accounts (see
+	/// `v0::lane_rewards_account`), so old rewards can't be simply re-keyed. Instead, all
+	/// outstanding rewards are paid out from the lane rewards accounts using `C` and removed
+	/// from the map. Rewards that can't be paid are removed too and are only logged.
+	pub struct MigrateToV1<T, C>(PhantomData<(T, C)>);
+
+	impl<T, C> OnRuntimeUpgrade for MigrateToV1<T, C>
+	where
+		T: Config,
+		C: Transfer<T::AccountId, Balance = T::Reward>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_storage_version != 0 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping migration of relayer rewards: storage version is {:?}",
+					on_chain_storage_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut migrated_rewards: u64 = 0;
+			for (relayer, lane_id, reward) in v0::RelayerRewards::<T>::drain() {
+				let lane_rewards_account = v0::lane_rewards_account(lane_id);
+				match C::transfer(&lane_rewards_account, &relayer, reward, false) {
+					Ok(_) => log::trace!(
+						target: LOG_TARGET,
+						"Paid {:?} reward for serving lane {:?} to {:?} during migration",
+						reward,
+						lane_id,
+						relayer,
+					),
+					Err(e) => log::error!(
+						target: LOG_TARGET,
+						"Failed to pay {:?} reward for serving lane {:?} to {:?} during migration: {:?}",
+						reward,
+						lane_id,
+						relayer,
+						e,
+					),
+				}
+				migrated_rewards += 1;
+			}
+
+			STORAGE_VERSION.put::<Pallet<T>>();
+
+			log::info!(
+				target: LOG_TARGET,
+				"Migrated relayer rewards to storage version {:?}: paid out {} rewards",
+				STORAGE_VERSION,
+				migrated_rewards,
+			);
+
+			// every reward is read and removed from the map and every transfer reads and writes
+			// both accounts. Plus storage version read and write
+			T::DbWeight::get().reads_writes(3 * migrated_rewards + 1, 3 * migrated_rewards + 1)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{mock::*, RelayerRewards};
+
+	use frame_support::traits::fungible::{Inspect, Mutate};
+
+	type Migration = v1::MigrateToV1<TestRuntime, Balances>;
+
+	#[test]
+	fn migration_pays_lane_rewards_and_bumps_storage_version() {
+		run_test(|| {
+			let lane_rewards_account = v0::lane_rewards_account(TEST_LANE_ID);
+			Balances::mint_into(&lane_rewards_account, ExistentialDeposit::get() + 100).unwrap();
+			v0::RelayerRewards::<TestRuntime>::insert(REGULAR_RELAYER, TEST_LANE_ID, 100);
+			let relayer_balance = Balances::balance(&REGULAR_RELAYER);
+
+			assert_eq!(Migration::on_runtime_upgrade(), DbWeight::get().reads_writes(4, 4));
+
+			assert_eq!(Balances::balance(&REGULAR_RELAYER), relayer_balance + 100);
+			assert_eq!(v0::RelayerRewards::<TestRuntime>::iter().count(), 0);
+			assert_eq!(RelayerRewards::<TestRuntime>::iter().count(), 0);
+			assert_eq!(Pallet::<TestRuntime>::on_chain_storage_version(), v1::STORAGE_VERSION);
+		});
+	}
+
+	#[test]
+	fn migration_removes_rewards_that_can_not_be_paid() {
+		run_test(|| {
+			v0::RelayerRewards::<TestRuntime>::insert(REGULAR_RELAYER, TEST_LANE_ID, 100);
+			let relayer_balance = Balances::balance(&REGULAR_RELAYER);
+
+			Migration::on_runtime_upgrade();
+
+			assert_eq!(Balances::balance(&REGULAR_RELAYER), relayer_balance);
+			assert_eq!(v0::RelayerRewards::<TestRuntime>::iter().count(), 0);
+			assert_eq!(Pallet::<TestRuntime>::on_chain_storage_version(), v1::STORAGE_VERSION);
+		});
+	}
+
+	#[test]
+	fn migration_is_skipped_if_storage_is_already_migrated() {
+		run_test(|| {
+			v1::STORAGE_VERSION.put::<Pallet<TestRuntime>>();
+			RelayerRewards::<TestRuntime>::insert(
+				REGULAR_RELAYER,
+				TEST_REWARDS_ACCOUNT_PARAMS,
+				100,
+			);
+
+			assert_eq!(Migration::on_runtime_upgrade(), DbWeight::get().reads(1));
+
+			assert_eq!(
+				RelayerRewards::<TestRuntime>::get(REGULAR_RELAYER, TEST_REWARDS_ACCOUNT_PARAMS),
+				Some(100),
+			);
+		});
+	}
+}
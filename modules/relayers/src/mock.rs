@@ -19,16 +19,24 @@
 use crate as pallet_bridge_relayers;
 
 use bp_messages::LaneId;
-use bp_relayers::PaymentProcedure;
-use frame_support::{parameter_types, weights::RuntimeDbWeight};
+use bp_relayers::{
+	PayRewardFromAccount, PaymentProcedure, RewardsAccountOwner, RewardsAccountParams,
+};
+use frame_support::{
+	parameter_types,
+	traits::{fungible::Mutate, ConstU32},
+	weights::RuntimeDbWeight,
+};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header as SubstrateHeader,
 	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
 };
 
 pub type AccountId = u64;
 pub type Balance = u64;
+pub type BlockNumber = u64;
 
 type Block = frame_system::mocking::MockBlock<TestRuntime>;
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<TestRuntime>;
@@ -45,15 +53,30 @@ frame_support::construct_runtime! {
 	}
 }
 
+pub type TestStakeAndSlash = pallet_bridge_relayers::StakeAndSlashNamed<
+	AccountId,
+	BlockNumber,
+	Balances,
+	ReserveId,
+	Stake,
+	Lease,
+>;
+
 parameter_types! {
 	pub const DbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 1, write: 2 };
+	pub const ExistentialDeposit: Balance = 1;
+	pub const ReserveId: [u8; 8] = *b"brdgrlrs";
+	pub const Stake: Balance = 1_000;
+	pub const Lease: BlockNumber = 8;
+	pub static TestSlashFraction: Perbill = Perbill::one();
+	pub static TestSlashDestination: Option<AccountId> = None;
 }
 
 impl frame_system::Config for TestRuntime {
 	type RuntimeOrigin = RuntimeOrigin;
 	type Index = u64;
 	type RuntimeCall = RuntimeCall;
-	type BlockNumber = u64;
+	type BlockNumber = BlockNumber;
 	type Hash = H256;
 	type Hashing = BlakeTwo256;
 	type AccountId = AccountId;
@@ -81,38 +104,54 @@ impl pallet_balances::Config for TestRuntime {
 	type Balance = Balance;
 	type DustRemoval = ();
 	type RuntimeEvent = RuntimeEvent;
-	type ExistentialDeposit = frame_support::traits::ConstU64<1>;
+	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = frame_system::Pallet<TestRuntime>;
 	type WeightInfo = ();
-	type MaxReserves = ();
-	type ReserveIdentifier = ();
+	type MaxReserves = ConstU32<1>;
+	type ReserveIdentifier = [u8; 8];
 }
 
 impl pallet_bridge_relayers::Config for TestRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type Reward = Balance;
 	type PaymentProcedure = TestPaymentProcedure;
+	type StakeAndSlash = TestStakeAndSlash;
+	type SlashFraction = TestSlashFraction;
+	type SlashDestination = TestSlashDestination;
 	type WeightInfo = ();
 }
 
 /// Message lane that we're using in tests.
 pub const TEST_LANE_ID: LaneId = LaneId([0, 0, 0, 0]);
 
+/// Rewards account params that we are using in tests.
+pub const TEST_REWARDS_ACCOUNT_PARAMS: RewardsAccountParams =
+	RewardsAccountParams::new(TEST_LANE_ID, *b"test", RewardsAccountOwner::ThisChain);
+
 /// Regular relayer that may receive rewards.
 pub const REGULAR_RELAYER: AccountId = 1;
 
 /// Relayer that can't receive rewards.
 pub const FAILING_RELAYER: AccountId = 2;
 
+/// Relayer that is able to register.
+pub const REGISTER_RELAYER: AccountId = 42;
+
 /// Payment procedure that rejects payments to the `FAILING_RELAYER`.
 pub struct TestPaymentProcedure;
 
+impl TestPaymentProcedure {
+	pub fn rewards_account(params: RewardsAccountParams) -> AccountId {
+		PayRewardFromAccount::<(), AccountId>::rewards_account(params)
+	}
+}
+
 impl PaymentProcedure<AccountId, Balance> for TestPaymentProcedure {
 	type Error = ();
 
 	fn pay_reward(
 		relayer: &AccountId,
-		_lane_id: LaneId,
+		_rewards_account_params: RewardsAccountParams,
 		_reward: Balance,
 	) -> Result<(), Self::Error> {
 		match *relayer {
@@ -126,5 +165,10 @@ impl PaymentProcedure<AccountId, Balance> for TestPaymentProcedure {
 pub fn run_test<T>(test: impl FnOnce() -> T) -> T {
 	let t = frame_system::GenesisConfig::default().build_storage::<TestRuntime>().unwrap();
 	let mut ext = sp_io::TestExternalities::new(t);
-	ext.execute_with(test)
+	ext.execute_with(|| {
+		Balances::mint_into(&REGISTER_RELAYER, ExistentialDeposit::get() + 10 * Stake::get())
+			.unwrap();
+
+		test()
+	})
 }
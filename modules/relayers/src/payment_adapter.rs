@@ -19,20 +19,30 @@
 use crate::{Config, Pallet};
 
 use bp_messages::source_chain::{DeliveryConfirmationPayments, RelayersRewards};
+use bp_relayers::{RewardsAccountOwner, RewardsAccountParams};
+use bp_runtime::ChainId;
 use frame_support::{sp_runtime::SaturatedConversion, traits::Get};
 use sp_arithmetic::traits::{Saturating, UniqueSaturatedFrom, Zero};
 use sp_std::{collections::vec_deque::VecDeque, marker::PhantomData, ops::RangeInclusive};
 
 /// Adapter that allows relayers pallet to be used as a delivery+dispatch payment mechanism
 /// for the messages pallet.
-pub struct DeliveryConfirmationPaymentsAdapter<T, DeliveryReward, ConfirmationReward>(
-	PhantomData<(T, DeliveryReward, ConfirmationReward)>,
-);
-
-impl<T, DeliveryReward, ConfirmationReward> DeliveryConfirmationPayments<T::AccountId>
-	for DeliveryConfirmationPaymentsAdapter<T, DeliveryReward, ConfirmationReward>
+///
+/// Rewards are registered for the [`RewardsAccountOwner::BridgedChain`] rewards account of the
+/// bridge with `BridgedChainId` chain.
+pub struct DeliveryConfirmationPaymentsAdapter<
+	T,
+	BridgedChainId,
+	DeliveryReward,
+	ConfirmationReward,
+>(PhantomData<(T, BridgedChainId, DeliveryReward, ConfirmationReward)>);
+
+impl<T, BridgedChainId, DeliveryReward, ConfirmationReward>
+	DeliveryConfirmationPayments<T::AccountId>
+	for DeliveryConfirmationPaymentsAdapter<T, BridgedChainId, DeliveryReward, ConfirmationReward>
 where
 	T: Config,
+	BridgedChainId: Get<ChainId>,
 	DeliveryReward: Get<T::Reward>,
 	ConfirmationReward: Get<T::Reward>,
 {
@@ -50,7 +60,11 @@ where
 		register_relayers_rewards::<T>(
 			confirmation_relayer,
 			relayers_rewards,
-			lane_id,
+			RewardsAccountParams::new(
+				lane_id,
+				BridgedChainId::get(),
+				RewardsAccountOwner::BridgedChain,
+			),
 			DeliveryReward::get(),
 			ConfirmationReward::get(),
 		);
@@ -61,7 +75,7 @@ where
 fn register_relayers_rewards<T: Config>(
 	confirmation_relayer: &T::AccountId,
 	relayers_rewards: RelayersRewards<T::AccountId>,
-	lane_id: bp_messages::LaneId,
+	rewards_account_params: RewardsAccountParams,
 	delivery_fee: T::Reward,
 	confirmation_fee: T::Reward,
 ) {
@@ -85,7 +99,7 @@ fn register_relayers_rewards<T: Config>(
 			relayer_reward = relayer_reward.saturating_sub(confirmation_reward);
 			confirmation_relayer_reward =
 				confirmation_relayer_reward.saturating_add(confirmation_reward);
-			Pallet::<T>::register_relayer_reward(lane_id, &relayer, relayer_reward);
+			Pallet::<T>::register_relayer_reward(rewards_account_params, &relayer, relayer_reward);
 		} else {
 			// If delivery confirmation is submitted by this relayer, let's add confirmation fee
 			// from other relayers to this relayer reward.
@@ -96,7 +110,7 @@ fn register_relayers_rewards<T: Config>(
 
 	// finally - pay reward to confirmation relayer
 	Pallet::<T>::register_relayer_reward(
-		lane_id,
+		rewards_account_params,
 		confirmation_relayer,
 		confirmation_relayer_reward,
 	);
@@ -121,13 +135,19 @@ mod tests {
 			register_relayers_rewards::<TestRuntime>(
 				&RELAYER_2,
 				relayers_rewards(),
-				TEST_LANE_ID,
+				TEST_REWARDS_ACCOUNT_PARAMS,
 				50,
 				10,
 			);
 
-			assert_eq!(RelayerRewards::<TestRuntime>::get(RELAYER_1, TEST_LANE_ID), Some(80));
-			assert_eq!(RelayerRewards::<TestRuntime>::get(RELAYER_2, TEST_LANE_ID), Some(170));
+			assert_eq!(
+				RelayerRewards::<TestRuntime>::get(RELAYER_1, TEST_REWARDS_ACCOUNT_PARAMS),
+				Some(80)
+			);
+			assert_eq!(
+				RelayerRewards::<TestRuntime>::get(RELAYER_2, TEST_REWARDS_ACCOUNT_PARAMS),
+				Some(170)
+			);
 		});
 	}
 
@@ -137,14 +157,23 @@ mod tests {
 			register_relayers_rewards::<TestRuntime>(
 				&RELAYER_3,
 				relayers_rewards(),
-				TEST_LANE_ID,
+				TEST_REWARDS_ACCOUNT_PARAMS,
 				50,
 				10,
 			);
 
-			assert_eq!(RelayerRewards::<TestRuntime>::get(RELAYER_1, TEST_LANE_ID), Some(80));
-			assert_eq!(RelayerRewards::<TestRuntime>::get(RELAYER_2, TEST_LANE_ID), Some(120));
-			assert_eq!(RelayerRewards::<TestRuntime>::get(RELAYER_3, TEST_LANE_ID), Some(50));
+			assert_eq!(
+				RelayerRewards::<TestRuntime>::get(RELAYER_1, TEST_REWARDS_ACCOUNT_PARAMS),
+				Some(80)
+			);
+			assert_eq!(
+				RelayerRewards::<TestRuntime>::get(RELAYER_2, TEST_REWARDS_ACCOUNT_PARAMS),
+				Some(120)
+			);
+			assert_eq!(
+				RelayerRewards::<TestRuntime>::get(RELAYER_3, TEST_REWARDS_ACCOUNT_PARAMS),
+				Some(50)
+			);
 		});
 	}
 
@@ -154,14 +183,23 @@ mod tests {
 			register_relayers_rewards::<TestRuntime>(
 				&RELAYER_3,
 				relayers_rewards(),
-				TEST_LANE_ID,
+				TEST_REWARDS_ACCOUNT_PARAMS,
 				50,
 				1000,
 			);
 
-			assert_eq!(RelayerRewards::<TestRuntime>::get(RELAYER_1, TEST_LANE_ID), None);
-			assert_eq!(RelayerRewards::<TestRuntime>::get(RELAYER_2, TEST_LANE_ID), None);
-			assert_eq!(RelayerRewards::<TestRuntime>::get(RELAYER_3, TEST_LANE_ID), Some(250));
+			assert_eq!(
+				RelayerRewards::<TestRuntime>::get(RELAYER_1, TEST_REWARDS_ACCOUNT_PARAMS),
+				None
+			);
+			assert_eq!(
+				RelayerRewards::<TestRuntime>::get(RELAYER_2, TEST_REWARDS_ACCOUNT_PARAMS),
+				None
+			);
+			assert_eq!(
+				RelayerRewards::<TestRuntime>::get(RELAYER_3, TEST_REWARDS_ACCOUNT_PARAMS),
+				Some(250)
+			);
 		});
 	}
 }
@@ -51,6 +51,9 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_bridge_relayers.
 pub trait WeightInfo {
 	fn claim_rewards() -> Weight;
+	fn register() -> Weight;
+	fn extend_registration() -> Weight;
+	fn deregister() -> Weight;
 }
 
 /// Weights for `pallet_bridge_relayers` that are generated using one of the Bridge testnets.
@@ -76,6 +79,60 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: BridgeRelayers RegisteredRelayers (r:1 w:1)
+	///
+	/// Proof: BridgeRelayers RegisteredRelayers (max_values: None, max_size: Some(64), added: 2539,
+	/// mode: MaxEncodedLen)
+	///
+	/// Storage: Balances Reserves (r:1 w:1)
+	///
+	/// Proof: Balances Reserves (max_values: None, max_size: Some(849), added: 3324, mode:
+	/// MaxEncodedLen)
+	fn register() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `87`
+		//  Estimated: `7843`
+		// Minimum execution time: 39_590 nanoseconds.
+		Weight::from_parts(40_546_000, 7843)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: BridgeRelayers RegisteredRelayers (r:1 w:1)
+	///
+	/// Proof: BridgeRelayers RegisteredRelayers (max_values: None, max_size: Some(64), added: 2539,
+	/// mode: MaxEncodedLen)
+	///
+	/// Storage: Balances Reserves (r:1 w:1)
+	///
+	/// Proof: Balances Reserves (max_values: None, max_size: Some(849), added: 3324, mode:
+	/// MaxEncodedLen)
+	fn extend_registration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `264`
+		//  Estimated: `7843`
+		// Minimum execution time: 37_951 nanoseconds.
+		Weight::from_parts(38_718_000, 7843)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: BridgeRelayers RegisteredRelayers (r:1 w:1)
+	///
+	/// Proof: BridgeRelayers RegisteredRelayers (max_values: None, max_size: Some(64), added: 2539,
+	/// mode: MaxEncodedLen)
+	///
+	/// Storage: Balances Reserves (r:1 w:1)
+	///
+	/// Proof: Balances Reserves (max_values: None, max_size: Some(849), added: 3324, mode:
+	/// MaxEncodedLen)
+	fn deregister() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `264`
+		//  Estimated: `7843`
+		// Minimum execution time: 43_034 nanoseconds.
+		Weight::from_parts(44_127_000, 7843)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -98,4 +155,58 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: BridgeRelayers RegisteredRelayers (r:1 w:1)
+	///
+	/// Proof: BridgeRelayers RegisteredRelayers (max_values: None, max_size: Some(64), added: 2539,
+	/// mode: MaxEncodedLen)
+	///
+	/// Storage: Balances Reserves (r:1 w:1)
+	///
+	/// Proof: Balances Reserves (max_values: None, max_size: Some(849), added: 3324, mode:
+	/// MaxEncodedLen)
+	fn register() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `87`
+		//  Estimated: `7843`
+		// Minimum execution time: 39_590 nanoseconds.
+		Weight::from_parts(40_546_000, 7843)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: BridgeRelayers RegisteredRelayers (r:1 w:1)
+	///
+	/// Proof: BridgeRelayers RegisteredRelayers (max_values: None, max_size: Some(64), added: 2539,
+	/// mode: MaxEncodedLen)
+	///
+	/// Storage: Balances Reserves (r:1 w:1)
+	///
+	/// Proof: Balances Reserves (max_values: None, max_size: Some(849), added: 3324, mode:
+	/// MaxEncodedLen)
+	fn extend_registration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `264`
+		//  Estimated: `7843`
+		// Minimum execution time: 37_951 nanoseconds.
+		Weight::from_parts(38_718_000, 7843)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: BridgeRelayers RegisteredRelayers (r:1 w:1)
+	///
+	/// Proof: BridgeRelayers RegisteredRelayers (max_values: None, max_size: Some(64), added: 2539,
+	/// mode: MaxEncodedLen)
+	///
+	/// Storage: Balances Reserves (r:1 w:1)
+	///
+	/// Proof: Balances Reserves (max_values: None, max_size: Some(849), added: 3324, mode:
+	/// MaxEncodedLen)
+	fn deregister() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `264`
+		//  Estimated: `7843`
+		// Minimum execution time: 43_034 nanoseconds.
+		Weight::from_parts(44_127_000, 7843)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
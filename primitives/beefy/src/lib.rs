@@ -19,21 +19,32 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 
-pub use binary_merkle_tree::merkle_root;
+pub use binary_merkle_tree::{
+	merkle_proof, merkle_root, verify_proof as verify_merkle_proof, MerkleProof,
+};
 pub use pallet_beefy_mmr::BeefyEcdsaToEthereum;
 pub use pallet_mmr::{
-	primitives::{DataOrHash as MmrDataOrHash, Proof as MmrProof},
+	primitives::{
+		DataOrHash as MmrDataOrHash, EncodableOpaqueLeaf as MmrEncodableOpaqueLeaf,
+		Error as MmrError, Proof as MmrProof,
+	},
 	verify_leaves_proof as verify_mmr_leaves_proof,
 };
+pub use signatures_subset::{
+	max_signers_bitfield_len, max_validator_proof_len, select_signers, signers_from_bitfield,
+	signers_to_bitfield, CommitmentClaim, SignaturesSubset, ValidatorSignatureWithProof,
+};
 pub use sp_beefy::{
 	crypto::{AuthorityId as EcdsaValidatorId, AuthoritySignature as EcdsaValidatorSignature},
 	known_payloads::MMR_ROOT_ID as MMR_ROOT_PAYLOAD_ID,
 	mmr::{BeefyAuthoritySet, MmrLeafVersion},
-	BeefyAuthorityId, Commitment, Payload as BeefyPayload, SignedCommitment, ValidatorSet,
-	ValidatorSetId, BEEFY_ENGINE_ID,
+	BeefyAuthorityId, Commitment, ConsensusLog as BeefyConsensusLog, Payload as BeefyPayload,
+	SignedCommitment, ValidatorSet, ValidatorSetId, VersionedFinalityProof, BEEFY_ENGINE_ID,
 };
 
-use bp_runtime::{BasicOperatingMode, BlockNumberOf, Chain, HashOf};
+use bp_runtime::{
+	BasicOperatingMode, BlockNumberOf, Chain, HashOf, UnderlyingChainOf, UnderlyingChainProvider,
+};
 use codec::{Decode, Encode};
 use frame_support::Parameter;
 use scale_info::TypeInfo;
@@ -43,6 +54,10 @@ use sp_runtime::{
 };
 use sp_std::prelude::*;
 
+pub mod signatures_subset;
+#[cfg(feature = "std")]
+pub mod storage_keys;
+
 /// Substrate-based chain with BEEFY && MMR pallets deployed.
 ///
 /// Both BEEFY and MMR pallets and their clients may be configured to use different
@@ -90,6 +105,20 @@ pub trait ChainWithBeefy: Chain {
 	type AuthorityIdToMerkleLeaf: Convert<Self::AuthorityId, Vec<u8>>;
 }
 
+impl<T> ChainWithBeefy for T
+where
+	T: Chain + UnderlyingChainProvider,
+	<T as UnderlyingChainProvider>::Chain: ChainWithBeefy,
+{
+	type CommitmentHasher = <UnderlyingChainOf<T> as ChainWithBeefy>::CommitmentHasher;
+	type MmrHashing = <UnderlyingChainOf<T> as ChainWithBeefy>::MmrHashing;
+	type MmrHash = <UnderlyingChainOf<T> as ChainWithBeefy>::MmrHash;
+	type BeefyMmrLeafExtra = <UnderlyingChainOf<T> as ChainWithBeefy>::BeefyMmrLeafExtra;
+	type AuthorityId = <UnderlyingChainOf<T> as ChainWithBeefy>::AuthorityId;
+	type AuthorityIdToMerkleLeaf =
+		<UnderlyingChainOf<T> as ChainWithBeefy>::AuthorityIdToMerkleLeaf;
+}
+
 /// BEEFY validator id used by given Substrate chain.
 pub type BeefyAuthorityIdOf<C> = <C as ChainWithBeefy>::AuthorityId;
 /// BEEFY validator set, containing both validator identifiers and the numeric set id.
@@ -102,6 +131,11 @@ pub type BeefyValidatorSignatureOf<C> =
 /// Signed BEEFY commitment used by given Substrate chain.
 pub type BeefySignedCommitmentOf<C> =
 	SignedCommitment<BlockNumberOf<C>, BeefyValidatorSignatureOf<C>>;
+/// BEEFY commitment used by given Substrate chain.
+pub type BeefyCommitmentOf<C> = Commitment<BlockNumberOf<C>>;
+/// BEEFY commitment signatures of the validators subset, used by given Substrate chain.
+pub type BeefySignaturesSubsetOf<C> =
+	SignaturesSubset<BeefyAuthorityIdOf<C>, BeefyValidatorSignatureOf<C>, MmrHashOf<C>>;
 /// Hash algorithm, used to compute the digest of the BEEFY commitment before signing it.
 pub type BeefyCommitmentHasher<C> = <C as ChainWithBeefy>::CommitmentHasher;
 /// Hash algorithm used in Beefy MMR construction by given Substrate chain.
@@ -143,3 +177,13 @@ pub struct ImportedCommitment<BlockNumber, BlockHash, MmrHash> {
 	/// MMR root at the imported block.
 	pub mmr_root: MmrHash,
 }
+
+/// Some high level info about the imported commitments.
+#[derive(Encode, Decode, RuntimeDebug, PartialEq, TypeInfo)]
+pub struct ImportedCommitmentsInfoData<BlockNumber> {
+	/// Best known block number, provided in a BEEFY commitment. However this is not
+	/// the best proven block. The best proven block is this block's parent.
+	pub best_block_number: BlockNumber,
+	/// The head of the `ImportedBlockNumbers` ring buffer.
+	pub next_block_number_index: u32,
+}
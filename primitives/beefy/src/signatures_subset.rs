@@ -0,0 +1,200 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Primitives of BEEFY commitments, that are signed by a subset of validators.
+//!
+//! This is the way Ethereum-side BEEFY light clients are verifying commitments. Instead of
+//! providing all validator keys and signatures, the submitter declares which validators
+//! have signed the commitment (using a bitfield) and provides signatures of a pseudo-random
+//! subset of those validators. Every signature comes with the merkle proof of the validator
+//! key membership in the authority set, so the size of the submitted data doesn't depend on
+//! the number of validators.
+//!
+//! The commitment is submitted in two phases. First, the submitter claims that the commitment
+//! has been signed by given validators. Then, after some blocks, the subset is selected using
+//! the on-chain randomness, that has been unknown when the claim has been submitted, as a seed.
+//! So neither submitter, nor validators that are signing the commitment, can affect the
+//! selection. The submitter may, however, resubmit the claim if the selected subset is not
+//! favorable for it, so the subset size must be chosen wisely.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Hash, RuntimeDebug};
+use sp_std::prelude::*;
+
+/// Signature of a single BEEFY validator, along with the merkle proof of its key.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct ValidatorSignatureWithProof<AuthorityId, Signature, Hash> {
+	/// Index of the validator in the authority set.
+	pub validator_index: u32,
+	/// Validator identifier.
+	pub validator_id: AuthorityId,
+	/// Validator signature of the commitment.
+	pub signature: Signature,
+	/// Merkle proof of the validator key membership in the authority set.
+	pub proof: Vec<Hash>,
+}
+
+/// Signatures of a subset of validators that have signed the commitment.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct SignaturesSubset<AuthorityId, Signature, Hash> {
+	/// Bitfield of validators that have signed the commitment.
+	///
+	/// The `i`-th bit (starting from the least significant bit of the first byte) is set if
+	/// the `i`-th validator has signed the commitment.
+	pub signers: Vec<u8>,
+	/// Signatures of validators, selected using `select_signers` function.
+	///
+	/// Signatures must be provided in the same order as they are returned by the
+	/// `select_signers` function.
+	pub signatures: Vec<ValidatorSignatureWithProof<AuthorityId, Signature, Hash>>,
+}
+
+/// Claim that the commitment has been signed by given validators.
+///
+/// The claim is stored by the pallet until the submitter provides signatures of the
+/// validators subset, that is selected using on-chain randomness.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct CommitmentClaim<Hash, BlockNumber> {
+	/// Hash of the claimed commitment and the signers bitfield.
+	pub claim_hash: Hash,
+	/// Number of the block, where the claim has been submitted. The claim may only be completed
+	/// using the randomness of the fixed block, that follows this block.
+	pub claimed_at: BlockNumber,
+}
+
+/// Returns maximal size of the signers bitfield for given maximal number of validators.
+pub fn max_signers_bitfield_len(max_validators: u32) -> u32 {
+	(max_validators + 7) / 8
+}
+
+/// Returns maximal number of items in the merkle proof of the validator key membership in the
+/// authority set for given maximal number of validators.
+pub fn max_validator_proof_len(max_validators: u32) -> u32 {
+	max_validators.next_power_of_two().trailing_zeros()
+}
+
+/// Build signers bitfield from indices of validators that have signed the commitment.
+pub fn signers_to_bitfield(signers: impl IntoIterator<Item = u32>, validators_len: u32) -> Vec<u8> {
+	let mut bitfield = vec![0u8; (validators_len as usize + 7) / 8];
+	for signer in signers.into_iter().filter(|signer| *signer < validators_len) {
+		bitfield[signer as usize / 8] |= 1 << (signer % 8);
+	}
+	bitfield
+}
+
+/// Returns indices of validators that are marked as signers in the bitfield.
+///
+/// Returns `None` if the bitfield size doesn't match the number of validators or if it has
+/// bits set for non-existing validators.
+pub fn signers_from_bitfield(bitfield: &[u8], validators_len: u32) -> Option<Vec<u32>> {
+	if bitfield.len() != (validators_len as usize + 7) / 8 {
+		return None
+	}
+
+	let mut signers = Vec::new();
+	for (byte_index, byte) in bitfield.iter().enumerate() {
+		for bit_index in 0..8 {
+			if byte & (1 << bit_index) == 0 {
+				continue
+			}
+
+			let signer = (byte_index * 8 + bit_index) as u32;
+			if signer >= validators_len {
+				return None
+			}
+			signers.push(signer);
+		}
+	}
+	Some(signers)
+}
+
+/// Select `count` signers, whose signatures must be provided along with the commitment.
+///
+/// The selection is a pseudo-random function of the `seed`, which must be derived from the
+/// randomness that has been unknown when the signers have been claimed. If `count` is larger
+/// than the number of signers, all signers are selected.
+pub fn select_signers<H: Hash>(seed: H::Output, mut signers: Vec<u32>, count: u32) -> Vec<u32> {
+	// partial Fisher-Yates shuffle
+	let count = sp_std::cmp::min(count as usize, signers.len());
+	for index in 0..count {
+		let random = H::hash_of(&(seed, index as u32));
+		let random = u32::decode(&mut random.as_ref()).unwrap_or_default() as usize;
+		let selected = index + random % (signers.len() - index);
+		signers.swap(index, selected);
+	}
+	signers.truncate(count);
+	signers
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_runtime::traits::Keccak256;
+
+	#[test]
+	fn signers_bitfield_is_encoded_and_decoded() {
+		let signers = vec![0, 3, 8, 9];
+		let bitfield = signers_to_bitfield(signers.clone(), 10);
+		assert_eq!(bitfield, vec![0b0000_1001, 0b0000_0011]);
+		assert_eq!(signers_from_bitfield(&bitfield, 10), Some(signers));
+	}
+
+	#[test]
+	fn signers_bitfield_with_wrong_size_is_rejected() {
+		assert_eq!(signers_from_bitfield(&[0b0000_0001], 10), None);
+		assert_eq!(signers_from_bitfield(&[0b0000_0001, 0, 0], 10), None);
+	}
+
+	#[test]
+	fn signers_bitfield_with_unknown_signers_is_rejected() {
+		assert_eq!(signers_from_bitfield(&[0b0000_0001, 0b0000_0100], 10), None);
+	}
+
+	#[test]
+	fn max_validator_proof_len_works() {
+		assert_eq!(max_validator_proof_len(1), 0);
+		assert_eq!(max_validator_proof_len(2), 1);
+		assert_eq!(max_validator_proof_len(5), 3);
+		assert_eq!(max_validator_proof_len(128), 7);
+		assert_eq!(max_validator_proof_len(129), 8);
+	}
+
+	#[test]
+	fn select_signers_is_deterministic() {
+		let seed = Keccak256::hash(b"commitment");
+		let signers = (0..100).collect::<Vec<_>>();
+		let selected = select_signers::<Keccak256>(seed, signers.clone(), 10);
+		assert_eq!(selected.len(), 10);
+		assert_eq!(selected, select_signers::<Keccak256>(seed, signers.clone(), 10));
+
+		let mut unique_selected = selected.clone();
+		unique_selected.sort();
+		unique_selected.dedup();
+		assert_eq!(unique_selected.len(), 10);
+
+		let other_seed = Keccak256::hash(b"other commitment");
+		assert_ne!(selected, select_signers::<Keccak256>(other_seed, signers, 10));
+	}
+
+	#[test]
+	fn select_signers_selects_all_signers_if_there_are_not_enough_signers() {
+		let seed = Keccak256::hash(b"commitment");
+		let mut selected = select_signers::<Keccak256>(seed, vec![1, 2, 3], 10);
+		selected.sort();
+		assert_eq!(selected, vec![1, 2, 3]);
+	}
+}
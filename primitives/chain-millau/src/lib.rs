@@ -210,6 +210,8 @@ frame_support::parameter_types! {
 
 /// Name of the With-Millau GRANDPA pallet instance that is deployed at bridged chains.
 pub const WITH_MILLAU_GRANDPA_PALLET_NAME: &str = "BridgeMillauGrandpa";
+/// Name of the With-Millau BEEFY pallet instance that is deployed at bridged chains.
+pub const WITH_MILLAU_BEEFY_PALLET_NAME: &str = "BridgeMillauBeefy";
 /// Name of the With-Millau messages pallet instance that is deployed at bridged chains.
 pub const WITH_MILLAU_MESSAGES_PALLET_NAME: &str = "BridgeMillauMessages";
 /// Name of the transaction payment pallet at the Millau runtime.
@@ -80,6 +80,14 @@ pub trait SourceHeaderChain {
 		proof: Self::MessagesProof,
 		messages_count: u32,
 	) -> Result<ProvedMessages<Message>, Self::Error>;
+
+	/// Returns number of lanes, covered by the messages proof.
+	///
+	/// Every lane, except the first one, increases the weight of the message delivery
+	/// transaction.
+	fn proof_lanes_count(_proof: &Self::MessagesProof) -> u32 {
+		1
+	}
 }
 
 /// Called when inbound message is received.
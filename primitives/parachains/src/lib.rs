@@ -19,6 +19,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use bp_header_chain::StoredHeaderData;
+// Weight is reexported to avoid additional frame-support dependencies in related crates.
+pub use frame_support::weights::Weight;
 
 use bp_polkadot_core::{
 	parachains::{ParaHash, ParaHead, ParaHeadsProof, ParaId},
@@ -20,8 +20,8 @@
 
 use codec::{Decode, Encode, FullCodec, MaxEncodedLen};
 use frame_support::{
-	log, pallet_prelude::DispatchResult, weights::Weight, PalletError, RuntimeDebug, StorageHasher,
-	StorageValue,
+	log, pallet_prelude::DispatchResult, traits::Get, weights::Weight, PalletError, RuntimeDebug,
+	StorageHasher, StorageValue,
 };
 use frame_system::RawOrigin;
 use scale_info::TypeInfo;
@@ -37,7 +37,7 @@ pub use chain::{
 };
 pub use frame_support::storage::storage_prefix as storage_value_final_key;
 use num_traits::{CheckedSub, One};
-use sp_runtime::transaction_validity::TransactionValidity;
+use sp_runtime::transaction_validity::{TransactionPriority, TransactionValidity};
 pub use storage_proof::{
 	record_all_keys as record_all_trie_keys, Error as StorageProofError,
 	ProofSize as StorageProofSize, StorageProofChecker,
@@ -496,6 +496,17 @@ pub trait FilterCall<Call> {
 	fn validate(call: &Call) -> TransactionValidity;
 }
 
+/// Compute priority boost for the bridge transaction that brings given number of useful items
+/// (e.g. messages) to the chain.
+///
+/// The boost is proportional to the number of items, so transactions that are bringing more
+/// items are preferred by the transaction pool.
+pub fn compute_priority_boost<PriorityBoostPerItem: Get<TransactionPriority>>(
+	n_items: u64,
+) -> TransactionPriority {
+	PriorityBoostPerItem::get().saturating_mul(n_items)
+}
+
 /// All extra operations with weights that we need in bridges.
 pub trait WeightExtraOps {
 	/// Checked division of individual components of two weights.
@@ -526,6 +537,21 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn compute_priority_boost_works() {
+		frame_support::parameter_types! {
+			const PriorityBoostPerItem: TransactionPriority = 1_000;
+		}
+
+		assert_eq!(compute_priority_boost::<PriorityBoostPerItem>(0), 0);
+		assert_eq!(compute_priority_boost::<PriorityBoostPerItem>(1), 1_000);
+		assert_eq!(compute_priority_boost::<PriorityBoostPerItem>(100), 100_000);
+		assert_eq!(
+			compute_priority_boost::<PriorityBoostPerItem>(u64::MAX),
+			TransactionPriority::MAX
+		);
+	}
+
 	#[test]
 	fn storage_value_key_works() {
 		assert_eq!(
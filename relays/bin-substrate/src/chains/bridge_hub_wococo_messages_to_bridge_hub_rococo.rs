@@ -61,4 +61,7 @@ impl SubstrateMessageLane for BridgeHubWococoMessagesToBridgeHubRococoMessageLan
 
 	type SourceBatchCallBuilder = ();
 	type TargetBatchCallBuilder = ();
+
+	// TODO: fix (https://github.com/paritytech/parity-bridges-common/issues/1640)
+	type DeliveryReward = ();
 }
@@ -0,0 +1,51 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Millau-to-Rialto BEEFY headers sync entrypoint.
+
+use crate::cli::bridge::{CliBridgeBase, RelayToRelayHeadersCliBridge};
+use substrate_relay_helper::finality::{
+	engine::Beefy as BeefyFinalityEngine, DirectSubmitBeefyCommitmentCallBuilder,
+	SubstrateFinalitySyncPipeline,
+};
+
+/// Description of Millau -> Rialto BEEFY finalized headers bridge.
+#[derive(Clone, Debug)]
+pub struct MillauBeefyFinalityToRialto;
+
+impl SubstrateFinalitySyncPipeline for MillauBeefyFinalityToRialto {
+	type SourceChain = relay_millau_client::Millau;
+	type TargetChain = relay_rialto_client::Rialto;
+
+	type FinalityEngine = BeefyFinalityEngine<Self::SourceChain>;
+	type SubmitFinalityProofCallBuilder = DirectSubmitBeefyCommitmentCallBuilder<
+		Self,
+		rialto_runtime::Runtime,
+		rialto_runtime::MillauBeefyInstance,
+	>;
+}
+
+//// `Millau` to `Rialto` BEEFY bridge definition.
+pub struct MillauBeefyToRialtoCliBridge {}
+
+impl CliBridgeBase for MillauBeefyToRialtoCliBridge {
+	type Source = relay_millau_client::Millau;
+	type Target = relay_rialto_client::Rialto;
+}
+
+impl RelayToRelayHeadersCliBridge for MillauBeefyToRialtoCliBridge {
+	type Finality = MillauBeefyFinalityToRialto;
+}
@@ -44,4 +44,6 @@ impl SubstrateMessageLane for MillauMessagesToRialto {
 
 	type SourceBatchCallBuilder = ();
 	type TargetBatchCallBuilder = ();
+
+	type DeliveryReward = millau_runtime::MessageDeliveryReward;
 }
@@ -47,4 +47,6 @@ impl SubstrateMessageLane for MillauMessagesToRialtoParachain {
 
 	type SourceBatchCallBuilder = ();
 	type TargetBatchCallBuilder = ();
+
+	type DeliveryReward = millau_runtime::MessageDeliveryReward;
 }
@@ -18,6 +18,7 @@
 
 pub mod bridge_hub_rococo_messages_to_bridge_hub_wococo;
 pub mod bridge_hub_wococo_messages_to_bridge_hub_rococo;
+pub mod millau_beefy_headers_to_rialto;
 pub mod millau_headers_to_rialto;
 pub mod millau_headers_to_rialto_parachain;
 pub mod millau_messages_to_rialto;
@@ -85,7 +86,7 @@ mod tests {
 				spec_version: 1,
 				transaction_version: 1,
 				genesis_hash: Default::default(),
-				signer: sp_keyring::AccountKeyring::Alice.pair(),
+				signer: sp_keyring::AccountKeyring::Alice.pair().into(),
 			},
 			UnsignedTransaction::new(rialto_call.clone().into(), 0),
 		)
@@ -109,7 +110,7 @@ mod tests {
 				spec_version: 0,
 				transaction_version: 0,
 				genesis_hash: Default::default(),
-				signer: sp_keyring::AccountKeyring::Alice.pair(),
+				signer: sp_keyring::AccountKeyring::Alice.pair().into(),
 			},
 			UnsignedTransaction::new(millau_call.clone().into(), 0),
 		)
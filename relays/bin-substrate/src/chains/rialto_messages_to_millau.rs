@@ -44,4 +44,6 @@ impl SubstrateMessageLane for RialtoMessagesToMillau {
 
 	type SourceBatchCallBuilder = ();
 	type TargetBatchCallBuilder = ();
+
+	type DeliveryReward = rialto_runtime::MessageDeliveryReward;
 }
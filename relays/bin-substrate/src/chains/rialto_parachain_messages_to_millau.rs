@@ -48,4 +48,7 @@ impl SubstrateMessageLane for RialtoParachainMessagesToMillau {
 
 	type SourceBatchCallBuilder = ();
 	type TargetBatchCallBuilder = UtilityPalletBatchCallBuilder<Millau>;
+
+	// must match the `MessageDeliveryReward` of the RialtoParachain runtime
+	type DeliveryReward = frame_support::traits::ConstU128<100_000>;
 }
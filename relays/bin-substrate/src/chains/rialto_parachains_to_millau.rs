@@ -40,6 +40,10 @@ impl SubstrateParachainsPipeline for RialtoParachainsToMillau {
 	type TargetChain = Millau;
 
 	type SubmitParachainHeadsCallBuilder = RialtoParachainsToMillauSubmitParachainHeadsCallBuilder;
+	type TargetParachainsWeightInfo =
+		<millau_runtime::Runtime as pallet_bridge_parachains::Config<
+			millau_runtime::WithRialtoParachainsInstance,
+		>>::WeightInfo;
 }
 
 /// `submit_parachain_heads` call builder for Rialto-to-Millau parachains sync pipeline.
@@ -39,6 +39,8 @@ impl SubstrateParachainsPipeline for BridgeHubRococoToBridgeHubWococo {
 	type TargetChain = relay_bridge_hub_wococo_client::BridgeHubWococo;
 
 	type SubmitParachainHeadsCallBuilder = BridgeHubRococoToBridgeHubWococoCallBuilder;
+	// TODO: fix (https://github.com/paritytech/parity-bridges-common/issues/1640)
+	type TargetParachainsWeightInfo = ();
 }
 
 pub struct BridgeHubRococoToBridgeHubWococoCallBuilder;
@@ -39,6 +39,10 @@ impl SubstrateParachainsPipeline for WestendParachainsToMillau {
 	type TargetChain = Millau;
 
 	type SubmitParachainHeadsCallBuilder = WestendParachainsToMillauSubmitParachainHeadsCallBuilder;
+	type TargetParachainsWeightInfo =
+		<millau_runtime::Runtime as pallet_bridge_parachains::Config<
+			millau_runtime::WithWestendParachainsInstance,
+		>>::WeightInfo;
 }
 
 /// `submit_parachain_heads` call builder for Rialto-to-Millau parachains sync pipeline.
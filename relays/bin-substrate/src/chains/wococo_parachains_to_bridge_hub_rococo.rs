@@ -39,6 +39,8 @@ impl SubstrateParachainsPipeline for BridgeHubWococoToBridgeHubRococo {
 	type TargetChain = relay_bridge_hub_rococo_client::BridgeHubRococo;
 
 	type SubmitParachainHeadsCallBuilder = BridgeHubWococoToBridgeHubRococoCallBuilder;
+	// TODO: fix (https://github.com/paritytech/parity-bridges-common/issues/1640)
+	type TargetParachainsWeightInfo = ();
 }
 
 pub struct BridgeHubWococoToBridgeHubRococoCallBuilder;
@@ -15,7 +15,9 @@
 
 // along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
 
-use relay_substrate_client::{AccountKeyPairOf, ChainWithTransactions};
+use relay_substrate_client::{
+	AccountKeyPairOf, ChainWithTransactions, SignerPoolOf, SignerSelection,
+};
 use structopt::StructOpt;
 use strum::{EnumString, EnumVariantNames};
 
@@ -34,6 +36,24 @@ pub enum RuntimeVersionType {
 	Bundle,
 }
 
+#[doc = "Strategy of selecting transactions signer from the pool."]
+#[derive(StructOpt, Debug, PartialEq, Eq, Clone, Copy, EnumString, EnumVariantNames)]
+pub enum SignerSelectionType {
+	/// Select signers one after another.
+	RoundRobin,
+	/// Select signer with the smallest number of active submissions.
+	LeastBusy,
+}
+
+impl From<SignerSelectionType> for SignerSelection {
+	fn from(selection: SignerSelectionType) -> Self {
+		match selection {
+			SignerSelectionType::RoundRobin => SignerSelection::RoundRobin,
+			SignerSelectionType::LeastBusy => SignerSelection::LeastBusy,
+		}
+	}
+}
+
 /// Create chain-specific set of runtime version parameters.
 #[macro_export]
 macro_rules! declare_chain_runtime_version_params_cli_schema {
@@ -101,6 +121,18 @@ macro_rules! declare_chain_connection_params_cli_schema {
 				#[doc = "Use secure websocket connection."]
 				#[structopt(long)]
 				pub [<$chain_prefix _secure>]: bool,
+				#[doc = "Connect to " $chain " node at given URI (`ws`, `wss`, `http`, `https` or `ipc` scheme) or start embedded light client (`light://<chain spec path>[?relay_chain_spec=<relay chain spec path>]`, requires `light-client` feature). Overrides host, port and secure options."]
+				#[structopt(long)]
+				pub [<$chain_prefix _uri>]: Option<String>,
+				#[doc = "HTTP header (`Name: value`), sent to all " $chain " nodes. TLS roots are read from the system store or the `SSL_CERT_FILE` file."]
+				#[structopt(long)]
+				pub [<$chain_prefix _rpc_header>]: Vec<String>,
+				#[doc = "Connect to additional " $chain " node at given URI or `host:port`. The healthiest node is used to send requests. If request fails, it is retried at the next healthy node."]
+				#[structopt(long)]
+				pub [<$chain_prefix _fallback_endpoint>]: Vec<String>,
+				#[doc = "Number of " $chain " nodes that must agree on storage reads at finalized blocks (lane state, parachain heads)."]
+				#[structopt(long, default_value = "1")]
+				pub [<$chain_prefix _rpc_quorum>]: usize,
 				#[doc = "Custom runtime version"]
 				#[structopt(flatten)]
 				pub [<$chain_prefix _runtime_version>]: [<$chain RuntimeVersionParams>],
@@ -115,10 +147,53 @@ macro_rules! declare_chain_connection_params_cli_schema {
 					let chain_runtime_version = self
 						.[<$chain_prefix _runtime_version>]
 						.into_runtime_version(Chain::RUNTIME_VERSION)?;
+					let secure = self.[<$chain_prefix _secure>];
+					let headers = self
+						.[<$chain_prefix _rpc_header>]
+						.iter()
+						.map(|header| {
+							let (name, value) = header.split_once(':').ok_or_else(|| {
+								anyhow::Error::msg(format!("Expected `Name: value` header, got: {header}"))
+							})?;
+							Ok((name.trim().into(), value.trim().into()))
+						})
+						.collect::<anyhow::Result<Vec<_>>>()?;
+					let fallback_endpoints = self
+						.[<$chain_prefix _fallback_endpoint>]
+						.iter()
+						.map(|endpoint| {
+							if endpoint.contains("://") {
+								return Ok(relay_substrate_client::RpcEndpoint {
+									uri: endpoint.clone(),
+									headers: headers.clone(),
+								})
+							}
+
+							let (host, port) = endpoint.rsplit_once(':').ok_or_else(|| {
+								anyhow::Error::msg(format!("Expected URI or `host:port` endpoint, got: {endpoint}"))
+							})?;
+							Ok(relay_substrate_client::RpcEndpoint {
+								headers: headers.clone(),
+								..relay_substrate_client::RpcEndpoint::websocket(host, port.parse()?, secure)
+							})
+						})
+						.collect::<anyhow::Result<Vec<_>>>()?;
+					let quorum = self.[<$chain_prefix _rpc_quorum>];
+					let endpoints_count = 1 + fallback_endpoints.len();
+					if quorum > endpoints_count {
+						return Err(anyhow::format_err!(
+							"The {} is {quorum}, but only {endpoints_count} RPC endpoints are configured",
+							stringify!([<$chain_prefix _rpc_quorum>]),
+						))
+					}
 					Ok(relay_substrate_client::Client::new(relay_substrate_client::ConnectionParams {
 						host: self.[<$chain_prefix _host>],
 						port: self.[<$chain_prefix _port>],
-						secure: self.[<$chain_prefix _secure>],
+						secure,
+						uri: self.[<$chain_prefix _uri>],
+						headers,
+						fallback_endpoints,
+						quorum,
 						chain_runtime_version,
 					})
 					.await
@@ -136,14 +211,14 @@ pub trait TransactionParamsProvider {
 	/// Returns transaction parameters.
 	fn transaction_params<Chain: ChainWithTransactions>(
 		&self,
-	) -> anyhow::Result<TransactionParams<AccountKeyPairOf<Chain>>>;
+	) -> anyhow::Result<TransactionParams<SignerPoolOf<Chain>>>;
 
 	/// Returns transaction parameters, defined by `self` provider or, if they're not defined,
 	/// defined by `other` provider.
 	fn transaction_params_or<Chain: ChainWithTransactions, T: TransactionParamsProvider>(
 		&self,
 		other: &T,
-	) -> anyhow::Result<TransactionParams<AccountKeyPairOf<Chain>>> {
+	) -> anyhow::Result<TransactionParams<SignerPoolOf<Chain>>> {
 		if self.is_defined() {
 			self.transaction_params::<Chain>()
 		} else {
@@ -174,9 +249,36 @@ macro_rules! declare_chain_signing_params_cli_schema {
 				#[structopt(long)]
 				pub [<$chain_prefix _signer_password_file>]: Option<std::path::PathBuf>,
 
+				#[doc = "Path to the keystore directory, that contains secret key to use when transactions are submitted to the " $chain " node. Requires " $chain_prefix "_signer_public option."]
+				#[structopt(long)]
+				pub [<$chain_prefix _signer_keystore>]: Option<std::path::PathBuf>,
+				#[doc = "Address (host:port) of the remote signer, that signs transactions submitted to the " $chain " node. Requires " $chain_prefix "_signer_public option."]
+				#[structopt(long)]
+				pub [<$chain_prefix _signer_remote>]: Option<String>,
+				#[doc = "Hex-encoded public key of the signer, stored in the keystore or managed by the remote signer."]
+				#[structopt(long)]
+				pub [<$chain_prefix _signer_public>]: Option<String>,
+
+				#[doc = "The SURI of additional secret key to use when transactions are submitted to the " $chain " node. May be specified multiple times. Transactions of different accounts are submitted in parallel."]
+				#[structopt(long)]
+				pub [<$chain_prefix _additional_signer>]: Vec<String>,
+				#[doc = "Strategy of selecting the account to sign the next transaction, submitted to the " $chain " node."]
+				#[structopt(long, default_value = "RoundRobin")]
+				pub [<$chain_prefix _signer_selection>]: SignerSelectionType,
+
 				#[doc = "Transactions mortality period, in blocks. MUST be a power of two in [4; 65536] range. MAY NOT be larger than `BlockHashCount` parameter of the chain system module."]
 				#[structopt(long)]
 				pub [<$chain_prefix _transactions_mortality>]: Option<u32>,
+
+				#[doc = "Number of blocks the transaction may stay in the " $chain " transaction pool, before it is resubmitted with higher tip. Requires " $chain_prefix "_tip_step and " $chain_prefix "_tip_limit options."]
+				#[structopt(long)]
+				pub [<$chain_prefix _tip_escalation_blocks>]: Option<u32>,
+				#[doc = "Tip increase step of stalled " $chain " transactions."]
+				#[structopt(long)]
+				pub [<$chain_prefix _tip_step>]: Option<$crate::cli::Balance>,
+				#[doc = "Tip limit of stalled " $chain " transactions. We'll never submit transaction with larger tip."]
+				#[structopt(long)]
+				pub [<$chain_prefix _tip_limit>]: Option<$crate::cli::Balance>,
 			}
 
 			impl [<$chain SigningParams>] {
@@ -199,6 +301,70 @@ macro_rules! declare_chain_signing_params_cli_schema {
 						.transpose()
 				}
 
+				/// Return tip escalation parameters.
+				#[allow(dead_code)]
+				pub fn tip_escalation(&self) -> anyhow::Result<Option<relay_substrate_client::TipEscalation>> {
+					match (
+						self.[<$chain_prefix _tip_escalation_blocks>],
+						self.[<$chain_prefix _tip_step>],
+						self.[<$chain_prefix _tip_limit>],
+					) {
+						(None, None, None) => Ok(None),
+						(Some(pending_blocks), Some(tip_step), Some(tip_limit)) => Ok(Some(relay_substrate_client::TipEscalation {
+							pending_blocks,
+							tip_step: tip_step.0,
+							tip_limit: tip_limit.0,
+						})),
+						_ => Err(anyhow::format_err!(
+							"All options must be specified to enable tip escalation: '{}', '{}' and '{}'",
+							stringify!([<$chain_prefix _tip_escalation_blocks>]),
+							stringify!([<$chain_prefix _tip_step>]),
+							stringify!([<$chain_prefix _tip_limit>]),
+						)),
+					}
+				}
+
+				/// Parse signing params into chain-specific transactions signer.
+				#[allow(dead_code)]
+				pub fn to_signer<Chain: ChainWithTransactions>(&self) -> anyhow::Result<relay_substrate_client::SignerOf<Chain>> {
+					let signer_public = || self.[<$chain_prefix _signer_public>].as_ref().ok_or_else(|| anyhow::format_err!(
+						"Option '{}' must be specified",
+						stringify!([<$chain_prefix _signer_public>]),
+					));
+
+					if let Some(keystore) = self.[<$chain_prefix _signer_keystore>].as_ref() {
+						return Ok(relay_substrate_client::Signer::new(relay_substrate_client::KeystoreSigner::open(
+							keystore,
+							signer_public()?,
+							self.signer_password()?,
+						)?))
+					}
+					if let Some(remote) = self.[<$chain_prefix _signer_remote>].as_ref() {
+						return Ok(relay_substrate_client::Signer::new(relay_substrate_client::RemoteSigner::new(remote.clone(), signer_public()?)?))
+					}
+
+					self.to_keypair::<Chain>().map(Into::into)
+				}
+
+				/// Parse signing params into chain-specific pool of transactions signers.
+				#[allow(dead_code)]
+				pub fn to_signer_pool<Chain: ChainWithTransactions>(&self) -> anyhow::Result<relay_substrate_client::SignerPoolOf<Chain>> {
+					let mut signers = vec![self.to_signer::<Chain>()?];
+					for suri in &self.[<$chain_prefix _additional_signer>] {
+						use sp_core::crypto::Pair;
+
+						let suri_password = self.signer_password()?;
+						let additional_signer = AccountKeyPairOf::<Chain>::from_string(
+							suri,
+							suri_password.as_deref(),
+						).map_err(|e| anyhow::format_err!("{:?}", e))?;
+						signers.push(additional_signer.into());
+					}
+
+					relay_substrate_client::SignerPool::new(signers, self.[<$chain_prefix _signer_selection>].into())
+						.map_err(|e| anyhow::format_err!("{:?}", e))
+				}
+
 				/// Parse signing params into chain-specific KeyPair.
 				#[allow(dead_code)]
 				pub fn to_keypair<Chain: ChainWithTransactions>(&self) -> anyhow::Result<AccountKeyPairOf<Chain>> {
@@ -216,8 +382,19 @@ macro_rules! declare_chain_signing_params_cli_schema {
 							stringify!([<$chain_prefix _signer_file>]),
 						)),
 					};
+					let suri_password = self.signer_password()?;
+
+					use sp_core::crypto::Pair;
+
+					AccountKeyPairOf::<Chain>::from_string(
+						&suri,
+						suri_password.as_deref()
+					).map_err(|e| anyhow::format_err!("{:?}", e))
+				}
 
-					let suri_password = match (
+				/// Return password of the signer key.
+				fn signer_password(&self) -> anyhow::Result<Option<String>> {
+					Ok(match (
 						self.[<$chain_prefix _signer_password>].as_ref(),
 						self.[<$chain_prefix _signer_password_file>].as_ref(),
 					) {
@@ -230,27 +407,24 @@ macro_rules! declare_chain_signing_params_cli_schema {
 								err,
 							))?,
 						_ => None,
-					};
-
-					use sp_core::crypto::Pair;
-
-					AccountKeyPairOf::<Chain>::from_string(
-						&suri,
-						suri_password.as_deref()
-					).map_err(|e| anyhow::format_err!("{:?}", e))
+					})
 				}
 			}
 
 			#[allow(dead_code)]
 			impl TransactionParamsProvider for [<$chain SigningParams>] {
 				fn is_defined(&self) -> bool {
-					self.[<$chain_prefix _signer>].is_some() || self.[<$chain_prefix _signer_file>].is_some()
+					self.[<$chain_prefix _signer>].is_some()
+						|| self.[<$chain_prefix _signer_file>].is_some()
+						|| self.[<$chain_prefix _signer_keystore>].is_some()
+						|| self.[<$chain_prefix _signer_remote>].is_some()
 				}
 
-				fn transaction_params<Chain: ChainWithTransactions>(&self) -> anyhow::Result<TransactionParams<AccountKeyPairOf<Chain>>> {
+				fn transaction_params<Chain: ChainWithTransactions>(&self) -> anyhow::Result<TransactionParams<relay_substrate_client::SignerPoolOf<Chain>>> {
 					Ok(TransactionParams {
 						mortality: self.transactions_mortality()?,
-						signer: self.to_keypair::<Chain>()?,
+						signer: self.to_signer_pool::<Chain>()?,
+						tip_escalation: self.tip_escalation()?,
 					})
 				}
 			}
@@ -308,9 +482,19 @@ mod tests {
 				target_signer_file: None,
 				target_signer_password_file: None,
 
+				target_signer_keystore: None,
+				target_signer_remote: None,
+				target_signer_public: None,
+				target_additional_signer: Vec::new(),
+				target_signer_selection: SignerSelectionType::RoundRobin,
+
 				target_transactions_mortality: None,
+
+				target_tip_escalation_blocks: None,
+				target_tip_step: None,
+				target_tip_limit: None,
 			}
-			.to_keypair::<relay_rialto_client::Rialto>()
+			.to_signer::<relay_rialto_client::Rialto>()
 			.map(|p| p.public())
 			.map_err(drop),
 			Ok(alice.public()),
@@ -325,9 +509,19 @@ mod tests {
 				target_signer_file: Some(suri_file_path.clone()),
 				target_signer_password_file: Some(password_file_path.clone()),
 
+				target_signer_keystore: None,
+				target_signer_remote: None,
+				target_signer_public: None,
+				target_additional_signer: Vec::new(),
+				target_signer_selection: SignerSelectionType::RoundRobin,
+
 				target_transactions_mortality: None,
+
+				target_tip_escalation_blocks: None,
+				target_tip_step: None,
+				target_tip_limit: None,
 			}
-			.to_keypair::<relay_rialto_client::Rialto>()
+			.to_signer::<relay_rialto_client::Rialto>()
 			.map(|p| p.public())
 			.map_err(drop),
 			Ok(bob.public()),
@@ -342,9 +536,19 @@ mod tests {
 				target_signer_file: Some(suri_file_path.clone()),
 				target_signer_password_file: Some(password_file_path.clone()),
 
+				target_signer_keystore: None,
+				target_signer_remote: None,
+				target_signer_public: None,
+				target_additional_signer: Vec::new(),
+				target_signer_selection: SignerSelectionType::RoundRobin,
+
 				target_transactions_mortality: None,
+
+				target_tip_escalation_blocks: None,
+				target_tip_step: None,
+				target_tip_limit: None,
 			}
-			.to_keypair::<relay_rialto_client::Rialto>()
+			.to_signer::<relay_rialto_client::Rialto>()
 			.map(|p| p.public())
 			.map_err(drop),
 			Ok(bob_with_alice_password.public()),
@@ -359,12 +563,38 @@ mod tests {
 				target_signer_file: Some(suri_file_path),
 				target_signer_password_file: Some(password_file_path),
 
+				target_signer_keystore: None,
+				target_signer_remote: None,
+				target_signer_public: None,
+				target_additional_signer: Vec::new(),
+				target_signer_selection: SignerSelectionType::RoundRobin,
+
 				target_transactions_mortality: None,
+
+				target_tip_escalation_blocks: None,
+				target_tip_step: None,
+				target_tip_limit: None,
 			}
-			.to_keypair::<relay_rialto_client::Rialto>()
+			.to_signer::<relay_rialto_client::Rialto>()
 			.map(|p| p.public())
 			.map_err(drop),
 			Ok(alice.public()),
 		);
 	}
+
+	#[test]
+	fn into_client_fails_if_quorum_exceeds_endpoints_count() {
+		let params = TargetConnectionParams::from_iter(vec![
+			"target-connection-params",
+			"--target-fallback-endpoint",
+			"127.0.0.1:9945",
+			"--target-rpc-quorum",
+			"3",
+		]);
+
+		let error = async_std::task::block_on(params.into_client::<relay_millau_client::Millau>())
+			.map(drop)
+			.unwrap_err();
+		assert!(error.to_string().contains("only 2 RPC endpoints are configured"));
+	}
 }
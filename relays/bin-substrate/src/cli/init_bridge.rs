@@ -19,6 +19,7 @@ use codec::Encode;
 
 use crate::{
 	chains::{
+		millau_beefy_headers_to_rialto::MillauBeefyToRialtoCliBridge,
 		millau_headers_to_rialto::MillauToRialtoCliBridge,
 		millau_headers_to_rialto_parachain::MillauToRialtoParachainCliBridge,
 		rialto_headers_to_millau::RialtoToMillauCliBridge,
@@ -33,7 +34,9 @@ use relay_substrate_client::{AccountKeyPairOf, Chain, UnsignedTransaction};
 use sp_core::Pair;
 use structopt::StructOpt;
 use strum::{EnumString, EnumVariantNames, VariantNames};
-use substrate_relay_helper::finality::engine::{Engine, Grandpa as GrandpaFinalityEngine};
+use substrate_relay_helper::finality::engine::{
+	Beefy as BeefyFinalityEngine, Engine, Grandpa as GrandpaFinalityEngine,
+};
 
 /// Initialize bridge pallet.
 #[derive(StructOpt)]
@@ -57,6 +60,7 @@ pub struct InitBridge {
 /// Bridge to initialize.
 pub enum InitBridgeName {
 	MillauToRialto,
+	MillauBeefyToRialto,
 	RialtoToMillau,
 	WestendToMillau,
 	MillauToRialtoParachain,
@@ -80,7 +84,7 @@ where
 	async fn init_bridge(data: InitBridge) -> anyhow::Result<()> {
 		let source_client = data.source.into_client::<Self::Source>().await?;
 		let target_client = data.target.into_client::<Self::Target>().await?;
-		let target_sign = data.target_sign.to_keypair::<Self::Target>()?;
+		let target_sign = data.target_sign.to_signer::<Self::Target>()?;
 		let dry_run = data.dry_run;
 
 		substrate_relay_helper::finality::initialize::initialize::<Self::Engine, _, _, _>(
@@ -117,6 +121,19 @@ impl BridgeInitializer for MillauToRialtoCliBridge {
 	}
 }
 
+impl BridgeInitializer for MillauBeefyToRialtoCliBridge {
+	type Engine = BeefyFinalityEngine<Self::Source>;
+
+	fn encode_init_bridge(
+		init_data: <Self::Engine as Engine<Self::Source>>::InitializationData,
+	) -> <Self::Target as Chain>::Call {
+		rialto_runtime::SudoCall::sudo {
+			call: Box::new(rialto_runtime::BridgeBeefyCall::initialize { init_data }.into()),
+		}
+		.into()
+	}
+}
+
 impl BridgeInitializer for MillauToRialtoParachainCliBridge {
 	type Engine = GrandpaFinalityEngine<Self::Source>;
 
@@ -203,6 +220,7 @@ impl InitBridge {
 	pub async fn run(self) -> anyhow::Result<()> {
 		match self.bridge {
 			InitBridgeName::MillauToRialto => MillauToRialtoCliBridge::init_bridge(self),
+			InitBridgeName::MillauBeefyToRialto => MillauBeefyToRialtoCliBridge::init_bridge(self),
 			InitBridgeName::RialtoToMillau => RialtoToMillauCliBridge::init_bridge(self),
 			InitBridgeName::WestendToMillau => WestendToMillauCliBridge::init_bridge(self),
 			InitBridgeName::MillauToRialtoParachain =>
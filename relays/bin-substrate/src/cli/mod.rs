@@ -16,7 +16,7 @@
 
 //! Deal with CLI args of substrate-to-substrate relay.
 
-use std::convert::TryInto;
+use std::{convert::TryInto, path::PathBuf};
 
 use async_std::prelude::*;
 use codec::{Decode, Encode};
@@ -256,6 +256,25 @@ pub struct PrometheusParams {
 	pub prometheus_port: u16,
 }
 
+/// Relay state persistence parameters.
+#[derive(Clone, Debug, PartialEq, StructOpt)]
+pub struct StateStoreParams {
+	/// Directory where the relay persists its state between restarts. If not specified, the
+	/// relay state is only kept in memory.
+	#[structopt(long)]
+	pub state_dir: Option<PathBuf>,
+}
+
+impl StateStoreParams {
+	/// Tries to open the relay state store.
+	pub fn into_state_store(self) -> anyhow::Result<Option<relay_utils::StateStore>> {
+		self.state_dir
+			.map(relay_utils::StateStore::open)
+			.transpose()
+			.map_err(|e| anyhow::format_err!("{:?}", e))
+	}
+}
+
 /// Struct to get git commit info and build time.
 #[derive(BuildInfo)]
 struct SubstrateRelayBuildInfo;
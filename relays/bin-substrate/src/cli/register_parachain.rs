@@ -29,10 +29,7 @@ use polkadot_runtime_parachains::paras::ParaLifecycle;
 use relay_substrate_client::{AccountIdOf, CallOf, Chain, Client, UnsignedTransaction};
 use relay_utils::{TrackedTransactionStatus, TransactionTracker};
 use rialto_runtime::SudoCall;
-use sp_core::{
-	storage::{well_known_keys::CODE, StorageKey},
-	Pair,
-};
+use sp_core::storage::{well_known_keys::CODE, StorageKey};
 use structopt::StructOpt;
 use strum::{EnumString, EnumVariantNames, VariantNames};
 
@@ -91,7 +88,7 @@ impl RegisterParachain {
 	pub async fn run(self) -> anyhow::Result<()> {
 		select_bridge!(self.parachain, {
 			let relay_client = self.relay_connection.into_client::<Relaychain>().await?;
-			let relay_sign = self.relay_sign.to_keypair::<Relaychain>()?;
+			let relay_sign = self.relay_sign.to_signer::<Relaychain>()?;
 			let para_client = self.para_connection.into_client::<Parachain>().await?;
 
 			// hopefully we're the only actor that is registering parachain right now
@@ -112,12 +109,16 @@ impl RegisterParachain {
 			let reserve_parachain_id_call: CallOf<Relaychain> =
 				ParaRegistrarCall::reserve {}.into();
 			let reserve_result = relay_client
-				.submit_and_watch_signed_extrinsic(&relay_sign, move |_, transaction_nonce| {
-					Ok(UnsignedTransaction::new(
-						reserve_parachain_id_call.into(),
-						transaction_nonce,
-					))
-				})
+				.submit_and_watch_signed_extrinsic(
+					&relay_sign,
+					None,
+					move |_, transaction_nonce| {
+						Ok(UnsignedTransaction::new(
+							reserve_parachain_id_call.into(),
+							transaction_nonce,
+						))
+					},
+				)
 				.await?
 				.wait()
 				.await;
@@ -151,9 +152,16 @@ impl RegisterParachain {
 			}
 			.into();
 			let register_result = relay_client
-				.submit_and_watch_signed_extrinsic(&relay_sign, move |_, transaction_nonce| {
-					Ok(UnsignedTransaction::new(register_parathread_call.into(), transaction_nonce))
-				})
+				.submit_and_watch_signed_extrinsic(
+					&relay_sign,
+					None,
+					move |_, transaction_nonce| {
+						Ok(UnsignedTransaction::new(
+							register_parathread_call.into(),
+							transaction_nonce,
+						))
+					},
+				)
 				.await?
 				.wait()
 				.await;
@@ -295,6 +303,10 @@ mod tests {
 					relaychain_host: "127.0.0.1".into(),
 					relaychain_port: 9944,
 					relaychain_secure: false,
+					relaychain_uri: None,
+					relaychain_rpc_header: vec![],
+					relaychain_fallback_endpoint: vec![],
+					relaychain_rpc_quorum: 1,
 					relaychain_runtime_version: RelaychainRuntimeVersionParams {
 						relaychain_version_mode: RuntimeVersionType::Bundle,
 						relaychain_spec_version: None,
@@ -306,12 +318,24 @@ mod tests {
 					relaychain_signer_password: None,
 					relaychain_signer_file: None,
 					relaychain_signer_password_file: None,
+					relaychain_signer_keystore: None,
+					relaychain_signer_remote: None,
+					relaychain_signer_public: None,
+					relaychain_additional_signer: Vec::new(),
+					relaychain_signer_selection: SignerSelectionType::RoundRobin,
 					relaychain_transactions_mortality: None,
+					relaychain_tip_escalation_blocks: None,
+					relaychain_tip_step: None,
+					relaychain_tip_limit: None,
 				},
 				para_connection: ParachainConnectionParams {
 					parachain_host: "127.0.0.1".into(),
 					parachain_port: 11949,
 					parachain_secure: false,
+					parachain_uri: None,
+					parachain_rpc_header: vec![],
+					parachain_fallback_endpoint: vec![],
+					parachain_rpc_quorum: 1,
 					parachain_runtime_version: ParachainRuntimeVersionParams {
 						parachain_version_mode: RuntimeVersionType::Bundle,
 						parachain_spec_version: None,
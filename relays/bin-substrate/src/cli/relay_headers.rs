@@ -21,6 +21,7 @@ use structopt::StructOpt;
 use strum::{EnumString, EnumVariantNames, VariantNames};
 
 use crate::chains::{
+	millau_beefy_headers_to_rialto::MillauBeefyToRialtoCliBridge,
 	millau_headers_to_rialto::MillauToRialtoCliBridge,
 	millau_headers_to_rialto_parachain::MillauToRialtoParachainCliBridge,
 	rialto_headers_to_millau::RialtoToMillauCliBridge,
@@ -31,7 +32,7 @@ use crate::chains::{
 use relay_utils::metrics::{GlobalMetrics, StandaloneMetric};
 use substrate_relay_helper::finality::SubstrateFinalitySyncPipeline;
 
-use crate::cli::{bridge::*, chain_schema::*, PrometheusParams};
+use crate::cli::{bridge::*, chain_schema::*, PrometheusParams, StateStoreParams};
 
 /// Start headers relayer process.
 #[derive(StructOpt)]
@@ -51,6 +52,8 @@ pub struct RelayHeaders {
 	target_sign: TargetSigningParams,
 	#[structopt(flatten)]
 	prometheus_params: PrometheusParams,
+	#[structopt(flatten)]
+	state_store_params: StateStoreParams,
 }
 
 #[derive(Debug, EnumString, EnumVariantNames)]
@@ -58,6 +61,7 @@ pub struct RelayHeaders {
 /// Headers relay bridge.
 pub enum RelayHeadersBridge {
 	MillauToRialto,
+	MillauBeefyToRialto,
 	RialtoToMillau,
 	WestendToMillau,
 	MillauToRialtoParachain,
@@ -75,7 +79,7 @@ where
 		let source_client = data.source.into_client::<Self::Source>().await?;
 		let target_client = data.target.into_client::<Self::Target>().await?;
 		let target_transactions_mortality = data.target_sign.target_transactions_mortality;
-		let target_sign = data.target_sign.to_keypair::<Self::Target>()?;
+		let target_sign = data.target_sign.to_signer_pool::<Self::Target>()?;
 
 		let metrics_params: relay_utils::metrics::MetricsParams =
 			data.prometheus_params.into_metrics_params()?;
@@ -84,6 +88,7 @@ where
 		let target_transactions_params = substrate_relay_helper::TransactionParams {
 			signer: target_sign,
 			mortality: target_transactions_mortality,
+			tip_escalation: data.target_sign.tip_escalation()?,
 		};
 		Self::Finality::start_relay_guards(
 			&target_client,
@@ -98,12 +103,14 @@ where
 			data.only_mandatory_headers,
 			target_transactions_params,
 			metrics_params,
+			data.state_store_params.into_state_store()?,
 		)
 		.await
 	}
 }
 
 impl HeadersRelayer for MillauToRialtoCliBridge {}
+impl HeadersRelayer for MillauBeefyToRialtoCliBridge {}
 impl HeadersRelayer for RialtoToMillauCliBridge {}
 impl HeadersRelayer for WestendToMillauCliBridge {}
 impl HeadersRelayer for MillauToRialtoParachainCliBridge {}
@@ -115,6 +122,8 @@ impl RelayHeaders {
 	pub async fn run(self) -> anyhow::Result<()> {
 		match self.bridge {
 			RelayHeadersBridge::MillauToRialto => MillauToRialtoCliBridge::relay_headers(self),
+			RelayHeadersBridge::MillauBeefyToRialto =>
+				MillauBeefyToRialtoCliBridge::relay_headers(self),
 			RelayHeadersBridge::RialtoToMillau => RialtoToMillauCliBridge::relay_headers(self),
 			RelayHeadersBridge::WestendToMillau => WestendToMillauCliBridge::relay_headers(self),
 			RelayHeadersBridge::MillauToRialtoParachain =>
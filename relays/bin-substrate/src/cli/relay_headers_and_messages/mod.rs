@@ -54,7 +54,7 @@ use crate::{
 		},
 		chain_schema::*,
 		relay_headers_and_messages::parachain_to_parachain::ParachainToParachainBridge,
-		CliChain, HexLaneId, PrometheusParams,
+		CliChain, HexLaneId, PrometheusParams, StateStoreParams,
 	},
 	declare_chain_cli_schema,
 };
@@ -62,12 +62,15 @@ use bp_messages::LaneId;
 use bp_runtime::BalanceOf;
 use relay_substrate_client::{
 	AccountIdOf, AccountKeyPairOf, Chain, ChainWithBalances, ChainWithMessages,
-	ChainWithTransactions, Client, Parachain,
+	ChainWithTransactions, Client, Parachain, SignerPoolOf, TipEscalation,
 };
-use relay_utils::metrics::MetricsParams;
+use relay_utils::{metrics::MetricsParams, StateStore};
 use sp_core::Pair;
 use substrate_relay_helper::{
-	messages_lane::MessagesRelayParams, on_demand::OnDemandRelay, TaggedAccount, TransactionParams,
+	messages_lane::{MessagesRelayParams, SubstrateMessageLane},
+	on_demand::OnDemandRelay,
+	transactions_batcher::TransactionsBatcher,
+	TaggedAccount, TransactionParams,
 };
 
 /// Parameters that have the same names across all bridges.
@@ -82,6 +85,8 @@ pub struct HeadersAndMessagesSharedParams {
 	pub only_mandatory_headers: bool,
 	#[structopt(flatten)]
 	pub prometheus_params: PrometheusParams,
+	#[structopt(flatten)]
+	pub state_store_params: StateStoreParams,
 }
 
 /// Bridge parameters, shared by all bridge types.
@@ -98,6 +103,8 @@ pub struct Full2WayBridgeCommonParams<
 
 	/// Common metric parameters.
 	pub metrics_params: MetricsParams,
+	/// Common relay state store.
+	pub state_store: Option<StateStore>,
 }
 
 impl<Left: ChainWithTransactions + CliChain, Right: ChainWithTransactions + CliChain>
@@ -112,8 +119,9 @@ impl<Left: ChainWithTransactions + CliChain, Right: ChainWithTransactions + CliC
 		// Create metrics registry.
 		let metrics_params = shared.prometheus_params.clone().into_metrics_params()?;
 		let metrics_params = relay_utils::relay_metrics(metrics_params).into_params();
+		let state_store = shared.state_store_params.clone().into_state_store()?;
 
-		Ok(Self { shared, left, right, metrics_params })
+		Ok(Self { shared, left, right, metrics_params, state_store })
 	}
 }
 
@@ -122,9 +130,11 @@ pub struct BridgeEndCommonParams<Chain: ChainWithTransactions + CliChain> {
 	/// Chain client.
 	pub client: Client<Chain>,
 	/// Transactions signer.
-	pub sign: AccountKeyPairOf<Chain>,
+	pub sign: SignerPoolOf<Chain>,
 	/// Transactions mortality.
 	pub transactions_mortality: Option<u32>,
+	/// Tip escalation of stalled transactions.
+	pub tip_escalation: Option<TipEscalation>,
 	/// Accounts, which balances are exposed as metrics by the relay process.
 	pub accounts: Vec<TaggedAccount<AccountIdOf<Chain>>>,
 }
@@ -162,28 +172,51 @@ where
 		Self { source, target, metrics_params, _phantom_data: Default::default() }
 	}
 
+	/// Creates shared stream of source chain transactions.
+	fn source_transactions_batcher(&self) -> TransactionsBatcher<Source> {
+		TransactionsBatcher::new::<
+			<Bridge::MessagesLane as SubstrateMessageLane>::SourceBatchCallBuilder,
+		>(
+			self.source.client.clone(),
+			TransactionParams {
+				signer: self.source.sign.clone(),
+				mortality: self.source.transactions_mortality,
+				tip_escalation: self.source.tip_escalation,
+			},
+		)
+	}
+
 	/// Returns message relay parameters.
 	fn messages_relay_params(
 		&self,
 		source_to_target_headers_relay: Arc<dyn OnDemandRelay<Source, Target>>,
 		target_to_source_headers_relay: Arc<dyn OnDemandRelay<Target, Source>>,
-		lane_id: LaneId,
+		source_transactions_batcher: TransactionsBatcher<Source>,
+		target_transactions_batcher: TransactionsBatcher<Target>,
+		lanes: Vec<LaneId>,
+		state_store: Option<StateStore>,
 	) -> MessagesRelayParams<Bridge::MessagesLane> {
 		MessagesRelayParams {
 			source_client: self.source.client.clone(),
 			source_transaction_params: TransactionParams {
 				signer: self.source.sign.clone(),
 				mortality: self.source.transactions_mortality,
+				tip_escalation: self.source.tip_escalation,
 			},
 			target_client: self.target.client.clone(),
 			target_transaction_params: TransactionParams {
 				signer: self.target.sign.clone(),
 				mortality: self.target.transactions_mortality,
+				tip_escalation: self.target.tip_escalation,
 			},
 			source_to_target_headers_relay: Some(source_to_target_headers_relay),
 			target_to_source_headers_relay: Some(target_to_source_headers_relay),
-			lane_id,
+			source_transactions_batcher: Some(source_transactions_batcher),
+			target_transactions_batcher: Some(target_transactions_batcher),
+			lanes,
+			profitability_params: None,
 			metrics_params: self.metrics_params.clone().disable(),
+			state_store,
 		}
 	}
 }
@@ -302,14 +335,18 @@ where
 		// Register standalone metrics.
 		{
 			let common = self.mut_base().mut_common();
-			common.left.accounts.push(TaggedAccount::Messages {
-				id: common.left.sign.public().into(),
-				bridged_chain: Self::Right::NAME.to_string(),
-			});
-			common.right.accounts.push(TaggedAccount::Messages {
-				id: common.right.sign.public().into(),
-				bridged_chain: Self::Left::NAME.to_string(),
-			});
+			common.left.accounts.extend(common.left.sign.signers().map(|signer| {
+				TaggedAccount::Messages {
+					id: signer.public().into(),
+					bridged_chain: Self::Right::NAME.to_string(),
+				}
+			}));
+			common.right.accounts.extend(common.right.sign.signers().map(|signer| {
+				TaggedAccount::Messages {
+					id: signer.public().into(),
+					bridged_chain: Self::Left::NAME.to_string(),
+				}
+			}));
 		}
 
 		// start on-demand header relays
@@ -344,38 +381,50 @@ where
 			.await?;
 		}
 
-		// Need 2x capacity since we consider both directions for each lane
-		let mut message_relays = Vec::with_capacity(lanes.len() * 2);
-		for lane in lanes {
-			let left_to_right_messages = substrate_relay_helper::messages_lane::run::<
-				<Self::L2R as MessagesCliBridge>::MessagesLane,
-			>(self.left_to_right().messages_relay_params(
-				left_to_right_on_demand_headers.clone(),
-				right_to_left_on_demand_headers.clone(),
-				lane,
-			))
-			.map_err(|e| anyhow::format_err!("{}", e))
-			.boxed();
-			message_relays.push(left_to_right_messages);
-
-			let right_to_left_messages = substrate_relay_helper::messages_lane::run::<
-				<Self::R2L as MessagesCliBridge>::MessagesLane,
-			>(self.right_to_left().messages_relay_params(
-				right_to_left_on_demand_headers.clone(),
-				left_to_right_on_demand_headers.clone(),
-				lane,
-			))
-			.map_err(|e| anyhow::format_err!("{}", e))
-			.boxed();
-			message_relays.push(right_to_left_messages);
-		}
+		// messages of all lanes are delivered by the single loop in every direction. Both loops
+		// are sharing the same transactions stream for every chain, so they never compete for
+		// the signer account nonce
+		let left_transactions_batcher = self.left_to_right().source_transactions_batcher();
+		let right_transactions_batcher = self.right_to_left().source_transactions_batcher();
+		// both loops are sharing the same state store - its keys are different for every
+		// direction
+		let state_store = self.base().common().state_store.clone();
+
+		let left_to_right_messages = substrate_relay_helper::messages_lane::run::<
+			<Self::L2R as MessagesCliBridge>::MessagesLane,
+		>(self.left_to_right().messages_relay_params(
+			left_to_right_on_demand_headers.clone(),
+			right_to_left_on_demand_headers.clone(),
+			left_transactions_batcher.clone(),
+			right_transactions_batcher.clone(),
+			lanes.clone(),
+			state_store.clone(),
+		))
+		.map_err(|e| anyhow::format_err!("{}", e))
+		.boxed();
+
+		let right_to_left_messages = substrate_relay_helper::messages_lane::run::<
+			<Self::R2L as MessagesCliBridge>::MessagesLane,
+		>(self.right_to_left().messages_relay_params(
+			right_to_left_on_demand_headers,
+			left_to_right_on_demand_headers,
+			right_transactions_batcher,
+			left_transactions_batcher,
+			lanes,
+			state_store,
+		))
+		.map_err(|e| anyhow::format_err!("{}", e))
+		.boxed();
 
 		relay_utils::relay_metrics(self.base().common().metrics_params.clone())
 			.expose()
 			.await
 			.map_err(|e| anyhow::format_err!("{}", e))?;
 
-		futures::future::select_all(message_relays).await.0
+		futures::future::select(left_to_right_messages, right_to_left_messages)
+			.await
+			.factor_first()
+			.0
 	}
 }
 
@@ -535,11 +584,16 @@ mod tests {
 						prometheus_host: "0.0.0.0".into(),
 						prometheus_port: 9616,
 					},
+					state_store_params: StateStoreParams { state_dir: None },
 				},
 				left: MillauConnectionParams {
 					millau_host: "millau-node-alice".into(),
 					millau_port: 9944,
 					millau_secure: false,
+					millau_uri: None,
+					millau_rpc_header: vec![],
+					millau_fallback_endpoint: vec![],
+					millau_rpc_quorum: 1,
 					millau_runtime_version: MillauRuntimeVersionParams {
 						millau_version_mode: RuntimeVersionType::Bundle,
 						millau_spec_version: None,
@@ -551,19 +605,39 @@ mod tests {
 					millau_signer_password: None,
 					millau_signer_file: None,
 					millau_signer_password_file: None,
+					millau_signer_keystore: None,
+					millau_signer_remote: None,
+					millau_signer_public: None,
+					millau_additional_signer: Vec::new(),
+					millau_signer_selection: SignerSelectionType::RoundRobin,
 					millau_transactions_mortality: Some(64),
+					millau_tip_escalation_blocks: None,
+					millau_tip_step: None,
+					millau_tip_limit: None,
 				},
 				left_headers_to_right_sign_override: MillauHeadersToRialtoSigningParams {
 					millau_headers_to_rialto_signer: None,
 					millau_headers_to_rialto_signer_password: None,
 					millau_headers_to_rialto_signer_file: None,
 					millau_headers_to_rialto_signer_password_file: None,
+					millau_headers_to_rialto_signer_keystore: None,
+					millau_headers_to_rialto_signer_remote: None,
+					millau_headers_to_rialto_signer_public: None,
+					millau_headers_to_rialto_additional_signer: Vec::new(),
+					millau_headers_to_rialto_signer_selection: SignerSelectionType::RoundRobin,
 					millau_headers_to_rialto_transactions_mortality: None,
+					millau_headers_to_rialto_tip_escalation_blocks: None,
+					millau_headers_to_rialto_tip_step: None,
+					millau_headers_to_rialto_tip_limit: None,
 				},
 				right: RialtoConnectionParams {
 					rialto_host: "rialto-node-alice".into(),
 					rialto_port: 9944,
 					rialto_secure: false,
+					rialto_uri: None,
+					rialto_rpc_header: vec![],
+					rialto_fallback_endpoint: vec![],
+					rialto_rpc_quorum: 1,
 					rialto_runtime_version: RialtoRuntimeVersionParams {
 						rialto_version_mode: RuntimeVersionType::Bundle,
 						rialto_spec_version: None,
@@ -575,14 +649,30 @@ mod tests {
 					rialto_signer_password: None,
 					rialto_signer_file: None,
 					rialto_signer_password_file: None,
+					rialto_signer_keystore: None,
+					rialto_signer_remote: None,
+					rialto_signer_public: None,
+					rialto_additional_signer: Vec::new(),
+					rialto_signer_selection: SignerSelectionType::RoundRobin,
 					rialto_transactions_mortality: Some(64),
+					rialto_tip_escalation_blocks: None,
+					rialto_tip_step: None,
+					rialto_tip_limit: None,
 				},
 				right_headers_to_left_sign_override: RialtoHeadersToMillauSigningParams {
 					rialto_headers_to_millau_signer: None,
 					rialto_headers_to_millau_signer_password: None,
 					rialto_headers_to_millau_signer_file: None,
 					rialto_headers_to_millau_signer_password_file: None,
+					rialto_headers_to_millau_signer_keystore: None,
+					rialto_headers_to_millau_signer_remote: None,
+					rialto_headers_to_millau_signer_public: None,
+					rialto_headers_to_millau_additional_signer: Vec::new(),
+					rialto_headers_to_millau_signer_selection: SignerSelectionType::RoundRobin,
 					rialto_headers_to_millau_transactions_mortality: None,
+					rialto_headers_to_millau_tip_escalation_blocks: None,
+					rialto_headers_to_millau_tip_step: None,
+					rialto_headers_to_millau_tip_limit: None,
 				},
 			}),
 		);
@@ -635,11 +725,16 @@ mod tests {
 							prometheus_host: "0.0.0.0".into(),
 							prometheus_port: 9616,
 						},
+						state_store_params: StateStoreParams { state_dir: None },
 					},
 					left: MillauConnectionParams {
 						millau_host: "millau-node-alice".into(),
 						millau_port: 9944,
 						millau_secure: false,
+						millau_uri: None,
+						millau_rpc_header: vec![],
+						millau_fallback_endpoint: vec![],
+						millau_rpc_quorum: 1,
 						millau_runtime_version: MillauRuntimeVersionParams {
 							millau_version_mode: RuntimeVersionType::Bundle,
 							millau_spec_version: None,
@@ -651,7 +746,15 @@ mod tests {
 						millau_signer_password: None,
 						millau_signer_file: None,
 						millau_signer_password_file: None,
+						millau_signer_keystore: None,
+						millau_signer_remote: None,
+						millau_signer_public: None,
+						millau_additional_signer: Vec::new(),
+						millau_signer_selection: SignerSelectionType::RoundRobin,
 						millau_transactions_mortality: Some(64),
+						millau_tip_escalation_blocks: None,
+						millau_tip_step: None,
+						millau_tip_limit: None,
 					},
 					left_headers_to_right_sign_override:
 						MillauHeadersToRialtoParachainSigningParams {
@@ -659,12 +762,25 @@ mod tests {
 							millau_headers_to_rialto_parachain_signer_password: None,
 							millau_headers_to_rialto_parachain_signer_file: None,
 							millau_headers_to_rialto_parachain_signer_password_file: None,
+							millau_headers_to_rialto_parachain_signer_keystore: None,
+							millau_headers_to_rialto_parachain_signer_remote: None,
+							millau_headers_to_rialto_parachain_signer_public: None,
+							millau_headers_to_rialto_parachain_additional_signer: Vec::new(),
+							millau_headers_to_rialto_parachain_signer_selection:
+								SignerSelectionType::RoundRobin,
 							millau_headers_to_rialto_parachain_transactions_mortality: None,
+							millau_headers_to_rialto_parachain_tip_escalation_blocks: None,
+							millau_headers_to_rialto_parachain_tip_step: None,
+							millau_headers_to_rialto_parachain_tip_limit: None,
 						},
 					right: RialtoParachainConnectionParams {
 						rialto_parachain_host: "rialto-parachain-collator-charlie".into(),
 						rialto_parachain_port: 9944,
 						rialto_parachain_secure: false,
+						rialto_parachain_uri: None,
+						rialto_parachain_rpc_header: vec![],
+						rialto_parachain_fallback_endpoint: vec![],
+						rialto_parachain_rpc_quorum: 1,
 						rialto_parachain_runtime_version: RialtoParachainRuntimeVersionParams {
 							rialto_parachain_version_mode: RuntimeVersionType::Bundle,
 							rialto_parachain_spec_version: None,
@@ -676,26 +792,55 @@ mod tests {
 						rialto_parachain_signer_password: None,
 						rialto_parachain_signer_file: None,
 						rialto_parachain_signer_password_file: None,
+						rialto_parachain_signer_keystore: None,
+						rialto_parachain_signer_remote: None,
+						rialto_parachain_signer_public: None,
+						rialto_parachain_additional_signer: Vec::new(),
+						rialto_parachain_signer_selection: SignerSelectionType::RoundRobin,
 						rialto_parachain_transactions_mortality: Some(64),
+						rialto_parachain_tip_escalation_blocks: None,
+						rialto_parachain_tip_step: None,
+						rialto_parachain_tip_limit: None,
 					},
 					right_relay_headers_to_left_sign_override: RialtoHeadersToMillauSigningParams {
 						rialto_headers_to_millau_signer: Some("//Ken".into()),
 						rialto_headers_to_millau_signer_password: None,
 						rialto_headers_to_millau_signer_file: None,
 						rialto_headers_to_millau_signer_password_file: None,
+						rialto_headers_to_millau_signer_keystore: None,
+						rialto_headers_to_millau_signer_remote: None,
+						rialto_headers_to_millau_signer_public: None,
+						rialto_headers_to_millau_additional_signer: Vec::new(),
+						rialto_headers_to_millau_signer_selection: SignerSelectionType::RoundRobin,
 						rialto_headers_to_millau_transactions_mortality: None,
+						rialto_headers_to_millau_tip_escalation_blocks: None,
+						rialto_headers_to_millau_tip_step: None,
+						rialto_headers_to_millau_tip_limit: None,
 					},
 					right_parachains_to_left_sign_override: RialtoParachainsToMillauSigningParams {
 						rialto_parachains_to_millau_signer: None,
 						rialto_parachains_to_millau_signer_password: None,
 						rialto_parachains_to_millau_signer_file: None,
 						rialto_parachains_to_millau_signer_password_file: None,
+						rialto_parachains_to_millau_signer_keystore: None,
+						rialto_parachains_to_millau_signer_remote: None,
+						rialto_parachains_to_millau_signer_public: None,
+						rialto_parachains_to_millau_additional_signer: Vec::new(),
+						rialto_parachains_to_millau_signer_selection:
+							SignerSelectionType::RoundRobin,
 						rialto_parachains_to_millau_transactions_mortality: None,
+						rialto_parachains_to_millau_tip_escalation_blocks: None,
+						rialto_parachains_to_millau_tip_step: None,
+						rialto_parachains_to_millau_tip_limit: None,
 					},
 					right_relay: RialtoConnectionParams {
 						rialto_host: "rialto-node-alice".into(),
 						rialto_port: 9944,
 						rialto_secure: false,
+						rialto_uri: None,
+						rialto_rpc_header: vec![],
+						rialto_fallback_endpoint: vec![],
+						rialto_rpc_quorum: 1,
 						rialto_runtime_version: RialtoRuntimeVersionParams {
 							rialto_version_mode: RuntimeVersionType::Bundle,
 							rialto_spec_version: None,
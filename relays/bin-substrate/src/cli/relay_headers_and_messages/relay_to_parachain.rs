@@ -28,7 +28,7 @@ use crate::cli::{
 use bp_polkadot_core::parachains::ParaHash;
 use pallet_bridge_parachains::{RelayBlockHash, RelayBlockHasher, RelayBlockNumber};
 use relay_substrate_client::{
-	AccountIdOf, AccountKeyPairOf, Chain, ChainWithTransactions, Client, Parachain,
+	AccountIdOf, AccountKeyPairOf, Chain, ChainWithTransactions, Client, Parachain, SignerPoolOf,
 };
 use sp_core::Pair;
 use substrate_relay_helper::{
@@ -57,13 +57,13 @@ pub struct RelayToParachainBridge<
 
 	/// Override for right_relay->left headers signer.
 	pub right_headers_to_left_transaction_params:
-		TransactionParams<AccountKeyPairOf<<R2L as CliBridgeBase>::Target>>,
+		TransactionParams<SignerPoolOf<<R2L as CliBridgeBase>::Target>>,
 	/// Override for right->left parachains signer.
 	pub right_parachains_to_left_transaction_params:
-		TransactionParams<AccountKeyPairOf<<R2L as CliBridgeBase>::Target>>,
+		TransactionParams<SignerPoolOf<<R2L as CliBridgeBase>::Target>>,
 	/// Override for left->right headers signer.
 	pub left_headers_to_right_transaction_params:
-		TransactionParams<AccountKeyPairOf<<L2R as CliBridgeBase>::Target>>,
+		TransactionParams<SignerPoolOf<<L2R as CliBridgeBase>::Target>>,
 }
 
 macro_rules! declare_relay_to_parachain_bridge_schema {
@@ -122,14 +122,16 @@ macro_rules! declare_relay_to_parachain_bridge_schema {
 							self.shared,
 							BridgeEndCommonParams {
 								client: self.left.into_client::<Left>().await?,
-								sign: self.left_sign.to_keypair::<Left>()?,
+								sign: self.left_sign.to_signer_pool::<Left>()?,
 								transactions_mortality: self.left_sign.transactions_mortality()?,
+								tip_escalation: self.left_sign.tip_escalation()?,
 								accounts: vec![],
 							},
 							BridgeEndCommonParams {
 								client: self.right.into_client::<Right>().await?,
-								sign: self.right_sign.to_keypair::<Right>()?,
+								sign: self.right_sign.to_signer_pool::<Right>()?,
 								transactions_mortality: self.right_sign.transactions_mortality()?,
+								tip_escalation: self.right_sign.tip_escalation()?,
 								accounts: vec![],
 							},
 						)?,
@@ -189,18 +191,30 @@ where
 		Arc<dyn OnDemandRelay<Self::Left, Self::Right>>,
 		Arc<dyn OnDemandRelay<Self::Right, Self::Left>>,
 	)> {
-		self.common.left.accounts.push(TaggedAccount::Headers {
-			id: self.right_headers_to_left_transaction_params.signer.public().into(),
-			bridged_chain: RightRelay::NAME.to_string(),
-		});
-		self.common.left.accounts.push(TaggedAccount::Parachains {
-			id: self.right_parachains_to_left_transaction_params.signer.public().into(),
-			bridged_chain: RightRelay::NAME.to_string(),
-		});
-		self.common.right.accounts.push(TaggedAccount::Headers {
-			id: self.left_headers_to_right_transaction_params.signer.public().into(),
-			bridged_chain: Left::NAME.to_string(),
-		});
+		self.common.left.accounts.extend(
+			self.right_headers_to_left_transaction_params.signer.signers().map(|signer| {
+				TaggedAccount::Headers {
+					id: signer.public().into(),
+					bridged_chain: RightRelay::NAME.to_string(),
+				}
+			}),
+		);
+		self.common.left.accounts.extend(
+			self.right_parachains_to_left_transaction_params.signer.signers().map(|signer| {
+				TaggedAccount::Parachains {
+					id: signer.public().into(),
+					bridged_chain: RightRelay::NAME.to_string(),
+				}
+			}),
+		);
+		self.common.right.accounts.extend(
+			self.left_headers_to_right_transaction_params.signer.signers().map(|signer| {
+				TaggedAccount::Headers {
+					id: signer.public().into(),
+					bridged_chain: Left::NAME.to_string(),
+				}
+			}),
+		);
 
 		<L2R as RelayToRelayHeadersCliBridge>::Finality::start_relay_guards(
 			&self.common.right.client,
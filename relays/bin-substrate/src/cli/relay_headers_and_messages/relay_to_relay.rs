@@ -22,7 +22,7 @@ use crate::cli::{
 	relay_headers_and_messages::{Full2WayBridgeBase, Full2WayBridgeCommonParams},
 	CliChain,
 };
-use relay_substrate_client::{AccountIdOf, AccountKeyPairOf, ChainWithTransactions};
+use relay_substrate_client::{AccountIdOf, AccountKeyPairOf, ChainWithTransactions, SignerPoolOf};
 use sp_core::Pair;
 use substrate_relay_helper::{
 	finality::SubstrateFinalitySyncPipeline,
@@ -42,10 +42,10 @@ pub struct RelayToRelayBridge<
 		Full2WayBridgeCommonParams<<R2L as CliBridgeBase>::Target, <L2R as CliBridgeBase>::Target>,
 	/// Override for right->left headers signer.
 	pub right_to_left_transaction_params:
-		TransactionParams<AccountKeyPairOf<<R2L as CliBridgeBase>::Target>>,
+		TransactionParams<SignerPoolOf<<R2L as CliBridgeBase>::Target>>,
 	/// Override for left->right headers signer.
 	pub left_to_right_transaction_params:
-		TransactionParams<AccountKeyPairOf<<L2R as CliBridgeBase>::Target>>,
+		TransactionParams<SignerPoolOf<<L2R as CliBridgeBase>::Target>>,
 }
 
 macro_rules! declare_relay_to_relay_bridge_schema {
@@ -88,14 +88,16 @@ macro_rules! declare_relay_to_relay_bridge_schema {
 							self.shared,
 							BridgeEndCommonParams {
 								client: self.left.into_client::<Left>().await?,
-								sign: self.left_sign.to_keypair::<Left>()?,
+								sign: self.left_sign.to_signer_pool::<Left>()?,
 								transactions_mortality: self.left_sign.transactions_mortality()?,
+								tip_escalation: self.left_sign.tip_escalation()?,
 								accounts: vec![],
 							},
 							BridgeEndCommonParams {
 								client: self.right.into_client::<Right>().await?,
-								sign: self.right_sign.to_keypair::<Right>()?,
+								sign: self.right_sign.to_signer_pool::<Right>()?,
 								transactions_mortality: self.right_sign.transactions_mortality()?,
+								tip_escalation: self.right_sign.tip_escalation()?,
 								accounts: vec![],
 							},
 						)?,
@@ -145,14 +147,22 @@ where
 		Arc<dyn OnDemandRelay<Self::Left, Self::Right>>,
 		Arc<dyn OnDemandRelay<Self::Right, Self::Left>>,
 	)> {
-		self.common.right.accounts.push(TaggedAccount::Headers {
-			id: self.left_to_right_transaction_params.signer.public().into(),
-			bridged_chain: Self::Left::NAME.to_string(),
-		});
-		self.common.left.accounts.push(TaggedAccount::Headers {
-			id: self.right_to_left_transaction_params.signer.public().into(),
-			bridged_chain: Self::Right::NAME.to_string(),
-		});
+		self.common.right.accounts.extend(
+			self.left_to_right_transaction_params.signer.signers().map(|signer| {
+				TaggedAccount::Headers {
+					id: signer.public().into(),
+					bridged_chain: Self::Left::NAME.to_string(),
+				}
+			}),
+		);
+		self.common.left.accounts.extend(
+			self.right_to_left_transaction_params.signer.signers().map(|signer| {
+				TaggedAccount::Headers {
+					id: signer.public().into(),
+					bridged_chain: Self::Right::NAME.to_string(),
+				}
+			}),
+		);
 
 		<L2R as RelayToRelayHeadersCliBridge>::Finality::start_relay_guards(
 			&self.common.right.client,
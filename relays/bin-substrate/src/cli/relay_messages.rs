@@ -28,9 +28,15 @@ use crate::chains::{
 	rialto_parachains_to_millau::RialtoParachainToMillauCliBridge,
 };
 use relay_substrate_client::{AccountIdOf, AccountKeyPairOf, BalanceOf, ChainWithTransactions};
-use substrate_relay_helper::{messages_lane::MessagesRelayParams, TransactionParams};
+use sp_runtime::{FixedU128, Percent};
+use substrate_relay_helper::{
+	messages_lane::{DeliveryProfitabilityParams, MessagesRelayParams},
+	TransactionParams,
+};
 
-use crate::cli::{bridge::*, chain_schema::*, CliChain, HexLaneId, PrometheusParams};
+use crate::cli::{
+	bridge::*, chain_schema::*, CliChain, HexLaneId, PrometheusParams, StateStoreParams,
+};
 
 /// Start messages relayer process.
 #[derive(StructOpt)]
@@ -49,8 +55,19 @@ pub struct RelayMessages {
 	target: TargetConnectionParams,
 	#[structopt(flatten)]
 	target_sign: TargetSigningParams,
+	/// Price of the single target chain token, expressed in the source chain tokens. If
+	/// specified, the relayer only delivers messages when the delivery reward covers the
+	/// delivery transaction cost.
+	#[structopt(long)]
+	target_to_source_conversion_rate: Option<f64>,
+	/// Percent of the delivery transaction fee that is refunded to the relayer by the target
+	/// chain runtime. Only used when `--target-to-source-conversion-rate` is specified.
+	#[structopt(long, default_value = "0")]
+	refunded_delivery_fee_percent: u8,
 	#[structopt(flatten)]
 	prometheus_params: PrometheusParams,
+	#[structopt(flatten)]
+	state_store_params: StateStoreParams,
 }
 
 #[async_trait]
@@ -62,11 +79,12 @@ where
 	BalanceOf<Self::Source>: TryFrom<BalanceOf<Self::Target>>,
 {
 	async fn relay_messages(data: RelayMessages) -> anyhow::Result<()> {
+		let profitability_params = data.profitability_params()?;
 		let source_client = data.source.into_client::<Self::Source>().await?;
-		let source_sign = data.source_sign.to_keypair::<Self::Source>()?;
+		let source_sign = data.source_sign.to_signer_pool::<Self::Source>()?;
 		let source_transactions_mortality = data.source_sign.transactions_mortality()?;
 		let target_client = data.target.into_client::<Self::Target>().await?;
-		let target_sign = data.target_sign.to_keypair::<Self::Target>()?;
+		let target_sign = data.target_sign.to_signer_pool::<Self::Target>()?;
 		let target_transactions_mortality = data.target_sign.transactions_mortality()?;
 
 		substrate_relay_helper::messages_lane::run::<Self::MessagesLane>(MessagesRelayParams {
@@ -74,16 +92,22 @@ where
 			source_transaction_params: TransactionParams {
 				signer: source_sign,
 				mortality: source_transactions_mortality,
+				tip_escalation: data.source_sign.tip_escalation()?,
 			},
 			target_client,
 			target_transaction_params: TransactionParams {
 				signer: target_sign,
 				mortality: target_transactions_mortality,
+				tip_escalation: data.target_sign.tip_escalation()?,
 			},
 			source_to_target_headers_relay: None,
 			target_to_source_headers_relay: None,
-			lane_id: data.lane.into(),
+			source_transactions_batcher: None,
+			target_transactions_batcher: None,
+			lanes: vec![data.lane.into()],
+			profitability_params,
 			metrics_params: data.prometheus_params.into_metrics_params()?,
+			state_store: data.state_store_params.into_state_store()?,
 		})
 		.await
 		.map_err(|e| anyhow::format_err!("{}", e))
@@ -114,4 +138,31 @@ impl RelayMessages {
 		}
 		.await
 	}
+
+	/// Returns messages delivery profitability parameters.
+	fn profitability_params(&self) -> anyhow::Result<Option<DeliveryProfitabilityParams>> {
+		let target_to_source_conversion_rate = match self.target_to_source_conversion_rate {
+			Some(target_to_source_conversion_rate) => target_to_source_conversion_rate,
+			None => return Ok(None),
+		};
+		if !target_to_source_conversion_rate.is_finite() || target_to_source_conversion_rate < 0.0 {
+			anyhow::bail!(
+				"Invalid target to source conversion rate: {}",
+				target_to_source_conversion_rate
+			);
+		}
+		if self.refunded_delivery_fee_percent > 100 {
+			anyhow::bail!(
+				"Invalid refunded delivery fee percent: {}",
+				self.refunded_delivery_fee_percent
+			);
+		}
+
+		Ok(Some(DeliveryProfitabilityParams {
+			target_to_source_conversion_rate: FixedU128::from_float(
+				target_to_source_conversion_rate,
+			),
+			refunded_fee_share: Percent::from_percent(self.refunded_delivery_fee_percent),
+		}))
+	}
 }
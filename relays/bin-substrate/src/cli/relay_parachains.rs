@@ -23,18 +23,19 @@ use crate::chains::{
 use async_std::sync::Mutex;
 use async_trait::async_trait;
 use bp_polkadot_core::parachains::ParaId;
+use frame_support::{traits::Get, weights::constants::RocksDbWeight};
 use parachains_relay::parachains_loop::{
 	AvailableHeader, ParachainSyncParams, SourceClient, TargetClient,
 };
 use relay_substrate_client::{Parachain, ParachainBase};
 use relay_utils::metrics::{GlobalMetrics, StandaloneMetric};
-use std::sync::Arc;
+use std::{collections::BTreeSet, sync::Arc};
 use structopt::StructOpt;
 use strum::{EnumString, EnumVariantNames, VariantNames};
 use substrate_relay_helper::{
 	parachains::{
-		source::ParachainsSource, target::ParachainsTarget, ParachainsPipelineAdapter,
-		SubstrateParachainsPipeline,
+		select_parachains_batch_limits, source::ParachainsSource, target::ParachainsTarget,
+		ParachainsPipelineAdapter, SubstrateParachainsPipeline,
 	},
 	TransactionParams,
 };
@@ -42,7 +43,7 @@ use substrate_relay_helper::{
 use crate::cli::{
 	bridge::{CliBridgeBase, ParachainToRelayHeadersCliBridge},
 	chain_schema::*,
-	PrometheusParams,
+	PrometheusParams, StateStoreParams,
 };
 
 /// Start parachain heads relayer process.
@@ -57,8 +58,19 @@ pub struct RelayParachains {
 	target: TargetConnectionParams,
 	#[structopt(flatten)]
 	target_sign: TargetSigningParams,
+	/// Id of additional parachain to relay heads of. May be specified multiple times. Heads of
+	/// the bridged parachain are always relayed. The target chain must accept heads of all
+	/// given parachains.
+	#[structopt(long)]
+	parachain: Vec<u32>,
+	/// Maximal number of parachain heads to submit in a single transaction. If not specified,
+	/// all outdated parachain heads are submitted in a single transaction.
+	#[structopt(long)]
+	max_parachains_in_batch: Option<u32>,
 	#[structopt(flatten)]
 	prometheus_params: PrometheusParams,
+	#[structopt(flatten)]
+	state_store_params: StateStoreParams,
 }
 
 /// Parachain heads relay bridge.
@@ -82,15 +94,24 @@ where
 	<Self as CliBridgeBase>::Source: Parachain,
 {
 	async fn relay_headers(data: RelayParachains) -> anyhow::Result<()> {
+		let parachains = std::iter::once(
+			<Self::ParachainFinality as SubstrateParachainsPipeline>::SourceParachain::PARACHAIN_ID,
+		)
+		.chain(data.parachain)
+		.map(ParaId)
+		.collect::<BTreeSet<_>>();
+
 		let source_client = data.source.into_client::<Self::SourceRelay>().await?;
 		let source_client = ParachainsSource::<Self::ParachainFinality>::new(
 			source_client,
+			parachains.iter().cloned(),
 			Arc::new(Mutex::new(AvailableHeader::Missing)),
 		);
 
 		let target_transaction_params = TransactionParams {
-			signer: data.target_sign.to_keypair::<Self::Target>()?,
+			signer: data.target_sign.to_signer_pool::<Self::Target>()?,
 			mortality: data.target_sign.target_transactions_mortality,
+			tip_escalation: data.target_sign.tip_escalation()?,
 		};
 		let target_client = data.target.into_client::<Self::Target>().await?;
 		let target_client = ParachainsTarget::<Self::ParachainFinality>::new(
@@ -102,15 +123,26 @@ where
 			data.prometheus_params.into_metrics_params()?;
 		GlobalMetrics::new()?.register_and_spawn(&metrics_params.registry)?;
 
+		let batch_limits = data.max_parachains_in_batch.map(|max_parachains| {
+			select_parachains_batch_limits::<
+				<Self::ParachainFinality as SubstrateParachainsPipeline>::TargetParachainsWeightInfo,
+			>(
+				RocksDbWeight::get(),
+				Self::Target::max_extrinsic_size(),
+				Self::Target::max_extrinsic_weight(),
+				max_parachains,
+			)
+		});
+
 		parachains_relay::parachains_loop::run(
 			source_client,
 			target_client,
 			ParachainSyncParams {
-				parachains: vec![
-					ParaId(<Self::ParachainFinality as SubstrateParachainsPipeline>::SourceParachain::PARACHAIN_ID)
-				],
+				parachains: parachains.into_iter().collect(),
 				stall_timeout: std::time::Duration::from_secs(60),
 				strategy: parachains_relay::parachains_loop::ParachainSyncStrategy::Any,
+				batch_limits,
+				state_store: data.state_store_params.into_state_store()?,
 			},
 			metrics_params,
 			futures::future::pending(),
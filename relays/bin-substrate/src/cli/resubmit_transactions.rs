@@ -20,8 +20,8 @@ use bp_runtime::HeaderIdProvider;
 use codec::{Decode, Encode};
 use num_traits::{One, Zero};
 use relay_substrate_client::{
-	AccountKeyPairOf, BlockWithJustification, Chain, ChainWithTransactions, Client,
-	Error as SubstrateError, HeaderIdOf, HeaderOf, SignParam,
+	BlockWithJustification, Chain, ChainWithTransactions, Client, Error as SubstrateError,
+	HeaderIdOf, HeaderOf, SignParam, SignerOf,
 };
 use relay_utils::FailedClient;
 use sp_core::Bytes;
@@ -105,8 +105,9 @@ impl ResubmitTransactions {
 			let relay_loop_name = format!("ResubmitTransactions{}", Target::NAME);
 			let client = self.target.into_client::<Target>().await?;
 			let transaction_params = TransactionParams {
-				signer: self.target_sign.to_keypair::<Target>()?,
+				signer: self.target_sign.to_signer::<Target>()?,
 				mortality: self.target_sign.target_transactions_mortality,
+				tip_escalation: None,
 			};
 
 			relay_utils::relay_loop((), client)
@@ -204,7 +205,7 @@ impl<C: Chain> Context<C> {
 /// Run resubmit transactions loop.
 async fn run_until_connection_lost<C: ChainWithTransactions>(
 	client: Client<C>,
-	transaction_params: TransactionParams<AccountKeyPairOf<C>>,
+	transaction_params: TransactionParams<SignerOf<C>>,
 	mut context: Context<C>,
 ) -> Result<(), FailedClient> {
 	loop {
@@ -229,7 +230,7 @@ async fn run_until_connection_lost<C: ChainWithTransactions>(
 /// Run single loop iteration.
 async fn run_loop_iteration<C: ChainWithTransactions>(
 	client: Client<C>,
-	transaction_params: TransactionParams<AccountKeyPairOf<C>>,
+	transaction_params: TransactionParams<SignerOf<C>>,
 	mut context: Context<C>,
 ) -> Result<Context<C>, SubstrateError> {
 	// correct best header is required for all other actions
@@ -304,7 +305,7 @@ async fn run_loop_iteration<C: ChainWithTransactions>(
 /// Search transaction pool for transaction, signed by given key pair.
 async fn lookup_signer_transaction<C: ChainWithTransactions>(
 	client: &Client<C>,
-	key_pair: &AccountKeyPairOf<C>,
+	key_pair: &SignerOf<C>,
 ) -> Result<Option<C::SignedTransaction>, SubstrateError> {
 	let pending_transactions = client.pending_extrinsics().await?;
 	for pending_transaction in pending_transactions {
@@ -389,7 +390,7 @@ fn select_transaction_from_queue<C: Chain>(
 /// Try to find appropriate tip for transaction so that its priority is larger than given.
 async fn update_transaction_tip<C: ChainWithTransactions>(
 	client: &Client<C>,
-	transaction_params: &TransactionParams<AccountKeyPairOf<C>>,
+	transaction_params: &TransactionParams<SignerOf<C>>,
 	at_block: HeaderIdOf<C>,
 	tx: C::SignedTransaction,
 	tip_step: C::Balance,
@@ -420,21 +421,18 @@ async fn update_transaction_tip<C: ChainWithTransactions>(
 		);
 
 		unsigned_tx.tip = next_tip;
-		current_priority = client
-			.validate_transaction(
-				at_block.1,
-				C::sign_transaction(
-					SignParam {
-						spec_version: runtime_version.spec_version,
-						transaction_version: runtime_version.transaction_version,
-						genesis_hash: *client.genesis_hash(),
-						signer: transaction_params.signer.clone(),
-					},
-					unsigned_tx.clone(),
-				)?,
+		let signed_tx = client
+			.sign_transaction(
+				SignParam {
+					spec_version: runtime_version.spec_version,
+					transaction_version: runtime_version.transaction_version,
+					genesis_hash: *client.genesis_hash(),
+					signer: transaction_params.signer.clone(),
+				},
+				unsigned_tx.clone(),
 			)
-			.await??
-			.priority;
+			.await?;
+		current_priority = client.validate_transaction(at_block.1, signed_tx).await??.priority;
 	}
 
 	log::debug!(
@@ -447,18 +445,20 @@ async fn update_transaction_tip<C: ChainWithTransactions>(
 
 	Ok((
 		old_tip != unsigned_tx.tip,
-		C::sign_transaction(
-			SignParam {
-				spec_version: runtime_version.spec_version,
-				transaction_version: runtime_version.transaction_version,
-				genesis_hash: *client.genesis_hash(),
-				signer: transaction_params.signer.clone(),
-			},
-			unsigned_tx.era(relay_substrate_client::TransactionEra::new(
-				at_block,
-				transaction_params.mortality,
-			)),
-		)?,
+		client
+			.sign_transaction(
+				SignParam {
+					spec_version: runtime_version.spec_version,
+					transaction_version: runtime_version.transaction_version,
+					genesis_hash: *client.genesis_hash(),
+					signer: transaction_params.signer.clone(),
+				},
+				unsigned_tx.era(relay_substrate_client::TransactionEra::new(
+					at_block,
+					transaction_params.mortality,
+				)),
+			)
+			.await?,
 	))
 }
 
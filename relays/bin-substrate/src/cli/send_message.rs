@@ -68,7 +68,7 @@ where
 		let payload = encode_message::encode_message::<Self::Source, Self::Target>(&data.message)?;
 
 		let source_client = data.source.into_client::<Self::Source>().await?;
-		let source_sign = data.source_sign.to_keypair::<Self::Source>()?;
+		let source_sign = data.source_sign.to_signer::<Self::Source>()?;
 
 		let payload_len = payload.encoded_size();
 		let send_message_call = Self::Source::encode_send_xcm(
@@ -18,12 +18,13 @@
 
 use bp_bridge_hub_wococo::{PolkadotSignedExtension, AVERAGE_BLOCK_INTERVAL};
 use bp_messages::MessageNonce;
+use bp_runtime::ChainId;
 use codec::Encode;
 use relay_substrate_client::{
 	Chain, ChainWithBalances, ChainWithMessages, ChainWithTransactions, Error as SubstrateError,
-	SignParam, UnderlyingChainProvider, UnsignedTransaction,
+	SignParam, Signer, UnderlyingChainProvider, UnsignedTransaction,
 };
-use sp_core::{storage::StorageKey, Pair};
+use sp_core::storage::StorageKey;
 use sp_runtime::{generic::SignedPayload, traits::IdentifyAccount};
 use std::time::Duration;
 
@@ -76,7 +77,7 @@ impl ChainWithTransactions for BridgeHubWococo {
 			),
 		)?;
 
-		let signature = raw_payload.using_encoded(|payload| param.signer.sign(payload));
+		let signature = raw_payload.using_encoded(|payload| param.signer.sign(payload))?;
 		let signer: sp_runtime::MultiSigner = param.signer.public().into();
 		let (call, extra, _) = raw_payload.deconstruct();
 
@@ -92,7 +93,7 @@ impl ChainWithTransactions for BridgeHubWococo {
 		tx.signature.is_some()
 	}
 
-	fn is_signed_by(signer: &Self::AccountKeyPair, tx: &Self::SignedTransaction) -> bool {
+	fn is_signed_by(signer: &Signer<Self::AccountKeyPair>, tx: &Self::SignedTransaction) -> bool {
 		tx.signature
 			.as_ref()
 			.map(|(address, _, _)| {
@@ -108,6 +109,7 @@ impl ChainWithTransactions for BridgeHubWococo {
 }
 
 impl ChainWithMessages for BridgeHubWococo {
+	const ID: ChainId = bp_runtime::BRIDGE_HUB_WOCOCO_CHAIN_ID;
 	const WITH_CHAIN_MESSAGES_PALLET_NAME: &'static str =
 		bp_bridge_hub_wococo::WITH_BRIDGE_HUB_WOCOCO_MESSAGES_PALLET_NAME;
 	const WITH_CHAIN_RELAYERS_PALLET_NAME: Option<&'static str> = None;
@@ -130,6 +132,7 @@ impl ChainWithMessages for BridgeHubWococo {
 mod tests {
 	use super::*;
 	use relay_substrate_client::TransactionEra;
+	use sp_core::Pair;
 
 	#[test]
 	fn parse_transaction_works() {
@@ -145,7 +148,7 @@ mod tests {
 				spec_version: 42,
 				transaction_version: 50000,
 				genesis_hash: [42u8; 32].into(),
-				signer: sp_core::sr25519::Pair::from_seed_slice(&[1u8; 32]).unwrap(),
+				signer: sp_core::sr25519::Pair::from_seed_slice(&[1u8; 32]).unwrap().into(),
 			},
 			unsigned.clone(),
 		)
@@ -17,13 +17,15 @@
 //! Types used to connect to the Millau-Substrate chain.
 
 use bp_messages::MessageNonce;
+use bp_runtime::ChainId;
 use codec::{Compact, Decode, Encode};
 use relay_substrate_client::{
-	BalanceOf, Chain, ChainWithBalances, ChainWithGrandpa, ChainWithMessages,
+	BalanceOf, Chain, ChainWithBalances, ChainWithBeefy, ChainWithGrandpa, ChainWithMessages,
 	ChainWithTransactions, ChainWithUtilityPallet, Error as SubstrateError,
-	FullRuntimeUtilityPallet, IndexOf, SignParam, UnderlyingChainProvider, UnsignedTransaction,
+	FullRuntimeUtilityPallet, IndexOf, SignParam, Signer, UnderlyingChainProvider,
+	UnsignedTransaction,
 };
-use sp_core::{storage::StorageKey, Pair};
+use sp_core::storage::StorageKey;
 use sp_runtime::{generic::SignedPayload, traits::IdentifyAccount};
 use std::time::Duration;
 
@@ -42,7 +44,12 @@ impl ChainWithGrandpa for Millau {
 	const WITH_CHAIN_GRANDPA_PALLET_NAME: &'static str = bp_millau::WITH_MILLAU_GRANDPA_PALLET_NAME;
 }
 
+impl ChainWithBeefy for Millau {
+	const WITH_CHAIN_BEEFY_PALLET_NAME: &'static str = bp_millau::WITH_MILLAU_BEEFY_PALLET_NAME;
+}
+
 impl ChainWithMessages for Millau {
+	const ID: ChainId = bp_runtime::MILLAU_CHAIN_ID;
 	const WITH_CHAIN_MESSAGES_PALLET_NAME: &'static str =
 		bp_millau::WITH_MILLAU_MESSAGES_PALLET_NAME;
 	// TODO (https://github.com/paritytech/parity-bridges-common/issues/1692): change the name
@@ -99,6 +106,7 @@ impl ChainWithTransactions for Millau {
 				frame_system::CheckWeight::<millau_runtime::Runtime>::new(),
 				pallet_transaction_payment::ChargeTransactionPayment::<millau_runtime::Runtime>::from(unsigned.tip),
 				millau_runtime::BridgeRejectObsoleteHeadersAndMessages,
+				millau_runtime::BridgeRefundRialtoRelayers::default(),
 				millau_runtime::BridgeRefundRialtoParachainRelayers::default(),
 			),
 			(
@@ -111,10 +119,11 @@ impl ChainWithTransactions for Millau {
 				(),
 				(),
 				(),
+				(),
 				()
 			),
 		);
-		let signature = raw_payload.using_encoded(|payload| param.signer.sign(payload));
+		let signature = raw_payload.using_encoded(|payload| param.signer.sign(payload))?;
 		let signer: sp_runtime::MultiSigner = param.signer.public().into();
 		let (call, extra, _) = raw_payload.deconstruct();
 
@@ -130,7 +139,7 @@ impl ChainWithTransactions for Millau {
 		tx.signature.is_some()
 	}
 
-	fn is_signed_by(signer: &Self::AccountKeyPair, tx: &Self::SignedTransaction) -> bool {
+	fn is_signed_by(signer: &Signer<Self::AccountKeyPair>, tx: &Self::SignedTransaction) -> bool {
 		tx.signature
 			.as_ref()
 			.map(|(address, _, _)| {
@@ -165,6 +174,7 @@ pub type SyncHeader = relay_substrate_client::SyncHeader<millau_runtime::Header>
 mod tests {
 	use super::*;
 	use relay_substrate_client::TransactionEra;
+	use sp_core::Pair;
 
 	#[test]
 	fn parse_transaction_works() {
@@ -182,7 +192,7 @@ mod tests {
 				spec_version: 42,
 				transaction_version: 50000,
 				genesis_hash: [42u8; 64].into(),
-				signer: sp_core::sr25519::Pair::from_seed_slice(&[1u8; 32]).unwrap(),
+				signer: sp_core::sr25519::Pair::from_seed_slice(&[1u8; 32]).unwrap().into(),
 			},
 			unsigned.clone(),
 		)
@@ -20,12 +20,13 @@ pub mod codegen_runtime;
 
 use bp_messages::MessageNonce;
 use bp_polkadot_core::PolkadotSignedExtension;
+use bp_runtime::ChainId;
 use codec::Encode;
 use relay_substrate_client::{
 	Chain, ChainWithBalances, ChainWithMessages, ChainWithTransactions, Error as SubstrateError,
-	SignParam, UnderlyingChainProvider, UnsignedTransaction,
+	SignParam, Signer, UnderlyingChainProvider, UnsignedTransaction,
 };
-use sp_core::{storage::StorageKey, Pair};
+use sp_core::storage::StorageKey;
 use sp_runtime::{generic::SignedPayload, traits::IdentifyAccount, MultiAddress};
 use std::time::Duration;
 
@@ -67,6 +68,7 @@ impl ChainWithBalances for RialtoParachain {
 }
 
 impl ChainWithMessages for RialtoParachain {
+	const ID: ChainId = bp_runtime::RIALTO_PARACHAIN_CHAIN_ID;
 	const WITH_CHAIN_MESSAGES_PALLET_NAME: &'static str =
 		bp_rialto_parachain::WITH_RIALTO_PARACHAIN_MESSAGES_PALLET_NAME;
 	// TODO (https://github.com/paritytech/parity-bridges-common/issues/1692): change the name
@@ -103,7 +105,7 @@ impl ChainWithTransactions for RialtoParachain {
 			),
 		)?;
 
-		let signature = raw_payload.using_encoded(|payload| param.signer.sign(payload));
+		let signature = raw_payload.using_encoded(|payload| param.signer.sign(payload))?;
 		let signer: sp_runtime::MultiSigner = param.signer.public().into();
 		let (call, extra, _) = raw_payload.deconstruct();
 
@@ -119,7 +121,7 @@ impl ChainWithTransactions for RialtoParachain {
 		tx.signature.is_some()
 	}
 
-	fn is_signed_by(signer: &Self::AccountKeyPair, tx: &Self::SignedTransaction) -> bool {
+	fn is_signed_by(signer: &Signer<Self::AccountKeyPair>, tx: &Self::SignedTransaction) -> bool {
 		tx.signature
 			.as_ref()
 			.map(|(address, _, _)| *address == Address::Id(signer.public().into()))
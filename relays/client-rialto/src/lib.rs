@@ -17,13 +17,14 @@
 //! Types used to connect to the Rialto-Substrate chain.
 
 use bp_messages::MessageNonce;
+use bp_runtime::ChainId;
 use codec::{Compact, Decode, Encode};
 use relay_substrate_client::{
 	BalanceOf, Chain, ChainWithBalances, ChainWithGrandpa, ChainWithMessages,
 	ChainWithTransactions, Error as SubstrateError, IndexOf, RelayChain, SignParam,
 	UnderlyingChainProvider, UnsignedTransaction,
 };
-use sp_core::{storage::StorageKey, Pair};
+use sp_core::storage::StorageKey;
 use sp_runtime::{generic::SignedPayload, traits::IdentifyAccount};
 use std::time::Duration;
 
@@ -61,6 +62,7 @@ impl ChainWithGrandpa for Rialto {
 }
 
 impl ChainWithMessages for Rialto {
+	const ID: ChainId = bp_runtime::RIALTO_CHAIN_ID;
 	const WITH_CHAIN_MESSAGES_PALLET_NAME: &'static str =
 		bp_rialto::WITH_RIALTO_MESSAGES_PALLET_NAME;
 	// TODO (https://github.com/paritytech/parity-bridges-common/issues/1692): change the name
@@ -104,6 +106,7 @@ impl ChainWithTransactions for Rialto {
 				frame_system::CheckNonce::<rialto_runtime::Runtime>::from(unsigned.nonce),
 				frame_system::CheckWeight::<rialto_runtime::Runtime>::new(),
 				pallet_transaction_payment::ChargeTransactionPayment::<rialto_runtime::Runtime>::from(unsigned.tip),
+				rialto_runtime::BridgeRejectObsoleteHeadersAndMessages,
 			),
 			(
 				(),
@@ -114,9 +117,10 @@ impl ChainWithTransactions for Rialto {
 				(),
 				(),
 				(),
+				(),
 			),
 		);
-		let signature = raw_payload.using_encoded(|payload| param.signer.sign(payload));
+		let signature = raw_payload.using_encoded(|payload| param.signer.sign(payload))?;
 		let signer: sp_runtime::MultiSigner = param.signer.public().into();
 		let (call, extra, _) = raw_payload.deconstruct();
 
@@ -132,7 +136,7 @@ impl ChainWithTransactions for Rialto {
 		tx.signature.is_some()
 	}
 
-	fn is_signed_by(signer: &Self::AccountKeyPair, tx: &Self::SignedTransaction) -> bool {
+	fn is_signed_by(signer: &Signer<Self::AccountKeyPair>, tx: &Self::SignedTransaction) -> bool {
 		tx.signature
 			.as_ref()
 			.map(|(address, _, _)| *address == rialto_runtime::Address::Id(signer.public().into()))
@@ -161,6 +165,7 @@ pub type SyncHeader = relay_substrate_client::SyncHeader<rialto_runtime::Header>
 mod tests {
 	use super::*;
 	use relay_substrate_client::TransactionEra;
+	use sp_core::Pair;
 
 	#[test]
 	fn parse_transaction_works() {
@@ -178,7 +183,7 @@ mod tests {
 				spec_version: 42,
 				transaction_version: 50000,
 				genesis_hash: [42u8; 32].into(),
-				signer: sp_core::sr25519::Pair::from_seed_slice(&[1u8; 32]).unwrap(),
+				signer: sp_core::sr25519::Pair::from_seed_slice(&[1u8; 32]).unwrap().into(),
 			},
 			unsigned.clone(),
 		)
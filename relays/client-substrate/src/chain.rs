@@ -14,10 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
 
+use crate::signer::{DecodableKeyPair, Signer, SignerOf};
+
 use bp_messages::MessageNonce;
 use bp_runtime::{
-	Chain as ChainBase, EncodedOrDecodedCall, HashOf, Parachain as ParachainBase, TransactionEra,
-	TransactionEraOf, UnderlyingChainProvider,
+	Chain as ChainBase, ChainId, EncodedOrDecodedCall, HashOf, Parachain as ParachainBase,
+	TransactionEra, TransactionEraOf, UnderlyingChainProvider,
 };
 use codec::{Codec, Encode};
 use jsonrpsee::core::{DeserializeOwned, Serialize};
@@ -92,8 +94,24 @@ pub trait ChainWithGrandpa: Chain {
 	const WITH_CHAIN_GRANDPA_PALLET_NAME: &'static str;
 }
 
+/// Substrate-based chain that is using BEEFY finality from minimal relay-client point of view.
+pub trait ChainWithBeefy: Chain + bp_beefy::ChainWithBeefy {
+	/// Name of the bridge BEEFY pallet (used in `construct_runtime` macro call) that is deployed
+	/// at some other chain to bridge with this `ChainWithBeefy`.
+	///
+	/// We assume that all chains that are bridging with this `ChainWithBeefy` are using
+	/// the same name.
+	const WITH_CHAIN_BEEFY_PALLET_NAME: &'static str;
+}
+
 /// Substrate-based chain with messaging support from minimal relay-client point of view.
 pub trait ChainWithMessages: Chain {
+	/// Identifier of this chain, used by other chains to distinguish bridges with this chain.
+	///
+	/// E.g. relayer rewards at the bridged chain are stored under key that includes this
+	/// identifier.
+	const ID: ChainId;
+
 	/// Name of the bridge messages pallet (used in `construct_runtime` macro call) that is deployed
 	/// at some other chain to bridge with this `ChainWithMessages`.
 	///
@@ -195,7 +213,7 @@ pub type AccountKeyPairOf<S> = <S as ChainWithTransactions>::AccountKeyPair;
 /// Substrate-based chain transactions signing scheme.
 pub trait ChainWithTransactions: Chain {
 	/// Type of key pairs used to sign transactions.
-	type AccountKeyPair: Pair;
+	type AccountKeyPair: Pair + DecodableKeyPair;
 	/// Signed transaction.
 	type SignedTransaction: Clone + Debug + Codec + Send + 'static;
 
@@ -211,7 +229,7 @@ pub trait ChainWithTransactions: Chain {
 	fn is_signed(tx: &Self::SignedTransaction) -> bool;
 
 	/// Returns true if transaction is signed by given signer.
-	fn is_signed_by(signer: &Self::AccountKeyPair, tx: &Self::SignedTransaction) -> bool;
+	fn is_signed_by(signer: &Signer<Self::AccountKeyPair>, tx: &Self::SignedTransaction) -> bool;
 
 	/// Parse signed transaction into its unsigned part.
 	///
@@ -220,6 +238,7 @@ pub trait ChainWithTransactions: Chain {
 }
 
 /// Sign transaction parameters
+#[derive(Clone)]
 pub struct SignParam<C: ChainWithTransactions> {
 	/// Version of the runtime specification.
 	pub spec_version: u32,
@@ -228,7 +247,7 @@ pub struct SignParam<C: ChainWithTransactions> {
 	/// Hash of the genesis block.
 	pub genesis_hash: HashOf<C>,
 	/// Signer account
-	pub signer: AccountKeyPairOf<C>,
+	pub signer: SignerOf<C>,
 }
 
 impl<Block: BlockT> BlockWithJustification<Block::Header> for SignedBlock<Block> {
@@ -23,8 +23,9 @@ use crate::{
 		SubstrateFrameSystemClient, SubstrateStateClient, SubstrateSystemClient,
 		SubstrateTransactionPaymentClient,
 	},
-	transaction_stall_timeout, AccountKeyPairOf, ConnectionParams, Error, HashOf, HeaderIdOf,
-	Result, SignParam, TransactionTracker, UnsignedTransaction,
+	transaction_stall_timeout, BlockWithJustification, ConnectionParams, Error, HashOf, HeaderIdOf,
+	Result, RpcClient, RpcEndpoint, SignParam, SignerOf, TipEscalation, TransactionResubmitter,
+	TransactionStatusOf, TransactionTracker, UnsignedTransaction,
 };
 
 use async_std::sync::{Arc, Mutex};
@@ -33,14 +34,13 @@ use bp_runtime::{HeaderIdProvider, StorageDoubleMapKeyProvider, StorageMapKeyPro
 use codec::{Decode, Encode};
 use frame_system::AccountInfo;
 use futures::{SinkExt, StreamExt};
-use jsonrpsee::{
-	core::DeserializeOwned,
-	ws_client::{WsClient as RpcClient, WsClientBuilder as RpcClientBuilder},
-};
-use num_traits::{Bounded, Saturating, Zero};
+use jsonrpsee::core::{DeserializeOwned, Error as RpcError};
+use num_traits::{Bounded, One, Saturating, Zero};
 use pallet_balances::AccountData;
 use pallet_transaction_payment::InclusionFee;
-use relay_utils::{relay_loop::RECONNECT_DELAY, STALL_TIMEOUT};
+use relay_utils::{relay_loop::RECONNECT_DELAY, MaybeConnectionError, STALL_TIMEOUT};
+use sc_rpc_api::system::Health;
+use sc_transaction_pool_api::TransactionStatus;
 use sp_core::{
 	storage::{StorageData, StorageKey},
 	Bytes, Hasher, Pair,
@@ -48,14 +48,24 @@ use sp_core::{
 use sp_runtime::{
 	traits::Header as HeaderT,
 	transaction_validity::{TransactionSource, TransactionValidity},
+	SaturatedConversion,
 };
 use sp_trie::StorageProof;
 use sp_version::RuntimeVersion;
-use std::{convert::TryFrom, future::Future};
+use std::{
+	collections::BTreeMap,
+	convert::TryFrom,
+	future::Future,
+	ops::ControlFlow,
+	sync::RwLock,
+	time::{Duration, Instant},
+};
 
 const SUB_API_GRANDPA_AUTHORITIES: &str = "GrandpaApi_grandpa_authorities";
 const SUB_API_TXPOOL_VALIDATE_TRANSACTION: &str = "TaggedTransactionQueue_validate_transaction";
-const MAX_SUBSCRIPTION_CAPACITY: usize = 4096;
+pub(crate) const MAX_SUBSCRIPTION_CAPACITY: usize = 4096;
+/// Interval between checks of the health of connected RPC endpoints.
+const ENDPOINTS_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(60);
 
 /// The difference between best block number and number of its ancestor, that is enough
 /// for us to consider that ancestor an "ancient" block with dropped state.
@@ -108,6 +118,98 @@ pub enum ChainRuntimeVersion {
 	Custom(SimpleRuntimeVersion),
 }
 
+/// Health of the RPC endpoint, used to choose the endpoint to work with.
+///
+/// Endpoints are compared by their sync state first and then by their best block number, so
+/// synced nodes are always preferred over syncing or isolated nodes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct EndpointHealth<N> {
+	/// Whether the node is synced and has peers (if it should have any).
+	is_synced: bool,
+	/// Best block number, known to the node.
+	best_number: N,
+}
+
+impl<N> EndpointHealth<N> {
+	/// Create endpoint health from the `system_health` response and best block number.
+	fn new(health: &Health, best_number: N) -> Self {
+		EndpointHealth { is_synced: is_synced(health), best_number }
+	}
+}
+
+/// Returns true if node with given health is connected to at least one peer (if it should have
+/// peers) and is in synced state.
+fn is_synced(health: &Health) -> bool {
+	!health.is_syncing && (!health.should_have_peers || health.peers > 0)
+}
+
+/// Select the best block number that is known to at least `quorum` endpoints.
+///
+/// Endpoints may legitimately be at different blocks, so we can't expect them to return the
+/// same number. If `quorum` endpoints have returned number `N` or better, then all of them know
+/// block `N`.
+fn select_quorum_block_number<N: Ord>(responses: Vec<Result<N>>, quorum: usize) -> Result<N> {
+	let mut numbers = Vec::with_capacity(responses.len());
+	let mut connection_error = None;
+	for response in responses {
+		match response {
+			Ok(number) => numbers.push(number),
+			Err(error) if error.is_connection_error() && connection_error.is_none() =>
+				connection_error = Some(error),
+			Err(_) => (),
+		}
+	}
+
+	if numbers.len() < quorum.max(1) {
+		return Err(connection_error.unwrap_or(Error::QuorumNotReached(quorum, numbers.len())))
+	}
+
+	numbers.sort_by(|number1, number2| number2.cmp(number1));
+	Ok(numbers.swap_remove(quorum.saturating_sub(1)))
+}
+
+/// Select response that has been returned by at least `quorum` endpoints.
+///
+/// If there's no such response and some endpoint has failed with connection error, this error
+/// is returned, so that the caller could reconnect.
+fn select_quorum_response<T: PartialEq>(responses: Vec<Result<T>>, quorum: usize) -> Result<T> {
+	let mut agreed: Vec<(T, usize)> = Vec::new();
+	let mut connection_error = None;
+	for response in responses {
+		match response {
+			Ok(response) => match agreed.iter_mut().find(|(value, _)| *value == response) {
+				Some((_, count)) => *count += 1,
+				None => agreed.push((response, 1)),
+			},
+			Err(error) if error.is_connection_error() && connection_error.is_none() =>
+				connection_error = Some(error),
+			Err(_) => (),
+		}
+	}
+
+	let max_agreed = agreed.iter().map(|(_, count)| *count).max().unwrap_or(0);
+	match agreed.into_iter().find(|(_, count)| *count >= quorum) {
+		Some((response, _)) => Ok(response),
+		None => Err(connection_error.unwrap_or(Error::QuorumNotReached(quorum, max_agreed))),
+	}
+}
+
+/// RPC clients of all connected endpoints.
+struct Endpoints {
+	/// Substrate RPC clients of all connected endpoints, ordered by their health. The first
+	/// client is the active (healthiest) one.
+	clients: Arc<Vec<Arc<RpcClient>>>,
+	/// Time when the health of endpoints has been checked last time.
+	checked_at: Instant,
+}
+
+impl Endpoints {
+	/// Create endpoints from clients, that are already ordered by their health.
+	fn new(clients: Vec<Arc<RpcClient>>) -> Arc<RwLock<Self>> {
+		Arc::new(RwLock::new(Endpoints { clients: Arc::new(clients), checked_at: Instant::now() }))
+	}
+}
+
 /// Substrate client type.
 ///
 /// Cloning `Client` is a cheap operation.
@@ -116,15 +218,17 @@ pub struct Client<C: Chain> {
 	tokio: Arc<tokio::runtime::Runtime>,
 	/// Client connection params.
 	params: Arc<ConnectionParams>,
-	/// Substrate RPC client.
-	client: Arc<RpcClient>,
+	/// RPC clients of all connected endpoints. Endpoints are reordered by their health
+	/// periodically and after failover.
+	endpoints: Arc<RwLock<Endpoints>>,
 	/// Genesis block hash.
 	genesis_hash: HashOf<C>,
 	/// If several tasks are submitting their transactions simultaneously using
 	/// `submit_signed_extrinsic` method, they may get the same transaction nonce. So one of
-	/// transactions will be rejected from the pool. This lock is here to prevent situations like
-	/// that.
-	submit_signed_extrinsic_lock: Arc<Mutex<()>>,
+	/// transactions will be rejected from the pool. These locks are here to prevent situations
+	/// like that. Nonces are selected independently for every account, so transactions of
+	/// different accounts are never waiting for each other.
+	submit_signed_extrinsic_locks: Arc<Mutex<BTreeMap<C::AccountId, Arc<Mutex<()>>>>>,
 	/// Saved chain runtime version
 	chain_runtime_version: ChainRuntimeVersion,
 }
@@ -134,9 +238,9 @@ impl<C: Chain> relay_utils::relay_loop::Client for Client<C> {
 	type Error = Error;
 
 	async fn reconnect(&mut self) -> Result<()> {
-		let (tokio, client) = Self::build_client(&self.params).await?;
+		let (tokio, clients) = Self::build_clients(&self.params).await?;
 		self.tokio = tokio;
-		self.client = client;
+		self.endpoints = Endpoints::new(clients);
 		Ok(())
 	}
 }
@@ -146,9 +250,9 @@ impl<C: Chain> Clone for Client<C> {
 		Client {
 			tokio: self.tokio.clone(),
 			params: self.params.clone(),
-			client: self.client.clone(),
+			endpoints: self.endpoints.clone(),
 			genesis_hash: self.genesis_hash,
-			submit_signed_extrinsic_lock: self.submit_signed_extrinsic_lock.clone(),
+			submit_signed_extrinsic_locks: self.submit_signed_extrinsic_locks.clone(),
 			chain_runtime_version: self.chain_runtime_version.clone(),
 		}
 	}
@@ -186,7 +290,9 @@ impl<C: Chain> Client<C> {
 	/// Try to connect to Substrate node over websocket. Returns Substrate RPC client if connection
 	/// has been established or error otherwise.
 	pub async fn try_connect(params: Arc<ConnectionParams>) -> Result<Self> {
-		let (tokio, client) = Self::build_client(&params).await?;
+		let (tokio, clients) = Self::build_clients(&params).await?;
+		let client = clients[0].clone();
+		let endpoints = Endpoints::new(clients);
 
 		let number: C::BlockNumber = Zero::zero();
 		let genesis_hash_client = client.clone();
@@ -200,36 +306,125 @@ impl<C: Chain> Client<C> {
 		Ok(Self {
 			tokio,
 			params,
-			client,
+			endpoints,
 			genesis_hash,
-			submit_signed_extrinsic_lock: Arc::new(Mutex::new(())),
+			submit_signed_extrinsic_locks: Arc::new(Mutex::new(BTreeMap::new())),
 			chain_runtime_version,
 		})
 	}
 
-	/// Build client to use in connection.
-	async fn build_client(
+	/// Build clients to use in connection.
+	///
+	/// Connects to all configured endpoints and returns clients of endpoints that we have managed
+	/// to connect to, ordered by endpoint health. Fails only if all endpoints are unavailable.
+	async fn build_clients(
 		params: &ConnectionParams,
-	) -> Result<(Arc<tokio::runtime::Runtime>, Arc<RpcClient>)> {
+	) -> Result<(Arc<tokio::runtime::Runtime>, Vec<Arc<RpcClient>>)> {
 		let tokio = tokio::runtime::Runtime::new()?;
-		let uri = format!(
-			"{}://{}:{}",
-			if params.secure { "wss" } else { "ws" },
-			params.host,
-			params.port,
+		let endpoints = params.endpoints();
+		let connections = futures::future::join_all(
+			endpoints
+				.iter()
+				.cloned()
+				.map(|endpoint| tokio.spawn(Self::connect_endpoint(endpoint))),
+		)
+		.await;
+
+		let mut clients = Vec::with_capacity(connections.len());
+		let mut last_error = None;
+		for (endpoint, connection) in endpoints.iter().zip(connections) {
+			match connection.map_err(Error::from).and_then(|connection| connection) {
+				Ok((health, client)) => clients.push((health, Arc::new(client))),
+				Err(error) => {
+					log::warn!(
+						target: "bridge",
+						"Failed to connect to {} node at {}: {:?}",
+						C::NAME,
+						endpoint.uri,
+						error,
+					);
+					last_error = Some(error);
+				},
+			}
+		}
+
+		// stable sort, so endpoints with the same health are used in the configured order
+		clients.sort_by(|(health1, _), (health2, _)| health2.cmp(health1));
+		if clients.is_empty() {
+			return Err(last_error.unwrap_or_else(|| Error::Custom("No RPC endpoints".into())))
+		}
+
+		Ok((Arc::new(tokio), clients.into_iter().map(|(_, client)| client).collect()))
+	}
+
+	/// Connect to given endpoint and read its health.
+	async fn connect_endpoint(
+		endpoint: RpcEndpoint,
+	) -> Result<(EndpointHealth<C::BlockNumber>, RpcClient)> {
+		log::info!(target: "bridge", "Connecting to {} node at {}", C::NAME, endpoint.uri);
+
+		let client = RpcClient::connect(&endpoint).await?;
+		let health = Self::endpoint_health(&client).await?;
+		log::debug!(target: "bridge", "{} node at {} health: {:?}", C::NAME, endpoint.uri, health);
+
+		Ok((health, client))
+	}
+
+	/// Read health of the connected endpoint.
+	async fn endpoint_health(client: &RpcClient) -> Result<EndpointHealth<C::BlockNumber>> {
+		let health = SubstrateSystemClient::<C>::health(client).await?;
+		let best_header = SubstrateChainClient::<C>::header(client, None).await?;
+		Ok(EndpointHealth::new(&health, *best_header.number()))
+	}
+
+	/// Check health of all connected endpoints and reorder them, so that the healthiest endpoint
+	/// becomes active.
+	///
+	/// Endpoints that have failed to respond are moved to the end of the list.
+	async fn rescore_endpoints(endpoints: Arc<RwLock<Endpoints>>) {
+		let clients =
+			endpoints.read().expect("endpoints lock is never poisoned; qed").clients.clone();
+		let healths = futures::future::join_all(
+			clients
+				.iter()
+				.map(|client| async move { Self::endpoint_health(client).await.ok() }),
+		)
+		.await;
+
+		let mut clients = healths.into_iter().zip(clients.iter().cloned()).collect::<Vec<_>>();
+		// stable sort, so endpoints with the same health keep their order
+		clients.sort_by(|(health1, _), (health2, _)| health2.cmp(health1));
+		log::debug!(
+			target: "bridge",
+			"Updated health of {} RPC endpoints: {:?}",
+			C::NAME,
+			clients.iter().map(|(health, _)| health).collect::<Vec<_>>(),
 		);
-		log::info!(target: "bridge", "Connecting to {} node at {}", C::NAME, uri);
 
-		let client = tokio
-			.spawn(async move {
-				RpcClientBuilder::default()
-					.max_notifs_per_subscription(MAX_SUBSCRIPTION_CAPACITY)
-					.build(&uri)
-					.await
-			})
-			.await??;
+		let mut endpoints = endpoints.write().expect("endpoints lock is never poisoned; qed");
+		endpoints.clients = Arc::new(clients.into_iter().map(|(_, client)| client).collect());
+		endpoints.checked_at = Instant::now();
+	}
 
-		Ok((Arc::new(tokio), Arc::new(client)))
+	/// Returns clients of all connected endpoints, ordered by their health.
+	///
+	/// If endpoints health has not been checked for `ENDPOINTS_HEALTH_CHECK_INTERVAL`, the check
+	/// is started in background.
+	fn clients(&self) -> Arc<Vec<Arc<RpcClient>>> {
+		let mut endpoints = self.endpoints.write().expect("endpoints lock is never poisoned; qed");
+		if endpoints.clients.len() > 1 &&
+			endpoints.checked_at.elapsed() >= ENDPOINTS_HEALTH_CHECK_INTERVAL
+		{
+			// update the time right now, so that concurrent requests don't start another check
+			endpoints.checked_at = Instant::now();
+			self.tokio.spawn(Self::rescore_endpoints(self.endpoints.clone()));
+		}
+		endpoints.clients.clone()
+	}
+
+	/// Returns client of the active (healthiest) endpoint.
+	fn active_client(&self) -> Arc<RpcClient> {
+		self.clients()[0].clone()
 	}
 }
 
@@ -249,8 +444,7 @@ impl<C: Chain> Client<C> {
 	pub async fn ensure_synced(&self) -> Result<()> {
 		self.jsonrpsee_execute(|client| async move {
 			let health = SubstrateSystemClient::<C>::health(&*client).await?;
-			let is_synced = !health.is_syncing && (!health.should_have_peers || health.peers > 0);
-			if is_synced {
+			if is_synced(&health) {
 				Ok(())
 			} else {
 				Err(Error::ClientNotSynced(health))
@@ -265,9 +459,36 @@ impl<C: Chain> Client<C> {
 	}
 
 	/// Return hash of the best finalized block.
+	///
+	/// If quorum is configured, this is the hash of the best block that is finalized by at least
+	/// the required number of endpoints.
 	pub async fn best_finalized_header_hash(&self) -> Result<C::Hash> {
-		self.jsonrpsee_execute(|client| async move {
-			Ok(SubstrateChainClient::<C>::finalized_head(&*client).await?)
+		if self.params.quorum < 2 {
+			return self
+				.jsonrpsee_execute(|client| async move {
+					Ok(SubstrateChainClient::<C>::finalized_head(&*client).await?)
+				})
+				.await
+		}
+
+		let quorum = self.params.quorum;
+		let clients = self.clients();
+		let number = self
+			.tokio
+			.spawn(async move {
+				let responses =
+					futures::future::join_all(clients.iter().cloned().map(|client| async move {
+						let hash = SubstrateChainClient::<C>::finalized_head(&*client).await?;
+						let header =
+							SubstrateChainClient::<C>::header(&*client, Some(hash)).await?;
+						Ok::<_, Error>(*header.number())
+					}))
+					.await;
+				select_quorum_block_number(responses, quorum)
+			})
+			.await??;
+		self.jsonrpsee_execute_with_quorum(move |client| async move {
+			Ok(SubstrateChainClient::<C>::block_hash(&*client, Some(number)).await?)
 		})
 		.await
 	}
@@ -278,8 +499,14 @@ impl<C: Chain> Client<C> {
 	}
 
 	/// Return header of the best finalized block.
+	///
+	/// If quorum is configured, the header must be returned by the required number of endpoints.
 	pub async fn best_finalized_header(&self) -> Result<C::Header> {
-		self.header_by_hash(self.best_finalized_header_hash().await?).await
+		let hash = self.best_finalized_header_hash().await?;
+		self.jsonrpsee_execute_with_quorum(move |client| async move {
+			Ok(SubstrateChainClient::<C>::header(&*client, Some(hash)).await?)
+		})
+		.await
 	}
 
 	/// Returns the best Substrate header.
@@ -388,15 +615,26 @@ impl<C: Chain> Client<C> {
 	}
 
 	/// Read raw value from runtime storage.
+	///
+	/// If quorum is configured and the `block_hash` is given, the same value must be returned by
+	/// required number of endpoints. The best block may legitimately be different at different
+	/// nodes, so reads at the best block are never checked.
 	pub async fn raw_storage_value(
 		&self,
 		storage_key: StorageKey,
 		block_hash: Option<C::Hash>,
 	) -> Result<Option<StorageData>> {
-		self.jsonrpsee_execute(move |client| async move {
-			Ok(SubstrateStateClient::<C>::storage(&*client, storage_key, block_hash).await?)
-		})
-		.await
+		let read_storage = move |client: Arc<RpcClient>| {
+			let storage_key = storage_key.clone();
+			async move {
+				Ok(SubstrateStateClient::<C>::storage(&*client, storage_key, block_hash).await?)
+			}
+		};
+
+		match block_hash {
+			Some(_) => self.jsonrpsee_execute_with_quorum(read_storage).await,
+			None => self.jsonrpsee_execute(read_storage).await,
+		}
 	}
 
 	/// Return native tokens balance of the account.
@@ -404,17 +642,21 @@ impl<C: Chain> Client<C> {
 	where
 		C: ChainWithBalances,
 	{
-		self.jsonrpsee_execute(move |client| async move {
-			let storage_key = C::account_info_storage_key(&account);
-			let encoded_account_data =
-				SubstrateStateClient::<C>::storage(&*client, storage_key, None)
-					.await?
-					.ok_or(Error::AccountDoesNotExist)?;
-			let decoded_account_data = AccountInfo::<C::Index, AccountData<C::Balance>>::decode(
-				&mut &encoded_account_data.0[..],
-			)
-			.map_err(Error::ResponseParseFailed)?;
-			Ok(decoded_account_data.data.free)
+		let storage_key = C::account_info_storage_key(&account);
+		self.jsonrpsee_execute(move |client| {
+			let storage_key = storage_key.clone();
+			async move {
+				let encoded_account_data =
+					SubstrateStateClient::<C>::storage(&*client, storage_key, None)
+						.await?
+						.ok_or(Error::AccountDoesNotExist)?;
+				let decoded_account_data =
+					AccountInfo::<C::Index, AccountData<C::Balance>>::decode(
+						&mut &encoded_account_data.0[..],
+					)
+					.map_err(Error::ResponseParseFailed)?;
+				Ok(decoded_account_data.data.free)
+			}
 		})
 		.await
 	}
@@ -423,8 +665,11 @@ impl<C: Chain> Client<C> {
 	///
 	/// Note: It's the caller's responsibility to make sure `account` is a valid SS58 address.
 	pub async fn next_account_index(&self, account: C::AccountId) -> Result<C::Index> {
-		self.jsonrpsee_execute(move |client| async move {
-			Ok(SubstrateFrameSystemClient::<C>::account_next_index(&*client, account).await?)
+		self.jsonrpsee_execute(move |client| {
+			let account = account.clone();
+			async move {
+				Ok(SubstrateFrameSystemClient::<C>::account_next_index(&*client, account).await?)
+			}
 		})
 		.await
 	}
@@ -433,7 +678,7 @@ impl<C: Chain> Client<C> {
 	///
 	/// Note: The given transaction needs to be SCALE encoded beforehand.
 	pub async fn submit_unsigned_extrinsic(&self, transaction: Bytes) -> Result<C::Hash> {
-		self.jsonrpsee_execute(move |client| async move {
+		self.jsonrpsee_execute_at_active_endpoint(move |client| async move {
 			let tx_hash = SubstrateAuthorClient::<C>::submit_extrinsic(&*client, transaction)
 				.await
 				.map_err(|e| {
@@ -446,7 +691,7 @@ impl<C: Chain> Client<C> {
 		.await
 	}
 
-	async fn build_sign_params(&self, signer: AccountKeyPairOf<C>) -> Result<SignParam<C>>
+	async fn build_sign_params(&self, signer: SignerOf<C>) -> Result<SignParam<C>>
 	where
 		C: ChainWithTransactions,
 	{
@@ -459,16 +704,44 @@ impl<C: Chain> Client<C> {
 		})
 	}
 
+	/// Create transaction for given runtime call, signed by given account.
+	///
+	/// Signer backend may block the thread (e.g. while waiting for the remote signing service
+	/// response), so the transaction is signed in the blocking task of the client runtime.
+	pub async fn sign_transaction(
+		&self,
+		signing_data: SignParam<C>,
+		unsigned: UnsignedTransaction<C>,
+	) -> Result<C::SignedTransaction>
+	where
+		C: ChainWithTransactions,
+	{
+		self.tokio
+			.spawn_blocking(move || C::sign_transaction(signing_data, unsigned))
+			.await?
+	}
+
+	/// Returns lock that guards nonce selection of given signer account.
+	async fn submit_signed_extrinsic_lock(&self, account: &C::AccountId) -> Arc<Mutex<()>> {
+		self.submit_signed_extrinsic_locks
+			.lock()
+			.await
+			.entry(account.clone())
+			.or_insert_with(|| Arc::new(Mutex::new(())))
+			.clone()
+	}
+
 	/// Submit an extrinsic signed by given account.
 	///
-	/// All calls of this method are synchronized, so there can't be more than one active
-	/// `submit_signed_extrinsic()` call. This guarantees that no nonces collision may happen
-	/// if all client instances are clones of the same initial `Client`.
+	/// All calls of this method, made for the same signer account, are synchronized, so there
+	/// can't be more than one active `submit_signed_extrinsic()` call for that account. This
+	/// guarantees that no nonces collision may happen if all client instances are clones of the
+	/// same initial `Client`. Calls for different accounts are running in parallel.
 	///
 	/// Note: The given transaction needs to be SCALE encoded beforehand.
 	pub async fn submit_signed_extrinsic(
 		&self,
-		signer: &AccountKeyPairOf<C>,
+		signer: &SignerOf<C>,
 		prepare_extrinsic: impl FnOnce(HeaderIdOf<C>, C::Index) -> Result<UnsignedTransaction<C>>
 			+ Send
 			+ 'static,
@@ -477,8 +750,10 @@ impl<C: Chain> Client<C> {
 		C: ChainWithTransactions,
 		C::AccountId: From<<C::AccountKeyPair as Pair>::Public>,
 	{
-		let _guard = self.submit_signed_extrinsic_lock.lock().await;
-		let transaction_nonce = self.next_account_index(signer.public().into()).await?;
+		let signer_account: C::AccountId = signer.public().into();
+		let account_lock = self.submit_signed_extrinsic_lock(&signer_account).await;
+		let _guard = account_lock.lock().await;
+		let transaction_nonce = self.next_account_index(signer_account).await?;
 		let best_header = self.best_header().await?;
 		let signing_data = self.build_sign_params(signer.clone()).await?;
 
@@ -489,9 +764,9 @@ impl<C: Chain> Client<C> {
 		// will be dropped from the pool.
 		let best_header_id = best_header.parent_id().unwrap_or_else(|| best_header.id());
 
-		self.jsonrpsee_execute(move |client| async move {
-			let extrinsic = prepare_extrinsic(best_header_id, transaction_nonce)?;
-			let signed_extrinsic = C::sign_transaction(signing_data, extrinsic)?.encode();
+		let extrinsic = prepare_extrinsic(best_header_id, transaction_nonce)?;
+		let signed_extrinsic = self.sign_transaction(signing_data, extrinsic).await?.encode();
+		self.jsonrpsee_execute_at_active_endpoint(move |client| async move {
 			let tx_hash =
 				SubstrateAuthorClient::<C>::submit_extrinsic(&*client, Bytes(signed_extrinsic))
 					.await
@@ -507,9 +782,13 @@ impl<C: Chain> Client<C> {
 
 	/// Does exactly the same as `submit_signed_extrinsic`, but keeps watching for extrinsic status
 	/// after submission.
+	///
+	/// If `tip_escalation` is specified, the returned tracker will resubmit the same transaction
+	/// with higher tip when it stays in the pool for too long.
 	pub async fn submit_and_watch_signed_extrinsic(
 		&self,
-		signer: &AccountKeyPairOf<C>,
+		signer: &SignerOf<C>,
+		tip_escalation: Option<TipEscalation>,
 		prepare_extrinsic: impl FnOnce(HeaderIdOf<C>, C::Index) -> Result<UnsignedTransaction<C>>
 			+ Send
 			+ 'static,
@@ -518,22 +797,64 @@ impl<C: Chain> Client<C> {
 		C: ChainWithTransactions,
 		C::AccountId: From<<C::AccountKeyPair as Pair>::Public>,
 	{
-		let self_clone = self.clone();
 		let signing_data = self.build_sign_params(signer.clone()).await?;
-		let _guard = self.submit_signed_extrinsic_lock.lock().await;
-		let transaction_nonce = self.next_account_index(signer.public().into()).await?;
+		let signer_account: C::AccountId = signer.public().into();
+		let account_lock = self.submit_signed_extrinsic_lock(&signer_account).await;
+		let _guard = account_lock.lock().await;
+		let transaction_nonce = self.next_account_index(signer_account).await?;
 		let best_header = self.best_header().await?;
 		let best_header_id = best_header.id();
+
+		let extrinsic = prepare_extrinsic(best_header_id, transaction_nonce)?;
+		let stall_timeout = transaction_stall_timeout(
+			extrinsic.era.mortality_period(),
+			C::AVERAGE_BLOCK_INTERVAL,
+			STALL_TIMEOUT,
+		);
+		let signed_extrinsic =
+			self.sign_transaction(signing_data.clone(), extrinsic.clone()).await?;
+		let (tx_hash, subscription) = self.submit_and_watch_extrinsic(signed_extrinsic).await?;
+		let tracker = TransactionTracker::new(self.clone(), stall_timeout, tx_hash, subscription);
+		Ok(match tip_escalation {
+			Some(tip_escalation) => tracker.with_tip_escalation(
+				C::AVERAGE_BLOCK_INTERVAL * tip_escalation.pending_blocks,
+				TipEscalator {
+					client: self.clone(),
+					signing_data,
+					transaction: extrinsic,
+					tip_step: tip_escalation.tip_step.saturated_into(),
+					tip_limit: tip_escalation.tip_limit.saturated_into(),
+				},
+			),
+			None => tracker,
+		})
+	}
+
+	/// Submit signed extrinsic and start watching its status.
+	async fn submit_and_watch_extrinsic(
+		&self,
+		signed_extrinsic: impl Encode + Send + 'static,
+	) -> Result<(HashOf<C>, Subscription<TransactionStatusOf<C>>)> {
 		let (sender, receiver) = futures::channel::mpsc::channel(MAX_SUBSCRIPTION_CAPACITY);
-		let (tracker, subscription) = self
-			.jsonrpsee_execute(move |client| async move {
-				let extrinsic = prepare_extrinsic(best_header_id, transaction_nonce)?;
-				let stall_timeout = transaction_stall_timeout(
-					extrinsic.era.mortality_period(),
-					C::AVERAGE_BLOCK_INTERVAL,
-					STALL_TIMEOUT,
-				);
-				let signed_extrinsic = C::sign_transaction(signing_data, extrinsic)?.encode();
+		if !self.active_client().supports_subscriptions() {
+			let tx_hash = self
+				.jsonrpsee_execute_at_active_endpoint(move |client| async move {
+					Ok(SubstrateAuthorClient::<C>::submit_extrinsic(
+						&*client,
+						Bytes(signed_extrinsic.encode()),
+					)
+					.await?)
+				})
+				.await?;
+			log::trace!(target: "bridge", "Sent transaction to {} node: {:?}", C::NAME, tx_hash);
+			self.tokio
+				.spawn(Self::poll_transaction_status(self.active_client(), tx_hash, sender));
+			return Ok((tx_hash, Subscription(Mutex::new(receiver))))
+		}
+
+		let (tx_hash, subscription) = self
+			.jsonrpsee_execute_at_active_endpoint(move |client| async move {
+				let signed_extrinsic = signed_extrinsic.encode();
 				let tx_hash = C::Hasher::hash(&signed_extrinsic);
 				let subscription = SubstrateAuthorClient::<C>::submit_and_watch_extrinsic(
 					&*client,
@@ -545,13 +866,7 @@ impl<C: Chain> Client<C> {
 					e
 				})?;
 				log::trace!(target: "bridge", "Sent transaction to {} node: {:?}", C::NAME, tx_hash);
-				let tracker = TransactionTracker::new(
-					self_clone,
-					stall_timeout,
-					tx_hash,
-					Subscription(Mutex::new(receiver)),
-				);
-				Ok((tracker, subscription))
+				Ok((tx_hash, subscription))
 			})
 			.await?;
 		self.tokio.spawn(Subscription::background_worker(
@@ -560,7 +875,7 @@ impl<C: Chain> Client<C> {
 			subscription,
 			sender,
 		));
-		Ok(tracker)
+		Ok((tx_hash, Subscription(Mutex::new(receiver))))
 	}
 
 	/// Returns pending extrinsics from transaction pool.
@@ -577,16 +892,18 @@ impl<C: Chain> Client<C> {
 		at_block: C::Hash,
 		transaction: SignedTransaction,
 	) -> Result<TransactionValidity> {
-		self.jsonrpsee_execute(move |client| async move {
-			let call = SUB_API_TXPOOL_VALIDATE_TRANSACTION.to_string();
-			let data = Bytes((TransactionSource::External, transaction, at_block).encode());
-
-			let encoded_response =
-				SubstrateStateClient::<C>::call(&*client, call, data, Some(at_block)).await?;
-			let validity = TransactionValidity::decode(&mut &encoded_response.0[..])
-				.map_err(Error::ResponseParseFailed)?;
-
-			Ok(validity)
+		let data = Bytes((TransactionSource::External, transaction, at_block).encode());
+		self.jsonrpsee_execute(move |client| {
+			let data = data.clone();
+			async move {
+				let call = SUB_API_TXPOOL_VALIDATE_TRANSACTION.to_string();
+				let encoded_response =
+					SubstrateStateClient::<C>::call(&*client, call, data, Some(at_block)).await?;
+				let validity = TransactionValidity::decode(&mut &encoded_response.0[..])
+					.map_err(Error::ResponseParseFailed)?;
+
+				Ok(validity)
+			}
 		})
 		.await
 	}
@@ -596,28 +913,34 @@ impl<C: Chain> Client<C> {
 		&self,
 		transaction: Bytes,
 	) -> Result<InclusionFee<C::Balance>> {
-		self.jsonrpsee_execute(move |client| async move {
-			let fee_details =
-				SubstrateTransactionPaymentClient::<C>::fee_details(&*client, transaction, None)
-					.await?;
-			let inclusion_fee = fee_details
-				.inclusion_fee
-				.map(|inclusion_fee| InclusionFee {
-					base_fee: C::Balance::try_from(inclusion_fee.base_fee.into_u256())
-						.unwrap_or_else(|_| C::Balance::max_value()),
-					len_fee: C::Balance::try_from(inclusion_fee.len_fee.into_u256())
+		self.jsonrpsee_execute(move |client| {
+			let transaction = transaction.clone();
+			async move {
+				let fee_details = SubstrateTransactionPaymentClient::<C>::fee_details(
+					&*client,
+					transaction,
+					None,
+				)
+				.await?;
+				let inclusion_fee = fee_details
+					.inclusion_fee
+					.map(|inclusion_fee| InclusionFee {
+						base_fee: C::Balance::try_from(inclusion_fee.base_fee.into_u256())
+							.unwrap_or_else(|_| C::Balance::max_value()),
+						len_fee: C::Balance::try_from(inclusion_fee.len_fee.into_u256())
+							.unwrap_or_else(|_| C::Balance::max_value()),
+						adjusted_weight_fee: C::Balance::try_from(
+							inclusion_fee.adjusted_weight_fee.into_u256(),
+						)
 						.unwrap_or_else(|_| C::Balance::max_value()),
-					adjusted_weight_fee: C::Balance::try_from(
-						inclusion_fee.adjusted_weight_fee.into_u256(),
-					)
-					.unwrap_or_else(|_| C::Balance::max_value()),
-				})
-				.unwrap_or_else(|| InclusionFee {
-					base_fee: Zero::zero(),
-					len_fee: Zero::zero(),
-					adjusted_weight_fee: Zero::zero(),
-				});
-			Ok(inclusion_fee)
+					})
+					.unwrap_or_else(|| InclusionFee {
+						base_fee: Zero::zero(),
+						len_fee: Zero::zero(),
+						adjusted_weight_fee: Zero::zero(),
+					});
+				Ok(inclusion_fee)
+			}
 		})
 		.await
 	}
@@ -659,10 +982,13 @@ impl<C: Chain> Client<C> {
 		data: Bytes,
 		at_block: Option<C::Hash>,
 	) -> Result<Bytes> {
-		self.jsonrpsee_execute(move |client| async move {
-			SubstrateStateClient::<C>::call(&*client, method, data, at_block)
-				.await
-				.map_err(Into::into)
+		self.jsonrpsee_execute(move |client| {
+			let (method, data) = (method.clone(), data.clone());
+			async move {
+				SubstrateStateClient::<C>::call(&*client, method, data, at_block)
+					.await
+					.map_err(Into::into)
+			}
 		})
 		.await
 	}
@@ -673,13 +999,16 @@ impl<C: Chain> Client<C> {
 		keys: Vec<StorageKey>,
 		at_block: C::Hash,
 	) -> Result<StorageProof> {
-		self.jsonrpsee_execute(move |client| async move {
-			SubstrateStateClient::<C>::prove_storage(&*client, keys, Some(at_block))
-				.await
-				.map(|proof| {
-					StorageProof::new(proof.proof.into_iter().map(|b| b.0).collect::<Vec<_>>())
-				})
-				.map_err(Into::into)
+		self.jsonrpsee_execute(move |client| {
+			let keys = keys.clone();
+			async move {
+				SubstrateStateClient::<C>::prove_storage(&*client, keys, Some(at_block))
+					.await
+					.map(|proof| {
+						StorageProof::new(proof.proof.into_iter().map(|b| b.0).collect::<Vec<_>>())
+					})
+					.map_err(Into::into)
+			}
 		})
 		.await
 	}
@@ -697,32 +1026,215 @@ impl<C: Chain> Client<C> {
 	pub async fn subscribe_finality_justifications<FC: SubstrateFinalityClient<C>>(
 		&self,
 	) -> Result<Subscription<Bytes>> {
-		let subscription = self
-			.jsonrpsee_execute(move |client| async move {
+		let (sender, receiver) = futures::channel::mpsc::channel(MAX_SUBSCRIPTION_CAPACITY);
+		let subscription = if self.active_client().supports_subscriptions() {
+			self.jsonrpsee_execute_at_active_endpoint(move |client| async move {
 				Ok(FC::subscribe_justifications(&client).await?)
 			})
-			.await?;
-		let (sender, receiver) = futures::channel::mpsc::channel(MAX_SUBSCRIPTION_CAPACITY);
-		self.tokio.spawn(Subscription::background_worker(
-			C::NAME.into(),
-			"justification".into(),
-			subscription,
-			sender,
-		));
+			.await
+			.map(Some)
+		} else {
+			Ok(None)
+		};
+		match subscription {
+			Ok(Some(subscription)) => {
+				self.tokio.spawn(Subscription::background_worker(
+					C::NAME.into(),
+					"justification".into(),
+					subscription,
+					sender,
+				));
+			},
+			// subscriptions are not supported by the transport or justifications subscription
+			// is not supported by the server (e.g. by the embedded light client)
+			Ok(None) | Err(Error::RpcError(RpcError::Call(_))) => {
+				self.tokio.spawn(Self::poll_finalized_blocks(
+					self.active_client(),
+					"justification",
+					sender,
+					|_, block| {
+						ControlFlow::Continue(
+							block.justification(FC::ENGINE_ID).cloned().map(Bytes),
+						)
+					},
+				));
+			},
+			Err(e) => return Err(e),
+		}
 		Ok(Subscription(Mutex::new(receiver)))
 	}
 
 	/// Execute jsonrpsee future in tokio context.
+	///
+	/// The request is sent to the active endpoint first. If it fails with connection error, it is
+	/// retried at the next connected endpoint, in the order of their health.
 	async fn jsonrpsee_execute<MF, F, T>(&self, make_jsonrpsee_future: MF) -> Result<T>
+	where
+		MF: Fn(Arc<RpcClient>) -> F + Send + Sync + 'static,
+		F: Future<Output = Result<T>> + Send,
+		T: Send + 'static,
+	{
+		let clients = self.clients();
+		let endpoints = self.endpoints.clone();
+		self.tokio
+			.spawn(async move {
+				let (last_client, clients) =
+					clients.split_last().expect("we never build client without endpoints; qed");
+				for (index, client) in clients.iter().enumerate() {
+					match make_jsonrpsee_future(client.clone()).await {
+						Err(error) if error.is_connection_error() => log::warn!(
+							target: "bridge",
+							"RPC request to {} node has failed: {:?}. Retrying at the next endpoint",
+							C::NAME,
+							error,
+						),
+						result => {
+							if index != 0 {
+								// the active endpoint has failed, so let's select the new one
+								tokio::spawn(Self::rescore_endpoints(endpoints));
+							}
+							return result
+						},
+					}
+				}
+				if !clients.is_empty() {
+					tokio::spawn(Self::rescore_endpoints(endpoints));
+				}
+				make_jsonrpsee_future(last_client.clone()).await
+			})
+			.await?
+	}
+
+	/// Execute jsonrpsee future at the active endpoint in tokio context.
+	///
+	/// Used for requests that must not be repeated at other endpoints, e.g. transaction
+	/// submissions and subscriptions.
+	async fn jsonrpsee_execute_at_active_endpoint<MF, F, T>(
+		&self,
+		make_jsonrpsee_future: MF,
+	) -> Result<T>
 	where
 		MF: FnOnce(Arc<RpcClient>) -> F + Send + 'static,
 		F: Future<Output = Result<T>> + Send,
 		T: Send + 'static,
 	{
-		let client = self.client.clone();
+		let client = self.active_client();
 		self.tokio.spawn(async move { make_jsonrpsee_future(client).await }).await?
 	}
 
+	/// Execute jsonrpsee future at all connected endpoints in tokio context and return response
+	/// that has been returned by at least `quorum` endpoints.
+	///
+	/// If quorum is not configured, this is the same as `jsonrpsee_execute`.
+	async fn jsonrpsee_execute_with_quorum<MF, F, T>(&self, make_jsonrpsee_future: MF) -> Result<T>
+	where
+		MF: Fn(Arc<RpcClient>) -> F + Send + Sync + 'static,
+		F: Future<Output = Result<T>> + Send,
+		T: PartialEq + Send + 'static,
+	{
+		let quorum = self.params.quorum;
+		if quorum < 2 {
+			return self.jsonrpsee_execute(make_jsonrpsee_future).await
+		}
+
+		let clients = self.clients();
+		self.tokio
+			.spawn(async move {
+				let responses =
+					futures::future::join_all(clients.iter().cloned().map(&make_jsonrpsee_future))
+						.await;
+				select_quorum_response(responses, quorum)
+			})
+			.await?
+	}
+
+	/// Poll finalized blocks until the transaction is found in one of them. Used instead of
+	/// transaction status subscription when subscriptions are not supported by the transport.
+	///
+	/// Only `Ready` and `Finalized` statuses are reported. If the transaction never gets
+	/// finalized, it is detected by the transaction tracker stall timeout.
+	async fn poll_transaction_status(
+		client: Arc<RpcClient>,
+		tx_hash: C::Hash,
+		mut sender: futures::channel::mpsc::Sender<Option<TransactionStatusOf<C>>>,
+	) {
+		if sender.send(Some(TransactionStatus::Ready)).await.is_err() {
+			return
+		}
+
+		Self::poll_finalized_blocks(client, "extrinsic", sender, |block_hash, block| {
+			match block
+				.extrinsics()
+				.iter()
+				.position(|extrinsic| C::Hasher::hash(extrinsic) == tx_hash)
+			{
+				Some(index) =>
+					ControlFlow::Break(TransactionStatus::Finalized((block_hash, index))),
+				None => ControlFlow::Continue(None),
+			}
+		})
+		.await
+	}
+
+	/// Poll new finalized blocks and send items, produced by `on_block` to the `sender`. Used
+	/// instead of subscriptions when subscriptions are not supported by the transport.
+	///
+	/// Polling starts at the best finalized block and stops when `on_block` breaks, when the
+	/// receiver is dropped or when the RPC error happens.
+	async fn poll_finalized_blocks<T>(
+		client: Arc<RpcClient>,
+		item_type: &'static str,
+		mut sender: futures::channel::mpsc::Sender<Option<T>>,
+		mut on_block: impl FnMut(C::Hash, C::SignedBlock) -> ControlFlow<T, Option<T>>,
+	) {
+		let mut next_number: Option<C::BlockNumber> = None;
+		loop {
+			let poll_result: Result<bool> = async {
+				let finalized_hash = SubstrateChainClient::<C>::finalized_head(&*client).await?;
+				let finalized_header =
+					SubstrateChainClient::<C>::header(&*client, Some(finalized_hash)).await?;
+				let finalized_number = *finalized_header.number();
+				while next_number.unwrap_or(finalized_number) <= finalized_number {
+					let number = next_number.unwrap_or(finalized_number);
+					let hash =
+						SubstrateChainClient::<C>::block_hash(&*client, Some(number)).await?;
+					let block = SubstrateChainClient::<C>::block(&*client, Some(hash)).await?;
+					next_number = Some(number + One::one());
+
+					match on_block(hash, block) {
+						ControlFlow::Continue(None) => (),
+						ControlFlow::Continue(Some(item)) =>
+							if sender.send(Some(item)).await.is_err() {
+								return Ok(false)
+							},
+						ControlFlow::Break(item) => {
+							let _ = sender.send(Some(item)).await;
+							return Ok(false)
+						},
+					}
+				}
+				Ok(true)
+			}
+			.await;
+
+			match poll_result {
+				Ok(true) => async_std::task::sleep(C::AVERAGE_BLOCK_INTERVAL).await,
+				Ok(false) => break,
+				Err(e) => {
+					log::trace!(
+						target: "bridge",
+						"{} {} polling has failed with '{:?}'. Stream needs to be restarted.",
+						C::NAME,
+						item_type,
+						e,
+					);
+					let _ = sender.send(None).await;
+					break
+				},
+			}
+		}
+	}
+
 	/// Returns `true` if version guard can be started.
 	///
 	/// There's no reason to run version guard when version mode is set to `Auto`. It can
@@ -733,6 +1245,52 @@ impl<C: Chain> Client<C> {
 	}
 }
 
+/// Transaction resubmitter that re-signs the same transaction with higher tip.
+struct TipEscalator<C: ChainWithTransactions> {
+	/// Client to submit transaction to.
+	client: Client<C>,
+	/// Transaction signing parameters.
+	signing_data: SignParam<C>,
+	/// Last submitted transaction.
+	transaction: UnsignedTransaction<C>,
+	/// Tip increase step.
+	tip_step: C::Balance,
+	/// Tip limit.
+	tip_limit: C::Balance,
+}
+
+#[async_trait]
+impl<C: ChainWithTransactions> TransactionResubmitter<C> for TipEscalator<C> {
+	async fn resubmit(
+		&mut self,
+	) -> Option<Result<(HashOf<C>, Subscription<TransactionStatusOf<C>>)>> {
+		let next_tip = self.transaction.tip.saturating_add(self.tip_step);
+		if next_tip <= self.transaction.tip || next_tip > self.tip_limit {
+			return None
+		}
+
+		log::debug!(
+			target: "bridge",
+			"Increasing tip of {} transaction with nonce {:?}: {:?} -> {:?}",
+			C::NAME,
+			self.transaction.nonce,
+			self.transaction.tip,
+			next_tip,
+		);
+
+		self.transaction.tip = next_tip;
+		let signed_extrinsic = match self
+			.client
+			.sign_transaction(self.signing_data.clone(), self.transaction.clone())
+			.await
+		{
+			Ok(signed_extrinsic) => signed_extrinsic,
+			Err(e) => return Some(Err(e)),
+		};
+		Some(self.client.submit_and_watch_extrinsic(signed_extrinsic).await)
+	}
+}
+
 impl<T: DeserializeOwned> Subscription<T> {
 	/// Consumes subscription and returns future statuses stream.
 	pub fn into_stream(self) -> impl futures::Stream<Item = T> {
@@ -787,3 +1345,64 @@ impl<T: DeserializeOwned> Subscription<T> {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn health(is_syncing: bool, peers: usize) -> Health {
+		Health { peers, is_syncing, should_have_peers: true }
+	}
+
+	#[test]
+	fn synced_endpoint_is_preferred_over_endpoint_with_better_block() {
+		let synced = EndpointHealth::new(&health(false, 1), 100u32);
+		let syncing = EndpointHealth::new(&health(true, 1), 200u32);
+		let isolated = EndpointHealth::new(&health(false, 0), 200u32);
+		let lagging = EndpointHealth::new(&health(false, 1), 90u32);
+
+		assert!(synced > syncing);
+		assert!(synced > isolated);
+		assert!(synced > lagging);
+	}
+
+	#[test]
+	fn select_quorum_block_number_works() {
+		assert_eq!(select_quorum_block_number(vec![Ok(10), Ok(12), Ok(11)], 1).unwrap(), 12);
+		assert_eq!(select_quorum_block_number(vec![Ok(10), Ok(12), Ok(11)], 2).unwrap(), 11);
+		assert_eq!(select_quorum_block_number(vec![Ok(10), Ok(12), Ok(11)], 3).unwrap(), 10);
+		assert_eq!(
+			select_quorum_block_number(vec![Ok(10), Err(Error::AccountDoesNotExist), Ok(12)], 2)
+				.unwrap(),
+			10,
+		);
+		assert!(matches!(
+			select_quorum_block_number(vec![Ok(10), Err(Error::AccountDoesNotExist)], 2),
+			Err(Error::QuorumNotReached(2, 1)),
+		));
+		assert!(matches!(
+			select_quorum_block_number(
+				vec![Ok(10), Err(Error::ClientNotSynced(health(true, 0)))],
+				2
+			),
+			Err(Error::ClientNotSynced(_)),
+		));
+	}
+
+	#[test]
+	fn select_quorum_response_works() {
+		assert_eq!(select_quorum_response(vec![Ok(1), Ok(2), Ok(1)], 2).unwrap(), 1);
+		assert_eq!(
+			select_quorum_response(vec![Ok(1), Err(Error::AccountDoesNotExist), Ok(1)], 2).unwrap(),
+			1,
+		);
+		assert!(matches!(
+			select_quorum_response(vec![Ok(1), Ok(2), Ok(3)], 2),
+			Err(Error::QuorumNotReached(2, 1)),
+		));
+		assert!(matches!(
+			select_quorum_response(vec![Ok(1), Err(Error::ClientNotSynced(health(true, 0)))], 2),
+			Err(Error::ClientNotSynced(_)),
+		));
+	}
+}
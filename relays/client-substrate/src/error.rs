@@ -55,6 +55,9 @@ pub enum Error {
 	/// The client we're connected to is not synced, so we can't rely on its state.
 	#[error("Substrate client is not synced {0}.")]
 	ClientNotSynced(Health),
+	/// Not enough RPC endpoints have returned the same response to the critical read.
+	#[error("Only {1} of required {0} RPC endpoints have agreed on the response.")]
+	QuorumNotReached(usize, usize),
 	/// The bridge pallet is halted and all transactions will be rejected.
 	#[error("Bridge pallet is halted.")]
 	BridgePalletIsHalted,
@@ -70,6 +73,9 @@ pub enum Error {
 	/// The Substrate transaction is invalid.
 	#[error("Substrate transaction is invalid: {0:?}")]
 	TransactionInvalid(#[from] TransactionValidityError),
+	/// Transaction signer has failed to sign the payload.
+	#[error("Failed to sign transaction: {0}")]
+	Signer(String),
 	/// Custom logic error.
 	#[error("{0}")]
 	Custom(String),
@@ -90,7 +96,8 @@ impl MaybeConnectionError for Error {
 				// we're getting this error
 				| Error::RpcError(RpcError::Internal(_))
 				| Error::RpcError(RpcError::RestartNeeded(_))
-				| Error::ClientNotSynced(_),
+				| Error::ClientNotSynced(_)
+				| Error::QuorumNotReached(_, _),
 		)
 	}
 }
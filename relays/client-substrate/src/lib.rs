@@ -21,9 +21,14 @@
 mod chain;
 mod client;
 mod error;
+#[cfg(feature = "light-client")]
+mod light_client;
 mod rpc;
+mod signer;
+mod signer_pool;
 mod sync_header;
 mod transaction_tracker;
+mod transport;
 
 pub mod calls;
 pub mod guard;
@@ -34,7 +39,7 @@ use std::time::Duration;
 
 pub use crate::{
 	chain::{
-		AccountKeyPairOf, BlockWithJustification, CallOf, Chain, ChainWithBalances,
+		AccountKeyPairOf, BlockWithJustification, CallOf, Chain, ChainWithBalances, ChainWithBeefy,
 		ChainWithGrandpa, ChainWithMessages, ChainWithTransactions, ChainWithUtilityPallet,
 		FullRuntimeUtilityPallet, Parachain, RelayChain, SignParam, TransactionStatusOf,
 		UnsignedTransaction, UtilityPallet,
@@ -45,8 +50,14 @@ pub use crate::{
 	},
 	error::{Error, Result},
 	rpc::{SubstrateBeefyFinalityClient, SubstrateFinalityClient, SubstrateGrandpaFinalityClient},
+	signer::{
+		DecodableKeyPair, KeystoreSigner, LocalSigner, RemoteSigner, Signer, SignerBackend,
+		SignerOf,
+	},
+	signer_pool::{PooledSigner, SignerPool, SignerPoolOf, SignerSelection},
 	sync_header::SyncHeader,
-	transaction_tracker::TransactionTracker,
+	transaction_tracker::{TipEscalation, TransactionResubmitter, TransactionTracker},
+	transport::RpcClient,
 };
 pub use bp_runtime::{
 	AccountIdOf, AccountPublicOf, BalanceOf, BlockNumberOf, Chain as ChainBase, HashOf, HeaderIdOf,
@@ -54,7 +65,7 @@ pub use bp_runtime::{
 	UnderlyingChainProvider,
 };
 
-/// Substrate-over-websocket connection params.
+/// Substrate node connection params.
 #[derive(Debug, Clone)]
 pub struct ConnectionParams {
 	/// Websocket server host name.
@@ -63,21 +74,78 @@ pub struct ConnectionParams {
 	pub port: u16,
 	/// Use secure websocket connection.
 	pub secure: bool,
+	/// Full URI of the RPC endpoint. If set, `host`, `port` and `secure` are ignored.
+	///
+	/// Supported schemes are `ws`, `wss`, `http`, `https`, `ipc` (path to the Unix socket,
+	/// e.g. `ipc:///tmp/node.ipc`) and `light` (path to the chain specification of the embedded
+	/// light client, e.g. `light:///specs/chain.json`). Parachain light client also needs the
+	/// relay chain specification: `light:///specs/para.json?relay_chain_spec=/specs/relay.json`.
+	pub uri: Option<String>,
+	/// HTTP headers that are sent to the endpoint (e.g. authorization headers of the RPC
+	/// provider).
+	pub headers: Vec<(String, String)>,
+	/// Additional endpoints of the same chain.
+	///
+	/// The client connects to all endpoints and uses the healthiest one. When the active endpoint
+	/// fails, the client switches to the next healthiest endpoint on reconnect.
+	pub fallback_endpoints: Vec<RpcEndpoint>,
+	/// Number of endpoints that must return the same response to storage reads at given block.
+	/// Values less than `2` disable the check.
+	pub quorum: usize,
 	/// Defined chain runtime version
 	pub chain_runtime_version: ChainRuntimeVersion,
 }
 
+impl ConnectionParams {
+	/// Returns all endpoints of the chain, starting with the primary endpoint.
+	pub fn endpoints(&self) -> Vec<RpcEndpoint> {
+		let primary_endpoint = match self.uri {
+			Some(ref uri) => RpcEndpoint { uri: uri.clone(), headers: self.headers.clone() },
+			None => RpcEndpoint {
+				headers: self.headers.clone(),
+				..RpcEndpoint::websocket(&self.host, self.port, self.secure)
+			},
+		};
+		std::iter::once(primary_endpoint)
+			.chain(self.fallback_endpoints.iter().cloned())
+			.collect()
+	}
+}
+
 impl Default for ConnectionParams {
 	fn default() -> Self {
 		ConnectionParams {
 			host: "localhost".into(),
 			port: 9944,
 			secure: false,
+			uri: None,
+			headers: vec![],
+			fallback_endpoints: vec![],
+			quorum: 1,
 			chain_runtime_version: ChainRuntimeVersion::Auto,
 		}
 	}
 }
 
+/// Substrate node RPC endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcEndpoint {
+	/// Endpoint URI. See `ConnectionParams::uri` for supported schemes.
+	pub uri: String,
+	/// HTTP headers that are sent to the endpoint.
+	pub headers: Vec<(String, String)>,
+}
+
+impl RpcEndpoint {
+	/// Create websocket endpoint at given host and port.
+	pub fn websocket(host: &str, port: u16, secure: bool) -> Self {
+		RpcEndpoint {
+			uri: format!("{}://{}:{}", if secure { "wss" } else { "ws" }, host, port),
+			headers: vec![],
+		}
+	}
+}
+
 /// Returns stall timeout for relay loop.
 ///
 /// Relay considers himself stalled if he has submitted transaction to the node, but it has not
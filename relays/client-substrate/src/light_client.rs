@@ -0,0 +1,214 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Embedded light client, that may be used instead of the connection to the full node.
+//!
+//! The light client is started in-process from the chain specification and serves its JSON-RPC
+//! requests, so the relay does not need to trust any third-party RPC node.
+
+use crate::{Error, Result};
+
+use async_trait::async_trait;
+use jsonrpsee::core::client::{ReceivedMessage, TransportReceiverT, TransportSenderT};
+use smoldot_light::{
+	platform::default::DefaultPlatform, AddChainConfig, AddChainConfigJsonRpc, AddChainSuccess,
+	ChainId, JsonRpcResponses,
+};
+use std::{num::NonZeroU32, sync::Arc};
+
+/// Maximal number of JSON-RPC requests that may be queued by the light client.
+const MAX_PENDING_REQUESTS: u32 = 128;
+/// Maximal number of active JSON-RPC subscriptions of the light client.
+const MAX_SUBSCRIPTIONS: u32 = 1024;
+/// Separator between the chain specification path and the relay chain specification path.
+const RELAY_CHAIN_SPEC_SEPARATOR: &str = "?relay_chain_spec=";
+
+/// Light client chain specifications, parsed from the endpoint URI path.
+#[derive(Debug, PartialEq, Eq)]
+struct ChainSpecPaths<'a> {
+	/// Path to the specification of the chain we are connecting to.
+	chain_spec: &'a str,
+	/// Path to the specification of the relay chain, if we are connecting to the parachain.
+	relay_chain_spec: Option<&'a str>,
+}
+
+impl<'a> ChainSpecPaths<'a> {
+	/// Parse specification paths from the endpoint URI path
+	/// (`<chain spec path>[?relay_chain_spec=<relay chain spec path>]`).
+	fn parse(path: &'a str) -> Self {
+		match path.split_once(RELAY_CHAIN_SPEC_SEPARATOR) {
+			Some((chain_spec, relay_chain_spec)) =>
+				ChainSpecPaths { chain_spec, relay_chain_spec: Some(relay_chain_spec) },
+			None => ChainSpecPaths { chain_spec: path, relay_chain_spec: None },
+		}
+	}
+}
+
+/// Start the embedded light client and return the transport to its JSON-RPC server.
+pub(crate) fn start(path: &str) -> Result<(LightClientSender, LightClientReceiver)> {
+	let paths = ChainSpecPaths::parse(path);
+	let mut client = smoldot_light::Client::new(DefaultPlatform::new(
+		env!("CARGO_PKG_NAME").into(),
+		env!("CARGO_PKG_VERSION").into(),
+	));
+
+	let relay_chain_id = paths
+		.relay_chain_spec
+		.map(|relay_chain_spec| {
+			let relay_chain_spec = read_chain_spec(relay_chain_spec)?;
+			client
+				.add_chain(AddChainConfig {
+					specification: &relay_chain_spec,
+					json_rpc: AddChainConfigJsonRpc::Disabled,
+					potential_relay_chains: std::iter::empty(),
+					database_content: "",
+					user_data: (),
+				})
+				.map(|success| success.chain_id)
+				.map_err(|e| Error::Custom(format!("Failed to start light client: {e}")))
+		})
+		.transpose()?;
+
+	let chain_spec = read_chain_spec(paths.chain_spec)?;
+	let AddChainSuccess { chain_id, json_rpc_responses } = client
+		.add_chain(AddChainConfig {
+			specification: &chain_spec,
+			json_rpc: AddChainConfigJsonRpc::Enabled {
+				max_pending_requests: NonZeroU32::new(MAX_PENDING_REQUESTS)
+					.expect("MAX_PENDING_REQUESTS is not zero; qed"),
+				max_subscriptions: MAX_SUBSCRIPTIONS,
+			},
+			potential_relay_chains: relay_chain_id.into_iter(),
+			database_content: "",
+			user_data: (),
+		})
+		.map_err(|e| Error::Custom(format!("Failed to start light client: {e}")))?;
+	let json_rpc_responses = json_rpc_responses.ok_or_else(|| {
+		Error::Custom("Light client has been started with JSON-RPC disabled".into())
+	})?;
+
+	log::info!(target: "bridge", "Started light client from {}", paths.chain_spec);
+
+	Ok((LightClientSender { client, chain_id }, LightClientReceiver(json_rpc_responses)))
+}
+
+/// Read chain specification from the file.
+fn read_chain_spec(path: &str) -> Result<String> {
+	std::fs::read_to_string(path)
+		.map_err(|e| Error::Custom(format!("Failed to read chain specification {path}: {e}")))
+}
+
+/// Sending half of the light client transport. It owns the light client, so the light client is
+/// stopped when the transport is closed.
+pub(crate) struct LightClientSender {
+	/// Embedded light client.
+	client: smoldot_light::Client<Arc<DefaultPlatform>>,
+	/// Identifier of the chain we are sending requests to.
+	chain_id: ChainId,
+}
+
+#[async_trait]
+impl TransportSenderT for LightClientSender {
+	type Error = std::io::Error;
+
+	async fn send(&mut self, message: String) -> std::result::Result<(), Self::Error> {
+		self.client
+			.json_rpc_request(message, self.chain_id)
+			.map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))
+	}
+
+	async fn send_ping(&mut self) -> std::result::Result<(), Self::Error> {
+		// the light client lives in our process, so there's no connection to keep alive
+		Ok(())
+	}
+}
+
+/// Receiving half of the light client transport.
+pub(crate) struct LightClientReceiver(JsonRpcResponses);
+
+#[async_trait]
+impl TransportReceiverT for LightClientReceiver {
+	type Error = std::io::Error;
+
+	async fn receive(&mut self) -> std::result::Result<ReceivedMessage, Self::Error> {
+		match self.0.next().await {
+			Some(response) => Ok(ReceivedMessage::Text(response)),
+			None => Err(std::io::ErrorKind::UnexpectedEof.into()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{transport::RpcClient, RpcEndpoint};
+	use jsonrpsee::{core::client::ClientT, rpc_params};
+
+	/// Path to the development chain specification, bundled with the crate. The chain starts
+	/// from the checkpoint (`lightSyncState`), so the light client doesn't need the runtime code.
+	const DEV_CHAIN_SPEC: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/res/dev-chain-spec.json");
+	/// Genesis hash of the development chain.
+	const DEV_GENESIS_HASH: &str =
+		"0xab45ab07ab7c605f5cee09a930fb516095be0e70455c614650ab5b13df3411a1";
+
+	#[test]
+	fn chain_spec_paths_are_parsed() {
+		assert_eq!(
+			ChainSpecPaths::parse("/specs/rialto.json"),
+			ChainSpecPaths { chain_spec: "/specs/rialto.json", relay_chain_spec: None },
+		);
+		assert_eq!(
+			ChainSpecPaths::parse(
+				"/specs/rialto-parachain.json?relay_chain_spec=/specs/rialto.json"
+			),
+			ChainSpecPaths {
+				chain_spec: "/specs/rialto-parachain.json",
+				relay_chain_spec: Some("/specs/rialto.json"),
+			},
+		);
+	}
+
+	#[test]
+	fn start_fails_if_chain_spec_is_missing() {
+		assert!(matches!(start("/non/existing/spec.json"), Err(Error::Custom(_))));
+	}
+
+	#[test]
+	fn start_fails_if_chain_spec_is_invalid() {
+		let dir = tempfile::tempdir().unwrap();
+		let chain_spec = dir.path().join("spec.json");
+		std::fs::write(&chain_spec, "{}").unwrap();
+
+		assert!(matches!(start(chain_spec.to_str().unwrap()), Err(Error::Custom(_))));
+	}
+
+	#[test]
+	fn serves_requests_from_dev_chain_spec() {
+		tokio::runtime::Runtime::new().unwrap().block_on(async {
+			let endpoint =
+				RpcEndpoint { uri: format!("light://{DEV_CHAIN_SPEC}"), headers: vec![] };
+			let client = RpcClient::connect(&endpoint).await.unwrap();
+			assert!(client.supports_subscriptions());
+
+			let chain: String = client.request("system_chain", None).await.unwrap();
+			assert_eq!(chain, "Development");
+
+			let genesis_hash: String =
+				client.request("chain_getBlockHash", rpc_params![0]).await.unwrap();
+			assert_eq!(genesis_hash, DEV_GENESIS_HASH);
+		});
+	}
+}
@@ -18,12 +18,11 @@
 
 use async_trait::async_trait;
 
-use crate::{Chain, ChainWithGrandpa, TransactionStatusOf};
+use crate::{Chain, ChainWithBeefy, ChainWithGrandpa, RpcClient, TransactionStatusOf};
 
 use jsonrpsee::{
 	core::{client::Subscription, RpcResult},
 	proc_macros::rpc,
-	ws_client::WsClient,
 };
 use pallet_transaction_payment_rpc_runtime_api::FeeDetails;
 use sc_rpc_api::{state::ReadProof, system::Health};
@@ -32,6 +31,7 @@ use sp_core::{
 	Bytes,
 };
 use sp_rpc::number::NumberOrHex;
+use sp_runtime::ConsensusEngineId;
 use sp_version::RuntimeVersion;
 
 /// RPC methods of Substrate `system` namespace, that we are using.
@@ -109,8 +109,12 @@ pub(crate) trait SubstrateState<C> {
 /// RPC methods that we are using for a certain finality gadget.
 #[async_trait]
 pub trait SubstrateFinalityClient<C: Chain> {
+	/// Identifier of the finality engine. Used to read justifications from blocks when
+	/// subscriptions are not supported by the RPC transport.
+	const ENGINE_ID: ConsensusEngineId;
+
 	/// Subscribe to finality justifications.
-	async fn subscribe_justifications(client: &WsClient) -> RpcResult<Subscription<Bytes>>;
+	async fn subscribe_justifications(client: &RpcClient) -> RpcResult<Subscription<Bytes>>;
 }
 
 /// RPC methods of Substrate `grandpa` namespace, that we are using.
@@ -125,14 +129,15 @@ pub(crate) trait SubstrateGrandpa<C> {
 pub struct SubstrateGrandpaFinalityClient;
 #[async_trait]
 impl<C: ChainWithGrandpa> SubstrateFinalityClient<C> for SubstrateGrandpaFinalityClient {
-	async fn subscribe_justifications(client: &WsClient) -> RpcResult<Subscription<Bytes>> {
+	const ENGINE_ID: ConsensusEngineId = sp_finality_grandpa::GRANDPA_ENGINE_ID;
+
+	async fn subscribe_justifications(client: &RpcClient) -> RpcResult<Subscription<Bytes>> {
 		SubstrateGrandpaClient::<C>::subscribe_justifications(client).await
 	}
 }
 
-// TODO: Use `ChainWithBeefy` instead of `Chain` after #1606 is merged
 /// RPC methods of Substrate `beefy` namespace, that we are using.
-#[rpc(client, client_bounds(C: Chain), namespace = "beefy")]
+#[rpc(client, client_bounds(C: ChainWithBeefy), namespace = "beefy")]
 pub(crate) trait SubstrateBeefy<C> {
 	/// Subscribe to BEEFY justifications.
 	#[subscription(name = "subscribeJustifications", unsubscribe = "unsubscribeJustifications", item = Bytes)]
@@ -141,10 +146,11 @@ pub(crate) trait SubstrateBeefy<C> {
 
 /// RPC finality methods of Substrate `beefy` namespace, that we are using.
 pub struct SubstrateBeefyFinalityClient;
-// TODO: Use `ChainWithBeefy` instead of `Chain` after #1606 is merged
 #[async_trait]
-impl<C: Chain> SubstrateFinalityClient<C> for SubstrateBeefyFinalityClient {
-	async fn subscribe_justifications(client: &WsClient) -> RpcResult<Subscription<Bytes>> {
+impl<C: ChainWithBeefy> SubstrateFinalityClient<C> for SubstrateBeefyFinalityClient {
+	const ENGINE_ID: ConsensusEngineId = bp_beefy::BEEFY_ENGINE_ID;
+
+	async fn subscribe_justifications(client: &RpcClient) -> RpcResult<Subscription<Bytes>> {
 		SubstrateBeefyClient::<C>::subscribe_justifications(client).await
 	}
 }
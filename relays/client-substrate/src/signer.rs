@@ -0,0 +1,413 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Transactions signers.
+//!
+//! By default, the relay keeps the secret key of its account in memory. That's not always
+//! acceptable, so the [`Signer`] may also be backed by the key in the Substrate keystore
+//! directory (the key is only loaded when the transaction is signed) or by the remote signing
+//! service (the key never leaves that service).
+
+use crate::{error::Error, AccountKeyPairOf};
+
+use codec::Decode;
+use jsonrpsee::{core::client::ClientT, http_client::HttpClientBuilder, rpc_params};
+use sp_core::Pair;
+use std::{
+	fmt::Debug,
+	path::{Path, PathBuf},
+	sync::Arc,
+	time::Duration,
+};
+
+/// Signer of the given chain transactions.
+pub type SignerOf<C> = Signer<AccountKeyPairOf<C>>;
+
+/// Timeout of the remote signer request.
+const REMOTE_SIGNER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Key pair, which public key and signature may be decoded from their SCALE encoding.
+pub trait DecodableKeyPair: Pair {
+	/// Decode public key from its SCALE encoding.
+	fn decode_public(encoded: &[u8]) -> Result<Self::Public, Error>;
+	/// Decode signature from its SCALE encoding.
+	fn decode_signature(encoded: &[u8]) -> Result<Self::Signature, Error>;
+}
+
+impl<P: Pair> DecodableKeyPair for P
+where
+	P::Public: Decode,
+	P::Signature: Decode,
+{
+	fn decode_public(mut encoded: &[u8]) -> Result<Self::Public, Error> {
+		Ok(Decode::decode(&mut encoded)?)
+	}
+
+	fn decode_signature(mut encoded: &[u8]) -> Result<Self::Signature, Error> {
+		Ok(Decode::decode(&mut encoded)?)
+	}
+}
+
+/// Backend that is able to produce signatures of the single account.
+pub trait SignerBackend<P: Pair>: Send + Sync {
+	/// Returns public key of the signer account.
+	fn public(&self) -> P::Public;
+	/// Sign given payload.
+	fn sign(&self, payload: &[u8]) -> Result<P::Signature, Error>;
+}
+
+/// Transactions signer.
+pub struct Signer<P: Pair> {
+	/// Public key of the signer account.
+	public: P::Public,
+	/// Signer backend.
+	backend: Arc<dyn SignerBackend<P>>,
+}
+
+impl<P: Pair> Signer<P> {
+	/// Create signer, backed by given backend.
+	pub fn new(backend: impl SignerBackend<P> + 'static) -> Self {
+		Signer { public: backend.public(), backend: Arc::new(backend) }
+	}
+
+	/// Returns public key of the signer account.
+	pub fn public(&self) -> P::Public {
+		self.public.clone()
+	}
+
+	/// Sign given payload.
+	pub fn sign(&self, payload: &[u8]) -> Result<P::Signature, Error> {
+		self.backend.sign(payload)
+	}
+}
+
+impl<P: Pair> Clone for Signer<P> {
+	fn clone(&self) -> Self {
+		Signer { public: self.public.clone(), backend: self.backend.clone() }
+	}
+}
+
+impl<P: Pair> Debug for Signer<P> {
+	fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		fmt.debug_struct("Signer").field("public", &hex::encode(&self.public)).finish()
+	}
+}
+
+impl<P: Pair> From<P> for Signer<P> {
+	fn from(pair: P) -> Self {
+		Signer::new(LocalSigner(pair))
+	}
+}
+
+/// Signer that keeps the key pair in memory.
+pub struct LocalSigner<P>(pub P);
+
+impl<P: Pair> SignerBackend<P> for LocalSigner<P> {
+	fn public(&self) -> P::Public {
+		self.0.public()
+	}
+
+	fn sign(&self, payload: &[u8]) -> Result<P::Signature, Error> {
+		Ok(self.0.sign(payload))
+	}
+}
+
+/// Signer that is backed by the key from the Substrate keystore directory.
+///
+/// The keystore file is read every time we need to sign something, so the key pair only lives
+/// in memory while the transaction is signed.
+pub struct KeystoreSigner<P: Pair> {
+	/// Path to the key file.
+	path: PathBuf,
+	/// Password of the key.
+	password: Option<String>,
+	/// Public key of the signer account.
+	public: P::Public,
+}
+
+impl<P: Pair> KeystoreSigner<P> {
+	/// Open the key with given hex-encoded public key from the keystore directory.
+	///
+	/// Substrate keystore saves every key in the separate file, which name is the hex-encoded
+	/// key type, followed by the hex-encoded public key. The file contains JSON string with the
+	/// secret phrase or seed of the key.
+	pub fn open(keystore: &Path, public: &str, password: Option<String>) -> Result<Self, Error> {
+		let public = public.trim_start_matches("0x").to_lowercase();
+		let path = std::fs::read_dir(keystore)?
+			.filter_map(|entry| entry.ok())
+			.map(|entry| entry.path())
+			.find(|path| {
+				path.file_name()
+					.and_then(|file_name| file_name.to_str())
+					.map(|file_name| {
+						file_name.len() == public.len() + 8 && file_name.ends_with(&public)
+					})
+					.unwrap_or(false)
+			})
+			.ok_or_else(|| {
+				Error::Signer(format!("Key {public} is missing from the keystore {keystore:?}"))
+			})?;
+
+		let pair = load_keystore_pair::<P>(&path, password.as_deref())?;
+		if hex::encode(pair.public()) != public {
+			return Err(Error::Signer(format!(
+				"Keystore file {path:?} contains key {} instead of {public}",
+				hex::encode(pair.public()),
+			)))
+		}
+
+		Ok(KeystoreSigner { path, password, public: pair.public() })
+	}
+}
+
+impl<P: Pair> SignerBackend<P> for KeystoreSigner<P> {
+	fn public(&self) -> P::Public {
+		self.public.clone()
+	}
+
+	fn sign(&self, payload: &[u8]) -> Result<P::Signature, Error> {
+		load_keystore_pair::<P>(&self.path, self.password.as_deref()).map(|pair| pair.sign(payload))
+	}
+}
+
+/// Load key pair from the keystore file.
+fn load_keystore_pair<P: Pair>(path: &Path, password: Option<&str>) -> Result<P, Error> {
+	let content = std::fs::read_to_string(path)?;
+	let phrase: String = serde_json::from_str(&content)
+		.map_err(|e| Error::Signer(format!("Failed to parse keystore file {path:?}: {e}")))?;
+	P::from_string(&phrase, password)
+		.map_err(|e| Error::Signer(format!("Invalid key in the keystore file {path:?}: {e:?}")))
+}
+
+/// Signer that is backed by the remote signing service.
+///
+/// The service is expected to be running on the same host (or in the trusted network), because
+/// the payload is sent using plain JSON-RPC over HTTP. The only method of the service is
+/// `signer_sign`, which accepts the hex-encoded public key of the signer and the hex-encoded
+/// payload and returns the hex-encoded SCALE-encoded signature. The signature is verified
+/// before it is used, so the misbehaving service can't make us submit invalid transactions.
+///
+/// The signing API is synchronous, so the request blocks the current thread. It must be called
+/// from the thread of the multi-threaded tokio runtime, e.g. from the blocking task that is spawned
+/// by the [`crate::Client::sign_transaction`].
+pub struct RemoteSigner<P: Pair> {
+	/// URL of the signing service.
+	url: String,
+	/// Public key of the signer account.
+	public: P::Public,
+}
+
+impl<P: DecodableKeyPair> RemoteSigner<P> {
+	/// Create signer, backed by the remote signing service at given address (`host:port`).
+	pub fn new(address: String, public: &str) -> Result<Self, Error> {
+		let public = P::decode_public(&decode_hex(public)?)?;
+		Ok(RemoteSigner { url: format!("http://{address}"), public })
+	}
+}
+
+impl<P: DecodableKeyPair> SignerBackend<P> for RemoteSigner<P> {
+	fn public(&self) -> P::Public {
+		self.public.clone()
+	}
+
+	fn sign(&self, payload: &[u8]) -> Result<P::Signature, Error> {
+		// signing API is synchronous, so we're executing the request in the tokio runtime of the
+		// caller, telling it that the current thread is blocked until the request is completed.
+		// The HTTP client is bound to the runtime where it has been created, so we're creating
+		// new client for every request
+		let runtime = tokio::runtime::Handle::try_current().map_err(|_| {
+			Error::Signer("Remote signer may only be used from the tokio runtime".into())
+		})?;
+		let public = format!("0x{}", hex::encode(&self.public));
+		let encoded_payload = format!("0x{}", hex::encode(payload));
+		let signature = tokio::task::block_in_place(|| {
+			runtime.block_on(async {
+				HttpClientBuilder::default()
+					.request_timeout(REMOTE_SIGNER_TIMEOUT)
+					.build(&self.url)?
+					.request::<String>("signer_sign", rpc_params![public, encoded_payload])
+					.await
+			})
+		})
+		.map_err(|e| Error::Signer(format!("Remote signer request has failed: {e}")))?;
+
+		let signature = P::decode_signature(&decode_hex(&signature)?)?;
+		if !P::verify(&signature, payload, &self.public) {
+			return Err(Error::Signer(format!(
+				"Remote signer has returned invalid signature of 0x{} payload",
+				hex::encode(payload),
+			)))
+		}
+
+		Ok(signature)
+	}
+}
+
+/// Decode hex-encoded string.
+fn decode_hex(value: &str) -> Result<Vec<u8>, Error> {
+	hex::decode(value.trim_start_matches("0x"))
+		.map_err(|e| Error::Signer(format!("Failed to decode hex value {value}: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use sp_core::sr25519;
+	use std::{
+		io::{Read, Write},
+		net::TcpListener,
+	};
+
+	const ALICE: &str = "//Alice";
+
+	fn alice() -> sr25519::Pair {
+		sr25519::Pair::from_string(ALICE, None).unwrap()
+	}
+
+	#[test]
+	fn local_signer_works() {
+		let signer: Signer<sr25519::Pair> = alice().into();
+		let signature = signer.sign(b"payload").unwrap();
+		assert_eq!(signer.public(), alice().public());
+		assert!(sr25519::Pair::verify(&signature, b"payload", &alice().public()));
+	}
+
+	#[test]
+	fn keystore_signer_works() {
+		let keystore = tempfile::tempdir().unwrap();
+		let public = hex::encode(alice().public());
+		std::fs::write(
+			keystore.path().join(format!("{}{public}", hex::encode(b"acco"))),
+			serde_json::to_string(ALICE).unwrap(),
+		)
+		.unwrap();
+
+		let signer = Signer::new(
+			KeystoreSigner::<sr25519::Pair>::open(keystore.path(), &public, None).unwrap(),
+		);
+		let signature = signer.sign(b"payload").unwrap();
+		assert_eq!(signer.public(), alice().public());
+		assert!(sr25519::Pair::verify(&signature, b"payload", &alice().public()));
+	}
+
+	#[test]
+	fn keystore_signer_fails_if_key_is_missing() {
+		let keystore = tempfile::tempdir().unwrap();
+		assert!(KeystoreSigner::<sr25519::Pair>::open(
+			keystore.path(),
+			&hex::encode(alice().public()),
+			None,
+		)
+		.is_err());
+	}
+
+	/// Start remote signer service, that signs the single request using given key pair.
+	fn start_remote_signer(pair: sr25519::Pair) -> (String, std::thread::JoinHandle<()>) {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let address = listener.local_addr().unwrap().to_string();
+		let server = std::thread::spawn(move || {
+			let (mut stream, _) = listener.accept().unwrap();
+			let mut request = Vec::new();
+			let mut buffer = [0u8; 1024];
+			let body = loop {
+				let read = stream.read(&mut buffer).unwrap();
+				request.extend_from_slice(&buffer[..read]);
+				let request = String::from_utf8_lossy(&request).to_string();
+				if let Some((_, body)) = request.split_once("\r\n\r\n") {
+					if serde_json::from_str::<serde_json::Value>(body).is_ok() {
+						break body.to_string()
+					}
+				}
+			};
+
+			let request: serde_json::Value = serde_json::from_str(&body).unwrap();
+			let payload =
+				hex::decode(request["params"][1].as_str().unwrap().trim_start_matches("0x"))
+					.unwrap();
+			let signature = format!("0x{}", hex::encode(pair.sign(&payload).encode()));
+			let response =
+				serde_json::json!({ "jsonrpc": "2.0", "id": request["id"], "result": signature })
+					.to_string();
+			write!(
+				stream,
+				"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+				Connection: close\r\n\r\n{response}",
+				response.len(),
+			)
+			.unwrap();
+		});
+		(address, server)
+	}
+
+	/// Sign payload using given signer from the blocking task, spawned in the tokio runtime.
+	fn sign_in_tokio_runtime(
+		signer: Signer<sr25519::Pair>,
+		payload: &'static [u8],
+	) -> Result<sr25519::Signature, Error> {
+		let runtime = tokio::runtime::Runtime::new().unwrap();
+		runtime.block_on(runtime.spawn_blocking(move || signer.sign(payload))).unwrap()
+	}
+
+	#[test]
+	fn remote_signer_works() {
+		let (address, server) = start_remote_signer(alice());
+		let signer = Signer::new(
+			RemoteSigner::<sr25519::Pair>::new(address, &hex::encode(alice().public())).unwrap(),
+		);
+		let signature = sign_in_tokio_runtime(signer, b"payload").unwrap();
+		server.join().unwrap();
+		assert!(sr25519::Pair::verify(&signature, b"payload", &alice().public()));
+	}
+
+	#[test]
+	fn remote_signer_works_at_runtime_worker_thread() {
+		let (address, server) = start_remote_signer(alice());
+		let signer = Signer::new(
+			RemoteSigner::<sr25519::Pair>::new(address, &hex::encode(alice().public())).unwrap(),
+		);
+		let runtime = tokio::runtime::Runtime::new().unwrap();
+		let signature = runtime
+			.block_on(runtime.spawn(async move { signer.sign(b"payload") }))
+			.unwrap()
+			.unwrap();
+		server.join().unwrap();
+		assert!(sr25519::Pair::verify(&signature, b"payload", &alice().public()));
+	}
+
+	#[test]
+	fn remote_signer_rejects_invalid_signature() {
+		let bob = sr25519::Pair::from_string("//Bob", None).unwrap();
+		let (address, server) = start_remote_signer(bob);
+		let signer = Signer::new(
+			RemoteSigner::<sr25519::Pair>::new(address, &hex::encode(alice().public())).unwrap(),
+		);
+		assert!(matches!(sign_in_tokio_runtime(signer, b"payload"), Err(Error::Signer(_))));
+		server.join().unwrap();
+	}
+
+	#[test]
+	fn remote_signer_fails_outside_of_tokio_runtime() {
+		let signer = Signer::new(
+			RemoteSigner::<sr25519::Pair>::new(
+				"127.0.0.1:1".into(),
+				&hex::encode(alice().public()),
+			)
+			.unwrap(),
+		);
+		assert!(matches!(signer.sign(b"payload"), Err(Error::Signer(_))));
+	}
+}
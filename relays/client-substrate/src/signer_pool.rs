@@ -0,0 +1,226 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Pool of transactions signers.
+//!
+//! Transactions of the single account are submitted one-by-one, because every transaction needs
+//! its own nonce. When the relay component is submitting many transactions, it may use several
+//! accounts, so that transactions of different accounts are submitted in parallel.
+
+use crate::{error::Error, signer::Signer, AccountKeyPairOf};
+
+use sp_core::Pair;
+use std::{
+	fmt::Debug,
+	ops::Deref,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+};
+
+/// Pool of signers of the given chain transactions.
+pub type SignerPoolOf<C> = SignerPool<AccountKeyPairOf<C>>;
+
+/// Strategy of selecting signer from the pool.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignerSelection {
+	/// Signers are selected one after another.
+	RoundRobin,
+	/// Signer with the smallest number of active submissions is selected.
+	LeastBusy,
+}
+
+/// Pool of transactions signers.
+///
+/// The first signer of the pool is the primary signer. It is used when the relay needs the
+/// single account (e.g. to identify itself when claiming rewards).
+pub struct SignerPool<P: Pair> {
+	/// All signers of the pool.
+	signers: Arc<Vec<PoolEntry<P>>>,
+	/// Signer selection strategy.
+	selection: SignerSelection,
+	/// Index of the next signer to select.
+	next: Arc<AtomicUsize>,
+}
+
+/// Signer of the pool.
+struct PoolEntry<P: Pair> {
+	/// The signer itself.
+	signer: Signer<P>,
+	/// Number of active submissions of the signer.
+	busy: Arc<AtomicUsize>,
+}
+
+impl<P: Pair> SignerPool<P> {
+	/// Create pool of given signers.
+	pub fn new(signers: Vec<Signer<P>>, selection: SignerSelection) -> Result<Self, Error> {
+		if signers.is_empty() {
+			return Err(Error::Custom("Signers pool must contain at least one signer".into()))
+		}
+
+		Ok(SignerPool {
+			signers: Arc::new(
+				signers
+					.into_iter()
+					.map(|signer| PoolEntry { signer, busy: Arc::new(AtomicUsize::new(0)) })
+					.collect(),
+			),
+			selection,
+			next: Arc::new(AtomicUsize::new(0)),
+		})
+	}
+
+	/// Returns primary signer of the pool.
+	pub fn primary(&self) -> &Signer<P> {
+		&self.signers[0].signer
+	}
+
+	/// Returns all signers of the pool.
+	pub fn signers(&self) -> impl Iterator<Item = &Signer<P>> {
+		self.signers.iter().map(|entry| &entry.signer)
+	}
+
+	/// Select signer for the next transaction.
+	///
+	/// The signer is considered busy until the returned guard is dropped. So it must be kept
+	/// alive until the transaction is submitted.
+	pub fn select(&self) -> PooledSigner<P> {
+		let len = self.signers.len();
+		let start = self.next.fetch_add(1, Ordering::Relaxed) % len;
+		let index = match self.selection {
+			SignerSelection::RoundRobin => start,
+			SignerSelection::LeastBusy => (0..len)
+				.map(|i| (start + i) % len)
+				.min_by_key(|index| self.signers[*index].busy.load(Ordering::Relaxed))
+				.unwrap_or(start),
+		};
+
+		let entry = &self.signers[index];
+		entry.busy.fetch_add(1, Ordering::Relaxed);
+		PooledSigner { signer: entry.signer.clone(), busy: entry.busy.clone() }
+	}
+}
+
+impl<P: Pair> Clone for SignerPool<P> {
+	fn clone(&self) -> Self {
+		SignerPool {
+			signers: self.signers.clone(),
+			selection: self.selection,
+			next: self.next.clone(),
+		}
+	}
+}
+
+impl<P: Pair> Debug for SignerPool<P> {
+	fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		fmt.debug_struct("SignerPool")
+			.field("signers", &self.signers().collect::<Vec<_>>())
+			.field("selection", &self.selection)
+			.finish()
+	}
+}
+
+impl<P: Pair> From<Signer<P>> for SignerPool<P> {
+	fn from(signer: Signer<P>) -> Self {
+		SignerPool {
+			signers: Arc::new(vec![PoolEntry { signer, busy: Arc::new(AtomicUsize::new(0)) }]),
+			selection: SignerSelection::RoundRobin,
+			next: Arc::new(AtomicUsize::new(0)),
+		}
+	}
+}
+
+impl<P: Pair> From<P> for SignerPool<P> {
+	fn from(pair: P) -> Self {
+		Signer::from(pair).into()
+	}
+}
+
+/// Signer, selected from the pool.
+pub struct PooledSigner<P: Pair> {
+	/// Selected signer.
+	signer: Signer<P>,
+	/// Number of active submissions of the selected signer.
+	busy: Arc<AtomicUsize>,
+}
+
+impl<P: Pair> Deref for PooledSigner<P> {
+	type Target = Signer<P>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.signer
+	}
+}
+
+impl<P: Pair> Drop for PooledSigner<P> {
+	fn drop(&mut self) {
+		self.busy.fetch_sub(1, Ordering::Relaxed);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::sr25519;
+
+	fn pool(selection: SignerSelection) -> SignerPool<sr25519::Pair> {
+		SignerPool::new(
+			["//Alice", "//Bob", "//Charlie"]
+				.iter()
+				.map(|suri| sr25519::Pair::from_string(suri, None).unwrap().into())
+				.collect(),
+			selection,
+		)
+		.unwrap()
+	}
+
+	fn public(index: usize, pool: &SignerPool<sr25519::Pair>) -> sr25519::Public {
+		pool.signers().nth(index).unwrap().public()
+	}
+
+	#[test]
+	fn empty_pool_is_rejected() {
+		assert!(SignerPool::<sr25519::Pair>::new(vec![], SignerSelection::RoundRobin).is_err());
+	}
+
+	#[test]
+	fn round_robin_selection_works() {
+		let pool = pool(SignerSelection::RoundRobin);
+		let selected = (0..4).map(|_| pool.select().public()).collect::<Vec<_>>();
+		assert_eq!(
+			selected,
+			vec![public(0, &pool), public(1, &pool), public(2, &pool), public(0, &pool)],
+		);
+		assert_eq!(pool.primary().public(), public(0, &pool));
+	}
+
+	#[test]
+	fn least_busy_selection_works() {
+		let pool = pool(SignerSelection::LeastBusy);
+
+		// while first two signers are busy, the third is selected
+		let first = pool.select();
+		let second = pool.select();
+		assert_eq!(first.public(), public(0, &pool));
+		assert_eq!(second.public(), public(1, &pool));
+		assert_eq!(pool.select().public(), public(2, &pool));
+
+		// when the first signer is released, it is selected again
+		drop(first);
+		assert_eq!(pool.select().public(), public(0, &pool));
+	}
+}
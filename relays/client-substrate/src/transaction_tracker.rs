@@ -22,7 +22,13 @@ use async_trait::async_trait;
 use futures::{future::Either, Future, FutureExt, Stream, StreamExt};
 use relay_utils::{HeaderId, TrackedTransactionStatus};
 use sp_runtime::traits::Header as _;
-use std::time::Duration;
+use std::{
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
 
 /// Transaction tracker environment.
 #[async_trait]
@@ -38,6 +44,40 @@ impl<C: Chain> Environment<C> for Client<C> {
 	}
 }
 
+/// Parameters of the transaction tip escalation.
+///
+/// If transaction stays in the pool for too long, it is re-signed with the same nonce and higher
+/// tip. Since both transactions have the same nonce, the new transaction replaces the old one in
+/// the pool.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TipEscalation {
+	/// Number of blocks the transaction may stay in the pool before its tip is increased.
+	pub pending_blocks: u32,
+	/// Tip increase step (in chain base units).
+	pub tip_step: u128,
+	/// Tip limit (in chain base units). We'll never submit transaction with larger tip.
+	pub tip_limit: u128,
+}
+
+/// Transaction resubmitter, used to escalate tip of the stalled transaction.
+#[async_trait]
+pub trait TransactionResubmitter<C: Chain>: Send {
+	/// Re-sign the same transaction with the same nonce and higher tip and submit it.
+	///
+	/// Returns `None` if the tip can't be increased anymore.
+	async fn resubmit(
+		&mut self,
+	) -> Option<Result<(HashOf<C>, Subscription<TransactionStatusOf<C>>), Error>>;
+}
+
+/// Tip escalation of the tracked transaction.
+struct TrackerTipEscalation<C: Chain> {
+	/// Interval between tip increases.
+	interval: Duration,
+	/// Transaction resubmitter.
+	resubmitter: Box<dyn TransactionResubmitter<C>>,
+}
+
 /// Substrate transaction tracker implementation.
 ///
 /// Substrate node provides RPC API to submit and watch for transaction events. This way
@@ -58,11 +98,15 @@ impl<C: Chain> Environment<C> for Client<C> {
 ///    it is lost.
 ///
 /// This struct implements third option as it seems to be the most optimal.
+///
+/// Additionally, if tip escalation is enabled, the tracker re-signs the transaction with higher
+/// tip when it stays in the pool for too long or when we lose track of its status.
 pub struct TransactionTracker<C: Chain, E> {
 	environment: E,
 	transaction_hash: HashOf<C>,
 	stall_timeout: Duration,
 	subscription: Subscription<TransactionStatusOf<C>>,
+	tip_escalation: Option<TrackerTipEscalation<C>>,
 }
 
 impl<C: Chain, E: Environment<C>> TransactionTracker<C, E> {
@@ -73,7 +117,21 @@ impl<C: Chain, E: Environment<C>> TransactionTracker<C, E> {
 		transaction_hash: HashOf<C>,
 		subscription: Subscription<TransactionStatusOf<C>>,
 	) -> Self {
-		Self { environment, stall_timeout, transaction_hash, subscription }
+		Self { environment, stall_timeout, transaction_hash, subscription, tip_escalation: None }
+	}
+
+	/// Enable tip escalation of the tracked transaction.
+	///
+	/// The `resubmitter` is called every `interval` while the transaction is not finalized.
+	#[must_use]
+	pub fn with_tip_escalation(
+		mut self,
+		interval: Duration,
+		resubmitter: impl TransactionResubmitter<C> + 'static,
+	) -> Self {
+		self.tip_escalation =
+			Some(TrackerTipEscalation { interval, resubmitter: Box::new(resubmitter) });
+		self
 	}
 
 	/// Wait for final transaction status and return it along with last known internal invalidation
@@ -83,51 +141,192 @@ impl<C: Chain, E: Environment<C>> TransactionTracker<C, E> {
 		wait_for_stall_timeout: impl Future<Output = ()>,
 		wait_for_stall_timeout_rest: impl Future<Output = ()>,
 	) -> (TrackedTransactionStatus<HeaderIdOf<C>>, Option<InvalidationStatus<HeaderIdOf<C>>>) {
+		let TransactionTracker {
+			environment,
+			mut transaction_hash,
+			subscription,
+			mut tip_escalation,
+			..
+		} = self;
+
+		// tip is only escalated while the transaction is waiting in the pool
+		let is_pending = Arc::new(AtomicBool::new(false));
 		// sometimes we want to wait for the rest of the stall timeout even if
 		// `wait_for_invalidation` has been "select"ed first => it is shared
-		let wait_for_invalidation = watch_transaction_status::<_, C, _>(
-			self.environment,
-			self.transaction_hash,
-			self.subscription.into_stream(),
-		);
-		futures::pin_mut!(wait_for_stall_timeout, wait_for_invalidation);
-
-		match futures::future::select(wait_for_stall_timeout, wait_for_invalidation).await {
-			Either::Left((_, _)) => {
-				log::trace!(
-					target: "bridge",
-					"{} transaction {:?} is considered lost after timeout (no status response from the node)",
-					C::NAME,
-					self.transaction_hash,
-				);
-
-				(TrackedTransactionStatus::Lost, None)
-			},
-			Either::Right((invalidation_status, _)) => match invalidation_status {
-				InvalidationStatus::Finalized(at_block) =>
-					(TrackedTransactionStatus::Finalized(at_block), Some(invalidation_status)),
-				InvalidationStatus::Invalid =>
-					(TrackedTransactionStatus::Lost, Some(invalidation_status)),
-				InvalidationStatus::Lost => {
-					// wait for the rest of stall timeout - this way we'll be sure that the
-					// transaction is actually dead if it has been crafted properly
-					wait_for_stall_timeout_rest.await;
-					// if someone is still watching for our transaction, then we're reporting
-					// an error here (which is treated as "transaction lost")
+		let mut wait_for_invalidation = watch_transaction_status::<_, C, _>(
+			&environment,
+			transaction_hash,
+			track_pending_status::<C>(subscription, is_pending.clone()),
+		)
+		.boxed();
+		futures::pin_mut!(wait_for_stall_timeout);
+
+		loop {
+			let wait_for_tip_escalation = match tip_escalation {
+				Some(ref tip_escalation) =>
+					async_std::task::sleep(tip_escalation.interval).left_future(),
+				None => futures::future::pending().right_future(),
+			};
+			futures::pin_mut!(wait_for_tip_escalation);
+			let event = match futures::future::select(
+				futures::future::select(wait_for_stall_timeout.as_mut(), wait_for_tip_escalation),
+				&mut wait_for_invalidation,
+			)
+			.await
+			{
+				Either::Left((Either::Left(_), _)) => WaitEvent::StallTimeout,
+				Either::Left((Either::Right(_), _)) => WaitEvent::TipEscalation,
+				Either::Right((invalidation_status, _)) =>
+					WaitEvent::Invalidation(invalidation_status),
+			};
+
+			match event {
+				WaitEvent::StallTimeout => {
 					log::trace!(
 						target: "bridge",
-						"{} transaction {:?} is considered lost after timeout",
+						"{} transaction {:?} is considered lost after timeout (no status response from the node)",
 						C::NAME,
-						self.transaction_hash,
+						transaction_hash,
 					);
 
-					(TrackedTransactionStatus::Lost, Some(invalidation_status))
+					return (TrackedTransactionStatus::Lost, None)
 				},
-			},
+				WaitEvent::TipEscalation => {
+					// if the transaction has already been included into block, higher tip won't
+					// help - we'll only replace the included transaction in the pool
+					if !is_pending.load(Ordering::Relaxed) {
+						continue
+					}
+
+					// if we have failed to resubmit the transaction, we keep watching the old one
+					if let Some((new_transaction_hash, new_subscription)) =
+						escalate_tip(&mut tip_escalation, transaction_hash).await
+					{
+						transaction_hash = new_transaction_hash;
+						wait_for_invalidation = watch_transaction_status::<_, C, _>(
+							&environment,
+							transaction_hash,
+							track_pending_status::<C>(new_subscription, is_pending.clone()),
+						)
+						.boxed();
+					}
+				},
+				WaitEvent::Invalidation(invalidation_status) => match invalidation_status {
+					InvalidationStatus::Finalized(at_block) =>
+						return (
+							TrackedTransactionStatus::Finalized(at_block),
+							Some(invalidation_status),
+						),
+					InvalidationStatus::Invalid =>
+						return (TrackedTransactionStatus::Lost, Some(invalidation_status)),
+					InvalidationStatus::Lost => {
+						// the transaction is going to be lost (e.g. it has been dropped from the
+						// pool because of its low priority) => let's try to resubmit it with
+						// higher tip
+						if let Some((new_transaction_hash, new_subscription)) =
+							escalate_tip(&mut tip_escalation, transaction_hash).await
+						{
+							transaction_hash = new_transaction_hash;
+							wait_for_invalidation = watch_transaction_status::<_, C, _>(
+								&environment,
+								transaction_hash,
+								track_pending_status::<C>(new_subscription, is_pending.clone()),
+							)
+							.boxed();
+							continue
+						}
+
+						// wait for the rest of stall timeout - this way we'll be sure that the
+						// transaction is actually dead if it has been crafted properly
+						wait_for_stall_timeout_rest.await;
+						// if someone is still watching for our transaction, then we're reporting
+						// an error here (which is treated as "transaction lost")
+						log::trace!(
+							target: "bridge",
+							"{} transaction {:?} is considered lost after timeout",
+							C::NAME,
+							transaction_hash,
+						);
+
+						return (TrackedTransactionStatus::Lost, Some(invalidation_status))
+					},
+				},
+			}
 		}
 	}
 }
 
+/// Returns stream of transaction statuses, that remembers whether the transaction is waiting in
+/// the pool (its last status is `Future` or `Ready`).
+fn track_pending_status<C: Chain>(
+	subscription: Subscription<TransactionStatusOf<C>>,
+	is_pending: Arc<AtomicBool>,
+) -> impl Stream<Item = TransactionStatusOf<C>> {
+	is_pending.store(false, Ordering::Relaxed);
+	subscription.into_stream().inspect(move |status| match *status {
+		TransactionStatusOf::<C>::Future | TransactionStatusOf::<C>::Ready =>
+			is_pending.store(true, Ordering::Relaxed),
+		// transaction is still in the pool, it has just been gossiped to other nodes
+		TransactionStatusOf::<C>::Broadcast(_) => (),
+		_ => is_pending.store(false, Ordering::Relaxed),
+	})
+}
+
+/// Event that has happened while we have been waiting for the transaction.
+enum WaitEvent<BlockId> {
+	/// Stall timeout has expired.
+	StallTimeout,
+	/// It is time to increase transaction tip.
+	TipEscalation,
+	/// Transaction invalidation status is known.
+	Invalidation(InvalidationStatus<BlockId>),
+}
+
+/// Resubmit transaction with higher tip. Returns hash and status subscription of the new
+/// transaction.
+///
+/// If the tip can't be increased anymore or if resubmission has failed, tip escalation is
+/// disabled.
+async fn escalate_tip<C: Chain>(
+	tip_escalation: &mut Option<TrackerTipEscalation<C>>,
+	transaction_hash: HashOf<C>,
+) -> Option<(HashOf<C>, Subscription<TransactionStatusOf<C>>)> {
+	let result = tip_escalation.as_mut()?.resubmitter.resubmit().await;
+	match result {
+		Some(Ok((new_transaction_hash, new_subscription))) => {
+			log::info!(
+				target: "bridge",
+				"{} transaction {:?} has been resubmitted with higher tip: {:?}",
+				C::NAME,
+				transaction_hash,
+				new_transaction_hash,
+			);
+			Some((new_transaction_hash, new_subscription))
+		},
+		Some(Err(e)) => {
+			log::error!(
+				target: "bridge",
+				"Failed to resubmit {} transaction {:?} with higher tip: {:?}",
+				C::NAME,
+				transaction_hash,
+				e,
+			);
+			*tip_escalation = None;
+			None
+		},
+		None => {
+			log::debug!(
+				target: "bridge",
+				"Tip of {} transaction {:?} has reached the limit",
+				C::NAME,
+				transaction_hash,
+			);
+			*tip_escalation = None;
+			None
+		},
+	}
+}
+
 #[async_trait]
 impl<C: Chain, E: Environment<C>> relay_utils::TransactionTracker for TransactionTracker<C, E> {
 	type HeaderId = HeaderIdOf<C>;
@@ -160,7 +359,7 @@ async fn watch_transaction_status<
 	C: Chain,
 	S: Stream<Item = TransactionStatusOf<C>>,
 >(
-	environment: E,
+	environment: &E,
 	transaction_hash: HashOf<C>,
 	subscription: S,
 ) -> InvalidationStatus<HeaderIdOf<C>> {
@@ -300,6 +499,48 @@ mod tests {
 		}
 	}
 
+	struct TestResubmitter(Option<Subscription<TransactionStatusOf<TestChain>>>);
+
+	#[async_trait]
+	impl TransactionResubmitter<TestChain> for TestResubmitter {
+		async fn resubmit(
+			&mut self,
+		) -> Option<Result<(HashOf<TestChain>, Subscription<TransactionStatusOf<TestChain>>), Error>>
+		{
+			self.0.take().map(|subscription| Ok((Default::default(), subscription)))
+		}
+	}
+
+	async fn on_transaction_status_with_tip_escalation(
+		status: Option<TransactionStatus<HashOf<TestChain>, HashOf<TestChain>>>,
+		new_status: Option<TransactionStatus<HashOf<TestChain>, HashOf<TestChain>>>,
+		interval: Duration,
+		wait_for_stall_timeout: impl Future<Output = ()>,
+	) -> TrackedTransactionStatus<HeaderIdOf<TestChain>> {
+		let (mut sender, receiver) = futures::channel::mpsc::channel(1);
+		let (mut new_sender, new_receiver) = futures::channel::mpsc::channel(1);
+		let tx_tracker = TransactionTracker::<TestChain, TestEnvironment>::new(
+			TestEnvironment(Ok(HeaderId(0, Default::default()))),
+			Duration::from_secs(0),
+			Default::default(),
+			Subscription(async_std::sync::Mutex::new(receiver)),
+		)
+		.with_tip_escalation(
+			interval,
+			TestResubmitter(
+				new_status.map(|_| Subscription(async_std::sync::Mutex::new(new_receiver))),
+			),
+		);
+
+		if let Some(status) = status {
+			sender.send(Some(status)).await.unwrap();
+		}
+		if let Some(new_status) = new_status {
+			new_sender.send(Some(new_status)).await.unwrap();
+		}
+		tx_tracker.do_wait(wait_for_stall_timeout, futures::future::ready(())).await.0
+	}
+
 	async fn on_transaction_status(
 		status: TransactionStatus<HashOf<TestChain>, HashOf<TestChain>>,
 	) -> Option<(
@@ -338,7 +579,7 @@ mod tests {
 	async fn returns_lost_on_finalized_and_environment_error() {
 		assert_eq!(
 			watch_transaction_status::<_, TestChain, _>(
-				TestEnvironment(Err(Error::BridgePalletIsNotInitialized)),
+				&TestEnvironment(Err(Error::BridgePalletIsNotInitialized)),
 				Default::default(),
 				futures::stream::iter([TransactionStatus::Finalized(Default::default())])
 			)
@@ -417,7 +658,7 @@ mod tests {
 	async fn lost_on_subscription_error() {
 		assert_eq!(
 			watch_transaction_status::<_, TestChain, _>(
-				TestEnvironment(Ok(HeaderId(0, Default::default()))),
+				&TestEnvironment(Ok(HeaderId(0, Default::default()))),
 				Default::default(),
 				futures::stream::iter([])
 			)
@@ -444,4 +685,60 @@ mod tests {
 
 		assert_eq!(wait_result, Some((TrackedTransactionStatus::Lost, None)));
 	}
+
+	#[async_std::test]
+	async fn resubmits_pending_transaction_with_higher_tip() {
+		assert_eq!(
+			on_transaction_status_with_tip_escalation(
+				Some(TransactionStatus::Ready),
+				Some(TransactionStatus::Finalized(Default::default())),
+				Duration::from_millis(10),
+				futures::future::pending(),
+			)
+			.await,
+			TrackedTransactionStatus::Finalized(Default::default()),
+		);
+	}
+
+	#[async_std::test]
+	async fn does_not_escalate_tip_of_included_transaction() {
+		assert_eq!(
+			on_transaction_status_with_tip_escalation(
+				Some(TransactionStatus::InBlock(Default::default())),
+				Some(TransactionStatus::Finalized(Default::default())),
+				Duration::from_millis(10),
+				async_std::task::sleep(Duration::from_millis(100)),
+			)
+			.await,
+			TrackedTransactionStatus::Lost,
+		);
+	}
+
+	#[async_std::test]
+	async fn resubmits_dropped_transaction_with_higher_tip() {
+		assert_eq!(
+			on_transaction_status_with_tip_escalation(
+				Some(TransactionStatus::Dropped),
+				Some(TransactionStatus::Finalized(Default::default())),
+				Duration::from_secs(3600),
+				futures::future::pending(),
+			)
+			.await,
+			TrackedTransactionStatus::Finalized(Default::default()),
+		);
+	}
+
+	#[async_std::test]
+	async fn lost_on_dropped_when_tip_limit_is_reached() {
+		assert_eq!(
+			on_transaction_status_with_tip_escalation(
+				Some(TransactionStatus::Dropped),
+				None,
+				Duration::from_secs(3600),
+				futures::future::pending(),
+			)
+			.await,
+			TrackedTransactionStatus::Lost,
+		);
+	}
 }
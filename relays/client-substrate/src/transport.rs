@@ -0,0 +1,328 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC transports that may be used to connect to the Substrate node.
+
+use crate::{client::MAX_SUBSCRIPTION_CAPACITY, Error, Result, RpcEndpoint};
+
+use async_trait::async_trait;
+use http::{header::HeaderName, HeaderMap, HeaderValue};
+use jsonrpsee::{
+	core::{
+		client::{
+			CertificateStore, Client as AsyncClient, ClientBuilder as AsyncClientBuilder, ClientT,
+			ReceivedMessage, Subscription, SubscriptionClientT, TransportReceiverT,
+			TransportSenderT,
+		},
+		DeserializeOwned, Error as RpcError,
+	},
+	http_client::{HttpClient, HttpClientBuilder},
+	types::ParamsSer,
+	ws_client::WsClientBuilder,
+};
+use tokio::{
+	io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines},
+	net::{
+		unix::{OwnedReadHalf, OwnedWriteHalf},
+		UnixStream,
+	},
+};
+
+/// URI scheme of the Unix socket endpoint.
+const IPC_SCHEME: &str = "ipc";
+/// URI scheme of the embedded light client endpoint.
+const LIGHT_CLIENT_SCHEME: &str = "light";
+
+/// Substrate RPC client, connected to the node over one of supported transports.
+pub enum RpcClient {
+	/// Client that is connected over websocket, Unix socket or to the embedded light client.
+	/// Supports subscriptions.
+	Async(AsyncClient),
+	/// Client that is connected over HTTP. Does not support subscriptions.
+	Http(HttpClient),
+}
+
+impl RpcClient {
+	/// Connect to the given endpoint.
+	///
+	/// TLS connections are verified using native certificate store, so custom roots may be
+	/// added to the system store or provided using `SSL_CERT_FILE` environment variable.
+	pub async fn connect(endpoint: &RpcEndpoint) -> Result<Self> {
+		let (scheme, path) = endpoint.uri.split_once("://").ok_or_else(|| {
+			Error::Custom(format!("RPC endpoint URI has no scheme: {}", endpoint.uri))
+		})?;
+		match scheme {
+			"ws" | "wss" => Ok(RpcClient::Async(
+				WsClientBuilder::default()
+					.max_notifs_per_subscription(MAX_SUBSCRIPTION_CAPACITY)
+					.certificate_store(CertificateStore::Native)
+					.set_headers(header_map(&endpoint.headers)?)
+					.build(&endpoint.uri)
+					.await?,
+			)),
+			"http" | "https" => Ok(RpcClient::Http(
+				HttpClientBuilder::default()
+					.certificate_store(CertificateStore::Native)
+					.set_headers(header_map(&endpoint.headers)?)
+					.build(&endpoint.uri)?,
+			)),
+			IPC_SCHEME => {
+				let (reader, writer) = UnixStream::connect(path).await?.into_split();
+				Ok(RpcClient::Async(
+					AsyncClientBuilder::default()
+						.max_notifs_per_subscription(MAX_SUBSCRIPTION_CAPACITY)
+						.build_with_tokio(
+							IpcSender(writer),
+							IpcReceiver(BufReader::new(reader).lines()),
+						),
+				))
+			},
+			#[cfg(feature = "light-client")]
+			LIGHT_CLIENT_SCHEME => {
+				let (sender, receiver) = crate::light_client::start(path)?;
+				Ok(RpcClient::Async(
+					AsyncClientBuilder::default()
+						.max_notifs_per_subscription(MAX_SUBSCRIPTION_CAPACITY)
+						.build_with_tokio(sender, receiver),
+				))
+			},
+			#[cfg(not(feature = "light-client"))]
+			LIGHT_CLIENT_SCHEME =>
+				Err(Error::Custom("Light client support requires `light-client` feature".into())),
+			_ => Err(Error::Custom(format!("Unsupported RPC endpoint URI scheme: {scheme}"))),
+		}
+	}
+
+	/// Returns true if the client supports subscriptions.
+	///
+	/// If subscriptions are not supported, the client falls back to polling.
+	pub fn supports_subscriptions(&self) -> bool {
+		matches!(*self, RpcClient::Async(_))
+	}
+}
+
+#[async_trait]
+impl ClientT for RpcClient {
+	async fn notification<'a>(
+		&self,
+		method: &'a str,
+		params: Option<ParamsSer<'a>>,
+	) -> std::result::Result<(), RpcError> {
+		match *self {
+			RpcClient::Async(ref client) => client.notification(method, params).await,
+			RpcClient::Http(ref client) => client.notification(method, params).await,
+		}
+	}
+
+	async fn request<'a, R>(
+		&self,
+		method: &'a str,
+		params: Option<ParamsSer<'a>>,
+	) -> std::result::Result<R, RpcError>
+	where
+		R: DeserializeOwned,
+	{
+		match *self {
+			RpcClient::Async(ref client) => client.request(method, params).await,
+			RpcClient::Http(ref client) => client.request(method, params).await,
+		}
+	}
+
+	async fn batch_request<'a, R>(
+		&self,
+		batch: Vec<(&'a str, Option<ParamsSer<'a>>)>,
+	) -> std::result::Result<Vec<R>, RpcError>
+	where
+		R: DeserializeOwned + Default + Clone,
+	{
+		match *self {
+			RpcClient::Async(ref client) => client.batch_request(batch).await,
+			RpcClient::Http(ref client) => client.batch_request(batch).await,
+		}
+	}
+}
+
+#[async_trait]
+impl SubscriptionClientT for RpcClient {
+	async fn subscribe<'a, Notif>(
+		&self,
+		subscribe_method: &'a str,
+		params: Option<ParamsSer<'a>>,
+		unsubscribe_method: &'a str,
+	) -> std::result::Result<Subscription<Notif>, RpcError>
+	where
+		Notif: DeserializeOwned,
+	{
+		match *self {
+			RpcClient::Async(ref client) =>
+				client.subscribe(subscribe_method, params, unsubscribe_method).await,
+			RpcClient::Http(ref client) =>
+				client.subscribe(subscribe_method, params, unsubscribe_method).await,
+		}
+	}
+
+	async fn subscribe_to_method<'a, Notif>(
+		&self,
+		method: &'a str,
+	) -> std::result::Result<Subscription<Notif>, RpcError>
+	where
+		Notif: DeserializeOwned,
+	{
+		match *self {
+			RpcClient::Async(ref client) => client.subscribe_to_method(method).await,
+			RpcClient::Http(ref client) => client.subscribe_to_method(method).await,
+		}
+	}
+}
+
+/// Sending half of the Unix socket transport. Every message is sent as a single line.
+struct IpcSender(OwnedWriteHalf);
+
+#[async_trait]
+impl TransportSenderT for IpcSender {
+	type Error = std::io::Error;
+
+	async fn send(&mut self, message: String) -> std::result::Result<(), Self::Error> {
+		self.0.write_all(message.as_bytes()).await?;
+		self.0.write_all(b"\n").await
+	}
+
+	async fn send_ping(&mut self) -> std::result::Result<(), Self::Error> {
+		// there are no control frames in the Unix socket transport and we never configure ping
+		// interval for this client
+		Ok(())
+	}
+}
+
+/// Receiving half of the Unix socket transport. Every message is received as a single line.
+struct IpcReceiver(Lines<BufReader<OwnedReadHalf>>);
+
+#[async_trait]
+impl TransportReceiverT for IpcReceiver {
+	type Error = std::io::Error;
+
+	async fn receive(&mut self) -> std::result::Result<ReceivedMessage, Self::Error> {
+		match self.0.next_line().await? {
+			Some(message) => Ok(ReceivedMessage::Text(message)),
+			None => Err(std::io::ErrorKind::UnexpectedEof.into()),
+		}
+	}
+}
+
+/// Convert endpoint headers into HTTP header map.
+fn header_map(headers: &[(String, String)]) -> Result<HeaderMap> {
+	headers
+		.iter()
+		.map(|(name, value)| {
+			let name = HeaderName::from_bytes(name.as_bytes())
+				.map_err(|e| Error::Custom(format!("Invalid RPC header name {name}: {e}")))?;
+			let value = HeaderValue::from_str(value)
+				.map_err(|e| Error::Custom(format!("Invalid value of RPC header {name}: {e}")))?;
+			Ok((name, value))
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const TEST_CHAIN: &str = "Test";
+
+	/// Start JSON-RPC server that serves `system_chain` method over HTTP and websocket.
+	async fn start_server() -> (std::net::SocketAddr, jsonrpsee_server::ServerHandle) {
+		let server = jsonrpsee_server::ServerBuilder::default().build("127.0.0.1:0").await.unwrap();
+		let address = server.local_addr().unwrap();
+		let mut module = jsonrpsee_server::RpcModule::new(());
+		module.register_method("system_chain", |_, _| Ok(TEST_CHAIN)).unwrap();
+		(address, server.start(module).unwrap())
+	}
+
+	/// Start Unix socket server that serves `system_chain` method to the first connected client.
+	fn start_ipc_server(path: &std::path::Path) {
+		let listener = tokio::net::UnixListener::bind(path).unwrap();
+		tokio::spawn(async move {
+			let (reader, mut writer) = listener.accept().await.unwrap().0.into_split();
+			let mut requests = BufReader::new(reader).lines();
+			while let Some(request) = requests.next_line().await.unwrap() {
+				let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+				let response = serde_json::json!({
+					"jsonrpc": "2.0",
+					"result": TEST_CHAIN,
+					"id": request["id"],
+				});
+				writer.write_all(format!("{response}\n").as_bytes()).await.unwrap();
+			}
+		});
+	}
+
+	/// Connect to the endpoint and call `system_chain` method.
+	async fn connect_and_read_chain(uri: String) -> (RpcClient, String) {
+		let client = RpcClient::connect(&RpcEndpoint { uri, headers: vec![] }).await.unwrap();
+		let chain = client.request("system_chain", None).await.unwrap();
+		(client, chain)
+	}
+
+	#[test]
+	fn header_map_works() {
+		let headers = header_map(&[("Authorization".into(), "Bearer token".into())]).unwrap();
+		assert_eq!(headers.get("authorization").unwrap(), "Bearer token");
+
+		assert!(header_map(&[("Invalid Name".into(), "value".into())]).is_err());
+		assert!(header_map(&[("Name".into(), "invalid\nvalue".into())]).is_err());
+	}
+
+	#[async_std::test]
+	async fn connect_fails_on_unsupported_scheme() {
+		let endpoint = RpcEndpoint { uri: "ftp://localhost:9944".into(), headers: vec![] };
+		assert!(matches!(RpcClient::connect(&endpoint).await, Err(Error::Custom(_))));
+
+		let endpoint = RpcEndpoint { uri: "localhost:9944".into(), headers: vec![] };
+		assert!(matches!(RpcClient::connect(&endpoint).await, Err(Error::Custom(_))));
+	}
+
+	#[test]
+	fn connects_over_websocket() {
+		tokio::runtime::Runtime::new().unwrap().block_on(async {
+			let (address, _server) = start_server().await;
+			let (client, chain) = connect_and_read_chain(format!("ws://{address}")).await;
+			assert!(client.supports_subscriptions());
+			assert_eq!(chain, TEST_CHAIN);
+		});
+	}
+
+	#[test]
+	fn connects_over_http() {
+		tokio::runtime::Runtime::new().unwrap().block_on(async {
+			let (address, _server) = start_server().await;
+			let (client, chain) = connect_and_read_chain(format!("http://{address}")).await;
+			assert!(!client.supports_subscriptions());
+			assert_eq!(chain, TEST_CHAIN);
+		});
+	}
+
+	#[test]
+	fn connects_over_unix_socket() {
+		tokio::runtime::Runtime::new().unwrap().block_on(async {
+			let dir = tempfile::tempdir().unwrap();
+			let path = dir.path().join("node.ipc");
+			start_ipc_server(&path);
+			let (client, chain) = connect_and_read_chain(format!("ipc://{}", path.display())).await;
+			assert!(client.supports_subscriptions());
+			assert_eq!(chain, TEST_CHAIN);
+		});
+	}
+}
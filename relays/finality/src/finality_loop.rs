@@ -29,7 +29,8 @@ use futures::{select, Future, FutureExt, Stream, StreamExt};
 use num_traits::{One, Saturating};
 use relay_utils::{
 	metrics::MetricsParams, relay_loop::Client as RelayClient, retry_backoff, FailedClient,
-	HeaderId, MaybeConnectionError, TrackedTransactionStatus, TransactionTracker,
+	HeaderId, InFlightTransaction, MaybeConnectionError, MaybeRestoredTransactionTracker,
+	RestoredTransactionTracker, StateStore, TrackedTransactionStatus, TransactionTracker,
 };
 use std::{
 	fmt::Debug,
@@ -62,6 +63,9 @@ pub struct FinalitySyncParams {
 	pub stall_timeout: Duration,
 	/// If true, only mandatory headers are relayed.
 	pub only_mandatory_headers: bool,
+	/// Persistent state store. If specified, the loop saves submitted transaction and recent
+	/// finality proofs there, so they're restored after relay restart.
+	pub state_store: Option<StateStore>,
 }
 
 /// Source client used in finality synchronization loop.
@@ -139,6 +143,11 @@ pub async fn run<P: FinalitySyncPipeline>(
 		.await
 }
 
+/// Key of the transaction, submitted to the target node, in the state store.
+pub(crate) const SUBMITTED_TRANSACTION_KEY: &str = "SubmittedTransaction";
+/// Key of recent finality proofs in the state store.
+pub(crate) const RECENT_FINALITY_PROOFS_KEY: &str = "RecentFinalityProofs";
+
 /// Unjustified headers container. Ordered by header number.
 pub(crate) type UnjustifiedHeaders<H> = Vec<H>;
 /// Finality proofs container. Ordered by target header number.
@@ -214,28 +223,66 @@ impl<Tracker: TransactionTracker, Number: Debug + PartialOrd> Transaction<Tracke
 				// The transaction has been finalized, but it may have been finalized in the
 				// "failed" state. So let's check if the block number was actually updated.
 				// If it wasn't then we are stalled.
-				//
-				// Please also note that we're returning an error if we fail to read required data
-				// from the target client - that's the best we can do here to avoid actual stall.
-				target_client
-					.best_finalized_source_block_id()
-					.await
-					.map_err(|e| format!("failed to read best block from target node: {e:?}"))
-					.and_then(|best_id_at_target| {
-						if self.submitted_header_number > best_id_at_target.0 {
-							return Err(format!(
-								"best block at target after tx is {:?} and we've submitted {:?}",
-								best_id_at_target.0, self.submitted_header_number,
-							))
-						}
-						Ok(())
-					})
+				ensure_header_imported(target_client, self.submitted_header_number).await
 			},
 			TrackedTransactionStatus::Lost => Err("transaction failed".to_string()),
 		}
 	}
 }
 
+impl<Tracker: TransactionTracker, Number: Debug + PartialOrd>
+	Transaction<MaybeRestoredTransactionTracker<Tracker>, Number>
+{
+	/// Track transaction that may have been restored from the state store.
+	pub async fn track_maybe_restored<
+		C: TargetClient<P>,
+		P: FinalitySyncPipeline<Number = Number>,
+	>(
+		self,
+		target_client: &C,
+	) -> Result<(), String> {
+		match self.tracker {
+			MaybeRestoredTransactionTracker::Submitted(tracker) =>
+				Transaction { tracker, submitted_header_number: self.submitted_header_number }
+					.track(target_client)
+					.await,
+			MaybeRestoredTransactionTracker::Restored(tracker) => {
+				// we can't track the restored transaction, so we only check that the header has
+				// been imported before the transaction is considered lost
+				tracker.wait().await;
+				ensure_header_imported(target_client, self.submitted_header_number).await
+			},
+		}
+	}
+}
+
+/// Ensure that the submitted header has been imported by the target node.
+///
+/// Please note that we're returning an error if we fail to read required data from the target
+/// client - that's the best we can do here to avoid actual stall.
+async fn ensure_header_imported<
+	C: TargetClient<P>,
+	P: FinalitySyncPipeline<Number = Number>,
+	Number: Debug + PartialOrd,
+>(
+	target_client: &C,
+	submitted_header_number: Number,
+) -> Result<(), String> {
+	target_client
+		.best_finalized_source_block_id()
+		.await
+		.map_err(|e| format!("failed to read best block from target node: {e:?}"))
+		.and_then(|best_id_at_target| {
+			if submitted_header_number > best_id_at_target.0 {
+				return Err(format!(
+					"best block at target after tx is {:?} and we've submitted {:?}",
+					best_id_at_target.0, submitted_header_number,
+				))
+			}
+			Ok(())
+		})
+}
+
 /// Finality proofs stream that may be restarted.
 pub(crate) struct RestartableFinalityProofsStream<S> {
 	/// Flag that the stream needs to be restarted.
@@ -311,9 +358,13 @@ pub(crate) struct FinalityLoopState<'a, P: FinalitySyncPipeline, FinalityProofsS
 }
 
 /// Run finality relay loop until connection to one of nodes is lost.
-pub(crate) async fn run_until_connection_lost<P: FinalitySyncPipeline>(
-	source_client: impl SourceClient<P>,
-	target_client: impl TargetClient<P>,
+pub(crate) async fn run_until_connection_lost<
+	P: FinalitySyncPipeline,
+	SC: SourceClient<P>,
+	TC: TargetClient<P>,
+>(
+	source_client: SC,
+	target_client: TC,
 	sync_params: FinalitySyncParams,
 	metrics_sync: Option<SyncLoopMetrics>,
 	exit_signal: impl Future<Output = ()>,
@@ -324,11 +375,25 @@ pub(crate) async fn run_until_connection_lost<P: FinalitySyncPipeline>(
 
 	let mut finality_proofs_stream =
 		RestartableFinalityProofsStream::create_raw_stream(&source_client).await?.into();
-	let mut recent_finality_proofs = Vec::new();
 
 	let mut progress = (Instant::now(), None);
 	let mut retry_backoff = retry_backoff();
+
+	// restore the state, saved by the previous relay instance
+	let state_store = sync_params
+		.state_store
+		.as_ref()
+		.map(|store| store.scoped(&metrics_prefix::<P>()));
+	let mut recent_finality_proofs = restore_recent_finality_proofs::<P>(state_store.as_ref());
+	let mut saved_recent_finality_proofs = recent_finality_proofs_tip::<P>(&recent_finality_proofs);
 	let mut last_submitted_header_number = None;
+	if let Some(restored_transaction) =
+		restore_submitted_transaction::<P, TC::TransactionTracker>(state_store.as_ref())
+	{
+		last_submitted_header_number = Some(restored_transaction.submitted_header_number);
+		last_transaction_tracker
+			.set(restored_transaction.track_maybe_restored(&target_client).fuse());
+	}
 
 	loop {
 		// run loop iteration
@@ -346,11 +411,42 @@ pub(crate) async fn run_until_connection_lost<P: FinalitySyncPipeline>(
 		)
 		.await;
 
+		// save updated recent finality proofs
+		if let Some(ref state_store) = state_store {
+			let recent_finality_proofs_tip =
+				recent_finality_proofs_tip::<P>(&recent_finality_proofs);
+			if recent_finality_proofs_tip != saved_recent_finality_proofs {
+				state_store.save(
+					RECENT_FINALITY_PROOFS_KEY,
+					&recent_finality_proofs.iter().map(|(_, proof)| proof).collect::<Vec<_>>(),
+				);
+				saved_recent_finality_proofs = recent_finality_proofs_tip;
+			}
+		}
+
 		// deal with errors
 		let next_tick = match iteration_result {
 			Ok(Some(updated_transaction)) => {
 				last_submitted_header_number = Some(updated_transaction.submitted_header_number);
-				last_transaction_tracker.set(updated_transaction.track(&target_client).fuse());
+				if let Some(ref state_store) = state_store {
+					state_store.save(
+						SUBMITTED_TRANSACTION_KEY,
+						&InFlightTransaction::new(
+							updated_transaction.submitted_header_number,
+							sync_params.stall_timeout,
+						),
+					);
+				}
+				last_transaction_tracker.set(
+					Transaction {
+						tracker: MaybeRestoredTransactionTracker::Submitted(
+							updated_transaction.tracker,
+						),
+						submitted_header_number: updated_transaction.submitted_header_number,
+					}
+					.track_maybe_restored(&target_client)
+					.fuse(),
+				);
 				retry_backoff.reset();
 				sync_params.tick
 			},
@@ -369,6 +465,12 @@ pub(crate) async fn run_until_connection_lost<P: FinalitySyncPipeline>(
 		// wait till exit signal, or new source block
 		select! {
 			transaction_result = last_transaction_tracker => {
+				// the transaction is either mined or lost and we don't need to wait for it after
+				// restart
+				if let Some(ref state_store) = state_store {
+					state_store.forget(SUBMITTED_TRANSACTION_KEY);
+				}
+
 				transaction_result.map_err(|e| {
 					log::error!(
 						target: "bridge",
@@ -380,7 +482,7 @@ pub(crate) async fn run_until_connection_lost<P: FinalitySyncPipeline>(
 
 					// Restart the loop if we're stalled.
 					FailedClient::Both
-				})?
+				})?;
 			},
 			_ = async_std::task::sleep(next_tick).fuse() => {},
 			_ = exit_signal => return Ok(()),
@@ -388,6 +490,64 @@ pub(crate) async fn run_until_connection_lost<P: FinalitySyncPipeline>(
 	}
 }
 
+/// Restore transaction that has been submitted by the previous relay instance.
+///
+/// Returns `None` if there's no such transaction or if it is already considered lost.
+pub(crate) fn restore_submitted_transaction<
+	P: FinalitySyncPipeline,
+	Tracker: TransactionTracker,
+>(
+	state_store: Option<&StateStore>,
+) -> Option<Transaction<MaybeRestoredTransactionTracker<Tracker>, P::Number>> {
+	let state_store = state_store?;
+	let transaction: InFlightTransaction<P::Number> =
+		state_store.load(SUBMITTED_TRANSACTION_KEY)?;
+	let remaining_time = match transaction.remaining_time() {
+		Some(remaining_time) => remaining_time,
+		None => {
+			state_store.forget(SUBMITTED_TRANSACTION_KEY);
+			return None
+		},
+	};
+
+	log::info!(
+		target: "bridge",
+		"Restored finality proof transaction of {} header #{:?}. Will wait for it for {}s",
+		P::SOURCE_NAME,
+		transaction.data,
+		remaining_time.as_secs(),
+	);
+
+	Some(Transaction {
+		tracker: MaybeRestoredTransactionTracker::Restored(RestoredTransactionTracker::new(
+			remaining_time,
+		)),
+		submitted_header_number: transaction.data,
+	})
+}
+
+/// Restore recent finality proofs, saved by the previous relay instance.
+pub(crate) fn restore_recent_finality_proofs<P: FinalitySyncPipeline>(
+	state_store: Option<&StateStore>,
+) -> FinalityProofs<P> {
+	state_store
+		.and_then(|state_store| {
+			state_store.load::<Vec<P::FinalityProof>>(RECENT_FINALITY_PROOFS_KEY)
+		})
+		.unwrap_or_default()
+		.into_iter()
+		.map(|proof| (proof.target_header_number(), proof))
+		.collect()
+}
+
+/// Returns number of recent finality proofs and number of the header, justified by the last
+/// proof. It is used to detect changes of the recent finality proofs container.
+fn recent_finality_proofs_tip<P: FinalitySyncPipeline>(
+	recent_finality_proofs: FinalityProofsRef<P>,
+) -> (usize, Option<P::Number>) {
+	(recent_finality_proofs.len(), recent_finality_proofs.last().map(|(number, _)| *number))
+}
+
 pub(crate) async fn run_loop_iteration<P, SC, TC>(
 	source_client: &SC,
 	target_client: &TC,
@@ -20,10 +20,11 @@
 
 use crate::{
 	finality_loop::{
-		prune_recent_finality_proofs, read_finality_proofs_from_stream, run_loop_iteration,
+		prune_recent_finality_proofs, read_finality_proofs_from_stream,
+		restore_recent_finality_proofs, restore_submitted_transaction, run_loop_iteration,
 		run_until_connection_lost, select_better_recent_finality_proof, select_header_to_submit,
 		FinalityLoopState, FinalityProofs, FinalitySyncParams, RestartableFinalityProofsStream,
-		SourceClient, TargetClient,
+		SourceClient, TargetClient, RECENT_FINALITY_PROOFS_KEY, SUBMITTED_TRANSACTION_KEY,
 	},
 	sync_loop_metrics::SyncLoopMetrics,
 	FinalityProof, FinalitySyncPipeline, SourceHeader,
@@ -31,11 +32,12 @@ use crate::{
 
 use async_trait::async_trait;
 use bp_header_chain::GrandpaConsensusLogReader;
+use codec::{Decode, Encode};
 use futures::{FutureExt, Stream, StreamExt};
 use parking_lot::Mutex;
 use relay_utils::{
-	relay_loop::Client as RelayClient, FailedClient, HeaderId, MaybeConnectionError,
-	TrackedTransactionStatus, TransactionTracker,
+	relay_loop::Client as RelayClient, FailedClient, HeaderId, InFlightTransaction,
+	MaybeConnectionError, StateStore, TrackedTransactionStatus, TransactionTracker,
 };
 use std::{
 	collections::HashMap,
@@ -110,7 +112,7 @@ impl SourceHeader<TestHash, TestNumber, GrandpaConsensusLogReader<TestNumber>>
 	}
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
 struct TestFinalityProof(TestNumber);
 
 impl FinalityProof<TestNumber> for TestFinalityProof {
@@ -249,6 +251,7 @@ fn test_sync_params() -> FinalitySyncParams {
 		recent_finality_proofs_limit: 1024,
 		stall_timeout: Duration::from_secs(1),
 		only_mandatory_headers: false,
+		state_store: None,
 	}
 }
 
@@ -362,6 +365,7 @@ fn run_only_mandatory_headers_mode_test(
 			recent_finality_proofs_limit: 0,
 			stall_timeout: Duration::from_secs(0),
 			only_mandatory_headers,
+			state_store: None,
 		},
 	))
 	.unwrap()
@@ -596,3 +600,54 @@ fn stalls_when_transaction_tracker_returns_finalized_but_transaction_fails() {
 
 	assert_eq!(result, Err(FailedClient::Both));
 }
+
+#[test]
+fn recent_finality_proofs_are_restored_from_state_store() {
+	let dir = tempfile::tempdir().unwrap();
+	let state_store = StateStore::open(dir.path()).unwrap();
+	assert_eq!(
+		restore_recent_finality_proofs::<TestFinalitySyncPipeline>(Some(&state_store)),
+		vec![]
+	);
+
+	state_store
+		.save(RECENT_FINALITY_PROOFS_KEY, &vec![TestFinalityProof(12), TestFinalityProof(14)]);
+	assert_eq!(
+		restore_recent_finality_proofs::<TestFinalitySyncPipeline>(Some(&state_store)),
+		vec![(12, TestFinalityProof(12)), (14, TestFinalityProof(14))],
+	);
+}
+
+#[test]
+fn submitted_transaction_is_restored_from_state_store() {
+	let dir = tempfile::tempdir().unwrap();
+	let state_store = StateStore::open(dir.path()).unwrap();
+
+	state_store.save(
+		SUBMITTED_TRANSACTION_KEY,
+		&InFlightTransaction::<TestNumber>::new(10, Duration::from_secs(60)),
+	);
+	let restored = restore_submitted_transaction::<TestFinalitySyncPipeline, TestTransactionTracker>(
+		Some(&state_store),
+	);
+	assert_eq!(restored.map(|tx| tx.submitted_header_number), Some(10));
+}
+
+#[test]
+fn lost_submitted_transaction_is_not_restored_from_state_store() {
+	let dir = tempfile::tempdir().unwrap();
+	let state_store = StateStore::open(dir.path()).unwrap();
+
+	state_store.save(
+		SUBMITTED_TRANSACTION_KEY,
+		&InFlightTransaction::<TestNumber>::new(10, Duration::from_secs(0)),
+	);
+	assert!(restore_submitted_transaction::<TestFinalitySyncPipeline, TestTransactionTracker>(
+		Some(&state_store),
+	)
+	.is_none());
+	assert_eq!(
+		state_store.load::<InFlightTransaction<TestNumber>>(SUBMITTED_TRANSACTION_KEY),
+		None
+	);
+}
@@ -25,6 +25,7 @@ pub use crate::{
 };
 
 use bp_header_chain::{ConsensusLogReader, FinalityProof};
+use codec::{Decode, Encode};
 use std::fmt::Debug;
 
 mod finality_loop;
@@ -41,13 +42,13 @@ pub trait FinalitySyncPipeline: 'static + Clone + Debug + Send + Sync {
 	/// Headers we're syncing are identified by this hash.
 	type Hash: Eq + Clone + Copy + Send + Sync + Debug;
 	/// Headers we're syncing are identified by this number.
-	type Number: relay_utils::BlockNumberBase;
+	type Number: relay_utils::BlockNumberBase + Decode + Encode;
 	/// A reader that can extract the consensus log from the header digest and interpret it.
 	type ConsensusLogReader: ConsensusLogReader;
 	/// Type of header that we're syncing.
 	type Header: SourceHeader<Self::Hash, Self::Number, Self::ConsensusLogReader>;
 	/// Finality proof type.
-	type FinalityProof: FinalityProof<Self::Number>;
+	type FinalityProof: FinalityProof<Self::Number> + Decode + Encode;
 }
 
 /// Header that we're receiving from source node.
@@ -18,22 +18,38 @@
 
 use crate::error::Error;
 use async_trait::async_trait;
+use bp_beefy::{
+	BeefyAuthorityIdOf, BeefyAuthorityIdToMerkleLeafOf, BeefyAuthoritySet, BeefyAuthoritySetOf,
+	BeefyConsensusLog, BeefyMmrLeafExtraOf, BeefyMmrLeafOf, BeefyValidatorSignatureOf,
+	ImportedCommitmentsInfoData, MmrEncodableOpaqueLeaf, MmrError, MmrHashOf, MmrHashingOf,
+	MmrProofOf, SignedCommitment, ValidatorSetId, VersionedFinalityProof, BEEFY_ENGINE_ID,
+};
 use bp_header_chain::{
 	justification::{verify_justification, GrandpaJustification},
 	ConsensusLogReader, FinalityProof, GrandpaConsensusLogReader,
 };
 use bp_runtime::{BasicOperatingMode, OperatingMode};
-use codec::{Decode, Encode};
+use codec::{Codec, Decode, Encode};
 use finality_grandpa::voter_set::VoterSet;
 use num_traits::{One, Zero};
 use relay_substrate_client::{
-	BlockNumberOf, Chain, ChainWithGrandpa, Client, Error as SubstrateError, HashOf, HeaderOf,
-	Subscription, SubstrateFinalityClient, SubstrateGrandpaFinalityClient,
+	BlockNumberOf, Chain, ChainWithBeefy, ChainWithGrandpa, Client, Error as SubstrateError,
+	HashOf, HeaderIdOf, HeaderOf, Subscription, SubstrateBeefyFinalityClient,
+	SubstrateFinalityClient, SubstrateGrandpaFinalityClient,
 };
+use relay_utils::HeaderId;
 use sp_core::{storage::StorageKey, Bytes};
 use sp_finality_grandpa::{AuthorityList as GrandpaAuthoritiesSet, GRANDPA_ENGINE_ID};
-use sp_runtime::{traits::Header, ConsensusEngineId};
-use std::marker::PhantomData;
+use sp_runtime::{
+	traits::{Convert, Header},
+	ConsensusEngineId, Digest,
+};
+use std::{fmt::Debug, marker::PhantomData};
+
+/// Name of the `BeefyApi::validator_set` runtime method.
+const BEEFY_API_VALIDATOR_SET_METHOD: &str = "BeefyApi_validator_set";
+/// Name of the `MmrApi::generate_proof` runtime method.
+const MMR_API_GENERATE_PROOF_METHOD: &str = "MmrApi_generate_proof";
 
 /// Finality engine, used by the Substrate chain.
 #[async_trait]
@@ -46,6 +62,9 @@ pub trait Engine<C: Chain>: Send {
 	type FinalityClient: SubstrateFinalityClient<C>;
 	/// Type of finality proofs, used by consensus engine.
 	type FinalityProof: FinalityProof<BlockNumberOf<C>> + Decode + Encode;
+	/// Type of additional data that is required to build finality proof submission call, but
+	/// is not a part of the finality proof itself.
+	type FinalityProofContext: Send;
 	/// Type of bridge pallet initialization data.
 	type InitializationData: std::fmt::Debug + Send + Sync + 'static;
 	/// Type of bridge pallet operating mode.
@@ -82,6 +101,21 @@ pub trait Engine<C: Chain>: Send {
 			.unwrap_or(false))
 	}
 
+	/// Returns id of the best finalized source chain header, known to the bridge pallet at the
+	/// target chain.
+	async fn best_finalized_source_block_id_at_target<TargetChain: Chain>(
+		source_client: &Client<C>,
+		target_client: &Client<TargetChain>,
+	) -> Result<HeaderIdOf<C>, SubstrateError>;
+
+	/// Prepare additional data that is required to submit given finality proof to the target
+	/// chain.
+	async fn prepare_finality_proof_context(
+		source_client: &Client<C>,
+		header: &HeaderOf<C>,
+		proof: &Self::FinalityProof,
+	) -> Result<Self::FinalityProofContext, SubstrateError>;
+
 	/// A method to subscribe to encoded finality proofs, given source client.
 	async fn finality_proofs(client: &Client<C>) -> Result<Subscription<Bytes>, SubstrateError> {
 		client.subscribe_finality_justifications::<Self::FinalityClient>().await
@@ -128,6 +162,7 @@ impl<C: ChainWithGrandpa> Engine<C> for Grandpa<C> {
 	type ConsensusLogReader = GrandpaConsensusLogReader<<C::Header as Header>::Number>;
 	type FinalityClient = SubstrateGrandpaFinalityClient;
 	type FinalityProof = GrandpaJustification<HeaderOf<C>>;
+	type FinalityProofContext = ();
 	type InitializationData = bp_header_chain::InitializationData<C::Header>;
 	type OperatingMode = BasicOperatingMode;
 
@@ -139,6 +174,24 @@ impl<C: ChainWithGrandpa> Engine<C> for Grandpa<C> {
 		bp_header_chain::storage_keys::pallet_operating_mode_key(C::WITH_CHAIN_GRANDPA_PALLET_NAME)
 	}
 
+	async fn best_finalized_source_block_id_at_target<TargetChain: Chain>(
+		_source_client: &Client<C>,
+		target_client: &Client<TargetChain>,
+	) -> Result<HeaderIdOf<C>, SubstrateError> {
+		crate::messages_source::read_client_state::<TargetChain, C>(target_client, None)
+			.await?
+			.best_finalized_peer_at_best_self
+			.ok_or(SubstrateError::BridgePalletIsNotInitialized)
+	}
+
+	async fn prepare_finality_proof_context(
+		_source_client: &Client<C>,
+		_header: &HeaderOf<C>,
+		_proof: &Self::FinalityProof,
+	) -> Result<Self::FinalityProofContext, SubstrateError> {
+		Ok(())
+	}
+
 	/// Prepare initialization data for the GRANDPA verifier pallet.
 	async fn prepare_initialization_data(
 		source_client: Client<C>,
@@ -257,3 +310,272 @@ impl<C: ChainWithGrandpa> Engine<C> for Grandpa<C> {
 		})
 	}
 }
+
+/// BEEFY finality proof.
+///
+/// This is the signed commitment, wrapped into the versioned container. It is the same structure
+/// that is stored in the block justifications and is returned by the BEEFY RPC subscription.
+#[derive(Clone, Debug, Decode, Encode)]
+pub struct BeefyFinalityProof<Number, Signature>(pub VersionedFinalityProof<Number, Signature>);
+
+impl<Number, Signature> BeefyFinalityProof<Number, Signature> {
+	/// Returns reference to the signed commitment.
+	pub fn signed_commitment(&self) -> &SignedCommitment<Number, Signature> {
+		match self.0 {
+			VersionedFinalityProof::V1(ref signed_commitment) => signed_commitment,
+		}
+	}
+
+	/// Consumes self and returns the signed commitment.
+	pub fn into_signed_commitment(self) -> SignedCommitment<Number, Signature> {
+		match self.0 {
+			VersionedFinalityProof::V1(signed_commitment) => signed_commitment,
+		}
+	}
+}
+
+impl<Number, Signature> FinalityProof<Number> for BeefyFinalityProof<Number, Signature>
+where
+	Number: Copy + Debug + Send + Sync,
+	Signature: Clone + Debug + Send + Sync,
+{
+	fn target_header_number(&self) -> Number {
+		self.signed_commitment().commitment.block_number
+	}
+}
+
+/// BEEFY finality proof, used by given chain.
+pub type BeefyFinalityProofOf<C> =
+	BeefyFinalityProof<BlockNumberOf<C>, BeefyValidatorSignatureOf<C>>;
+
+/// Additional data that is required to submit BEEFY commitment to the target chain.
+pub struct BeefyFinalityProofContext<C: ChainWithBeefy> {
+	/// Validator set that has signed the commitment.
+	pub validator_set: BeefyAuthoritySetOf<C>,
+	/// MMR leaf of the block, finalized by the commitment.
+	pub mmr_leaf: BeefyMmrLeafOf<C>,
+	/// Proof of the `mmr_leaf` inclusion into the MMR, whose root is signed by the commitment.
+	pub mmr_proof: MmrProofOf<C>,
+}
+
+/// A struct that provides helper methods for querying the BEEFY consensus log.
+pub struct BeefyConsensusLogReader<AuthorityId>(PhantomData<AuthorityId>);
+
+impl<AuthorityId: Codec> ConsensusLogReader for BeefyConsensusLogReader<AuthorityId> {
+	fn schedules_authorities_change(digest: &Digest) -> bool {
+		// every BEEFY block has the `MmrRoot` log item, so we can't just look at the first one
+		digest.logs().iter().any(|log| {
+			matches!(
+				log.consensus_try_to(&BEEFY_ENGINE_ID),
+				Some(BeefyConsensusLog::<AuthorityId>::AuthoritiesChange(_))
+			)
+		})
+	}
+}
+
+/// BEEFY finality engine.
+pub struct Beefy<C>(PhantomData<C>);
+
+impl<C: ChainWithBeefy> Beefy<C>
+where
+	BeefyAuthorityIdOf<C>: Send + Sync,
+	BeefyValidatorSignatureOf<C>: Send + Sync,
+	BeefyMmrLeafExtraOf<C>: Send + Sync,
+	MmrHashOf<C>: Send + Sync,
+{
+	/// Read BEEFY validator set at given header.
+	async fn source_validator_set(
+		source_client: &Client<C>,
+		header_hash: C::Hash,
+	) -> Result<BeefyAuthoritySetOf<C>, SubstrateError> {
+		source_client
+			.typed_state_call::<_, Option<BeefyAuthoritySetOf<C>>>(
+				BEEFY_API_VALIDATOR_SET_METHOD.into(),
+				(),
+				Some(header_hash),
+			)
+			.await?
+			.ok_or_else(|| {
+				SubstrateError::Custom(format!(
+					"BEEFY validator set is missing from {} state at {}",
+					C::NAME,
+					header_hash,
+				))
+			})
+	}
+
+	/// Read BEEFY validator set with given id at given header or its parent.
+	///
+	/// Headers that are changing the validator set are signed by the new set, so
+	/// we look at the parent only if the set at the header itself doesn't match.
+	async fn source_signing_validator_set(
+		source_client: &Client<C>,
+		header: &HeaderOf<C>,
+		validator_set_id: ValidatorSetId,
+	) -> Result<BeefyAuthoritySetOf<C>, SubstrateError> {
+		let validator_set = Self::source_validator_set(source_client, header.hash()).await?;
+		if validator_set.id() == validator_set_id {
+			return Ok(validator_set)
+		}
+
+		let validator_set =
+			Self::source_validator_set(source_client, *header.parent_hash()).await?;
+		if validator_set.id() == validator_set_id {
+			return Ok(validator_set)
+		}
+
+		Err(SubstrateError::Custom(format!(
+			"Failed to find {} BEEFY validator set {} at header {} or its parent",
+			C::NAME,
+			validator_set_id,
+			header.hash(),
+		)))
+	}
+
+	/// Generate MMR proof of the leaf, that has been inserted into MMR at given header.
+	async fn source_mmr_leaf_and_proof(
+		source_client: &Client<C>,
+		header: &HeaderOf<C>,
+	) -> Result<(BeefyMmrLeafOf<C>, MmrProofOf<C>), SubstrateError> {
+		let block_number = *header.number();
+		let (leaves, mmr_proof) = source_client
+			.typed_state_call::<_, Result<(Vec<MmrEncodableOpaqueLeaf>, MmrProofOf<C>), MmrError>>(
+				MMR_API_GENERATE_PROOF_METHOD.into(),
+				(vec![block_number], Some(block_number)),
+				Some(header.hash()),
+			)
+			.await?
+			.map_err(|err| {
+				SubstrateError::Custom(format!(
+					"Failed to generate {} MMR proof at {}: {:?}",
+					C::NAME,
+					header.hash(),
+					err,
+				))
+			})?;
+		let mmr_leaf = leaves
+			.into_iter()
+			.next()
+			.and_then(|leaf| leaf.into_opaque_leaf().try_decode())
+			.ok_or_else(|| {
+				SubstrateError::Custom(format!(
+					"Failed to decode {} MMR leaf at {}",
+					C::NAME,
+					header.hash(),
+				))
+			})?;
+
+		Ok((mmr_leaf, mmr_proof))
+	}
+}
+
+#[async_trait]
+impl<C: ChainWithBeefy> Engine<C> for Beefy<C>
+where
+	BeefyAuthorityIdOf<C>: Send + Sync,
+	BeefyValidatorSignatureOf<C>: Send + Sync,
+	BeefyMmrLeafExtraOf<C>: Send + Sync,
+	MmrHashOf<C>: Send + Sync,
+{
+	const ID: ConsensusEngineId = BEEFY_ENGINE_ID;
+	type ConsensusLogReader = BeefyConsensusLogReader<BeefyAuthorityIdOf<C>>;
+	type FinalityClient = SubstrateBeefyFinalityClient;
+	type FinalityProof = BeefyFinalityProofOf<C>;
+	type FinalityProofContext = BeefyFinalityProofContext<C>;
+	type InitializationData = bp_beefy::InitializationData<BlockNumberOf<C>, MmrHashOf<C>>;
+	type OperatingMode = BasicOperatingMode;
+
+	fn is_initialized_key() -> StorageKey {
+		bp_beefy::storage_keys::imported_commitments_info_key(C::WITH_CHAIN_BEEFY_PALLET_NAME)
+	}
+
+	fn pallet_operating_mode_key() -> StorageKey {
+		bp_beefy::storage_keys::pallet_operating_mode_key(C::WITH_CHAIN_BEEFY_PALLET_NAME)
+	}
+
+	async fn best_finalized_source_block_id_at_target<TargetChain: Chain>(
+		source_client: &Client<C>,
+		target_client: &Client<TargetChain>,
+	) -> Result<HeaderIdOf<C>, SubstrateError> {
+		// the BEEFY pallet only stores number of the best finalized block, so we read its hash
+		// from the source chain
+		let best_block_number = target_client
+			.storage_value::<ImportedCommitmentsInfoData<BlockNumberOf<C>>>(
+				Self::is_initialized_key(),
+				None,
+			)
+			.await?
+			.ok_or(SubstrateError::BridgePalletIsNotInitialized)?
+			.best_block_number;
+		let best_block_hash = source_client.block_hash_by_number(best_block_number).await?;
+		Ok(HeaderId(best_block_number, best_block_hash))
+	}
+
+	async fn prepare_finality_proof_context(
+		source_client: &Client<C>,
+		header: &HeaderOf<C>,
+		proof: &Self::FinalityProof,
+	) -> Result<Self::FinalityProofContext, SubstrateError> {
+		let validator_set_id = proof.signed_commitment().commitment.validator_set_id;
+		let validator_set =
+			Self::source_signing_validator_set(source_client, header, validator_set_id).await?;
+		let (mmr_leaf, mmr_proof) = Self::source_mmr_leaf_and_proof(source_client, header).await?;
+		Ok(BeefyFinalityProofContext { validator_set, mmr_leaf, mmr_proof })
+	}
+
+	/// Prepare initialization data for the BEEFY verifier pallet.
+	async fn prepare_initialization_data(
+		source_client: Client<C>,
+	) -> Result<Self::InitializationData, Error<HashOf<C>, BlockNumberOf<C>>> {
+		// Read next justification - the header that it finalizes will be used as initial header.
+		let justifications = Self::finality_proofs(&source_client)
+			.await
+			.map_err(|err| Error::Subscribe(C::NAME, err))?;
+		let justification = justifications
+			.next()
+			.await
+			.map_err(|e| Error::ReadJustification(C::NAME, e))
+			.and_then(|justification| {
+				justification.ok_or(Error::ReadJustificationStreamEnded(C::NAME))
+			})?;
+		let justification: Self::FinalityProof = Decode::decode(&mut &justification.0[..])
+			.map_err(|err| Error::DecodeJustification(C::NAME, err))?;
+
+		let initial_header_number = justification.target_header_number();
+		let initial_header_hash =
+			source_client.block_hash_by_number(initial_header_number).await.map_err(|err| {
+				Error::RetrieveHeaderByNumber(C::NAME, initial_header_number, err)
+			})?;
+		log::trace!(target: "bridge", "Selected {} initial header: {}/{}",
+			C::NAME,
+			initial_header_number,
+			initial_header_hash,
+		);
+
+		// Read BEEFY validator set at initial header. This set will be verifying all commitments
+		// for descendants of the initial header (until it is changed).
+		let initial_validator_set = Self::source_validator_set(&source_client, initial_header_hash)
+			.await
+			.map_err(|err| Error::RetrieveValidatorSet(C::NAME, initial_header_hash, err))?;
+		log::trace!(target: "bridge", "Selected {} initial validator set: {:?}",
+			C::NAME,
+			initial_validator_set,
+		);
+
+		let merkle_leafs = initial_validator_set
+			.validators()
+			.iter()
+			.cloned()
+			.map(BeefyAuthorityIdToMerkleLeafOf::<C>::convert)
+			.collect::<Vec<_>>();
+		Ok(bp_beefy::InitializationData {
+			operating_mode: BasicOperatingMode::Normal,
+			best_block_number: initial_header_number,
+			authority_set: BeefyAuthoritySet {
+				id: initial_validator_set.id(),
+				len: initial_validator_set.len() as u32,
+				root: bp_beefy::merkle_root::<MmrHashingOf<C>, _>(merkle_leafs),
+			},
+		})
+	}
+}
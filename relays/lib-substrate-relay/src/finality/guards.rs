@@ -19,14 +19,14 @@
 use crate::TransactionParams;
 
 use relay_substrate_client::{
-	AccountIdOf, AccountKeyPairOf, ChainWithBalances, ChainWithTransactions,
+	AccountIdOf, AccountKeyPairOf, ChainWithBalances, ChainWithTransactions, SignerPoolOf,
 };
 use sp_core::Pair;
 
 /// Start finality relay guards.
 pub async fn start<C: ChainWithBalances + ChainWithTransactions>(
 	target_client: &relay_substrate_client::Client<C>,
-	transaction_params: &TransactionParams<AccountKeyPairOf<C>>,
+	transaction_params: &TransactionParams<SignerPoolOf<C>>,
 	enable_version_guard: bool,
 	maximal_balance_decrease_per_day: C::Balance,
 ) -> relay_substrate_client::Result<()>
@@ -39,10 +39,12 @@ where
 			target_client.simple_runtime_version().await?.spec_version,
 		);
 	}
-	relay_substrate_client::guard::abort_when_account_balance_decreased(
-		target_client.clone(),
-		transaction_params.signer.public().into(),
-		maximal_balance_decrease_per_day,
-	);
+	for signer in transaction_params.signer.signers() {
+		relay_substrate_client::guard::abort_when_account_balance_decreased(
+			target_client.clone(),
+			signer.public().into(),
+			maximal_balance_decrease_per_day,
+		);
+	}
 	Ok(())
 }
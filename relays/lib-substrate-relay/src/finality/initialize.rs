@@ -26,8 +26,7 @@ use sp_core::Pair;
 
 use bp_runtime::HeaderIdOf;
 use relay_substrate_client::{
-	AccountKeyPairOf, Chain, ChainWithTransactions, Client, Error as SubstrateError,
-	UnsignedTransaction,
+	Chain, ChainWithTransactions, Client, Error as SubstrateError, SignerOf, UnsignedTransaction,
 };
 use relay_utils::{TrackedTransactionStatus, TransactionTracker};
 use sp_runtime::traits::Header as HeaderT;
@@ -41,7 +40,7 @@ pub async fn initialize<
 >(
 	source_client: Client<SourceChain>,
 	target_client: Client<TargetChain>,
-	target_signer: AccountKeyPairOf<TargetChain>,
+	target_signer: SignerOf<TargetChain>,
 	prepare_initialize_transaction: F,
 	dry_run: bool,
 ) where
@@ -103,7 +102,7 @@ async fn do_initialize<
 >(
 	source_client: Client<SourceChain>,
 	target_client: Client<TargetChain>,
-	target_signer: AccountKeyPairOf<TargetChain>,
+	target_signer: SignerOf<TargetChain>,
 	prepare_initialize_transaction: F,
 	dry_run: bool,
 ) -> Result<
@@ -144,7 +143,7 @@ where
 	);
 
 	let tx_status = target_client
-		.submit_and_watch_signed_extrinsic(&target_signer, move |_, transaction_nonce| {
+		.submit_and_watch_signed_extrinsic(&target_signer, None, move |_, transaction_nonce| {
 			let tx = prepare_initialize_transaction(transaction_nonce, initialization_data);
 			if dry_run {
 				Err(SubstrateError::Custom(
@@ -19,8 +19,8 @@
 
 use crate::{
 	finality::{
-		engine::Engine,
-		source::{SubstrateFinalityProof, SubstrateFinalitySource},
+		engine::{BeefyFinalityProofContext, BeefyFinalityProofOf, Engine},
+		source::{SubstrateFinalityProof, SubstrateFinalityProofContext, SubstrateFinalitySource},
 		target::SubstrateFinalityTarget,
 	},
 	TransactionParams,
@@ -29,12 +29,13 @@ use crate::{
 use async_trait::async_trait;
 use bp_header_chain::justification::GrandpaJustification;
 use finality_relay::FinalitySyncPipeline;
+use pallet_bridge_beefy::{Call as BridgeBeefyCall, Config as BridgeBeefyConfig};
 use pallet_bridge_grandpa::{Call as BridgeGrandpaCall, Config as BridgeGrandpaConfig};
 use relay_substrate_client::{
 	transaction_stall_timeout, AccountIdOf, AccountKeyPairOf, BlockNumberOf, CallOf, Chain,
-	ChainWithTransactions, Client, HashOf, HeaderOf, SyncHeader,
+	ChainWithTransactions, Client, HashOf, HeaderOf, SignerPoolOf, SyncHeader,
 };
-use relay_utils::metrics::MetricsParams;
+use relay_utils::{metrics::MetricsParams, StateStore};
 use sp_core::Pair;
 use std::{fmt::Debug, marker::PhantomData};
 
@@ -66,7 +67,7 @@ pub trait SubstrateFinalitySyncPipeline: 'static + Clone + Debug + Send + Sync {
 	/// Add relay guards if required.
 	async fn start_relay_guards(
 		_target_client: &Client<Self::TargetChain>,
-		_transaction_params: &TransactionParams<AccountKeyPairOf<Self::TargetChain>>,
+		_transaction_params: &TransactionParams<SignerPoolOf<Self::TargetChain>>,
 		_enable_version_guard: bool,
 	) -> relay_substrate_client::Result<()> {
 		Ok(())
@@ -92,11 +93,13 @@ impl<P: SubstrateFinalitySyncPipeline> FinalitySyncPipeline for FinalitySyncPipe
 
 /// Different ways of building `submit_finality_proof` calls.
 pub trait SubmitFinalityProofCallBuilder<P: SubstrateFinalitySyncPipeline> {
-	/// Given source chain header and its finality proofs, build call of `submit_finality_proof`
-	/// function of bridge GRANDPA module at the target chain.
+	/// Given source chain header, its finality proof and additional data, required to verify
+	/// this proof, build call of `submit_finality_proof` function of bridge finality module at
+	/// the target chain.
 	fn build_submit_finality_proof_call(
 		header: SyncHeader<HeaderOf<P::SourceChain>>,
 		proof: SubstrateFinalityProof<P>,
+		context: SubstrateFinalityProofContext<P>,
 	) -> CallOf<P::TargetChain>;
 }
 
@@ -120,6 +123,7 @@ where
 	fn build_submit_finality_proof_call(
 		header: SyncHeader<HeaderOf<P::SourceChain>>,
 		proof: GrandpaJustification<HeaderOf<P::SourceChain>>,
+		_context: SubstrateFinalityProofContext<P>,
 	) -> CallOf<P::TargetChain> {
 		BridgeGrandpaCall::<R, I>::submit_finality_proof {
 			finality_target: Box::new(header.into_inner()),
@@ -129,6 +133,47 @@ where
 	}
 }
 
+/// Building `submit_commitment` call of the BEEFY bridge pallet when you have direct access to
+/// the target chain runtime.
+pub struct DirectSubmitBeefyCommitmentCallBuilder<P, R, I> {
+	_phantom: PhantomData<(P, R, I)>,
+}
+
+impl<P, R, I> SubmitFinalityProofCallBuilder<P> for DirectSubmitBeefyCommitmentCallBuilder<P, R, I>
+where
+	P: SubstrateFinalitySyncPipeline,
+	P::SourceChain: ChainWithBeefy,
+	R: BridgeBeefyConfig<I>,
+	I: 'static,
+	R::BridgedChain: bp_beefy::ChainWithBeefy<
+		BlockNumber = BlockNumberOf<P::SourceChain>,
+		Hash = HashOf<P::SourceChain>,
+		MmrHash = bp_beefy::MmrHashOf<P::SourceChain>,
+		AuthorityId = bp_beefy::BeefyAuthorityIdOf<P::SourceChain>,
+		BeefyMmrLeafExtra = bp_beefy::BeefyMmrLeafExtraOf<P::SourceChain>,
+	>,
+	CallOf<P::TargetChain>: From<BridgeBeefyCall<R, I>>,
+	P::FinalityEngine: Engine<
+		P::SourceChain,
+		FinalityProof = BeefyFinalityProofOf<P::SourceChain>,
+		FinalityProofContext = BeefyFinalityProofContext<P::SourceChain>,
+	>,
+{
+	fn build_submit_finality_proof_call(
+		_header: SyncHeader<HeaderOf<P::SourceChain>>,
+		proof: BeefyFinalityProofOf<P::SourceChain>,
+		context: BeefyFinalityProofContext<P::SourceChain>,
+	) -> CallOf<P::TargetChain> {
+		BridgeBeefyCall::<R, I>::submit_commitment {
+			commitment: proof.into_signed_commitment(),
+			validator_set: context.validator_set,
+			mmr_leaf: Box::new(context.mmr_leaf),
+			mmr_proof: context.mmr_proof,
+		}
+		.into()
+	}
+}
+
 /// Macro that generates `SubmitFinalityProofCallBuilder` implementation for the case when
 /// you only have an access to the mocked version of target chain runtime. In this case you
 /// should provide "name" of the call variant for the bridge GRANDPA calls and the "name" of
@@ -153,6 +198,7 @@ macro_rules! generate_submit_finality_proof_call_builder {
 						<$pipeline as $crate::finality::SubstrateFinalitySyncPipeline>::SourceChain
 					>
 				>,
+				_context: $crate::finality::source::SubstrateFinalityProofContext<$pipeline>,
 			) -> relay_substrate_client::CallOf<
 				<$pipeline as $crate::finality::SubstrateFinalitySyncPipeline>::TargetChain
 			> {
@@ -172,8 +218,9 @@ pub async fn run<P: SubstrateFinalitySyncPipeline>(
 	source_client: Client<P::SourceChain>,
 	target_client: Client<P::TargetChain>,
 	only_mandatory_headers: bool,
-	transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
+	transaction_params: TransactionParams<SignerPoolOf<P::TargetChain>>,
 	metrics_params: MetricsParams,
+	state_store: Option<StateStore>,
 ) -> anyhow::Result<()>
 where
 	AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
@@ -186,8 +233,8 @@ where
 	);
 
 	finality_relay::run(
-		SubstrateFinalitySource::<P>::new(source_client, None),
-		SubstrateFinalityTarget::<P>::new(target_client, transaction_params.clone()),
+		SubstrateFinalitySource::<P>::new(source_client.clone(), None),
+		SubstrateFinalityTarget::<P>::new(source_client, target_client, transaction_params.clone()),
 		finality_relay::FinalitySyncParams {
 			tick: std::cmp::max(
 				P::SourceChain::AVERAGE_BLOCK_INTERVAL,
@@ -200,6 +247,7 @@ where
 				relay_utils::STALL_TIMEOUT,
 			),
 			only_mandatory_headers,
+			state_store,
 		},
 		metrics_params,
 		futures::future::pending(),
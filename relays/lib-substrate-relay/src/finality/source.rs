@@ -47,6 +47,13 @@ pub type SubstrateFinalityProof<P> =
 		<P as SubstrateFinalitySyncPipeline>::SourceChain,
 	>>::FinalityProof;
 
+/// Additional data, required to submit Substrate finality proof. Specific to the used
+/// `FinalityEngine`.
+pub type SubstrateFinalityProofContext<P> =
+	<<P as SubstrateFinalitySyncPipeline>::FinalityEngine as Engine<
+		<P as SubstrateFinalitySyncPipeline>::SourceChain,
+	>>::FinalityProofContext;
+
 /// Substrate node as finality source.
 pub struct SubstrateFinalitySource<P: SubstrateFinalitySyncPipeline> {
 	client: Client<P::SourceChain>,
@@ -27,25 +27,30 @@ use crate::{
 use async_trait::async_trait;
 use finality_relay::TargetClient;
 use relay_substrate_client::{
-	AccountIdOf, AccountKeyPairOf, Client, Error, HeaderIdOf, HeaderOf, SyncHeader, TransactionEra,
-	TransactionTracker, UnsignedTransaction,
+	AccountIdOf, AccountKeyPairOf, Client, Error, HeaderIdOf, HeaderOf, SignerPoolOf, SyncHeader,
+	TransactionEra, TransactionTracker, UnsignedTransaction,
 };
 use relay_utils::relay_loop::Client as RelayClient;
 use sp_core::Pair;
 
 /// Substrate client as Substrate finality target.
 pub struct SubstrateFinalityTarget<P: SubstrateFinalitySyncPipeline> {
+	source_client: Client<P::SourceChain>,
 	client: Client<P::TargetChain>,
-	transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
+	transaction_params: TransactionParams<SignerPoolOf<P::TargetChain>>,
 }
 
 impl<P: SubstrateFinalitySyncPipeline> SubstrateFinalityTarget<P> {
 	/// Create new Substrate headers target.
+	///
+	/// The source client is used to read additional data that is required to submit finality
+	/// proofs to the target chain.
 	pub fn new(
+		source_client: Client<P::SourceChain>,
 		client: Client<P::TargetChain>,
-		transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
+		transaction_params: TransactionParams<SignerPoolOf<P::TargetChain>>,
 	) -> Self {
-		SubstrateFinalityTarget { client, transaction_params }
+		SubstrateFinalityTarget { source_client, client, transaction_params }
 	}
 
 	/// Ensure that the bridge pallet at target chain is active.
@@ -67,6 +72,7 @@ impl<P: SubstrateFinalitySyncPipeline> SubstrateFinalityTarget<P> {
 impl<P: SubstrateFinalitySyncPipeline> Clone for SubstrateFinalityTarget<P> {
 	fn clone(&self) -> Self {
 		SubstrateFinalityTarget {
+			source_client: self.source_client.clone(),
 			client: self.client.clone(),
 			transaction_params: self.transaction_params.clone(),
 		}
@@ -97,13 +103,11 @@ where
 		// we can't relay finality if bridge pallet at target chain is halted
 		self.ensure_pallet_active().await?;
 
-		Ok(crate::messages_source::read_client_state::<P::TargetChain, P::SourceChain>(
+		P::FinalityEngine::best_finalized_source_block_id_at_target(
+			&self.source_client,
 			&self.client,
-			None,
 		)
-		.await?
-		.best_finalized_peer_at_best_self
-		.ok_or(Error::BridgePalletIsNotInitialized)?)
+		.await
 	}
 
 	async fn submit_finality_proof(
@@ -112,11 +116,16 @@ where
 		proof: SubstrateFinalityProof<P>,
 	) -> Result<Self::TransactionTracker, Error> {
 		let transaction_params = self.transaction_params.clone();
-		let call =
-			P::SubmitFinalityProofCallBuilder::build_submit_finality_proof_call(header, proof);
+		let context =
+			P::FinalityEngine::prepare_finality_proof_context(&self.source_client, &header, &proof)
+				.await?;
+		let call = P::SubmitFinalityProofCallBuilder::build_submit_finality_proof_call(
+			header, proof, context,
+		);
 		self.client
 			.submit_and_watch_signed_extrinsic(
-				&self.transaction_params.signer,
+				&self.transaction_params.signer.select(),
+				self.transaction_params.tip_escalation,
 				move |best_block_id, transaction_nonce| {
 					Ok(UnsignedTransaction::new(call.into(), transaction_nonce)
 						.era(TransactionEra::new(best_block_id, transaction_params.mortality)))
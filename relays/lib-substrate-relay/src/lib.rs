@@ -18,18 +18,20 @@
 
 #![warn(missing_docs)]
 
-use relay_substrate_client::{Chain, ChainWithUtilityPallet, UtilityPallet};
+use relay_substrate_client::{Chain, ChainWithUtilityPallet, TipEscalation, UtilityPallet};
 
 use std::marker::PhantomData;
 
 pub mod error;
 pub mod finality;
+pub mod messages_delivery_combiner;
 pub mod messages_lane;
 pub mod messages_metrics;
 pub mod messages_source;
 pub mod messages_target;
 pub mod on_demand;
 pub mod parachains;
+pub mod transactions_batcher;
 
 /// Transaction creation parameters.
 #[derive(Clone, Debug)]
@@ -38,6 +40,8 @@ pub struct TransactionParams<TS> {
 	pub signer: TS,
 	/// Transactions mortality.
 	pub mortality: Option<u32>,
+	/// Tip escalation of stalled transactions.
+	pub tip_escalation: Option<TipEscalation>,
 }
 
 /// Tagged relay account, which balance may be exposed as metrics by the relay.
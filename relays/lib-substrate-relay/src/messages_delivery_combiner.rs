@@ -0,0 +1,356 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Combines message deliveries of several lanes into a single delivery transaction.
+//!
+//! Lanes of the multi-lane loop are usually delivering messages, generated at the same source
+//! chain header. Instead of submitting separate transaction for every lane, the combiner
+//! proves messages of all these lanes using single storage proof and submits the single
+//! `receive_messages_proof` call. So the target chain is paying for finality and proof
+//! verification overhead only once.
+
+use crate::{
+	messages_lane::SubstrateMessageLane,
+	messages_source::{prove_lanes_messages, SubstrateMessagesProof},
+	messages_target::make_messages_delivery_call,
+	transactions_batcher::{
+		SharedTransactionTracker, TransactionsBatcher, TRANSACTIONS_BATCH_WINDOW,
+	},
+};
+
+use bp_messages::{MessageNonce, Weight};
+use bp_runtime::Size;
+use bridge_runtime_common::messages::target::MAX_LANES_IN_PROOF;
+use futures::{
+	channel::{
+		mpsc::{unbounded, UnboundedReceiver, UnboundedSender},
+		oneshot,
+	},
+	StreamExt,
+};
+use relay_substrate_client::{
+	AccountIdOf, AccountKeyPairOf, ChainWithMessages, Client, Error as SubstrateError, HeaderIdOf,
+};
+use sp_core::Pair;
+
+/// Limits of the combined delivery transaction.
+#[derive(Clone, Copy, Debug)]
+pub struct CombinedDeliveryLimits {
+	/// Maximal number of messages in the combined transaction.
+	pub max_messages: MessageNonce,
+	/// Maximal cumulative dispatch weight of messages in the combined transaction.
+	pub max_messages_weight: Weight,
+	/// Maximal cumulative size of messages proofs in the combined transaction.
+	pub max_messages_size: u32,
+}
+
+/// Combiner of message deliveries of several lanes.
+pub struct MessagesDeliveryCombiner<P: SubstrateMessageLane> {
+	deliveries_sender: UnboundedSender<PendingDelivery<P>>,
+}
+
+impl<P: SubstrateMessageLane> Clone for MessagesDeliveryCombiner<P> {
+	fn clone(&self) -> Self {
+		MessagesDeliveryCombiner { deliveries_sender: self.deliveries_sender.clone() }
+	}
+}
+
+impl<P: SubstrateMessageLane> MessagesDeliveryCombiner<P>
+where
+	AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
+{
+	/// Create new combiner and start its background task.
+	///
+	/// Combined transactions are submitted using given transactions batcher.
+	pub fn new(
+		source_client: Client<P::SourceChain>,
+		transactions_batcher: TransactionsBatcher<P::TargetChain>,
+		relayer_id_at_source: AccountIdOf<P::SourceChain>,
+		limits: CombinedDeliveryLimits,
+	) -> Self {
+		let (deliveries_sender, deliveries_receiver) = unbounded();
+		async_std::task::spawn(background_task::<P>(
+			source_client,
+			transactions_batcher,
+			relayer_id_at_source,
+			limits,
+			deliveries_receiver,
+		));
+		MessagesDeliveryCombiner { deliveries_sender }
+	}
+
+	/// Submit messages delivery.
+	///
+	/// The `proof` is generated at the `generated_at` source header. If there are other
+	/// deliveries with proofs, generated at the same header, they're combined into single
+	/// delivery transaction.
+	pub async fn submit(
+		&self,
+		generated_at: HeaderIdOf<P::SourceChain>,
+		proof: SubstrateMessagesProof<P::SourceChain>,
+	) -> Result<SharedTransactionTracker<P::TargetChain>, SubstrateError> {
+		let (tracker_sender, tracker_receiver) = oneshot::channel();
+		self.deliveries_sender
+			.unbounded_send(PendingDelivery { generated_at, proof, tracker_sender })
+			.map_err(|_| combiner_stopped_error::<P>())?;
+		tracker_receiver.await.map_err(|_| combiner_stopped_error::<P>())?
+	}
+}
+
+/// Delivery, waiting for submission.
+struct PendingDelivery<P: SubstrateMessageLane> {
+	/// Source header, at which the proof has been generated.
+	generated_at: HeaderIdOf<P::SourceChain>,
+	/// Messages proof.
+	proof: SubstrateMessagesProof<P::SourceChain>,
+	/// Sender of the tracker of transaction that includes the delivery.
+	tracker_sender: TrackerSender<P>,
+}
+
+/// Sender of the tracker of transaction that includes the delivery.
+type TrackerSender<P> = oneshot::Sender<
+	Result<SharedTransactionTracker<<P as SubstrateMessageLane>::TargetChain>, SubstrateError>,
+>;
+
+/// Error that is returned when combiner background task has been stopped.
+fn combiner_stopped_error<P: SubstrateMessageLane>() -> SubstrateError {
+	SubstrateError::Custom(format!(
+		"{} -> {} messages delivery combiner has been stopped",
+		P::SourceChain::NAME,
+		P::TargetChain::NAME,
+	))
+}
+
+/// Background task of the deliveries combiner.
+async fn background_task<P: SubstrateMessageLane>(
+	source_client: Client<P::SourceChain>,
+	transactions_batcher: TransactionsBatcher<P::TargetChain>,
+	relayer_id_at_source: AccountIdOf<P::SourceChain>,
+	limits: CombinedDeliveryLimits,
+	mut deliveries_receiver: UnboundedReceiver<PendingDelivery<P>>,
+) where
+	AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
+{
+	while let Some(first_delivery) = deliveries_receiver.next().await {
+		// give other lanes a chance to submit their deliveries
+		async_std::task::sleep(TRANSACTIONS_BATCH_WINDOW).await;
+
+		let mut pending_deliveries = vec![first_delivery];
+		while let Ok(Some(pending_delivery)) = deliveries_receiver.try_next() {
+			pending_deliveries.push(pending_delivery);
+		}
+
+		let groups = split_into_groups(
+			pending_deliveries,
+			|delivery| {
+				(
+					delivery.generated_at,
+					delivery.proof.1.messages_count(),
+					delivery.proof.0,
+					delivery.proof.1.size(),
+				)
+			},
+			limits,
+		);
+		for group in groups {
+			submit_group::<P>(&source_client, &transactions_batcher, &relayer_id_at_source, group)
+				.await;
+		}
+	}
+}
+
+/// Submit group of deliveries in single transaction.
+async fn submit_group<P: SubstrateMessageLane>(
+	source_client: &Client<P::SourceChain>,
+	transactions_batcher: &TransactionsBatcher<P::TargetChain>,
+	relayer_id_at_source: &AccountIdOf<P::SourceChain>,
+	group: Vec<PendingDelivery<P>>,
+) where
+	AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
+{
+	let generated_at = group[0].generated_at;
+	let (proofs, tracker_senders): (Vec<_>, Vec<_>) = group
+		.into_iter()
+		.map(|delivery| (delivery.proof, delivery.tracker_sender))
+		.unzip();
+	let result = match combine_proofs::<P>(source_client, generated_at, proofs).await {
+		Ok(proof) => {
+			let weight =
+				<P::TargetChain as ChainWithMessages>::WeightInfo::receive_messages_proof_weight(
+					&proof.1,
+					proof.1.lanes_count(),
+					proof.1.messages_count() as _,
+					proof.0,
+				);
+			let call = make_messages_delivery_call::<P>(relayer_id_at_source.clone(), proof, true);
+			transactions_batcher.submit(call, Some(weight)).await
+		},
+		Err(error) => Err(error),
+	};
+
+	match result {
+		Ok(tracker) =>
+			for tracker_sender in tracker_senders {
+				let _ = tracker_sender.send(Ok(tracker.clone()));
+			},
+		Err(error) => {
+			log::error!(
+				target: "bridge",
+				"Failed to submit combined {} -> {} messages delivery of {} lanes: {:?}",
+				P::SourceChain::NAME,
+				P::TargetChain::NAME,
+				tracker_senders.len(),
+				error,
+			);
+
+			for tracker_sender in tracker_senders {
+				let _ = tracker_sender.send(Err(SubstrateError::Custom(format!(
+					"Failed to submit combined messages delivery: {error:?}",
+				))));
+			}
+		},
+	}
+}
+
+/// Combine proofs, generated at the same source header into single proof.
+///
+/// If there's single proof, it is returned as is. Otherwise, all lanes are proved again by the
+/// single storage proof. Outbound lane states are always included in the combined proof, since
+/// the target chain weight formula assumes that they're always delivered.
+async fn combine_proofs<P: SubstrateMessageLane>(
+	source_client: &Client<P::SourceChain>,
+	generated_at: HeaderIdOf<P::SourceChain>,
+	mut proofs: Vec<SubstrateMessagesProof<P::SourceChain>>,
+) -> Result<SubstrateMessagesProof<P::SourceChain>, SubstrateError> {
+	if proofs.len() == 1 {
+		return Ok(proofs.remove(0))
+	}
+
+	let dispatch_weight =
+		proofs.iter().fold(Weight::zero(), |total, proof| total.saturating_add(proof.0));
+	let lanes = proofs
+		.iter()
+		.flat_map(|proof| proof.1.lanes())
+		.map(|lane_messages| {
+			(lane_messages.lane, lane_messages.nonces_start..=lane_messages.nonces_end, true)
+		})
+		.collect();
+	let proof = prove_lanes_messages::<P>(source_client, generated_at, lanes).await?;
+	Ok((dispatch_weight, proof))
+}
+
+/// Split items into groups that may be combined into single delivery.
+///
+/// Only items with the same key (source header) are combined. Every group has at most
+/// `MAX_LANES_IN_PROOF` items and fits into given limits. The order of items within every group
+/// is preserved.
+fn split_into_groups<T, K: PartialEq>(
+	items: Vec<T>,
+	item_params: impl Fn(&T) -> (K, MessageNonce, Weight, u32),
+	limits: CombinedDeliveryLimits,
+) -> Vec<Vec<T>> {
+	// (key, messages, weight, size, items)
+	let mut groups: Vec<(K, MessageNonce, Weight, u32, Vec<T>)> = Vec::new();
+	for item in items {
+		let (key, messages, weight, size) = item_params(&item);
+		let group = groups.iter_mut().find(
+			|(group_key, group_messages, group_weight, group_size, group)| {
+				*group_key == key &&
+					group.len() < MAX_LANES_IN_PROOF as usize &&
+					group_messages.saturating_add(messages) <= limits.max_messages &&
+					!group_weight.saturating_add(weight).any_gt(limits.max_messages_weight) &&
+					group_size.saturating_add(size) <= limits.max_messages_size
+			},
+		);
+		match group {
+			Some((_, group_messages, group_weight, group_size, group)) => {
+				*group_messages = group_messages.saturating_add(messages);
+				*group_weight = group_weight.saturating_add(weight);
+				*group_size = group_size.saturating_add(size);
+				group.push(item);
+			},
+			None => groups.push((key, messages, weight, size, vec![item])),
+		}
+	}
+	groups.into_iter().map(|(_, _, _, _, group)| group).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// (id, key, messages, weight, size)
+	fn split(items: Vec<(u32, u32, MessageNonce, u64, u32)>) -> Vec<Vec<u32>> {
+		split_into_groups(
+			items,
+			|(_, key, messages, weight, size)| {
+				(*key, *messages, Weight::from_ref_time(*weight), *size)
+			},
+			CombinedDeliveryLimits {
+				max_messages: 10,
+				max_messages_weight: Weight::from_ref_time(100),
+				max_messages_size: 1000,
+			},
+		)
+		.into_iter()
+		.map(|group| group.into_iter().map(|(id, _, _, _, _)| id).collect())
+		.collect()
+	}
+
+	#[test]
+	fn deliveries_at_the_same_header_are_combined() {
+		assert_eq!(
+			split(vec![(1, 0, 1, 10, 10), (2, 0, 1, 10, 10), (3, 0, 1, 10, 10)]),
+			vec![vec![1, 2, 3]],
+		);
+	}
+
+	#[test]
+	fn deliveries_at_different_headers_are_not_combined() {
+		assert_eq!(
+			split(vec![(1, 0, 1, 10, 10), (2, 1, 1, 10, 10), (3, 0, 1, 10, 10)]),
+			vec![vec![1, 3], vec![2]],
+		);
+	}
+
+	#[test]
+	fn deliveries_are_split_when_limits_are_exceeded() {
+		// messages limit
+		assert_eq!(
+			split(vec![(1, 0, 6, 10, 10), (2, 0, 6, 10, 10), (3, 0, 4, 10, 10)]),
+			vec![vec![1, 3], vec![2]],
+		);
+		// weight limit
+		assert_eq!(
+			split(vec![(1, 0, 1, 60, 10), (2, 0, 1, 60, 10), (3, 0, 1, 40, 10)]),
+			vec![vec![1, 3], vec![2]],
+		);
+		// size limit
+		assert_eq!(
+			split(vec![(1, 0, 1, 10, 600), (2, 0, 1, 10, 600), (3, 0, 1, 10, 400)]),
+			vec![vec![1, 3], vec![2]],
+		);
+	}
+
+	#[test]
+	fn deliveries_are_split_when_lanes_limit_is_exceeded() {
+		let deliveries = (0..MAX_LANES_IN_PROOF + 1).map(|id| (id, 0, 1, 1, 1)).collect();
+		let groups = split(deliveries);
+		assert_eq!(groups.len(), 2);
+		assert_eq!(groups[0].len(), MAX_LANES_IN_PROOF as usize);
+		assert_eq!(groups[1], vec![MAX_LANES_IN_PROOF]);
+	}
+}
@@ -17,9 +17,11 @@
 //! Tools for supporting message lanes between two Substrate-based chains.
 
 use crate::{
+	messages_delivery_combiner::{CombinedDeliveryLimits, MessagesDeliveryCombiner},
 	messages_source::{SubstrateMessagesProof, SubstrateMessagesSource},
 	messages_target::{SubstrateMessagesDeliveryProof, SubstrateMessagesTarget},
 	on_demand::OnDemandRelay,
+	transactions_batcher::TransactionsBatcher,
 	BatchCallBuilder, BatchCallBuilderConstructor, TransactionParams,
 };
 
@@ -30,18 +32,23 @@ use bridge_runtime_common::messages::{
 	source::FromBridgedChainMessagesDeliveryProof, target::FromBridgedChainMessagesProof,
 };
 use codec::Encode;
-use frame_support::{dispatch::GetDispatchInfo, weights::Weight};
-use messages_relay::{message_lane::MessageLane, message_lane_loop::BatchTransaction};
+use frame_support::{dispatch::GetDispatchInfo, traits::Get, weights::Weight};
+use messages_relay::{
+	message_lane::MessageLane,
+	message_lane_loop::{BatchTransaction, RelayerMode},
+};
 use pallet_bridge_messages::{Call as BridgeMessagesCall, Config as BridgeMessagesConfig};
 use relay_substrate_client::{
 	transaction_stall_timeout, AccountKeyPairOf, BalanceOf, BlockNumberOf, CallOf, Chain,
 	ChainWithMessages, ChainWithTransactions, Client, Error as SubstrateError, HashOf,
+	SignerPoolOf,
 };
 use relay_utils::{
 	metrics::{GlobalMetrics, MetricsParams, StandaloneMetric},
-	STALL_TIMEOUT,
+	StateStore, STALL_TIMEOUT,
 };
 use sp_core::Pair;
+use sp_runtime::{FixedU128, Percent};
 use std::{convert::TryFrom, fmt::Debug, marker::PhantomData};
 
 /// Substrate -> Substrate messages synchronization pipeline.
@@ -60,6 +67,10 @@ pub trait SubstrateMessageLane: 'static + Clone + Debug + Send + Sync {
 	type SourceBatchCallBuilder: BatchCallBuilderConstructor<CallOf<Self::SourceChain>>;
 	/// How batch calls are built at the target chain?
 	type TargetBatchCallBuilder: BatchCallBuilderConstructor<CallOf<Self::TargetChain>>;
+
+	/// Reward that the source chain registers for the relayer when delivery of a single message
+	/// is confirmed.
+	type DeliveryReward: Get<BalanceOf<Self::SourceChain>>;
 }
 
 /// Adapter that allows all `SubstrateMessageLane` to act as `MessageLane`.
@@ -88,21 +99,43 @@ pub struct MessagesRelayParams<P: SubstrateMessageLane> {
 	/// Messages source client.
 	pub source_client: Client<P::SourceChain>,
 	/// Source transaction params.
-	pub source_transaction_params: TransactionParams<AccountKeyPairOf<P::SourceChain>>,
+	pub source_transaction_params: TransactionParams<SignerPoolOf<P::SourceChain>>,
 	/// Messages target client.
 	pub target_client: Client<P::TargetChain>,
 	/// Target transaction params.
-	pub target_transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
+	pub target_transaction_params: TransactionParams<SignerPoolOf<P::TargetChain>>,
 	/// Optional on-demand source to target headers relay.
 	pub source_to_target_headers_relay:
 		Option<Arc<dyn OnDemandRelay<P::SourceChain, P::TargetChain>>>,
 	/// Optional on-demand target to source headers relay.
 	pub target_to_source_headers_relay:
 		Option<Arc<dyn OnDemandRelay<P::TargetChain, P::SourceChain>>>,
-	/// Identifier of lane that needs to be served.
-	pub lane_id: LaneId,
+	/// Optional shared stream of source chain transactions. If `None` and there are several
+	/// lanes to serve, new stream is created for this relay.
+	pub source_transactions_batcher: Option<TransactionsBatcher<P::SourceChain>>,
+	/// Optional shared stream of target chain transactions. If `None` and there are several
+	/// lanes to serve, new stream is created for this relay.
+	pub target_transactions_batcher: Option<TransactionsBatcher<P::TargetChain>>,
+	/// Identifiers of lanes that need to be served. If there are several lanes, they're
+	/// served by the single multi-lane loop.
+	pub lanes: Vec<LaneId>,
+	/// Messages delivery profitability parameters. If `None`, the relayer delivers all messages
+	/// regardless of their reward.
+	pub profitability_params: Option<DeliveryProfitabilityParams>,
 	/// Metrics parameters.
 	pub metrics_params: MetricsParams,
+	/// Optional store, where the relay persists its state.
+	pub state_store: Option<StateStore>,
+}
+
+/// Parameters of the profitability-aware messages delivery.
+#[derive(Clone, Copy, Debug)]
+pub struct DeliveryProfitabilityParams {
+	/// Price of the single target chain token, expressed in the source chain tokens.
+	pub target_to_source_conversion_rate: FixedU128,
+	/// Share of the delivery transaction fee, that is refunded to the relayer by the target
+	/// chain runtime (e.g. by the refund signed extension).
+	pub refunded_fee_share: Percent,
 }
 
 /// Batch transaction that brings headers + and messages delivery/receiving confirmations to the
@@ -161,8 +194,12 @@ where
 {
 	let source_client = params.source_client;
 	let target_client = params.target_client;
+	let lanes = params.lanes;
+	let first_lane = *lanes
+		.first()
+		.ok_or_else(|| anyhow::format_err!("At least one messages lane must be specified"))?;
 	let relayer_id_at_source: AccountIdOf<P::SourceChain> =
-		params.source_transaction_params.signer.public().into();
+		params.source_transaction_params.signer.primary().public().into();
 
 	// 2/3 is reserved for proofs and tx overhead
 	let max_messages_size_in_single_batch = P::TargetChain::max_extrinsic_size() / 3;
@@ -178,70 +215,151 @@ where
 	let (max_messages_in_single_batch, max_messages_weight_in_single_batch) =
 		(max_messages_in_single_batch / 2, max_messages_weight_in_single_batch / 2);
 
+	let source_stall_timeout = transaction_stall_timeout(
+		params.source_transaction_params.mortality,
+		P::SourceChain::AVERAGE_BLOCK_INTERVAL,
+		STALL_TIMEOUT,
+	);
+	let target_stall_timeout = transaction_stall_timeout(
+		params.target_transaction_params.mortality,
+		P::TargetChain::AVERAGE_BLOCK_INTERVAL,
+		STALL_TIMEOUT,
+	);
+
+	let relayer_mode = match params.profitability_params {
+		Some(_) => RelayerMode::Rational,
+		None => RelayerMode::Altruistic,
+	};
+
 	log::info!(
 		target: "bridge",
 		"Starting {} -> {} messages relay.\n\t\
+			Lanes: {:?}\n\t\
 			{} relayer account id: {:?}\n\t\
+			Relayer mode: {:?}\n\t\
 			Max messages in single transaction: {}\n\t\
 			Max messages size in single transaction: {}\n\t\
 			Max messages weight in single transaction: {}\n\t\
 			Tx mortality: {:?} (~{}m)/{:?} (~{}m)",
 		P::SourceChain::NAME,
 		P::TargetChain::NAME,
+		lanes,
 		P::SourceChain::NAME,
 		relayer_id_at_source,
+		relayer_mode,
 		max_messages_in_single_batch,
 		max_messages_size_in_single_batch,
 		max_messages_weight_in_single_batch,
 		params.source_transaction_params.mortality,
-		transaction_stall_timeout(
-			params.source_transaction_params.mortality,
-			P::SourceChain::AVERAGE_BLOCK_INTERVAL,
-			STALL_TIMEOUT,
-		).as_secs_f64() / 60.0f64,
+		source_stall_timeout.as_secs_f64() / 60.0f64,
 		params.target_transaction_params.mortality,
-		transaction_stall_timeout(
-			params.target_transaction_params.mortality,
-			P::TargetChain::AVERAGE_BLOCK_INTERVAL,
-			STALL_TIMEOUT,
-		).as_secs_f64() / 60.0f64,
+		target_stall_timeout.as_secs_f64() / 60.0f64,
 	);
 
-	messages_relay::message_lane_loop::run(
-		messages_relay::message_lane_loop::Params {
-			lane: params.lane_id,
+	let delivery_params = messages_relay::message_lane_loop::MessageDeliveryParams {
+		max_unrewarded_relayer_entries_at_target:
+			P::SourceChain::MAX_UNREWARDED_RELAYERS_IN_CONFIRMATION_TX,
+		max_unconfirmed_nonces_at_target:
+			P::SourceChain::MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX,
+		max_messages_in_single_batch,
+		max_messages_weight_in_single_batch,
+		max_messages_size_in_single_batch,
+		relayer_mode,
+	};
+	// all lanes of the multi-lane loop are sharing the same transactions stream
+	let (source_transactions_batcher, target_transactions_batcher) = match lanes.len() {
+		1 => (params.source_transactions_batcher, params.target_transactions_batcher),
+		_ => (
+			Some(params.source_transactions_batcher.unwrap_or_else(|| {
+				TransactionsBatcher::new::<P::SourceBatchCallBuilder>(
+					source_client.clone(),
+					params.source_transaction_params.clone(),
+				)
+			})),
+			Some(params.target_transactions_batcher.unwrap_or_else(|| {
+				TransactionsBatcher::new::<P::TargetBatchCallBuilder>(
+					target_client.clone(),
+					params.target_transaction_params.clone(),
+				)
+			})),
+		),
+	};
+	let source = SubstrateMessagesSource::<P>::new(
+		source_client.clone(),
+		target_client.clone(),
+		first_lane,
+		params.source_transaction_params,
+		source_transactions_batcher,
+		params.target_to_source_headers_relay,
+	);
+	// deliveries of all lanes, generated at the same source header, are combined into single
+	// messages proof
+	let delivery_combiner = match target_transactions_batcher {
+		Some(ref target_transactions_batcher) if lanes.len() > 1 =>
+			Some(MessagesDeliveryCombiner::<P>::new(
+				source_client.clone(),
+				target_transactions_batcher.clone(),
+				relayer_id_at_source.clone(),
+				CombinedDeliveryLimits {
+					max_messages: max_messages_in_single_batch,
+					max_messages_weight: max_messages_weight_in_single_batch,
+					max_messages_size: max_messages_size_in_single_batch,
+				},
+			)),
+		_ => None,
+	};
+	let target = SubstrateMessagesTarget::<P>::new(
+		target_client,
+		source_client,
+		first_lane,
+		relayer_id_at_source,
+		params.target_transaction_params,
+		params.profitability_params,
+		target_transactions_batcher,
+		delivery_combiner,
+		params.source_to_target_headers_relay,
+	);
+	let metrics_params = {
+		GlobalMetrics::new()?.register_and_spawn(&params.metrics_params.registry)?;
+		params.metrics_params
+	};
+	// both races of every lane are sharing the same state store, so we're using the largest
+	// stall timeout here
+	let stall_timeout = std::cmp::max(source_stall_timeout, target_stall_timeout);
+
+	if lanes.len() == 1 {
+		return messages_relay::message_lane_loop::run(
+			messages_relay::message_lane_loop::Params {
+				lane: first_lane,
+				source_tick: P::SourceChain::AVERAGE_BLOCK_INTERVAL,
+				target_tick: P::TargetChain::AVERAGE_BLOCK_INTERVAL,
+				reconnect_delay: relay_utils::relay_loop::RECONNECT_DELAY,
+				delivery_params,
+				stall_timeout,
+				state_store: params.state_store,
+			},
+			source,
+			target,
+			metrics_params,
+			futures::future::pending(),
+		)
+		.await
+		.map_err(Into::into)
+	}
+
+	messages_relay::multi_lane_loop::run(
+		messages_relay::multi_lane_loop::Params {
+			lanes,
 			source_tick: P::SourceChain::AVERAGE_BLOCK_INTERVAL,
 			target_tick: P::TargetChain::AVERAGE_BLOCK_INTERVAL,
 			reconnect_delay: relay_utils::relay_loop::RECONNECT_DELAY,
-			delivery_params: messages_relay::message_lane_loop::MessageDeliveryParams {
-				max_unrewarded_relayer_entries_at_target:
-					P::SourceChain::MAX_UNREWARDED_RELAYERS_IN_CONFIRMATION_TX,
-				max_unconfirmed_nonces_at_target:
-					P::SourceChain::MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX,
-				max_messages_in_single_batch,
-				max_messages_weight_in_single_batch,
-				max_messages_size_in_single_batch,
-			},
-		},
-		SubstrateMessagesSource::<P>::new(
-			source_client.clone(),
-			target_client.clone(),
-			params.lane_id,
-			params.source_transaction_params,
-			params.target_to_source_headers_relay,
-		),
-		SubstrateMessagesTarget::<P>::new(
-			target_client,
-			source_client,
-			params.lane_id,
-			relayer_id_at_source,
-			params.target_transaction_params,
-			params.source_to_target_headers_relay,
-		),
-		{
-			GlobalMetrics::new()?.register_and_spawn(&params.metrics_params.registry)?;
-			params.metrics_params
+			delivery_params,
+			stall_timeout,
+			state_store: params.state_store,
 		},
+		source,
+		target,
+		metrics_params,
 		futures::future::pending(),
 	)
 	.await
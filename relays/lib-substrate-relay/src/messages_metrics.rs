@@ -19,6 +19,7 @@
 use crate::TaggedAccount;
 
 use bp_messages::LaneId;
+use bp_relayers::{RewardsAccountOwner, RewardsAccountParams};
 use bp_runtime::StorageDoubleMapKeyProvider;
 use codec::Decode;
 use frame_system::AccountInfo;
@@ -31,7 +32,7 @@ use relay_substrate_client::{
 use relay_utils::metrics::{MetricsParams, StandaloneMetric};
 use sp_core::storage::StorageData;
 use sp_runtime::{FixedPointNumber, FixedU128};
-use std::{convert::TryFrom, fmt::Debug, marker::PhantomData};
+use std::{collections::BTreeMap, convert::TryFrom, fmt::Debug, marker::PhantomData};
 
 /// Add relay accounts balance metrics.
 pub async fn add_relay_balances_metrics<C: ChainWithBalances, BC: ChainWithMessages>(
@@ -70,30 +71,49 @@ where
 		)
 	})?;
 
+	// the same relay component may use several accounts, so we append index of the account to
+	// the tag of every non-first account with the same tag
+	let mut tags = BTreeMap::<String, usize>::new();
 	for account in relay_accounts {
+		let tag = account.tag();
+		let tag_index = tags.entry(tag.clone()).or_default();
+		let tag = if *tag_index == 0 { tag } else { format!("{tag}{tag_index}") };
+		*tag_index += 1;
+
 		let relay_account_balance_metric = FloatStorageValueMetric::new(
 			AccountBalanceFromAccountInfo::<C> { token_decimals, _phantom: Default::default() },
 			client.clone(),
 			C::account_info_storage_key(account.id()),
-			format!("at_{}_relay_{}_balance", C::NAME, account.tag()),
-			format!("Balance of the {} relay account at the {}", account.tag(), C::NAME),
+			format!("at_{}_relay_{}_balance", C::NAME, tag),
+			format!("Balance of the {} relay account at the {}", tag, C::NAME),
 		)?;
 		relay_account_balance_metric.register_and_spawn(&metrics.registry)?;
 
 		if let Some(relayers_pallet_name) = BC::WITH_CHAIN_RELAYERS_PALLET_NAME {
 			for lane in lanes {
-				let relay_account_reward_metric = FloatStorageValueMetric::new(
+				FloatStorageValueMetric::new(
+					AccountBalance::<C> { token_decimals, _phantom: Default::default() },
+					client.clone(),
+					bp_relayers::RelayerRewardsKeyProvider::<AccountIdOf<C>, BalanceOf<C>>::final_key(
+						relayers_pallet_name,
+						account.id(),
+						&RewardsAccountParams::new(*lane, BC::ID, RewardsAccountOwner::ThisChain),
+					),
+					format!("at_{}_relay_{}_reward_for_msgs_from_{}_on_lane_{}", C::NAME, tag, BC::NAME, hex::encode(lane.as_ref())),
+					format!("Reward of the {} relay account at {} for delivering messages from {} on lane {:?}", tag, C::NAME, BC::NAME, lane),
+				)?.register_and_spawn(&metrics.registry)?;
+
+				FloatStorageValueMetric::new(
 					AccountBalance::<C> { token_decimals, _phantom: Default::default() },
 					client.clone(),
 					bp_relayers::RelayerRewardsKeyProvider::<AccountIdOf<C>, BalanceOf<C>>::final_key(
 						relayers_pallet_name,
 						account.id(),
-						lane,
+						&RewardsAccountParams::new(*lane, BC::ID, RewardsAccountOwner::BridgedChain),
 					),
-					format!("at_{}_relay_{}_reward_for_lane_{}_with_{}", C::NAME, account.tag(), hex::encode(lane.as_ref()), BC::NAME),
-					format!("Reward of the {} relay account for serving lane {:?} with {} at the {}", account.tag(), lane, BC::NAME, C::NAME),
-				)?;
-				relay_account_reward_metric.register_and_spawn(&metrics.registry)?;
+					format!("at_{}_relay_{}_reward_for_msgs_to_{}_on_lane_{}", C::NAME, tag, BC::NAME, hex::encode(lane.as_ref())),
+					format!("Reward of the {} relay account at {} for delivering messages confirmations from {} on lane {:?}", tag, C::NAME, BC::NAME, lane),
+				)?.register_and_spawn(&metrics.registry)?;
 			}
 		}
 	}
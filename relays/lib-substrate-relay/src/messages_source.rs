@@ -24,6 +24,7 @@ use crate::{
 		SubstrateMessageLane,
 	},
 	on_demand::OnDemandRelay,
+	transactions_batcher::{SharedTransactionTracker, TransactionsBatcher},
 	TransactionParams,
 };
 
@@ -35,7 +36,9 @@ use bp_messages::{
 	OutboundLaneData, OutboundMessageDetails,
 };
 use bp_runtime::{BasicOperatingMode, HeaderIdProvider};
-use bridge_runtime_common::messages::target::FromBridgedChainMessagesProof;
+use bridge_runtime_common::messages::target::{
+	FromBridgedChainLaneMessages, FromBridgedChainMessagesProof, MAX_LANES_IN_PROOF,
+};
 use codec::Encode;
 use frame_support::weights::Weight;
 use messages_relay::{
@@ -44,12 +47,11 @@ use messages_relay::{
 		ClientState, MessageDetails, MessageDetailsMap, MessageProofParameters, SourceClient,
 		SourceClientState,
 	},
+	multi_lane_loop::MultiLaneSourceClient,
 };
-use num_traits::Zero;
 use relay_substrate_client::{
 	AccountIdOf, AccountKeyPairOf, BalanceOf, Chain, ChainWithMessages, Client,
-	Error as SubstrateError, HashOf, HeaderIdOf, TransactionEra, TransactionTracker,
-	UnsignedTransaction,
+	Error as SubstrateError, HashOf, HeaderIdOf, SignerPoolOf, TransactionEra, UnsignedTransaction,
 };
 use relay_utils::relay_loop::Client as RelayClient;
 use sp_core::Pair;
@@ -66,7 +68,8 @@ pub struct SubstrateMessagesSource<P: SubstrateMessageLane> {
 	source_client: Client<P::SourceChain>,
 	target_client: Client<P::TargetChain>,
 	lane_id: LaneId,
-	transaction_params: TransactionParams<AccountKeyPairOf<P::SourceChain>>,
+	transaction_params: TransactionParams<SignerPoolOf<P::SourceChain>>,
+	transactions_batcher: Option<TransactionsBatcher<P::SourceChain>>,
 	target_to_source_headers_relay: Option<Arc<dyn OnDemandRelay<P::TargetChain, P::SourceChain>>>,
 }
 
@@ -76,7 +79,8 @@ impl<P: SubstrateMessageLane> SubstrateMessagesSource<P> {
 		source_client: Client<P::SourceChain>,
 		target_client: Client<P::TargetChain>,
 		lane_id: LaneId,
-		transaction_params: TransactionParams<AccountKeyPairOf<P::SourceChain>>,
+		transaction_params: TransactionParams<SignerPoolOf<P::SourceChain>>,
+		transactions_batcher: Option<TransactionsBatcher<P::SourceChain>>,
 		target_to_source_headers_relay: Option<
 			Arc<dyn OnDemandRelay<P::TargetChain, P::SourceChain>>,
 		>,
@@ -86,6 +90,7 @@ impl<P: SubstrateMessageLane> SubstrateMessagesSource<P> {
 			target_client,
 			lane_id,
 			transaction_params,
+			transactions_batcher,
 			target_to_source_headers_relay,
 		}
 	}
@@ -119,6 +124,7 @@ impl<P: SubstrateMessageLane> Clone for SubstrateMessagesSource<P> {
 			target_client: self.target_client.clone(),
 			lane_id: self.lane_id,
 			transaction_params: self.transaction_params.clone(),
+			transactions_batcher: self.transactions_batcher.clone(),
 			target_to_source_headers_relay: self.target_to_source_headers_relay.clone(),
 		}
 	}
@@ -141,7 +147,7 @@ where
 {
 	type BatchTransaction =
 		BatchProofTransaction<P::SourceChain, P::TargetChain, P::SourceBatchCallBuilder>;
-	type TransactionTracker = TransactionTracker<P::SourceChain, Client<P::SourceChain>>;
+	type TransactionTracker = SharedTransactionTracker<P::SourceChain>;
 
 	async fn state(&self) -> Result<SourceClientState<MessageLaneAdapter<P>>, SubstrateError> {
 		// we can't continue to deliver confirmations if source node is out of sync, because
@@ -269,7 +275,7 @@ where
 				MessageDetails {
 					dispatch_weight: out_msg_details.dispatch_weight,
 					size: out_msg_details.size as _,
-					reward: Zero::zero(),
+					reward: P::DeliveryReward::get(),
 				},
 			);
 		}
@@ -290,38 +296,12 @@ where
 		),
 		SubstrateError,
 	> {
-		let mut storage_keys =
-			Vec::with_capacity(nonces.end().saturating_sub(*nonces.start()) as usize + 1);
-		let mut message_nonce = *nonces.start();
-		while message_nonce <= *nonces.end() {
-			let message_key = bp_messages::storage_keys::message_key(
-				P::TargetChain::WITH_CHAIN_MESSAGES_PALLET_NAME,
-				&self.lane_id,
-				message_nonce,
-			);
-			storage_keys.push(message_key);
-			message_nonce += 1;
-		}
-		if proof_parameters.outbound_state_proof_required {
-			storage_keys.push(bp_messages::storage_keys::outbound_lane_data_key(
-				P::TargetChain::WITH_CHAIN_MESSAGES_PALLET_NAME,
-				&self.lane_id,
-			));
-		}
-
-		let proof = self
-			.source_client
-			.prove_storage(storage_keys, id.1)
-			.await?
-			.into_iter_nodes()
-			.collect();
-		let proof = FromBridgedChainMessagesProof {
-			bridged_header_hash: id.1,
-			storage_proof: proof,
-			lane: self.lane_id,
-			nonces_start: *nonces.start(),
-			nonces_end: *nonces.end(),
-		};
+		let proof = prove_lanes_messages::<P>(
+			&self.source_client,
+			id,
+			vec![(self.lane_id, nonces.clone(), proof_parameters.outbound_state_proof_required)],
+		)
+		.await?;
 		Ok((id, nonces, (proof_parameters.dispatch_weight, proof)))
 	}
 
@@ -331,6 +311,12 @@ where
 		_generated_at_block: TargetHeaderIdOf<MessageLaneAdapter<P>>,
 		proof: <MessageLaneAdapter<P> as MessageLane>::MessagesReceivingProof,
 	) -> Result<Self::TransactionTracker, SubstrateError> {
+		// batch transactions (with header proofs) are never combined with other calls
+		let weight = maybe_batch_tx.is_none().then(|| {
+			<P::SourceChain as ChainWithMessages>::WeightInfo::receive_messages_delivery_proof_weight(
+				&proof.1, &proof.0,
+			)
+		});
 		let messages_proof_call =
 			P::ReceiveMessagesDeliveryProofCallBuilder::build_receive_messages_delivery_proof_call(
 				proof,
@@ -341,16 +327,22 @@ where
 			None => messages_proof_call,
 		};
 
+		if let Some(ref transactions_batcher) = self.transactions_batcher {
+			return transactions_batcher.submit(final_call, weight).await
+		}
+
 		let transaction_params = self.transaction_params.clone();
 		self.source_client
 			.submit_and_watch_signed_extrinsic(
-				&self.transaction_params.signer,
+				&self.transaction_params.signer.select(),
+				self.transaction_params.tip_escalation,
 				move |best_block_id, transaction_nonce| {
 					Ok(UnsignedTransaction::new(final_call.into(), transaction_nonce)
 						.era(TransactionEra::new(best_block_id, transaction_params.mortality)))
 				},
 			)
 			.await
+			.map(SharedTransactionTracker::new)
 	}
 
 	async fn require_target_header_on_source(
@@ -371,6 +363,88 @@ where
 	}
 }
 
+#[async_trait]
+impl<P: SubstrateMessageLane> MultiLaneSourceClient<MessageLaneAdapter<P>>
+	for SubstrateMessagesSource<P>
+where
+	AccountIdOf<P::SourceChain>: From<<AccountKeyPairOf<P::SourceChain> as Pair>::Public>,
+{
+	type LaneClient = Self;
+
+	async fn state(&self) -> Result<SourceClientState<MessageLaneAdapter<P>>, SubstrateError> {
+		SourceClient::state(self).await
+	}
+
+	fn lane_client(&self, lane: LaneId) -> Self {
+		let mut lane_client = self.clone();
+		lane_client.lane_id = lane;
+		lane_client
+	}
+}
+
+/// Prove messages of several lanes at given source chain block.
+///
+/// Every item of `lanes` is the lane id, range of messages nonces that needs to be proved
+/// and a flag, telling whether the outbound lane state needs to be proved. All lanes are
+/// covered by the single storage proof, so the target chain is paying for finality and
+/// proof overhead only once. The first lane becomes the primary lane of the proof. At most
+/// `MAX_LANES_IN_PROOF` lanes may be proved at once.
+pub async fn prove_lanes_messages<P: SubstrateMessageLane>(
+	source_client: &Client<P::SourceChain>,
+	id: HeaderIdOf<P::SourceChain>,
+	lanes: Vec<(LaneId, RangeInclusive<MessageNonce>, bool)>,
+) -> Result<FromBridgedChainMessagesProof<HashOf<P::SourceChain>>, SubstrateError> {
+	if lanes.is_empty() || lanes.len() > MAX_LANES_IN_PROOF as usize {
+		return Err(SubstrateError::Custom(format!(
+			"Unable to prove messages of {} lanes in single proof. Maximal number of lanes is {}",
+			lanes.len(),
+			MAX_LANES_IN_PROOF,
+		)))
+	}
+
+	let mut storage_keys = Vec::new();
+	let mut proved_lanes = Vec::with_capacity(lanes.len());
+	for (lane, nonces, outbound_state_proof_required) in lanes {
+		let mut message_nonce = *nonces.start();
+		while message_nonce <= *nonces.end() {
+			let message_key = bp_messages::storage_keys::message_key(
+				P::TargetChain::WITH_CHAIN_MESSAGES_PALLET_NAME,
+				&lane,
+				message_nonce,
+			);
+			storage_keys.push(message_key);
+			message_nonce += 1;
+		}
+		if outbound_state_proof_required {
+			storage_keys.push(bp_messages::storage_keys::outbound_lane_data_key(
+				P::TargetChain::WITH_CHAIN_MESSAGES_PALLET_NAME,
+				&lane,
+			));
+		}
+
+		proved_lanes.push(FromBridgedChainLaneMessages {
+			lane,
+			nonces_start: *nonces.start(),
+			nonces_end: *nonces.end(),
+		});
+	}
+	let primary_lane = proved_lanes.remove(0);
+
+	let proof = source_client
+		.prove_storage(storage_keys, id.1)
+		.await?
+		.into_iter_nodes()
+		.collect();
+	Ok(FromBridgedChainMessagesProof {
+		bridged_header_hash: id.1,
+		storage_proof: proof,
+		lane: primary_lane.lane,
+		nonces_start: primary_lane.nonces_start,
+		nonces_end: primary_lane.nonces_end,
+		additional_lanes: proved_lanes.try_into().expect("number of lanes is checked above; qed"),
+	})
+}
+
 /// Ensure that the messages pallet at source chain is active.
 pub(crate) async fn ensure_messages_pallet_active<AtChain, WithChain>(
 	client: &Client<AtChain>,
@@ -19,12 +19,14 @@
 //! <BridgedName> chain.
 
 use crate::{
+	messages_delivery_combiner::MessagesDeliveryCombiner,
 	messages_lane::{
-		BatchProofTransaction, MessageLaneAdapter, ReceiveMessagesProofCallBuilder,
-		SubstrateMessageLane,
+		BatchProofTransaction, DeliveryProfitabilityParams, MessageLaneAdapter,
+		ReceiveMessagesProofCallBuilder, SubstrateMessageLane,
 	},
 	messages_source::{ensure_messages_pallet_active, read_client_state, SubstrateMessagesProof},
 	on_demand::OnDemandRelay,
+	transactions_batcher::{SharedTransactionTracker, TransactionsBatcher},
 	TransactionParams,
 };
 
@@ -32,19 +34,27 @@ use async_std::sync::Arc;
 use async_trait::async_trait;
 use bp_messages::{
 	storage_keys::inbound_lane_data_key, total_unrewarded_messages, InboundLaneData, LaneId,
-	MessageNonce, UnrewardedRelayersState,
+	MessageNonce, UnrewardedRelayersState, Weight,
 };
-use bridge_runtime_common::messages::source::FromBridgedChainMessagesDeliveryProof;
+use bridge_runtime_common::messages::{
+	source::FromBridgedChainMessagesDeliveryProof, target::FromBridgedChainMessagesProof,
+};
+use codec::Encode;
 use messages_relay::{
 	message_lane::{MessageLane, SourceHeaderIdOf, TargetHeaderIdOf},
-	message_lane_loop::{NoncesSubmitArtifacts, TargetClient, TargetClientState},
+	message_lane_loop::{
+		DeliveryTransactionCost, NoncesSubmitArtifacts, TargetClient, TargetClientState,
+	},
+	multi_lane_loop::MultiLaneTargetClient,
 };
+use num_traits::Bounded;
 use relay_substrate_client::{
-	AccountIdOf, AccountKeyPairOf, BalanceOf, CallOf, ChainWithMessages, Client,
-	Error as SubstrateError, HashOf, TransactionEra, TransactionTracker, UnsignedTransaction,
+	AccountIdOf, AccountKeyPairOf, BalanceOf, CallOf, Chain, ChainWithMessages, Client,
+	Error as SubstrateError, HashOf, SignParam, SignerPoolOf, TransactionEra, UnsignedTransaction,
 };
 use relay_utils::relay_loop::Client as RelayClient;
-use sp_core::Pair;
+use sp_core::{Bytes, Pair};
+use sp_runtime::{FixedPointNumber, FixedU128, PerThing};
 use std::{collections::VecDeque, convert::TryFrom, ops::RangeInclusive};
 
 /// Message receiving proof returned by the target Substrate node.
@@ -57,7 +67,10 @@ pub struct SubstrateMessagesTarget<P: SubstrateMessageLane> {
 	source_client: Client<P::SourceChain>,
 	lane_id: LaneId,
 	relayer_id_at_source: AccountIdOf<P::SourceChain>,
-	transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
+	transaction_params: TransactionParams<SignerPoolOf<P::TargetChain>>,
+	profitability_params: Option<DeliveryProfitabilityParams>,
+	transactions_batcher: Option<TransactionsBatcher<P::TargetChain>>,
+	delivery_combiner: Option<MessagesDeliveryCombiner<P>>,
 	source_to_target_headers_relay: Option<Arc<dyn OnDemandRelay<P::SourceChain, P::TargetChain>>>,
 }
 
@@ -68,7 +81,10 @@ impl<P: SubstrateMessageLane> SubstrateMessagesTarget<P> {
 		source_client: Client<P::SourceChain>,
 		lane_id: LaneId,
 		relayer_id_at_source: AccountIdOf<P::SourceChain>,
-		transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
+		transaction_params: TransactionParams<SignerPoolOf<P::TargetChain>>,
+		profitability_params: Option<DeliveryProfitabilityParams>,
+		transactions_batcher: Option<TransactionsBatcher<P::TargetChain>>,
+		delivery_combiner: Option<MessagesDeliveryCombiner<P>>,
 		source_to_target_headers_relay: Option<
 			Arc<dyn OnDemandRelay<P::SourceChain, P::TargetChain>>,
 		>,
@@ -79,6 +95,9 @@ impl<P: SubstrateMessageLane> SubstrateMessagesTarget<P> {
 			lane_id,
 			relayer_id_at_source,
 			transaction_params,
+			profitability_params,
+			transactions_batcher,
+			delivery_combiner,
 			source_to_target_headers_relay,
 		}
 	}
@@ -113,6 +132,9 @@ impl<P: SubstrateMessageLane> Clone for SubstrateMessagesTarget<P> {
 			lane_id: self.lane_id,
 			relayer_id_at_source: self.relayer_id_at_source.clone(),
 			transaction_params: self.transaction_params.clone(),
+			profitability_params: self.profitability_params,
+			transactions_batcher: self.transactions_batcher.clone(),
+			delivery_combiner: self.delivery_combiner.clone(),
 			source_to_target_headers_relay: self.source_to_target_headers_relay.clone(),
 		}
 	}
@@ -136,7 +158,7 @@ where
 {
 	type BatchTransaction =
 		BatchProofTransaction<P::TargetChain, P::SourceChain, P::TargetBatchCallBuilder>;
-	type TransactionTracker = TransactionTracker<P::TargetChain, Client<P::TargetChain>>;
+	type TransactionTracker = SharedTransactionTracker<P::TargetChain>;
 
 	async fn state(&self) -> Result<TargetClientState<MessageLaneAdapter<P>>, SubstrateError> {
 		// we can't continue to deliver confirmations if source node is out of sync, because
@@ -231,13 +253,27 @@ where
 	async fn submit_messages_proof(
 		&self,
 		maybe_batch_tx: Option<Self::BatchTransaction>,
-		_generated_at_header: SourceHeaderIdOf<MessageLaneAdapter<P>>,
+		generated_at_header: SourceHeaderIdOf<MessageLaneAdapter<P>>,
 		nonces: RangeInclusive<MessageNonce>,
 		proof: <MessageLaneAdapter<P> as MessageLane>::MessagesProof,
 	) -> Result<NoncesSubmitArtifacts<Self::TransactionTracker>, SubstrateError> {
+		// deliveries of different lanes may be combined into single transaction
+		if let (None, Some(ref delivery_combiner)) = (&maybe_batch_tx, &self.delivery_combiner) {
+			let tx_tracker = delivery_combiner.submit(generated_at_header, proof).await?;
+			return Ok(NoncesSubmitArtifacts { nonces, tx_tracker })
+		}
+
+		// batch transactions (with header proofs) are never combined with other calls
+		let weight = maybe_batch_tx.is_none().then(|| {
+			<P::TargetChain as ChainWithMessages>::WeightInfo::receive_messages_proof_weight(
+				&proof.1,
+				proof.1.lanes_count(),
+				proof.1.messages_count() as _,
+				proof.0,
+			)
+		});
 		let messages_proof_call = make_messages_delivery_call::<P>(
 			self.relayer_id_at_source.clone(),
-			proof.1.nonces_start..=proof.1.nonces_end,
 			proof,
 			maybe_batch_tx.is_none(),
 		);
@@ -246,18 +282,80 @@ where
 			None => messages_proof_call,
 		};
 
+		if let Some(ref transactions_batcher) = self.transactions_batcher {
+			let tx_tracker = transactions_batcher.submit(final_call, weight).await?;
+			return Ok(NoncesSubmitArtifacts { nonces, tx_tracker })
+		}
+
 		let transaction_params = self.transaction_params.clone();
 		let tx_tracker = self
 			.target_client
 			.submit_and_watch_signed_extrinsic(
-				&self.transaction_params.signer,
+				&self.transaction_params.signer.select(),
+				self.transaction_params.tip_escalation,
 				move |best_block_id, transaction_nonce| {
 					Ok(UnsignedTransaction::new(final_call.into(), transaction_nonce)
 						.era(TransactionEra::new(best_block_id, transaction_params.mortality)))
 				},
 			)
 			.await?;
-		Ok(NoncesSubmitArtifacts { nonces, tx_tracker })
+		Ok(NoncesSubmitArtifacts { nonces, tx_tracker: SharedTransactionTracker::new(tx_tracker) })
+	}
+
+	async fn estimate_delivery_transaction_in_source_tokens(
+		&self,
+		nonces: RangeInclusive<MessageNonce>,
+		total_dispatch_weight: Weight,
+		total_size: u32,
+	) -> Result<DeliveryTransactionCost<BalanceOf<P::SourceChain>>, SubstrateError> {
+		let profitability_params = self.profitability_params.ok_or_else(|| {
+			SubstrateError::Custom(format!(
+				"Delivery profitability parameters of {} -> {} messages relay are not configured",
+				P::SourceChain::NAME,
+				P::TargetChain::NAME,
+			))
+		})?;
+
+		// prepare 'dummy' delivery transaction - we only care about its length and dispatch weight
+		let delivery_call = make_messages_delivery_call::<P>(
+			self.relayer_id_at_source.clone(),
+			prepare_dummy_messages_proof::<P::SourceChain>(
+				nonces,
+				total_dispatch_weight,
+				total_size,
+			),
+			false,
+		);
+		let runtime_version = self.target_client.simple_runtime_version().await?;
+		let delivery_tx = self
+			.target_client
+			.sign_transaction(
+				SignParam {
+					spec_version: runtime_version.spec_version,
+					transaction_version: runtime_version.transaction_version,
+					genesis_hash: *self.target_client.genesis_hash(),
+					signer: self.transaction_params.signer.primary().clone(),
+				},
+				UnsignedTransaction::new(delivery_call.into(), Default::default()),
+			)
+			.await?;
+		let delivery_tx_fee = self
+			.target_client
+			.estimate_extrinsic_fee(Bytes(delivery_tx.encode()))
+			.await?
+			.inclusion_fee();
+		let delivery_tx_refund = profitability_params.refunded_fee_share.mul_floor(delivery_tx_fee);
+
+		Ok(DeliveryTransactionCost {
+			fee: convert_target_tokens_to_source_tokens::<P::SourceChain, P::TargetChain>(
+				profitability_params.target_to_source_conversion_rate,
+				delivery_tx_fee,
+			),
+			refund: convert_target_tokens_to_source_tokens::<P::SourceChain, P::TargetChain>(
+				profitability_params.target_to_source_conversion_rate,
+				delivery_tx_refund,
+			),
+		})
 	}
 
 	async fn require_source_header_on_target(
@@ -278,14 +376,35 @@ where
 	}
 }
 
+#[async_trait]
+impl<P: SubstrateMessageLane> MultiLaneTargetClient<MessageLaneAdapter<P>>
+	for SubstrateMessagesTarget<P>
+where
+	AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
+	BalanceOf<P::SourceChain>: TryFrom<BalanceOf<P::TargetChain>>,
+{
+	type LaneClient = Self;
+
+	async fn state(&self) -> Result<TargetClientState<MessageLaneAdapter<P>>, SubstrateError> {
+		TargetClient::state(self).await
+	}
+
+	fn lane_client(&self, lane: LaneId) -> Self {
+		// lane clients are sharing the same delivery combiner and transactions batcher, so
+		// deliveries of all lanes end up in the same transactions stream
+		let mut lane_client = self.clone();
+		lane_client.lane_id = lane;
+		lane_client
+	}
+}
+
 /// Make messages delivery call from given proof.
-fn make_messages_delivery_call<P: SubstrateMessageLane>(
+pub(crate) fn make_messages_delivery_call<P: SubstrateMessageLane>(
 	relayer_id_at_source: AccountIdOf<P::SourceChain>,
-	nonces: RangeInclusive<MessageNonce>,
 	proof: SubstrateMessagesProof<P::SourceChain>,
 	trace_call: bool,
 ) -> CallOf<P::TargetChain> {
-	let messages_count = nonces.end() - nonces.start() + 1;
+	let messages_count = proof.1.messages_count();
 	let dispatch_weight = proof.0;
 	P::ReceiveMessagesProofCallBuilder::build_receive_messages_proof_call(
 		relayer_id_at_source,
@@ -295,3 +414,81 @@ fn make_messages_delivery_call<P: SubstrateMessageLane>(
 		trace_call,
 	)
 }
+
+/// Prepare 'dummy' messages proof that will compose the delivery transaction.
+///
+/// We don't care about proof actually being the valid proof, because its validity doesn't
+/// affect the call weight - we only care about its size.
+fn prepare_dummy_messages_proof<SC: Chain>(
+	nonces: RangeInclusive<MessageNonce>,
+	total_dispatch_weight: Weight,
+	total_size: u32,
+) -> SubstrateMessagesProof<SC> {
+	(
+		total_dispatch_weight,
+		FromBridgedChainMessagesProof {
+			bridged_header_hash: Default::default(),
+			storage_proof: vec![vec![0; total_size as usize]],
+			lane: Default::default(),
+			nonces_start: *nonces.start(),
+			nonces_end: *nonces.end(),
+			additional_lanes: Default::default(),
+		},
+	)
+}
+
+/// Given delivery transaction fee in target chain tokens and conversion rate to the source
+/// chain tokens, compute transaction cost in source chain tokens.
+fn convert_target_tokens_to_source_tokens<SC: Chain, TC: Chain>(
+	target_to_source_conversion_rate: FixedU128,
+	target_transaction_fee: TC::Balance,
+) -> SC::Balance
+where
+	SC::Balance: TryFrom<TC::Balance>,
+{
+	SC::Balance::try_from(
+		target_to_source_conversion_rate.saturating_mul_int(target_transaction_fee),
+	)
+	.unwrap_or_else(|_| SC::Balance::max_value())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use relay_rialto_client::Rialto;
+
+	#[test]
+	fn convert_target_tokens_to_source_tokens_works() {
+		assert_eq!(
+			convert_target_tokens_to_source_tokens::<Rialto, Rialto>(
+				FixedU128::saturating_from_rational(150, 100),
+				1_000
+			),
+			1_500
+		);
+		assert_eq!(
+			convert_target_tokens_to_source_tokens::<Rialto, Rialto>(
+				FixedU128::saturating_from_rational(50, 100),
+				1_000
+			),
+			500
+		);
+		assert_eq!(
+			convert_target_tokens_to_source_tokens::<Rialto, Rialto>(
+				FixedU128::saturating_from_rational(100, 100),
+				1_000
+			),
+			1_000
+		);
+	}
+
+	#[test]
+	fn prepare_dummy_messages_proof_works() {
+		let (dispatch_weight, proof) =
+			prepare_dummy_messages_proof::<Rialto>(1..=10, Weight::from_ref_time(42), 1024);
+		assert_eq!(dispatch_weight, Weight::from_ref_time(42));
+		assert_eq!(proof.nonces_start, 1);
+		assert_eq!(proof.nonces_end, 10);
+		assert_eq!(proof.storage_proof, vec![vec![0; 1024]]);
+	}
+}
@@ -29,7 +29,7 @@ use sp_runtime::traits::Header;
 use finality_relay::{FinalitySyncParams, TargetClient as FinalityTargetClient};
 use relay_substrate_client::{
 	AccountIdOf, AccountKeyPairOf, BlockNumberOf, CallOf, Chain, Client, Error as SubstrateError,
-	HeaderIdOf,
+	HeaderIdOf, SignerPoolOf,
 };
 use relay_utils::{
 	metrics::MetricsParams, relay_loop::Client as RelayClient, FailedClient, MaybeConnectionError,
@@ -70,7 +70,7 @@ impl<P: SubstrateFinalitySyncPipeline> OnDemandHeadersRelay<P> {
 	pub fn new(
 		source_client: Client<P::SourceChain>,
 		target_client: Client<P::TargetChain>,
-		target_transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
+		target_transaction_params: TransactionParams<SignerPoolOf<P::TargetChain>>,
 		only_mandatory_headers: bool,
 		metrics_params: Option<MetricsParams>,
 	) -> Self
@@ -139,8 +139,12 @@ impl<P: SubstrateFinalitySyncPipeline> OnDemandRelay<P::SourceChain, P::TargetCh
 		);
 
 		// and then craft the submit-proof call
-		let call =
-			P::SubmitFinalityProofCallBuilder::build_submit_finality_proof_call(header, proof);
+		let context =
+			P::FinalityEngine::prepare_finality_proof_context(&self.source_client, &header, &proof)
+				.await?;
+		let call = P::SubmitFinalityProofCallBuilder::build_submit_finality_proof_call(
+			header, proof, context,
+		);
 
 		Ok((header_id, vec![call]))
 	}
@@ -150,7 +154,7 @@ impl<P: SubstrateFinalitySyncPipeline> OnDemandRelay<P::SourceChain, P::TargetCh
 async fn background_task<P: SubstrateFinalitySyncPipeline>(
 	source_client: Client<P::SourceChain>,
 	target_client: Client<P::TargetChain>,
-	target_transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
+	target_transaction_params: TransactionParams<SignerPoolOf<P::TargetChain>>,
 	only_mandatory_headers: bool,
 	required_header_number: RequiredHeaderNumberRef<P::SourceChain>,
 	metrics_params: Option<MetricsParams>,
@@ -163,8 +167,11 @@ async fn background_task<P: SubstrateFinalitySyncPipeline>(
 		source_client.clone(),
 		Some(required_header_number.clone()),
 	);
-	let mut finality_target =
-		SubstrateFinalityTarget::new(target_client.clone(), target_transaction_params);
+	let mut finality_target = SubstrateFinalityTarget::new(
+		source_client.clone(),
+		target_client.clone(),
+		target_transaction_params,
+	);
 	let mut latest_non_mandatory_at_source = Zero::zero();
 
 	let mut restart_relay = true;
@@ -315,6 +322,7 @@ async fn background_task<P: SubstrateFinalitySyncPipeline>(
 						recent_finality_proofs_limit: RECENT_FINALITY_PROOFS_LIMIT,
 						stall_timeout,
 						only_mandatory_headers,
+						state_store: None,
 					},
 					metrics_params.clone().unwrap_or_else(MetricsParams::disabled),
 					futures::future::pending(),
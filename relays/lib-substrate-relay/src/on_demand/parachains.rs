@@ -41,7 +41,7 @@ use parachains_relay::parachains_loop::{
 };
 use relay_substrate_client::{
 	is_ancient_block, AccountIdOf, AccountKeyPairOf, BlockNumberOf, CallOf, Chain, Client,
-	Error as SubstrateError, HashOf, HeaderIdOf, ParachainBase,
+	Error as SubstrateError, HashOf, HeaderIdOf, ParachainBase, SignerPoolOf,
 };
 use relay_utils::{
 	metrics::MetricsParams, relay_loop::Client as RelayClient, BlockNumberBase, FailedClient,
@@ -78,7 +78,7 @@ impl<P: SubstrateParachainsPipeline> OnDemandParachainsRelay<P> {
 	pub fn new(
 		source_relay_client: Client<P::SourceRelayChain>,
 		target_client: Client<P::TargetChain>,
-		target_transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
+		target_transaction_params: TransactionParams<SignerPoolOf<P::TargetChain>>,
 		on_demand_source_relay_to_target_headers: Arc<
 			dyn OnDemandRelay<P::SourceRelayChain, P::TargetChain>,
 		>,
@@ -142,6 +142,7 @@ where
 		// select headers to prove
 		let parachains_source = ParachainsSource::<P>::new(
 			self.source_relay_client.clone(),
+			None,
 			Arc::new(Mutex::new(AvailableHeader::Missing)),
 		);
 		let env = (self, &parachains_source);
@@ -225,7 +226,7 @@ where
 async fn background_task<P: SubstrateParachainsPipeline>(
 	source_relay_client: Client<P::SourceRelayChain>,
 	target_client: Client<P::TargetChain>,
-	target_transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
+	target_transaction_params: TransactionParams<SignerPoolOf<P::TargetChain>>,
 	on_demand_source_relay_to_target_headers: Arc<
 		dyn OnDemandRelay<P::SourceRelayChain, P::TargetChain>,
 	>,
@@ -249,6 +250,7 @@ async fn background_task<P: SubstrateParachainsPipeline>(
 
 	let mut parachains_source = ParachainsSource::<P>::new(
 		source_relay_client.clone(),
+		None,
 		required_para_header_number_ref.clone(),
 	);
 	let mut parachains_target =
@@ -306,8 +308,8 @@ async fn background_task<P: SubstrateParachainsPipeline>(
 		//
 		// 7) on-demand parachains relay sets `ParachainsSource::maximal_header_number` to the
 		//    `PH'.number()`.
-		// 8) parachains finality relay sees that the parachain head has been
-		//    updated and relays `PH'` to    the target chain.
+		// 8) parachains finality relay sees that the parachain head has been updated and relays
+		//    `PH'` to    the target chain.
 
 		// select headers to relay
 		let relay_data = read_relay_data(
@@ -383,6 +385,8 @@ async fn background_task<P: SubstrateParachainsPipeline>(
 						parachains: vec![P::SourceParachain::PARACHAIN_ID.into()],
 						stall_timeout: std::time::Duration::from_secs(60),
 						strategy: parachains_relay::parachains_loop::ParachainSyncStrategy::Any,
+						batch_limits: None,
+						state_store: None,
 					},
 					MetricsParams::disabled(),
 					futures::future::pending(),
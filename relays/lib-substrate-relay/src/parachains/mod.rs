@@ -19,11 +19,13 @@
 
 use async_trait::async_trait;
 use bp_polkadot_core::parachains::{ParaHash, ParaHeadsProof, ParaId};
+use frame_support::weights::{RuntimeDbWeight, Weight};
 use pallet_bridge_parachains::{
-	Call as BridgeParachainsCall, Config as BridgeParachainsConfig, RelayBlockHash,
-	RelayBlockHasher, RelayBlockNumber,
+	weights_ext::DEFAULT_PARACHAIN_HEAD_SIZE, Call as BridgeParachainsCall,
+	Config as BridgeParachainsConfig, RelayBlockHash, RelayBlockHasher, RelayBlockNumber,
+	WeightInfoExt,
 };
-use parachains_relay::ParachainsPipeline;
+use parachains_relay::{parachains_batch::ParachainsBatchLimits, ParachainsPipeline};
 use relay_substrate_client::{
 	CallOf, Chain, ChainWithTransactions, HeaderIdOf, Parachain, RelayChain,
 };
@@ -34,8 +36,9 @@ pub mod target;
 
 /// Substrate -> Substrate parachain finality proofs synchronization pipeline.
 ///
-/// This is currently restricted to the single parachain, because it is how it
-/// will be used (at least) initially.
+/// Heads of the `Self::SourceParachain` are always synchronized. The pipeline may also be
+/// used to synchronize heads of other parachains of the same relay chain (see
+/// `source::ParachainsSource::new`).
 #[async_trait]
 pub trait SubstrateParachainsPipeline: 'static + Clone + Debug + Send + Sync {
 	/// Headers of this parachain are submitted to the `Self::TargetChain`.
@@ -47,6 +50,8 @@ pub trait SubstrateParachainsPipeline: 'static + Clone + Debug + Send + Sync {
 
 	/// How submit parachains heads call is built?
 	type SubmitParachainHeadsCallBuilder: SubmitParachainHeadsCallBuilder<Self>;
+	/// Weights of the bridge parachains pallet at the `Self::TargetChain` runtime.
+	type TargetParachainsWeightInfo: WeightInfoExt;
 }
 
 /// Adapter that allows all `SubstrateParachainsPipeline` to act as `ParachainsPipeline`.
@@ -105,3 +110,75 @@ where
 		.into()
 	}
 }
+
+/// Returns parachain heads batch limits, based on given chain parameters and weights of the
+/// `pallet-bridge-parachains` at the target chain.
+pub fn select_parachains_batch_limits<W: WeightInfoExt>(
+	db_weight: RuntimeDbWeight,
+	max_extrinsic_size: u32,
+	max_extrinsic_weight: Weight,
+	max_parachains: u32,
+) -> ParachainsBatchLimits {
+	// same as with messages: 1/3 of max tx size and weight is reserved for transaction overhead
+	// and for other calls that may be batched with parachain heads (e.g. messages delivery)
+	let max_proof_size = max_extrinsic_size / 3 * 2;
+	let max_weight = (max_extrinsic_weight / 3).saturating_mul(2);
+
+	// every head may cause pruning or parachain deregistration
+	let head_overhead_weight = W::parachain_head_pruning_weight(db_weight)
+		.saturating_add(W::parachain_deregistration_weight(db_weight));
+	let single_head_weight = W::submit_parachain_heads_with_n_parachains(1);
+	let head_weight = W::submit_parachain_heads_with_n_parachains(2)
+		.saturating_sub(single_head_weight)
+		.saturating_add(head_overhead_weight);
+	let base_weight = single_head_weight
+		.saturating_add(head_overhead_weight)
+		.saturating_sub(head_weight);
+
+	ParachainsBatchLimits {
+		max_parachains,
+		max_proof_size,
+		max_weight,
+		expected_extra_proof_size: W::expected_extra_storage_proof_size(),
+		expected_head_proof_size: DEFAULT_PARACHAIN_HEAD_SIZE,
+		base_weight,
+		head_weight,
+		extra_proof_byte_weight: W::storage_proof_size_overhead(1),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bp_runtime::Chain;
+	use frame_support::{traits::Get, weights::constants::RocksDbWeight};
+
+	type RialtoParachainsWeights =
+		pallet_bridge_parachains::weights::BridgeWeight<rialto_runtime::Runtime>;
+
+	#[test]
+	fn select_parachains_batch_limits_is_sane() {
+		let limits = select_parachains_batch_limits::<RialtoParachainsWeights>(
+			RocksDbWeight::get(),
+			bp_rialto_parachain::RialtoParachain::max_extrinsic_size(),
+			bp_rialto_parachain::RialtoParachain::max_extrinsic_weight(),
+			64,
+		);
+
+		// expected weight of batch must match the weight, computed by the pallet
+		for parachains_count in 1..=limits.max_parachains_in_batch() {
+			let proof_size = limits.expected_proof_size(parachains_count);
+			assert_eq!(
+				limits.expected_weight(parachains_count, proof_size),
+				RialtoParachainsWeights::submit_parachain_heads_weight(
+					RocksDbWeight::get(),
+					&bp_runtime::PreComputedSize(proof_size as usize),
+					parachains_count,
+				),
+			);
+		}
+
+		// we want to deliver more than one parachain head in a single transaction
+		assert!(limits.max_parachains_in_batch() > 1);
+	}
+}
@@ -33,6 +33,7 @@ use relay_substrate_client::{
 	RelayChain,
 };
 use relay_utils::relay_loop::Client as RelayClient;
+use std::collections::BTreeSet;
 
 /// Shared updatable reference to the maximal parachain header id that we want to sync from the
 /// source.
@@ -42,16 +43,24 @@ pub type RequiredHeaderIdRef<C> = Arc<Mutex<AvailableHeader<HeaderIdOf<C>>>>;
 #[derive(Clone)]
 pub struct ParachainsSource<P: SubstrateParachainsPipeline> {
 	client: Client<P::SourceRelayChain>,
+	parachains: BTreeSet<ParaId>,
 	max_head_id: RequiredHeaderIdRef<P::SourceParachain>,
 }
 
 impl<P: SubstrateParachainsPipeline> ParachainsSource<P> {
 	/// Creates new parachains source client.
+	///
+	/// The source is serving heads of the `P::SourceParachain` and of all given `parachains`.
+	/// The `max_head_id` only limits heads of the `P::SourceParachain`.
 	pub fn new(
 		client: Client<P::SourceRelayChain>,
+		parachains: impl IntoIterator<Item = ParaId>,
 		max_head_id: RequiredHeaderIdRef<P::SourceParachain>,
 	) -> Self {
-		ParachainsSource { client, max_head_id }
+		let parachains = std::iter::once(ParaId(P::SourceParachain::PARACHAIN_ID))
+			.chain(parachains)
+			.collect();
+		ParachainsSource { client, parachains, max_head_id }
 	}
 
 	/// Returns reference to the underlying RPC client.
@@ -65,17 +74,40 @@ impl<P: SubstrateParachainsPipeline> ParachainsSource<P> {
 		at_block: HeaderIdOf<P::SourceRelayChain>,
 		para_id: ParaId,
 	) -> Result<Option<HeaderIdOf<P::SourceParachain>>, SubstrateError> {
-		let storage_key =
-			parachain_head_storage_key_at_source(P::SourceRelayChain::PARAS_PALLET_NAME, para_id);
-		let para_head = self.client.raw_storage_value(storage_key, Some(at_block.1)).await?;
-		let para_head = para_head.map(|h| ParaHead::decode(&mut &h.0[..])).transpose()?;
-		let para_head = match para_head {
+		let para_head = match self.on_chain_para_head(at_block, para_id).await? {
 			Some(para_head) => para_head,
 			None => return Ok(None),
 		};
 		let para_head: HeaderOf<P::SourceParachain> = Decode::decode(&mut &para_head.0[..])?;
 		Ok(Some(para_head.id()))
 	}
+
+	/// Return raw head of given parachain.
+	async fn on_chain_para_head(
+		&self,
+		at_block: HeaderIdOf<P::SourceRelayChain>,
+		para_id: ParaId,
+	) -> Result<Option<ParaHead>, SubstrateError> {
+		let storage_key =
+			parachain_head_storage_key_at_source(P::SourceRelayChain::PARAS_PALLET_NAME, para_id);
+		let para_head = self.client.raw_storage_value(storage_key, Some(at_block.1)).await?;
+		para_head
+			.map(|h| ParaHead::decode(&mut &h.0[..]))
+			.transpose()
+			.map_err(Into::into)
+	}
+
+	/// Ensure that the source is configured to serve heads of given parachain.
+	fn ensure_parachain_is_configured(&self, para_id: ParaId) -> Result<(), SubstrateError> {
+		if !self.parachains.contains(&para_id) {
+			return Err(SubstrateError::Custom(format!(
+				"Parachain {:?} is not among configured parachains {:?}",
+				para_id, self.parachains,
+			)))
+		}
+
+		Ok(())
+	}
 }
 
 #[async_trait]
@@ -107,14 +139,7 @@ where
 		metrics: Option<&ParachainsLoopMetrics>,
 		para_id: ParaId,
 	) -> Result<AvailableHeader<ParaHash>, Self::Error> {
-		// we don't need to support many parachains now
-		if para_id.0 != P::SourceParachain::PARACHAIN_ID {
-			return Err(SubstrateError::Custom(format!(
-				"Parachain id {} is not matching expected {}",
-				para_id.0,
-				P::SourceParachain::PARACHAIN_ID,
-			)))
-		}
+		self.ensure_parachain_is_configured(para_id)?;
 
 		// if requested relay header is ancient, then we don't even want to try to read the
 		// parachain head - we simply return `Unavailable`
@@ -123,6 +148,15 @@ where
 			return Ok(AvailableHeader::Unavailable)
 		}
 
+		// we can't decode headers of other parachains, so we only limit and report heads of the
+		// `P::SourceParachain`
+		if para_id.0 != P::SourceParachain::PARACHAIN_ID {
+			return Ok(match self.on_chain_para_head(at_block, para_id).await? {
+				Some(para_head) => AvailableHeader::Available(para_head.hash()),
+				None => AvailableHeader::Missing,
+			})
+		}
+
 		// else - try to read head from the source client
 		let mut para_head_id = AvailableHeader::Missing;
 		if let Some(on_chain_para_head_id) = self.on_chain_para_head_id(at_block, para_id).await? {
@@ -153,43 +187,55 @@ where
 		at_block: HeaderIdOf<P::SourceRelayChain>,
 		parachains: &[ParaId],
 	) -> Result<(ParaHeadsProof, Vec<ParaHash>), Self::Error> {
-		let parachain = ParaId(P::SourceParachain::PARACHAIN_ID);
-		if parachains != [parachain] {
-			return Err(SubstrateError::Custom(format!(
-				"Trying to prove unexpected parachains {parachains:?}. Expected {parachain:?}",
-			)))
+		for parachain in parachains {
+			self.ensure_parachain_is_configured(*parachain)?;
 		}
 
-		let parachain = parachains[0];
-		let storage_key =
-			parachain_head_storage_key_at_source(P::SourceRelayChain::PARAS_PALLET_NAME, parachain);
+		// all heads are proved using single storage proof
+		let storage_keys = parachains
+			.iter()
+			.map(|parachain| {
+				parachain_head_storage_key_at_source(
+					P::SourceRelayChain::PARAS_PALLET_NAME,
+					*parachain,
+				)
+			})
+			.collect::<Vec<_>>();
 		let parachain_heads_proof = self
 			.client
-			.prove_storage(vec![storage_key.clone()], at_block.1)
+			.prove_storage(storage_keys, at_block.1)
 			.await?
 			.into_iter_nodes()
 			.collect();
 
-		// why we're reading parachain head here once again (it has already been read at the
+		// why we're reading parachain heads here once again (they have already been read at the
 		// `parachain_head`)? that's because `parachain_head` sometimes returns obsolete parachain
 		// head and loop sometimes asks to prove this obsolete head and gets other (actual) head
 		// instead
 		//
 		// => since we want to provide proper hashes in our `submit_parachain_heads` call, we're
-		// rereading actual value here
-		let parachain_head = self
-			.client
-			.raw_storage_value(storage_key, Some(at_block.1))
-			.await?
-			.map(|h| ParaHead::decode(&mut &h.0[..]))
-			.transpose()?
-			.ok_or_else(|| {
-				SubstrateError::Custom(format!(
-					"Failed to read expected parachain {parachain:?} head at {at_block:?}"
-				))
-			})?;
-		let parachain_head_hash = parachain_head.hash();
-
-		Ok((ParaHeadsProof(parachain_heads_proof), vec![parachain_head_hash]))
+		// rereading actual values here
+		let mut parachain_head_hashes = Vec::with_capacity(parachains.len());
+		for parachain in parachains {
+			let parachain_head =
+				self.on_chain_para_head(at_block, *parachain).await?.ok_or_else(|| {
+					SubstrateError::Custom(format!(
+						"Failed to read expected parachain {parachain:?} head at {at_block:?}"
+					))
+				})?;
+			parachain_head_hashes.push(parachain_head.hash());
+		}
+
+		Ok((ParaHeadsProof(parachain_heads_proof), parachain_head_hashes))
+	}
+
+	async fn parachains_with_pending_messages(&self) -> Result<BTreeSet<ParaId>, Self::Error> {
+		// when `max_head_id` is set, it means that some other relay (e.g. messages relay)
+		// requires parachain head to be updated at the target chain
+		Ok(match *self.max_head_id.lock().await {
+			AvailableHeader::Available(_) =>
+				std::iter::once(ParaId(P::SourceParachain::PARACHAIN_ID)).collect(),
+			AvailableHeader::Unavailable | AvailableHeader::Missing => BTreeSet::new(),
+		})
 	}
 }
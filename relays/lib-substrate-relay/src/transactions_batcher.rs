@@ -0,0 +1,367 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Shared stream of transactions that are submitted to the same chain by several relay loops.
+//!
+//! All transactions are signed by the same signer and submitted sequentially, so relay loops
+//! that are sharing the batcher never compete for the same account nonce. Calls that are
+//! submitted at (almost) the same moment are combined into a single batch call, if the chain
+//! supports batching and the combined call fits into the chain transaction limits.
+
+use crate::{BatchCallBuilder, BatchCallBuilderConstructor, TransactionParams};
+
+use async_trait::async_trait;
+use codec::Encode;
+use frame_support::weights::Weight;
+use futures::{
+	channel::{
+		mpsc::{unbounded, UnboundedReceiver, UnboundedSender},
+		oneshot,
+	},
+	future::{BoxFuture, FutureExt, Shared},
+	StreamExt,
+};
+use relay_substrate_client::{
+	AccountIdOf, AccountKeyPairOf, CallOf, Chain, ChainWithTransactions, Client,
+	Error as SubstrateError, HeaderIdOf, SignerPoolOf, TransactionEra, UnsignedTransaction,
+};
+use relay_utils::{MaybeConnectionError, TrackedTransactionStatus, TransactionTracker};
+use sp_core::Pair;
+use std::time::Duration;
+
+/// Time, during which the batcher collects calls before submitting them.
+///
+/// Relay loops are usually submitting their transactions right after receiving the same
+/// chain state update, so it doesn't need to be large.
+pub const TRANSACTIONS_BATCH_WINDOW: Duration = Duration::from_millis(500);
+
+/// Shared stream of transactions, submitted to the chain `C`.
+pub struct TransactionsBatcher<C: Chain> {
+	calls_sender: UnboundedSender<PendingCall<C>>,
+}
+
+impl<C: Chain> Clone for TransactionsBatcher<C> {
+	fn clone(&self) -> Self {
+		TransactionsBatcher { calls_sender: self.calls_sender.clone() }
+	}
+}
+
+impl<C: ChainWithTransactions> TransactionsBatcher<C>
+where
+	AccountIdOf<C>: From<<AccountKeyPairOf<C> as Pair>::Public>,
+{
+	/// Create new transactions batcher and start its background task.
+	///
+	/// Batch calls are built using `B`. If it doesn't support batching, all calls are submitted
+	/// as separate transactions.
+	pub fn new<B: BatchCallBuilderConstructor<CallOf<C>>>(
+		client: Client<C>,
+		transaction_params: TransactionParams<SignerPoolOf<C>>,
+	) -> Self {
+		let (calls_sender, calls_receiver) = unbounded();
+		async_std::task::spawn(background_task(
+			client,
+			transaction_params,
+			B::new_builder(),
+			calls_receiver,
+		));
+		TransactionsBatcher { calls_sender }
+	}
+
+	/// Submit given call to the chain.
+	///
+	/// If `weight` is `None`, the call is always submitted in a separate transaction. Otherwise
+	/// it is the (estimated) weight of the call, that is used to decide whether the call may be
+	/// combined with other calls.
+	pub async fn submit(
+		&self,
+		call: CallOf<C>,
+		weight: Option<Weight>,
+	) -> Result<SharedTransactionTracker<C>, SubstrateError> {
+		let (tracker_sender, tracker_receiver) = oneshot::channel();
+		self.calls_sender
+			.unbounded_send(PendingCall { call, weight, tracker_sender })
+			.map_err(|_| batcher_stopped_error::<C>())?;
+		tracker_receiver.await.map_err(|_| batcher_stopped_error::<C>())?
+	}
+}
+
+/// Transaction tracker that may be shared by several callers.
+///
+/// All calls that are combined into single batch transaction are tracked using the same
+/// tracker.
+pub struct SharedTransactionTracker<C: Chain> {
+	status: Shared<BoxFuture<'static, TrackedTransactionStatus<HeaderIdOf<C>>>>,
+}
+
+impl<C: Chain> SharedTransactionTracker<C> {
+	/// Create shared tracker from regular transaction tracker.
+	pub fn new(tracker: impl TransactionTracker<HeaderId = HeaderIdOf<C>> + 'static) -> Self {
+		SharedTransactionTracker { status: tracker.wait().boxed().shared() }
+	}
+}
+
+impl<C: Chain> Clone for SharedTransactionTracker<C> {
+	fn clone(&self) -> Self {
+		SharedTransactionTracker { status: self.status.clone() }
+	}
+}
+
+#[async_trait]
+impl<C: Chain> TransactionTracker for SharedTransactionTracker<C> {
+	type HeaderId = HeaderIdOf<C>;
+
+	async fn wait(self) -> TrackedTransactionStatus<HeaderIdOf<C>> {
+		self.status.await
+	}
+}
+
+/// Call, waiting for submission.
+struct PendingCall<C: Chain> {
+	/// The call itself.
+	call: CallOf<C>,
+	/// Estimated call weight.
+	weight: Option<Weight>,
+	/// Sender of the tracker of transaction that includes the call.
+	tracker_sender: TrackerSender<C>,
+}
+
+/// Sender of the tracker of transaction that includes the call.
+type TrackerSender<C> = oneshot::Sender<Result<SharedTransactionTracker<C>, SubstrateError>>;
+
+/// Error that is returned when batcher background task has been stopped.
+fn batcher_stopped_error<C: Chain>() -> SubstrateError {
+	SubstrateError::Custom(format!("{} transactions batcher has been stopped", C::NAME))
+}
+
+/// Background task of the transactions batcher.
+async fn background_task<C: ChainWithTransactions>(
+	mut client: Client<C>,
+	transaction_params: TransactionParams<SignerPoolOf<C>>,
+	batch_call_builder: Option<Box<dyn BatchCallBuilder<CallOf<C>>>>,
+	mut calls_receiver: UnboundedReceiver<PendingCall<C>>,
+) where
+	AccountIdOf<C>: From<<AccountKeyPairOf<C> as Pair>::Public>,
+{
+	while let Some(first_call) = calls_receiver.next().await {
+		// give other relay loops a chance to submit their calls
+		async_std::task::sleep(TRANSACTIONS_BATCH_WINDOW).await;
+
+		let mut pending_calls = vec![first_call];
+		while let Ok(Some(pending_call)) = calls_receiver.try_next() {
+			pending_calls.push(pending_call);
+		}
+
+		let batching_supported = batch_call_builder.is_some();
+		let batches = split_into_batches(
+			pending_calls,
+			|pending_call| {
+				pending_call
+					.weight
+					.filter(|_| batching_supported)
+					.map(|weight| (weight, pending_call.call.encoded_size() as u32))
+			},
+			C::max_extrinsic_weight(),
+			C::max_extrinsic_size(),
+		);
+		for batch in batches {
+			let calls_count = batch.len();
+			let (call, tracker_senders) = prepare_batch_call(batch_call_builder.as_deref(), batch);
+			submit_batch(&mut client, &transaction_params, call, calls_count, tracker_senders)
+				.await;
+		}
+	}
+}
+
+/// Build single call from the batch of pending calls.
+fn prepare_batch_call<C: Chain>(
+	batch_call_builder: Option<&dyn BatchCallBuilder<CallOf<C>>>,
+	batch: Vec<PendingCall<C>>,
+) -> (CallOf<C>, Vec<TrackerSender<C>>) {
+	let (calls, tracker_senders): (Vec<_>, Vec<_>) = batch
+		.into_iter()
+		.map(|pending_call| (pending_call.call, pending_call.tracker_sender))
+		.unzip();
+	let call = match batch_call_builder {
+		Some(builder) if calls.len() > 1 => builder.build_batch_call(calls),
+		_ => calls.into_iter().next().expect("batches are never empty; qed"),
+	};
+	(call, tracker_senders)
+}
+
+/// Submit batch of calls in single transaction.
+async fn submit_batch<C: ChainWithTransactions>(
+	client: &mut Client<C>,
+	transaction_params: &TransactionParams<SignerPoolOf<C>>,
+	call: CallOf<C>,
+	calls_count: usize,
+	tracker_senders: Vec<TrackerSender<C>>,
+) where
+	AccountIdOf<C>: From<<AccountKeyPairOf<C> as Pair>::Public>,
+{
+	log::trace!(
+		target: "bridge",
+		"Submitting {} transaction with {} relay call(s)",
+		C::NAME,
+		calls_count,
+	);
+
+	let mortality = transaction_params.mortality;
+	let result = client
+		.submit_and_watch_signed_extrinsic(
+			&transaction_params.signer.select(),
+			transaction_params.tip_escalation,
+			move |best_block_id, transaction_nonce| {
+				Ok(UnsignedTransaction::new(call.into(), transaction_nonce)
+					.era(TransactionEra::new(best_block_id, mortality)))
+			},
+		)
+		.await;
+	match result {
+		Ok(tracker) => {
+			let tracker = SharedTransactionTracker::new(tracker);
+			for tracker_sender in tracker_senders {
+				let _ = tracker_sender.send(Ok(tracker.clone()));
+			}
+		},
+		Err(error) => {
+			log::error!(
+				target: "bridge",
+				"Failed to submit {} transaction with {} relay call(s): {:?}",
+				C::NAME,
+				calls_count,
+				error,
+			);
+
+			for tracker_sender in tracker_senders {
+				let _ = tracker_sender.send(Err(SubstrateError::Custom(format!(
+					"Failed to submit {} transaction: {:?}",
+					C::NAME,
+					error,
+				))));
+			}
+
+			if error.is_connection_error() {
+				if let Err(error) = client.reconnect().await {
+					log::error!(
+						target: "bridge",
+						"Failed to reconnect to {} node: {:?}",
+						C::NAME,
+						error,
+					);
+				}
+			}
+		},
+	}
+}
+
+/// Split items into batches, so that every batch fits into given limits.
+///
+/// Items with `None` limits are always put into separate batches. The order of items is
+/// preserved.
+fn split_into_batches<T>(
+	items: Vec<T>,
+	item_limits: impl Fn(&T) -> Option<(Weight, u32)>,
+	max_weight: Weight,
+	max_size: u32,
+) -> Vec<Vec<T>> {
+	let mut batches = Vec::new();
+	let mut current_batch = Vec::new();
+	let mut current_weight = Weight::zero();
+	let mut current_size = 0u32;
+	for item in items {
+		let (weight, size) = match item_limits(&item) {
+			Some(limits) => limits,
+			None => {
+				if !current_batch.is_empty() {
+					batches.push(std::mem::take(&mut current_batch));
+				}
+				batches.push(vec![item]);
+				continue
+			},
+		};
+
+		let new_weight = current_weight.saturating_add(weight);
+		let new_size = current_size.saturating_add(size);
+		if !current_batch.is_empty() && (new_weight.any_gt(max_weight) || new_size > max_size) {
+			batches.push(std::mem::take(&mut current_batch));
+			current_weight = weight;
+			current_size = size;
+		} else {
+			current_weight = new_weight;
+			current_size = new_size;
+		}
+		current_batch.push(item);
+	}
+	if !current_batch.is_empty() {
+		batches.push(current_batch);
+	}
+	batches
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn split(items: Vec<(u32, Option<(u64, u32)>)>) -> Vec<Vec<u32>> {
+		split_into_batches(
+			items,
+			|(_, limits)| limits.map(|(weight, size)| (Weight::from_ref_time(weight), size)),
+			Weight::from_ref_time(100),
+			100,
+		)
+		.into_iter()
+		.map(|batch| batch.into_iter().map(|(id, _)| id).collect())
+		.collect()
+	}
+
+	#[test]
+	fn calls_are_combined_when_they_fit_into_limits() {
+		assert_eq!(
+			split(vec![(1, Some((30, 30))), (2, Some((30, 30))), (3, Some((40, 40)))]),
+			vec![vec![1, 2, 3]],
+		);
+	}
+
+	#[test]
+	fn calls_are_split_when_weight_limit_is_exceeded() {
+		assert_eq!(
+			split(vec![(1, Some((60, 10))), (2, Some((60, 10))), (3, Some((30, 10)))]),
+			vec![vec![1], vec![2, 3]],
+		);
+	}
+
+	#[test]
+	fn calls_are_split_when_size_limit_is_exceeded() {
+		assert_eq!(
+			split(vec![(1, Some((10, 60))), (2, Some((10, 60))), (3, Some((10, 30)))]),
+			vec![vec![1], vec![2, 3]],
+		);
+	}
+
+	#[test]
+	fn calls_without_limits_are_never_combined() {
+		assert_eq!(
+			split(vec![(1, Some((10, 10))), (2, None), (3, Some((10, 10))), (4, Some((10, 10)))]),
+			vec![vec![1], vec![2], vec![3, 4]],
+		);
+	}
+
+	#[test]
+	fn oversized_call_is_submitted_alone() {
+		assert_eq!(split(vec![(1, Some((200, 10))), (2, Some((10, 10)))]), vec![vec![1], vec![2]],);
+	}
+}
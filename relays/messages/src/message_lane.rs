@@ -19,6 +19,7 @@
 //! 1) relay new messages from source to target node;
 //! 2) relay proof-of-delivery from target to source node.
 
+use codec::{Decode, Encode};
 use num_traits::{SaturatingAdd, Zero};
 use relay_utils::{BlockNumberBase, HeaderId};
 use sp_arithmetic::traits::AtLeast32BitUnsigned;
@@ -49,17 +50,19 @@ pub trait MessageLane: 'static + Clone + Send + Sync {
 		+ Sub<Output = Self::SourceChainBalance>
 		+ SaturatingAdd
 		+ Zero
+		+ Decode
+		+ Encode
 		+ Send
 		+ Sync;
 	/// Number of the source header.
-	type SourceHeaderNumber: BlockNumberBase;
+	type SourceHeaderNumber: BlockNumberBase + Decode + Encode;
 	/// Hash of the source header.
-	type SourceHeaderHash: Clone + Debug + Default + PartialEq + Send + Sync;
+	type SourceHeaderHash: Clone + Debug + Default + PartialEq + Decode + Encode + Send + Sync;
 
 	/// Number of the target header.
-	type TargetHeaderNumber: BlockNumberBase;
+	type TargetHeaderNumber: BlockNumberBase + Decode + Encode;
 	/// Hash of the target header.
-	type TargetHeaderHash: Clone + Debug + Default + PartialEq + Send + Sync;
+	type TargetHeaderHash: Clone + Debug + Default + PartialEq + Decode + Encode + Send + Sync;
 }
 
 /// Source header id within given one-way message lane.
@@ -18,7 +18,8 @@
 //!
 //! Single relay instance delivers messages of single lane in single direction.
 //! To serve two-way lane, you would need two instances of relay.
-//! To serve N two-way lanes, you would need N*2 instances of relay.
+//! To serve N two-way lanes, you would need N*2 instances of relay, or two instances of
+//! the multi-lane relay (see [`crate::multi_lane_loop`]).
 //!
 //! Please keep in mind that the best header in this file is actually best
 //! finalized header. I.e. when talking about headers in lane context, we
@@ -27,17 +28,19 @@
 use std::{collections::BTreeMap, fmt::Debug, future::Future, ops::RangeInclusive, time::Duration};
 
 use async_trait::async_trait;
+use codec::{Decode, Encode};
 use futures::{channel::mpsc::unbounded, future::FutureExt, stream::StreamExt};
 
 use bp_messages::{LaneId, MessageNonce, UnrewardedRelayersState, Weight};
 use relay_utils::{
 	interval, metrics::MetricsParams, process_future_result, relay_loop::Client as RelayClient,
-	retry_backoff, FailedClient, TransactionTracker,
+	retry_backoff, FailedClient, StateStore, TransactionTracker,
 };
 
 use crate::{
 	message_lane::{MessageLane, SourceHeaderIdOf, TargetHeaderIdOf},
 	message_race_delivery::run as run_message_delivery_race,
+	message_race_loop::RaceStateStore,
 	message_race_receiving::run as run_message_receiving_race,
 	metrics::MessageLaneLoopMetrics,
 };
@@ -55,6 +58,10 @@ pub struct Params {
 	pub reconnect_delay: Duration,
 	/// Message delivery race parameters.
 	pub delivery_params: MessageDeliveryParams,
+	/// Timeout after which submitted transaction is considered lost.
+	pub stall_timeout: Duration,
+	/// Optional store, where the loop persists its state.
+	pub state_store: Option<StateStore>,
 }
 
 /// Message delivery race parameters.
@@ -75,10 +82,32 @@ pub struct MessageDeliveryParams {
 	pub max_messages_weight_in_single_batch: Weight,
 	/// Maximal cumulative size of relayed messages in single delivery transaction.
 	pub max_messages_size_in_single_batch: u32,
+	/// Relayer operating mode.
+	pub relayer_mode: RelayerMode,
 }
 
-/// Message details.
+/// Relayer operating mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelayerMode {
+	/// The relayer doesn't care about rewards and delivers all messages it is able to deliver.
+	Altruistic,
+	/// The relayer only delivers messages if the delivery reward (plus the expected refund)
+	/// covers the cost of the delivery transaction.
+	Rational,
+}
+
+/// Estimated cost of the messages delivery transaction, expressed in the source chain tokens.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeliveryTransactionCost<SourceChainBalance> {
+	/// The fee that the relayer pays for the delivery transaction.
+	pub fee: SourceChainBalance,
+	/// The part of the fee, that is refunded to the relayer by the target chain runtime after
+	/// the transaction is dispatched.
+	pub refund: SourceChainBalance,
+}
+
+/// Message details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode)]
 pub struct MessageDetails<SourceChainBalance> {
 	/// Message dispatch weight.
 	pub dispatch_weight: Weight,
@@ -226,6 +255,18 @@ pub trait TargetClient<P: MessageLane>: RelayClient {
 		proof: P::MessagesProof,
 	) -> Result<NoncesSubmitArtifacts<Self::TransactionTracker>, Self::Error>;
 
+	/// Estimate cost of the single messages delivery transaction, expressed in the source chain
+	/// tokens.
+	///
+	/// The transaction delivers messages with given nonces, total dispatch weight and total
+	/// size. The estimation is only used by the `RelayerMode::Rational` relayers.
+	async fn estimate_delivery_transaction_in_source_tokens(
+		&self,
+		nonces: RangeInclusive<MessageNonce>,
+		total_dispatch_weight: Weight,
+		total_size: u32,
+	) -> Result<DeliveryTransactionCost<P::SourceChainBalance>, Self::Error>;
+
 	/// We need given finalized source header on target to continue synchronization.
 	///
 	/// The client may return `Some(_)`, which means that nothing has happened yet and
@@ -279,6 +320,18 @@ pub fn metrics_prefix<P: MessageLane>(lane: &LaneId) -> String {
 	format!("{}_to_{}_MessageLane_{}", P::SOURCE_NAME, P::TARGET_NAME, hex::encode(lane))
 }
 
+/// Return state store of the given lane races.
+pub(crate) fn race_state_store<P: MessageLane>(
+	state_store: &Option<StateStore>,
+	stall_timeout: Duration,
+	lane: &LaneId,
+) -> Option<RaceStateStore> {
+	state_store.as_ref().map(|state_store| RaceStateStore {
+		store: state_store.scoped(&metrics_prefix::<P>(lane)),
+		stall_timeout,
+	})
+}
+
 /// Run message lane service loop.
 pub async fn run<P: MessageLane>(
 	params: Params,
@@ -340,6 +393,7 @@ async fn run_until_connection_lost<P: MessageLane, SC: SourceClient<P>, TC: Targ
 		delivery_target_state_receiver,
 		metrics_msg.clone(),
 		params.delivery_params,
+		race_state_store::<P>(&params.state_store, params.stall_timeout, &params.lane),
 	)
 	.fuse();
 
@@ -353,6 +407,7 @@ async fn run_until_connection_lost<P: MessageLane, SC: SourceClient<P>, TC: Targ
 		target_client.clone(),
 		receiving_target_state_receiver,
 		metrics_msg.clone(),
+		race_state_store::<P>(&params.state_store, params.stall_timeout, &params.lane),
 	)
 	.fuse();
 
@@ -474,15 +529,20 @@ pub(crate) mod tests {
 	use futures::stream::StreamExt;
 	use parking_lot::Mutex;
 
-	use relay_utils::{HeaderId, MaybeConnectionError, TrackedTransactionStatus};
+	use relay_utils::{
+		HeaderId, InFlightTransaction, MaybeConnectionError, TrackedTransactionStatus,
+	};
 
 	use super::*;
+	use crate::message_race_loop::RaceStrategyState;
 
 	pub fn header_id(number: TestSourceHeaderNumber) -> TestSourceHeaderId {
 		HeaderId(number, number)
 	}
 
 	pub type TestSourceChainBalance = u64;
+	pub const BASE_MESSAGE_DELIVERY_TRANSACTION_COST: TestSourceChainBalance = 100;
+
 	pub type TestSourceHeaderId = HeaderId<TestSourceHeaderNumber, TestSourceHeaderHash>;
 	pub type TestTargetHeaderId = HeaderId<TestTargetHeaderNumber, TestTargetHeaderHash>;
 
@@ -903,6 +963,20 @@ pub(crate) mod tests {
 			})
 		}
 
+		async fn estimate_delivery_transaction_in_source_tokens(
+			&self,
+			nonces: RangeInclusive<MessageNonce>,
+			total_dispatch_weight: Weight,
+			total_size: u32,
+		) -> Result<DeliveryTransactionCost<TestSourceChainBalance>, TestError> {
+			Ok(DeliveryTransactionCost {
+				fee: BASE_MESSAGE_DELIVERY_TRANSACTION_COST * (nonces.end() - nonces.start() + 1) +
+					total_dispatch_weight.ref_time() +
+					total_size as TestSourceChainBalance,
+				refund: 0,
+			})
+		}
+
 		async fn require_source_header_on_target(
 			&self,
 			id: SourceHeaderIdOf<TestMessageLane>,
@@ -920,6 +994,59 @@ pub(crate) mod tests {
 		}
 	}
 
+	#[derive(Clone)]
+	pub struct TestMultiLaneClient<C> {
+		lanes: Vec<(LaneId, C)>,
+	}
+
+	#[async_trait]
+	impl<C: RelayClient<Error = TestError>> RelayClient for TestMultiLaneClient<C> {
+		type Error = TestError;
+
+		async fn reconnect(&mut self) -> Result<(), TestError> {
+			for (_, client) in &mut self.lanes {
+				client.reconnect().await?;
+			}
+			Ok(())
+		}
+	}
+
+	impl<C: Clone> TestMultiLaneClient<C> {
+		fn lane(&self, lane: LaneId) -> C {
+			self.lanes.iter().find(|(id, _)| *id == lane).map(|(_, c)| c.clone()).unwrap()
+		}
+	}
+
+	#[async_trait]
+	impl crate::multi_lane_loop::MultiLaneSourceClient<TestMessageLane>
+		for TestMultiLaneClient<TestSourceClient>
+	{
+		type LaneClient = TestSourceClient;
+
+		async fn state(&self) -> Result<SourceClientState<TestMessageLane>, TestError> {
+			self.lanes[0].1.state().await
+		}
+
+		fn lane_client(&self, lane: LaneId) -> TestSourceClient {
+			self.lane(lane)
+		}
+	}
+
+	#[async_trait]
+	impl crate::multi_lane_loop::MultiLaneTargetClient<TestMessageLane>
+		for TestMultiLaneClient<TestTargetClient>
+	{
+		type LaneClient = TestTargetClient;
+
+		async fn state(&self) -> Result<TargetClientState<TestMessageLane>, TestError> {
+			self.lanes[0].1.state().await
+		}
+
+		fn lane_client(&self, lane: LaneId) -> TestTargetClient {
+			self.lane(lane)
+		}
+	}
+
 	fn run_loop_test(
 		data: Arc<Mutex<TestClientData>>,
 		source_tick: Arc<dyn Fn(&mut TestClientData) + Send + Sync>,
@@ -927,6 +1054,26 @@ pub(crate) mod tests {
 		target_tick: Arc<dyn Fn(&mut TestClientData) + Send + Sync>,
 		target_post_tick: Arc<dyn Fn(&mut TestClientData) + Send + Sync>,
 		exit_signal: impl Future<Output = ()> + 'static + Send,
+	) -> TestClientData {
+		run_loop_test_with_state_store(
+			data,
+			source_tick,
+			source_post_tick,
+			target_tick,
+			target_post_tick,
+			exit_signal,
+			None,
+		)
+	}
+
+	fn run_loop_test_with_state_store(
+		data: Arc<Mutex<TestClientData>>,
+		source_tick: Arc<dyn Fn(&mut TestClientData) + Send + Sync>,
+		source_post_tick: Arc<dyn Fn(&mut TestClientData) + Send + Sync>,
+		target_tick: Arc<dyn Fn(&mut TestClientData) + Send + Sync>,
+		target_post_tick: Arc<dyn Fn(&mut TestClientData) + Send + Sync>,
+		exit_signal: impl Future<Output = ()> + 'static + Send,
+		state_store: Option<StateStore>,
 	) -> TestClientData {
 		async_std::task::block_on(async {
 			let source_client = TestSourceClient {
@@ -951,7 +1098,10 @@ pub(crate) mod tests {
 						max_messages_in_single_batch: 4,
 						max_messages_weight_in_single_batch: Weight::from_ref_time(4),
 						max_messages_size_in_single_batch: 4,
+						relayer_mode: RelayerMode::Altruistic,
 					},
+					stall_timeout: Duration::from_secs(60),
+					state_store,
 				},
 				source_client,
 				target_client,
@@ -1017,6 +1167,124 @@ pub(crate) mod tests {
 		assert_eq!(result.submitted_messages_proofs, vec![(1..=1, None)],);
 	}
 
+	#[test]
+	fn message_lane_loop_does_not_resubmit_restored_transaction() {
+		// the delivery transaction has been submitted before restart and it is still alive
+		// => we shall not submit the same nonces again
+		let dir = tempfile::tempdir().unwrap();
+		let state_store = StateStore::open(dir.path()).unwrap();
+		let race_state_store =
+			state_store.scoped(&metrics_prefix::<TestMessageLane>(&LaneId([0, 0, 0, 0])));
+		let submitted_nonces_key =
+			"TestSource::MessagesDelivery_to_TestTarget::MessagesDelivery_SubmittedNonces";
+		race_state_store.save(
+			submitted_nonces_key,
+			&InFlightTransaction::new((1u64, 1u64), Duration::from_secs(3600)),
+		);
+
+		let (exit_sender, exit_receiver) = unbounded();
+		let result = run_loop_test_with_state_store(
+			Arc::new(Mutex::new(TestClientData {
+				source_state: ClientState {
+					best_self: HeaderId(0, 0),
+					best_finalized_self: HeaderId(0, 0),
+					best_finalized_peer_at_best_self: Some(HeaderId(0, 0)),
+					actual_best_finalized_peer_at_best_self: Some(HeaderId(0, 0)),
+				},
+				source_latest_generated_nonce: 1,
+				target_state: ClientState {
+					best_self: HeaderId(0, 0),
+					best_finalized_self: HeaderId(0, 0),
+					best_finalized_peer_at_best_self: Some(HeaderId(0, 0)),
+					actual_best_finalized_peer_at_best_self: Some(HeaderId(0, 0)),
+				},
+				target_latest_received_nonce: 0,
+				..Default::default()
+			})),
+			Arc::new(|_| {}),
+			Arc::new(|_| {}),
+			Arc::new(move |data: &mut TestClientData| {
+				if data.target_state.best_finalized_peer_at_best_self.unwrap().0 < 10 {
+					data.target_state.best_finalized_peer_at_best_self = Some(HeaderId(
+						data.target_state.best_finalized_peer_at_best_self.unwrap().0 + 1,
+						data.target_state.best_finalized_peer_at_best_self.unwrap().0 + 1,
+					));
+				} else {
+					exit_sender.unbounded_send(()).unwrap();
+				}
+			}),
+			Arc::new(|_| {}),
+			exit_receiver.into_future().map(|(_, _)| ()),
+			Some(state_store),
+		);
+
+		assert_eq!(result.submitted_messages_proofs, vec![]);
+		assert!(race_state_store
+			.load::<InFlightTransaction<(MessageNonce, MessageNonce)>>(submitted_nonces_key)
+			.is_some());
+	}
+
+	#[test]
+	fn message_lane_loop_restores_race_strategy_state() {
+		// nonces have been read from the source node before restart => after restart the race
+		// shall resume from the persisted state and deliver them
+		let dir = tempfile::tempdir().unwrap();
+		let state_store = StateStore::open(dir.path()).unwrap();
+		let race_state_store =
+			state_store.scoped(&metrics_prefix::<TestMessageLane>(&LaneId([0, 0, 0, 0])));
+		let strategy_state_key =
+			"TestSource::MessagesDelivery_to_TestTarget::MessagesDelivery_StrategyState";
+		let message_details =
+			MessageDetails { dispatch_weight: Weight::from_ref_time(1), size: 1, reward: 1000 };
+		race_state_store.save(
+			strategy_state_key,
+			&RaceStrategyState {
+				best_target_nonce: Some(0),
+				source_queue: vec![(
+					header_id(1),
+					MessageDetailsMap::<TestSourceChainBalance>::from([(1, message_details)]),
+				)],
+			},
+		);
+
+		let (exit_sender, exit_receiver) = unbounded();
+		let result = run_loop_test_with_state_store(
+			Arc::new(Mutex::new(TestClientData {
+				source_state: ClientState {
+					best_self: HeaderId(1, 1),
+					best_finalized_self: HeaderId(1, 1),
+					best_finalized_peer_at_best_self: Some(HeaderId(0, 0)),
+					actual_best_finalized_peer_at_best_self: Some(HeaderId(0, 0)),
+				},
+				source_latest_generated_nonce: 1,
+				target_state: ClientState {
+					best_self: HeaderId(0, 0),
+					best_finalized_self: HeaderId(0, 0),
+					best_finalized_peer_at_best_self: Some(HeaderId(1, 1)),
+					actual_best_finalized_peer_at_best_self: Some(HeaderId(1, 1)),
+				},
+				target_latest_received_nonce: 0,
+				..Default::default()
+			})),
+			Arc::new(|_| {}),
+			Arc::new(|_| {}),
+			Arc::new(move |data: &mut TestClientData| {
+				if data.target_latest_received_nonce == 1 {
+					exit_sender.unbounded_send(()).unwrap();
+				}
+			}),
+			Arc::new(|_| {}),
+			exit_receiver.into_future().map(|(_, _)| ()),
+			Some(state_store),
+		);
+
+		assert_eq!(result.submitted_messages_proofs, vec![(1..=1, None)]);
+		let strategy_state: Option<
+			RaceStrategyState<TestSourceHeaderId, MessageDetailsMap<TestSourceChainBalance>>,
+		> = race_state_store.load(strategy_state_key);
+		assert!(strategy_state.is_some());
+	}
+
 	#[test]
 	fn message_lane_loop_is_able_to_recover_from_race_stall() {
 		// with this configuration, both source and target clients will lose their transactions =>
@@ -1304,4 +1572,101 @@ pub(crate) mod tests {
 		assert!(!result.target_to_source_header_requirements.is_empty());
 		assert!(!result.source_to_target_header_requirements.is_empty());
 	}
+
+	#[test]
+	fn multi_lane_loop_delivers_messages_of_all_lanes() {
+		let (exit_sender, exit_receiver) = unbounded();
+		let first_lane = LaneId([0, 0, 0, 0]);
+		let second_lane = LaneId([0, 0, 0, 1]);
+		let initial_data = TestClientData {
+			source_state: ClientState {
+				best_self: HeaderId(0, 0),
+				best_finalized_self: HeaderId(0, 0),
+				best_finalized_peer_at_best_self: Some(HeaderId(0, 0)),
+				actual_best_finalized_peer_at_best_self: Some(HeaderId(0, 0)),
+			},
+			target_state: ClientState {
+				best_self: HeaderId(0, 0),
+				best_finalized_self: HeaderId(0, 0),
+				best_finalized_peer_at_best_self: Some(HeaderId(0, 0)),
+				actual_best_finalized_peer_at_best_self: Some(HeaderId(0, 0)),
+			},
+			..Default::default()
+		};
+		let first_lane_data = Arc::new(Mutex::new(TestClientData {
+			source_latest_generated_nonce: 1,
+			..initial_data.clone()
+		}));
+		let second_lane_data = Arc::new(Mutex::new(TestClientData {
+			source_latest_generated_nonce: 2,
+			..initial_data
+		}));
+
+		// state of both chains is read from the first lane data only, so the second lane must
+		// only be served using shared state updates
+		let second_lane_data_clone = second_lane_data.clone();
+		let target_tick: Arc<dyn Fn(&mut TestClientData) + Send + Sync> =
+			Arc::new(move |data: &mut TestClientData| {
+				if data.target_state.best_finalized_peer_at_best_self.unwrap().0 < 10 {
+					data.target_state.best_finalized_peer_at_best_self = Some(HeaderId(
+						data.target_state.best_finalized_peer_at_best_self.unwrap().0 + 1,
+						data.target_state.best_finalized_peer_at_best_self.unwrap().0 + 1,
+					));
+				}
+				if !data.submitted_messages_proofs.is_empty() &&
+					!second_lane_data_clone.lock().submitted_messages_proofs.is_empty()
+				{
+					exit_sender.unbounded_send(()).unwrap();
+				}
+			});
+		let source_client = |data: &Arc<Mutex<TestClientData>>| TestSourceClient {
+			data: data.clone(),
+			tick: Arc::new(|_| {}),
+			post_tick: Arc::new(|_| {}),
+		};
+		let target_client =
+			|data: &Arc<Mutex<TestClientData>>,
+			 tick: Arc<dyn Fn(&mut TestClientData) + Send + Sync>| TestTargetClient {
+				data: data.clone(),
+				tick,
+				post_tick: Arc::new(|_| {}),
+			};
+
+		async_std::task::block_on(crate::multi_lane_loop::run(
+			crate::multi_lane_loop::Params {
+				lanes: vec![first_lane, second_lane],
+				source_tick: Duration::from_millis(100),
+				target_tick: Duration::from_millis(100),
+				reconnect_delay: Duration::from_millis(0),
+				delivery_params: MessageDeliveryParams {
+					max_unrewarded_relayer_entries_at_target: 4,
+					max_unconfirmed_nonces_at_target: 4,
+					max_messages_in_single_batch: 4,
+					max_messages_weight_in_single_batch: Weight::from_ref_time(4),
+					max_messages_size_in_single_batch: 4,
+					relayer_mode: RelayerMode::Altruistic,
+				},
+				stall_timeout: Duration::from_secs(60),
+				state_store: None,
+			},
+			TestMultiLaneClient {
+				lanes: vec![
+					(first_lane, source_client(&first_lane_data)),
+					(second_lane, source_client(&second_lane_data)),
+				],
+			},
+			TestMultiLaneClient {
+				lanes: vec![
+					(first_lane, target_client(&first_lane_data, target_tick)),
+					(second_lane, target_client(&second_lane_data, Arc::new(|_| {}))),
+				],
+			},
+			MetricsParams::disabled(),
+			exit_receiver.into_future().map(|(_, _)| ()),
+		))
+		.unwrap();
+
+		assert_eq!(first_lane_data.lock().submitted_messages_proofs[0].0, 1..=1);
+		assert_eq!(second_lane_data.lock().submitted_messages_proofs[0].0, 1..=2);
+	}
 }
@@ -25,13 +25,13 @@ use crate::{
 	message_lane::{MessageLane, SourceHeaderIdOf, TargetHeaderIdOf},
 	message_lane_loop::{
 		MessageDeliveryParams, MessageDetailsMap, MessageProofParameters, NoncesSubmitArtifacts,
-		SourceClient as MessageLaneSourceClient, SourceClientState,
+		RelayerMode, SourceClient as MessageLaneSourceClient, SourceClientState,
 		TargetClient as MessageLaneTargetClient, TargetClientState,
 	},
 	message_race_limits::{MessageRaceLimits, RelayMessagesBatchReference},
 	message_race_loop::{
-		MessageRace, NoncesRange, RaceState, RaceStrategy, SourceClient, SourceClientNonces,
-		TargetClient, TargetClientNonces,
+		MessageRace, NoncesRange, RaceState, RaceStateStore, RaceStrategy, RaceStrategyState,
+		SourceClient, SourceClientNonces, TargetClient, TargetClientNonces,
 	},
 	message_race_strategy::BasicStrategy,
 	metrics::MessageLaneLoopMetrics,
@@ -45,6 +45,7 @@ pub async fn run<P: MessageLane>(
 	target_state_updates: impl FusedStream<Item = TargetClientState<P>>,
 	metrics_msg: Option<MessageLaneLoopMetrics>,
 	params: MessageDeliveryParams,
+	state_store: Option<RaceStateStore>,
 ) -> Result<(), FailedClient> {
 	crate::message_race_loop::run(
 		MessageDeliveryRaceSource {
@@ -68,11 +69,13 @@ pub async fn run<P: MessageLane>(
 			max_messages_in_single_batch: params.max_messages_in_single_batch,
 			max_messages_weight_in_single_batch: params.max_messages_weight_in_single_batch,
 			max_messages_size_in_single_batch: params.max_messages_size_in_single_batch,
+			relayer_mode: params.relayer_mode,
 			latest_confirmed_nonces_at_source: VecDeque::new(),
 			target_nonces: None,
 			strategy: BasicStrategy::new(),
 			metrics_msg,
 		},
+		state_store,
 	)
 	.await
 }
@@ -252,6 +255,8 @@ struct MessageDeliveryStrategy<P: MessageLane, SC, TC> {
 	max_messages_weight_in_single_batch: Weight,
 	/// Maximal messages size in the single delivery transaction.
 	max_messages_size_in_single_batch: u32,
+	/// Relayer operating mode.
+	relayer_mode: RelayerMode,
 	/// Latest confirmed nonces at the source client + the header id where we have first met this
 	/// nonce.
 	latest_confirmed_nonces_at_source: VecDeque<(SourceHeaderIdOf<P>, MessageNonce)>,
@@ -283,6 +288,7 @@ impl<P: MessageLane, SC, TC> std::fmt::Debug for MessageDeliveryStrategy<P, SC,
 			.field("max_messages_in_single_batch", &self.max_messages_in_single_batch)
 			.field("max_messages_weight_in_single_batch", &self.max_messages_weight_in_single_batch)
 			.field("max_messages_size_in_single_batch", &self.max_messages_size_in_single_batch)
+			.field("relayer_mode", &self.relayer_mode)
 			.field("latest_confirmed_nonces_at_source", &self.latest_confirmed_nonces_at_source)
 			.field("target_nonces", &self.target_nonces)
 			.field("strategy", &self.strategy)
@@ -533,6 +539,7 @@ where
 			max_messages_in_this_batch: max_nonces,
 			max_messages_weight_in_single_batch,
 			max_messages_size_in_single_batch,
+			relayer_mode: self.relayer_mode,
 			lane_source_client: lane_source_client.clone(),
 			lane_target_client: lane_target_client.clone(),
 			nonces_queue: source_queue.clone(),
@@ -554,6 +561,17 @@ where
 			MessageProofParameters { outbound_state_proof_required, dispatch_weight },
 		))
 	}
+
+	fn state(&self) -> RaceStrategyState<SourceHeaderIdOf<P>, Self::SourceNoncesRange> {
+		self.strategy.state()
+	}
+
+	fn restore_state(
+		&mut self,
+		state: RaceStrategyState<SourceHeaderIdOf<P>, Self::SourceNoncesRange>,
+	) {
+		self.strategy.restore_state(state)
+	}
 }
 
 impl<SourceChainBalance: std::fmt::Debug> NoncesRange for MessageDetailsMap<SourceChainBalance> {
@@ -581,6 +599,7 @@ mod tests {
 		tests::{
 			header_id, TestMessageLane, TestMessagesProof, TestSourceChainBalance,
 			TestSourceClient, TestSourceHeaderId, TestTargetClient, TestTargetHeaderId,
+			BASE_MESSAGE_DELIVERY_TRANSACTION_COST,
 		},
 		MessageDetails,
 	};
@@ -633,6 +652,7 @@ mod tests {
 			max_messages_in_single_batch: 4,
 			max_messages_weight_in_single_batch: Weight::from_ref_time(4),
 			max_messages_size_in_single_batch: 4,
+			relayer_mode: RelayerMode::Altruistic,
 			latest_confirmed_nonces_at_source: vec![(header_id(1), 19)].into_iter().collect(),
 			lane_source_client: TestSourceClient::default(),
 			lane_target_client: TestTargetClient::default(),
@@ -749,6 +769,58 @@ mod tests {
 		);
 	}
 
+	fn set_messages_reward(
+		strategy: &mut TestStrategy,
+		nonces: RangeInclusive<MessageNonce>,
+		reward: TestSourceChainBalance,
+	) {
+		for (_, range) in strategy.strategy.source_queue_mut().iter_mut() {
+			for (_, details) in range.range_mut(nonces.clone()) {
+				details.reward = reward;
+			}
+		}
+	}
+
+	#[async_std::test]
+	async fn rational_strategy_selects_nothing_if_delivery_is_unprofitable() {
+		let (state, mut strategy) = prepare_strategy();
+		strategy.relayer_mode = RelayerMode::Rational;
+
+		// all messages have zero reward
+		assert_eq!(strategy.select_nonces_to_deliver(state).await, None);
+	}
+
+	#[async_std::test]
+	async fn rational_strategy_selects_all_messages_if_delivery_is_profitable() {
+		let (state, mut strategy) = prepare_strategy();
+		strategy.relayer_mode = RelayerMode::Rational;
+
+		// every message reward covers its share of the delivery transaction cost
+		set_messages_reward(&mut strategy, 20..=23, BASE_MESSAGE_DELIVERY_TRANSACTION_COST + 2);
+		assert_eq!(
+			strategy.select_nonces_to_deliver(state).await,
+			Some(((20..=23), proof_parameters(false, 4)))
+		);
+	}
+
+	#[async_std::test]
+	async fn rational_strategy_selects_largest_profitable_batch() {
+		let (state, mut strategy) = prepare_strategy();
+		strategy.relayer_mode = RelayerMode::Rational;
+
+		// the single message #20 isn't profitable, but reward of #21 covers cost of both
+		// messages. Adding any other message makes batch unprofitable again
+		set_messages_reward(
+			&mut strategy,
+			21..=21,
+			2 * (BASE_MESSAGE_DELIVERY_TRANSACTION_COST + 2),
+		);
+		assert_eq!(
+			strategy.select_nonces_to_deliver(state).await,
+			Some(((20..=21), proof_parameters(false, 2)))
+		);
+	}
+
 	#[async_std::test]
 	async fn message_delivery_strategy_selects_nothing_if_there_are_too_many_unrewarded_relayers() {
 		let (state, mut strategy) = prepare_strategy();
@@ -16,15 +16,15 @@
 
 //! enforcement strategy
 
-use num_traits::Zero;
-use std::ops::Range;
+use num_traits::{SaturatingAdd, Zero};
+use std::ops::{Range, RangeInclusive};
 
 use bp_messages::{MessageNonce, Weight};
 
 use crate::{
 	message_lane::MessageLane,
 	message_lane_loop::{
-		MessageDetails, MessageDetailsMap, SourceClient as MessageLaneSourceClient,
+		MessageDetails, MessageDetailsMap, RelayerMode, SourceClient as MessageLaneSourceClient,
 		TargetClient as MessageLaneTargetClient,
 	},
 	message_race_loop::NoncesRange,
@@ -70,6 +70,8 @@ pub struct RelayMessagesBatchReference<
 	pub max_messages_weight_in_single_batch: Weight,
 	/// Maximal cumulative size of relayed messages in single delivery transaction.
 	pub max_messages_size_in_single_batch: u32,
+	/// Relayer operating mode.
+	pub relayer_mode: RelayerMode,
 	/// The client that is connected to the message lane source node.
 	pub lane_source_client: SourceClient,
 	/// The client that is connected to the message lane target node.
@@ -86,6 +88,32 @@ pub struct RelayMessagesBatchReference<
 	pub nonces_queue_range: Range<usize>,
 }
 
+/// Reason of the messages delivery decision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryDecision {
+	/// Messages are delivered regardless of their reward.
+	Altruistic,
+	/// Reward (plus expected refund) of selected messages covers the delivery transaction cost.
+	Profitable,
+	/// Reward (plus expected refund) of any messages batch doesn't cover the delivery
+	/// transaction cost.
+	Unprofitable,
+	/// We have failed to estimate the delivery transaction cost.
+	CostEstimationFailed,
+}
+
+impl DeliveryDecision {
+	/// Returns the decision reason as a string.
+	pub fn as_str(&self) -> &'static str {
+		match *self {
+			DeliveryDecision::Altruistic => "altruistic",
+			DeliveryDecision::Profitable => "profitable",
+			DeliveryDecision::Unprofitable => "unprofitable",
+			DeliveryDecision::CostEstimationFailed => "cost_estimation_failed",
+		}
+	}
+}
+
 /// Limits of the message race transactions.
 #[derive(Clone)]
 pub struct MessageRaceLimits;
@@ -99,6 +127,10 @@ impl MessageRaceLimits {
 		reference: RelayMessagesBatchReference<P, SourceClient, TargetClient>,
 	) -> Option<MessageNonce> {
 		let mut hard_selected_count = 0;
+		// cumulative weight, size and reward of every hard-selected batch, starting from the
+		// batch with single message
+		let mut hard_selected_batches = Vec::new();
+		let mut selected_reward = P::SourceChainBalance::zero();
 
 		let mut selected_weight = Weight::zero();
 		let mut selected_count: MessageNonce = 0;
@@ -183,18 +215,152 @@ impl MessageRaceLimits {
 				break
 			}
 			relay_reference.selected_size = new_selected_size;
+			selected_reward = selected_reward.saturating_add(&details.reward);
 
 			hard_selected_count = index + 1;
 			selected_weight = new_selected_weight;
 			selected_count = new_selected_count;
+			hard_selected_batches.push(SelectedBatch {
+				weight: selected_weight,
+				size: new_selected_size,
+				reward: selected_reward,
+			});
 		}
 
+		// the rational relayer only delivers messages if it isn't losing funds
+		let mut cost_estimation_failed = false;
+		let soft_selected_count = match reference.relayer_mode {
+			RelayerMode::Altruistic => hard_selected_count,
+			RelayerMode::Rational => Self::select_profitable_batch(
+				&relay_reference.lane_target_client,
+				hard_selected_begin_nonce,
+				&hard_selected_batches,
+			)
+			.await
+			.unwrap_or_else(|| {
+				cost_estimation_failed = true;
+				0
+			}),
+		};
+
 		if hard_selected_count != 0 {
+			let decision = match reference.relayer_mode {
+				RelayerMode::Altruistic => DeliveryDecision::Altruistic,
+				RelayerMode::Rational if soft_selected_count != 0 => DeliveryDecision::Profitable,
+				RelayerMode::Rational if cost_estimation_failed =>
+					DeliveryDecision::CostEstimationFailed,
+				RelayerMode::Rational => DeliveryDecision::Unprofitable,
+			};
+			if let Some(ref metrics) = relay_reference.metrics {
+				metrics.note_delivery_decision(decision);
+			}
+		}
+
+		if soft_selected_count != 0 {
 			let selected_max_nonce =
-				hard_selected_begin_nonce + hard_selected_count as MessageNonce - 1;
+				hard_selected_begin_nonce + soft_selected_count as MessageNonce - 1;
 			Some(selected_max_nonce)
 		} else {
 			None
 		}
 	}
+
+	/// Returns number of messages in the largest profitable batch.
+	///
+	/// The delivery transaction has large fixed cost, so the batch with all hard-selected
+	/// messages is the most likely one to be profitable. So we estimate its cost first. If it
+	/// is unprofitable, we look for the largest profitable batch using binary search, which
+	/// requires `O(log(N))` cost estimations instead of `N`. Adding messages to the batch may
+	/// make it profitable again (e.g. if the next message has large reward), so the binary
+	/// search may miss some profitable batch. It'll be delivered later, when the race selects
+	/// nonces again.
+	///
+	/// Returns `None` if we have failed to estimate the cost.
+	async fn select_profitable_batch<P: MessageLane, TargetClient: MessageLaneTargetClient<P>>(
+		lane_target_client: &TargetClient,
+		begin_nonce: MessageNonce,
+		hard_selected_batches: &[SelectedBatch<P::SourceChainBalance>],
+	) -> Option<usize> {
+		let hard_selected_count = hard_selected_batches.len();
+		if hard_selected_count == 0 {
+			return Some(0)
+		}
+
+		let is_profitable = |count: usize| {
+			Self::is_profitable::<P, TargetClient>(
+				lane_target_client,
+				begin_nonce..=begin_nonce + count as MessageNonce - 1,
+				&hard_selected_batches[count - 1],
+			)
+		};
+		if is_profitable(hard_selected_count).await? {
+			return Some(hard_selected_count)
+		}
+
+		// `low` is the largest known profitable batch (or zero) and `high` is the largest batch
+		// that may still be profitable
+		let (mut low, mut high) = (0, hard_selected_count - 1);
+		while low < high {
+			let mid = (low + high + 1) / 2;
+			if is_profitable(mid).await? {
+				low = mid;
+			} else {
+				high = mid - 1;
+			}
+		}
+
+		Some(low)
+	}
+
+	/// Returns `Some(true)` if reward of given batch covers the delivery transaction cost.
+	/// Returns `None` if we have failed to estimate the cost.
+	async fn is_profitable<P: MessageLane, TargetClient: MessageLaneTargetClient<P>>(
+		lane_target_client: &TargetClient,
+		selected_nonces: RangeInclusive<MessageNonce>,
+		batch: &SelectedBatch<P::SourceChainBalance>,
+	) -> Option<bool> {
+		let cost = match lane_target_client
+			.estimate_delivery_transaction_in_source_tokens(
+				selected_nonces.clone(),
+				batch.weight,
+				batch.size,
+			)
+			.await
+		{
+			Ok(cost) => cost,
+			Err(err) => {
+				log::debug!(
+					target: "bridge",
+					"Failed to estimate delivery transaction cost for messages {:?}: {:?}",
+					selected_nonces,
+					err,
+				);
+				return None
+			},
+		};
+
+		let income = batch.reward.saturating_add(&cost.refund);
+		let is_profitable = income >= cost.fee;
+		log::trace!(
+			target: "bridge",
+			"Delivery of messages {:?} is {}: reward {:?}, refund {:?}, fee {:?}",
+			selected_nonces,
+			if is_profitable { "profitable" } else { "unprofitable" },
+			batch.reward,
+			cost.refund,
+			cost.fee,
+		);
+
+		Some(is_profitable)
+	}
+}
+
+/// Cumulative parameters of the hard-selected messages batch.
+struct SelectedBatch<Balance> {
+	/// Cumulative dispatch weight of messages in the batch.
+	weight: Weight,
+	/// Cumulative size of messages in the batch.
+	size: u32,
+	/// Cumulative reward of messages in the batch.
+	reward: Balance,
 }
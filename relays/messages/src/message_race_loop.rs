@@ -24,12 +24,14 @@ use crate::message_lane_loop::{BatchTransaction, ClientState, NoncesSubmitArtifa
 
 use async_trait::async_trait;
 use bp_messages::MessageNonce;
+use codec::{Decode, Encode};
 use futures::{
 	future::FutureExt,
 	stream::{FusedStream, StreamExt},
 };
 use relay_utils::{
-	process_future_result, retry_backoff, FailedClient, MaybeConnectionError,
+	process_future_result, retry_backoff, FailedClient, InFlightTransaction, MaybeConnectionError,
+	MaybeRestoredTransactionTracker, RestoredTransactionTracker, StateStore,
 	TrackedTransactionStatus, TransactionTracker,
 };
 use std::{
@@ -214,6 +216,20 @@ pub trait RaceStrategy<SourceHeaderId, TargetHeaderId, Proof>: Debug {
 		&mut self,
 		race_state: RaceState<SourceHeaderId, TargetHeaderId, Proof>,
 	) -> Option<(RangeInclusive<MessageNonce>, Self::ProofParameters)>;
+
+	/// Return the strategy state that needs to be persisted to resume the race after restart.
+	fn state(&self) -> RaceStrategyState<SourceHeaderId, Self::SourceNoncesRange>;
+	/// Restore the strategy state that has been persisted before restart.
+	fn restore_state(&mut self, state: RaceStrategyState<SourceHeaderId, Self::SourceNoncesRange>);
+}
+
+/// Persistent state of the race strategy.
+#[derive(Debug, Clone, Decode, Encode, PartialEq, Eq)]
+pub struct RaceStrategyState<SourceHeaderId, SourceNoncesRange> {
+	/// The best nonce known to the target node.
+	pub best_target_nonce: Option<MessageNonce>,
+	/// Nonces known to the source node, that are not yet delivered to the target node.
+	pub source_queue: Vec<(SourceHeaderId, SourceNoncesRange)>,
 }
 
 /// State of the race.
@@ -234,6 +250,29 @@ pub struct RaceState<SourceHeaderId, TargetHeaderId, Proof> {
 	pub nonces_submitted: Option<RangeInclusive<MessageNonce>>,
 }
 
+/// Persistent state of the race.
+///
+/// The range of submitted nonces is persisted along with the strategy state (the last known
+/// nonces and the queue of nonces, available at the source node), so the race resumes from the
+/// same point after restart.
+#[derive(Debug, Clone)]
+pub struct RaceStateStore {
+	/// The store, scoped to the lane.
+	pub store: StateStore,
+	/// Timeout after which the submitted transaction is considered lost.
+	pub stall_timeout: Duration,
+}
+
+/// Returns key that is used to store nonces, submitted by the race.
+fn submitted_nonces_key<P: MessageRace>() -> String {
+	format!("{}_to_{}_SubmittedNonces", P::source_name(), P::target_name())
+}
+
+/// Returns key that is used to store state of the race strategy.
+fn strategy_state_key<P: MessageRace>() -> String {
+	format!("{}_to_{}_StrategyState", P::source_name(), P::target_name())
+}
+
 /// Run race loop until connection with target or source node is lost.
 pub async fn run<P: MessageRace, SC: SourceClient<P>, TC: TargetClient<P>>(
 	race_source: SC,
@@ -248,7 +287,12 @@ pub async fn run<P: MessageRace, SC: SourceClient<P>, TC: TargetClient<P>>(
 		ProofParameters = SC::ProofParameters,
 		TargetNoncesData = TC::TargetNoncesData,
 	>,
-) -> Result<(), FailedClient> {
+	state_store: Option<RaceStateStore>,
+) -> Result<(), FailedClient>
+where
+	P::SourceHeaderId: Decode + Encode,
+	SC::NoncesRange: Clone + PartialEq + Decode + Encode,
+{
 	let mut progress_context = Instant::now();
 	let mut race_state = RaceState::default();
 
@@ -286,6 +330,62 @@ pub async fn run<P: MessageRace, SC: SourceClient<P>, TC: TargetClient<P>>(
 		target_go_offline_future,
 	);
 
+	// if we have submitted some nonces before restart, let's wait until they're delivered
+	let mut is_restored_tx_tracked = false;
+	let mut stored_nonces_submitted = None;
+	let mut stored_strategy_state = None;
+	if let Some(ref state_store) = state_store {
+		let strategy_state =
+			state_store.store.load::<RaceStrategyState<P::SourceHeaderId, SC::NoncesRange>>(
+				&strategy_state_key::<P>(),
+			);
+		if let Some(strategy_state) = strategy_state {
+			log::info!(
+				target: "bridge",
+				"Restored {} -> {} race state. Best target nonce: {:?}, queued source ranges: {}",
+				P::source_name(),
+				P::target_name(),
+				strategy_state.best_target_nonce,
+				strategy_state.source_queue.len(),
+			);
+
+			strategy.restore_state(strategy_state.clone());
+			stored_strategy_state = Some(strategy_state);
+		}
+
+		let stored_transaction = state_store
+			.store
+			.load::<InFlightTransaction<(MessageNonce, MessageNonce)>>(&submitted_nonces_key::<P>());
+		match stored_transaction {
+			Some(transaction) => match transaction.remaining_time() {
+				Some(remaining_time) => {
+					let (begin, end) = transaction.data;
+					log::info!(
+						target: "bridge",
+						"Restored {} -> {} transaction with nonces {:?}. Waiting up to {:?} for it",
+						P::source_name(),
+						P::target_name(),
+						begin..=end,
+						remaining_time,
+					);
+
+					race_state.nonces_submitted = Some(begin..=end);
+					stored_nonces_submitted = Some(begin..=end);
+					is_restored_tx_tracked = true;
+					target_tx_tracker.set(
+						MaybeRestoredTransactionTracker::<TC::TransactionTracker>::Restored(
+							RestoredTransactionTracker::new(remaining_time),
+						)
+						.wait()
+						.fuse(),
+					);
+				},
+				None => state_store.store.forget(&submitted_nonces_key::<P>()),
+			},
+			None => (),
+		}
+	}
+
 	loop {
 		futures::select! {
 			// when headers ids are updated
@@ -448,7 +548,12 @@ pub async fn run<P: MessageRace, SC: SourceClient<P>, TC: TargetClient<P>>(
 						target_batch_transaction = None;
 						race_state.nonces_to_submit = None;
 						race_state.nonces_submitted = Some(artifacts.nonces);
-						target_tx_tracker.set(artifacts.tx_tracker.wait().fuse());
+						is_restored_tx_tracked = false;
+						target_tx_tracker.set(
+							MaybeRestoredTransactionTracker::Submitted(artifacts.tx_tracker)
+								.wait()
+								.fuse(),
+						);
 					},
 					&mut target_go_offline_future,
 					async_std::task::sleep,
@@ -496,6 +601,11 @@ pub async fn run<P: MessageRace, SC: SourceClient<P>, TC: TargetClient<P>>(
 							strategy,
 						);
 
+						// the transaction is lost, so there's no need to wait for it after restart
+						if let Some(ref state_store) = state_store {
+							state_store.store.forget(&submitted_nonces_key::<P>());
+						}
+
 						return Err(FailedClient::Both);
 					},
 					_ => (),
@@ -511,6 +621,35 @@ pub async fn run<P: MessageRace, SC: SourceClient<P>, TC: TargetClient<P>>(
 			},
 		}
 
+		// the restored transaction may only be tracked until its nonces are delivered
+		if is_restored_tx_tracked && race_state.nonces_submitted.is_none() {
+			is_restored_tx_tracked = false;
+			target_tx_tracker.set(futures::future::Fuse::terminated());
+		}
+
+		// remember submitted nonces and strategy state
+		if let Some(ref state_store) = state_store {
+			if race_state.nonces_submitted != stored_nonces_submitted {
+				stored_nonces_submitted = race_state.nonces_submitted.clone();
+				match stored_nonces_submitted {
+					Some(ref nonces) => state_store.store.save(
+						&submitted_nonces_key::<P>(),
+						&InFlightTransaction::new(
+							(*nonces.start(), *nonces.end()),
+							state_store.stall_timeout,
+						),
+					),
+					None => state_store.store.forget(&submitted_nonces_key::<P>()),
+				}
+			}
+
+			let strategy_state = strategy.state();
+			if stored_strategy_state.as_ref() != Some(&strategy_state) {
+				state_store.store.save(&strategy_state_key::<P>(), &strategy_state);
+				stored_strategy_state = Some(strategy_state);
+			}
+		}
+
 		progress_context = print_race_progress::<P, _>(progress_context, &strategy);
 
 		if source_client_is_online {
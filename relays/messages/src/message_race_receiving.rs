@@ -20,7 +20,7 @@ use crate::{
 		TargetClient as MessageLaneTargetClient, TargetClientState,
 	},
 	message_race_loop::{
-		MessageRace, NoncesRange, SourceClient, SourceClientNonces, TargetClient,
+		MessageRace, NoncesRange, RaceStateStore, SourceClient, SourceClientNonces, TargetClient,
 		TargetClientNonces,
 	},
 	message_race_strategy::BasicStrategy,
@@ -50,6 +50,7 @@ pub async fn run<P: MessageLane>(
 	target_client: impl MessageLaneTargetClient<P>,
 	target_state_updates: impl FusedStream<Item = TargetClientState<P>>,
 	metrics_msg: Option<MessageLaneLoopMetrics>,
+	state_store: Option<RaceStateStore>,
 ) -> Result<(), FailedClient> {
 	crate::message_race_loop::run(
 		ReceivingConfirmationsRaceSource {
@@ -65,6 +66,7 @@ pub async fn run<P: MessageLane>(
 		},
 		source_state_updates,
 		ReceivingConfirmationsBasicStrategy::<P>::new(),
+		state_store,
 	)
 	.await
 }
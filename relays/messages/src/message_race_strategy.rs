@@ -18,7 +18,7 @@
 //!    block, which is known to the target node).
 
 use crate::message_race_loop::{
-	NoncesRange, RaceState, RaceStrategy, SourceClientNonces, TargetClientNonces,
+	NoncesRange, RaceState, RaceStrategy, RaceStrategyState, SourceClientNonces, TargetClientNonces,
 };
 
 use async_trait::async_trait;
@@ -170,7 +170,7 @@ impl<
 	> where
 	SourceHeaderHash: Clone + Debug + Send,
 	SourceHeaderNumber: Clone + Ord + Debug + Send,
-	SourceNoncesRange: NoncesRange + Debug + Send,
+	SourceNoncesRange: NoncesRange + Clone + Debug + Send,
 	TargetHeaderHash: Debug + Send,
 	TargetHeaderNumber: Debug + Send,
 	Proof: Debug + Send,
@@ -304,6 +304,23 @@ impl<
 		self.remove_le_nonces_from_source_queue(range_end);
 		Some((range_begin..=range_end, ()))
 	}
+
+	fn state(
+		&self,
+	) -> RaceStrategyState<HeaderId<SourceHeaderHash, SourceHeaderNumber>, SourceNoncesRange> {
+		RaceStrategyState {
+			best_target_nonce: self.best_target_nonce,
+			source_queue: self.source_queue.iter().cloned().collect(),
+		}
+	}
+
+	fn restore_state(
+		&mut self,
+		state: RaceStrategyState<HeaderId<SourceHeaderHash, SourceHeaderNumber>, SourceNoncesRange>,
+	) {
+		self.best_target_nonce = state.best_target_nonce;
+		self.source_queue = state.source_queue.into();
+	}
 }
 
 #[cfg(test)]
@@ -316,6 +333,7 @@ mod tests {
 			TestSourceHeaderNumber,
 		},
 	};
+	use codec::{Decode, Encode};
 
 	type SourceNoncesRange = RangeInclusive<MessageNonce>;
 
@@ -372,6 +390,24 @@ mod tests {
 		assert_eq!(strategy.source_queue, vec![(header_id(1), 1..=5)]);
 	}
 
+	#[test]
+	fn strategy_state_is_restored() {
+		let mut strategy = BasicStrategy::<TestMessageLane>::new();
+		strategy.best_target_nonces_updated(target_nonces(5), &mut Default::default());
+		strategy.source_nonces_updated(header_id(1), source_nonces(6..=10));
+		strategy.source_nonces_updated(header_id(2), source_nonces(11..=15));
+
+		let encoded_state = strategy.state().encode();
+		let mut restored_strategy = BasicStrategy::<TestMessageLane>::new();
+		restored_strategy.restore_state(Decode::decode(&mut &encoded_state[..]).unwrap());
+		assert_eq!(restored_strategy.best_target_nonce, Some(5));
+		assert_eq!(
+			restored_strategy.source_queue,
+			vec![(header_id(1), 6..=10), (header_id(2), 11..=15)]
+		);
+		assert_eq!(restored_strategy.best_at_source(), Some(15));
+	}
+
 	#[test]
 	fn target_nonce_is_never_lower_than_latest_known_target_nonce() {
 		let mut strategy = BasicStrategy::<TestMessageLane>::new();
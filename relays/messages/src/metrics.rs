@@ -19,12 +19,13 @@
 use crate::{
 	message_lane::MessageLane,
 	message_lane_loop::{SourceClientState, TargetClientState},
+	message_race_limits::DeliveryDecision,
 };
 
 use bp_messages::MessageNonce;
 use finality_relay::SyncLoopMetrics;
 use relay_utils::metrics::{
-	metric_name, register, GaugeVec, Metric, Opts, PrometheusError, Registry, U64,
+	metric_name, register, CounterVec, GaugeVec, Metric, Opts, PrometheusError, Registry, U64,
 };
 
 /// Message lane relay metrics.
@@ -39,6 +40,8 @@ pub struct MessageLaneLoopMetrics {
 	/// Lane state nonces: "source_latest_generated", "source_latest_confirmed",
 	/// "target_latest_received", "target_latest_confirmed".
 	lane_state_nonces: GaugeVec<U64>,
+	/// Number of messages delivery decisions, made by the relayer, by decision reason.
+	delivery_decisions: CounterVec<U64>,
 }
 
 impl MessageLaneLoopMetrics {
@@ -59,6 +62,13 @@ impl MessageLaneLoopMetrics {
 				Opts::new(metric_name(prefix, "lane_state_nonces"), "Nonces of the lane state"),
 				&["type"],
 			)?,
+			delivery_decisions: CounterVec::new(
+				Opts::new(
+					metric_name(prefix, "delivery_decisions"),
+					"Number of messages delivery decisions by reason",
+				),
+				&["reason"],
+			)?,
 		})
 	}
 
@@ -139,6 +149,11 @@ impl MessageLaneLoopMetrics {
 			.with_label_values(&["target_latest_confirmed"])
 			.set(target_latest_confirmed_nonce);
 	}
+
+	/// Note messages delivery decision.
+	pub fn note_delivery_decision(&self, decision: DeliveryDecision) {
+		self.delivery_decisions.with_label_values(&[decision.as_str()]).inc();
+	}
 }
 
 impl Metric for MessageLaneLoopMetrics {
@@ -146,6 +161,7 @@ impl Metric for MessageLaneLoopMetrics {
 		self.source_to_target_finality_metrics.register(registry)?;
 		self.target_to_source_finality_metrics.register(registry)?;
 		register(self.lane_state_nonces.clone(), registry)?;
+		register(self.delivery_decisions.clone(), registry)?;
 		Ok(())
 	}
 }
@@ -0,0 +1,363 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Multi-lane message delivery loop.
+//!
+//! Single instance of this loop delivers messages of several lanes in single direction. The
+//! state of source and target nodes is the same for all lanes, so it is polled once per tick
+//! and shared by message races of all lanes.
+//!
+//! Races are selecting nonces separately for every lane. But since all races are woken up by
+//! the same state update, they are usually proving their messages at the same source header.
+//! Lane clients are expected to combine such deliveries into single messages proof and single
+//! transaction, where the runtime allows that, and to submit all other transactions of the
+//! same chain through the single transactions stream.
+
+use std::{future::Future, time::Duration};
+
+use async_trait::async_trait;
+use futures::{
+	channel::mpsc::{unbounded, UnboundedSender},
+	future::FutureExt,
+	stream::{FuturesUnordered, StreamExt},
+};
+
+use bp_messages::LaneId;
+use relay_utils::{
+	interval,
+	metrics::{MetricsParams, PrometheusError, Registry},
+	process_future_result,
+	relay_loop::Client as RelayClient,
+	retry_backoff, FailedClient, StateStore,
+};
+
+use crate::{
+	message_lane::MessageLane,
+	message_lane_loop::{
+		metrics_prefix, race_state_store, MessageDeliveryParams, SourceClient, SourceClientState,
+		TargetClient, TargetClientState,
+	},
+	message_race_delivery::run as run_message_delivery_race,
+	message_race_receiving::run as run_message_receiving_race,
+	metrics::MessageLaneLoopMetrics,
+};
+
+/// Multi-lane message loop configuration params.
+#[derive(Debug, Clone)]
+pub struct Params {
+	/// Ids of lanes this loop is servicing.
+	pub lanes: Vec<LaneId>,
+	/// Interval at which we ask source node about its updates.
+	pub source_tick: Duration,
+	/// Interval at which we ask target node about its updates.
+	pub target_tick: Duration,
+	/// Delay between moments when connection error happens and our reconnect attempt.
+	pub reconnect_delay: Duration,
+	/// Message delivery race parameters. They're the same for all lanes.
+	pub delivery_params: MessageDeliveryParams,
+	/// Timeout after which submitted transaction is considered lost.
+	pub stall_timeout: Duration,
+	/// Optional store, where the loop persists state of every lane.
+	pub state_store: Option<StateStore>,
+}
+
+/// Source client, that is able to serve multiple lanes.
+#[async_trait]
+pub trait MultiLaneSourceClient<P: MessageLane>: RelayClient {
+	/// Type of the client that serves single lane.
+	type LaneClient: SourceClient<P, Error = Self::Error>;
+
+	/// Returns state of the client. The state is the same for all lanes.
+	async fn state(&self) -> Result<SourceClientState<P>, Self::Error>;
+
+	/// Returns client that serves given lane.
+	fn lane_client(&self, lane: LaneId) -> Self::LaneClient;
+}
+
+/// Target client, that is able to serve multiple lanes.
+#[async_trait]
+pub trait MultiLaneTargetClient<P: MessageLane>: RelayClient {
+	/// Type of the client that serves single lane.
+	type LaneClient: TargetClient<P, Error = Self::Error>;
+
+	/// Returns state of the client. The state is the same for all lanes.
+	async fn state(&self) -> Result<TargetClientState<P>, Self::Error>;
+
+	/// Returns client that serves given lane.
+	///
+	/// Messages proofs of different lanes, submitted by lane clients at the same time, should be
+	/// combined into single delivery transaction.
+	fn lane_client(&self, lane: LaneId) -> Self::LaneClient;
+}
+
+/// Metrics of all lanes, served by the multi-lane loop.
+#[derive(Clone)]
+pub struct MultiLaneLoopMetrics {
+	lanes: Vec<(LaneId, MessageLaneLoopMetrics)>,
+}
+
+impl MultiLaneLoopMetrics {
+	/// Create metrics of all given lanes.
+	pub fn new<P: MessageLane>(lanes: &[LaneId]) -> Result<Self, PrometheusError> {
+		Ok(MultiLaneLoopMetrics {
+			lanes: lanes
+				.iter()
+				.map(|lane| {
+					MessageLaneLoopMetrics::new(Some(&metrics_prefix::<P>(lane)))
+						.map(|metrics| (*lane, metrics))
+				})
+				.collect::<Result<_, _>>()?,
+		})
+	}
+
+	/// Returns metrics of given lane.
+	fn lane_metrics(&self, lane: &LaneId) -> Option<MessageLaneLoopMetrics> {
+		self.lanes.iter().find(|(id, _)| id == lane).map(|(_, metrics)| metrics.clone())
+	}
+}
+
+impl relay_utils::metrics::Metric for MultiLaneLoopMetrics {
+	fn register(&self, registry: &Registry) -> Result<(), PrometheusError> {
+		for (_, metrics) in &self.lanes {
+			metrics.register(registry)?;
+		}
+		Ok(())
+	}
+}
+
+/// Return prefix that is used by the multi-lane loop.
+pub fn multi_lane_loop_name<P: MessageLane>() -> String {
+	format!("{}_to_{}_MessageLanes", P::SOURCE_NAME, P::TARGET_NAME)
+}
+
+/// Run multi-lane message service loop.
+pub async fn run<P: MessageLane>(
+	params: Params,
+	source_client: impl MultiLaneSourceClient<P>,
+	target_client: impl MultiLaneTargetClient<P>,
+	metrics_params: MetricsParams,
+	exit_signal: impl Future<Output = ()> + Send + 'static,
+) -> Result<(), relay_utils::Error> {
+	let exit_signal = exit_signal.shared();
+	relay_utils::relay_loop(source_client, target_client)
+		.reconnect_delay(params.reconnect_delay)
+		.with_metrics(metrics_params)
+		.loop_metric(MultiLaneLoopMetrics::new::<P>(&params.lanes)?)?
+		.expose()
+		.await?
+		.run(multi_lane_loop_name::<P>(), move |source_client, target_client, metrics| {
+			run_until_connection_lost(
+				params.clone(),
+				source_client,
+				target_client,
+				metrics,
+				exit_signal.clone(),
+			)
+		})
+		.await
+}
+
+/// State update senders of single lane races.
+struct LaneStateSenders<P: MessageLane> {
+	/// Metrics of the lane.
+	metrics: Option<MessageLaneLoopMetrics>,
+	/// Senders of source state updates.
+	source: [UnboundedSender<SourceClientState<P>>; 2],
+	/// Senders of target state updates.
+	target: [UnboundedSender<TargetClientState<P>>; 2],
+}
+
+/// Run multi-lane message delivery loop until connection with target or source node is lost, or
+/// exit signal is received.
+async fn run_until_connection_lost<
+	P: MessageLane,
+	SC: MultiLaneSourceClient<P>,
+	TC: MultiLaneTargetClient<P>,
+>(
+	params: Params,
+	source_client: SC,
+	target_client: TC,
+	metrics: Option<MultiLaneLoopMetrics>,
+	exit_signal: impl Future<Output = ()>,
+) -> Result<(), FailedClient> {
+	let mut source_retry_backoff = retry_backoff();
+	let mut source_client_is_online = false;
+	let mut source_state_required = true;
+	let source_state = source_client.state().fuse();
+	let source_go_offline_future = futures::future::Fuse::terminated();
+	let source_tick_stream = interval(params.source_tick).fuse();
+
+	let mut target_retry_backoff = retry_backoff();
+	let mut target_client_is_online = false;
+	let mut target_state_required = true;
+	let target_state = target_client.state().fuse();
+	let target_go_offline_future = futures::future::Fuse::terminated();
+	let target_tick_stream = interval(params.target_tick).fuse();
+
+	// start delivery and receiving races for every lane
+	let mut lanes_state_senders = Vec::with_capacity(params.lanes.len());
+	let mut race_loops = FuturesUnordered::new();
+	for lane in &params.lanes {
+		let lane_source_client = source_client.lane_client(*lane);
+		let lane_target_client = target_client.lane_client(*lane);
+		let lane_metrics = metrics.as_ref().and_then(|metrics| metrics.lane_metrics(lane));
+
+		let (
+			(delivery_source_state_sender, delivery_source_state_receiver),
+			(delivery_target_state_sender, delivery_target_state_receiver),
+		) = (unbounded(), unbounded());
+		race_loops.push(
+			run_message_delivery_race(
+				lane_source_client.clone(),
+				delivery_source_state_receiver,
+				lane_target_client.clone(),
+				delivery_target_state_receiver,
+				lane_metrics.clone(),
+				params.delivery_params.clone(),
+				race_state_store::<P>(&params.state_store, params.stall_timeout, lane),
+			)
+			.boxed(),
+		);
+
+		let (
+			(receiving_source_state_sender, receiving_source_state_receiver),
+			(receiving_target_state_sender, receiving_target_state_receiver),
+		) = (unbounded(), unbounded());
+		race_loops.push(
+			run_message_receiving_race(
+				lane_source_client,
+				receiving_source_state_receiver,
+				lane_target_client,
+				receiving_target_state_receiver,
+				lane_metrics.clone(),
+				race_state_store::<P>(&params.state_store, params.stall_timeout, lane),
+			)
+			.boxed(),
+		);
+
+		lanes_state_senders.push(LaneStateSenders::<P> {
+			metrics: lane_metrics,
+			source: [delivery_source_state_sender, receiving_source_state_sender],
+			target: [delivery_target_state_sender, receiving_target_state_sender],
+		});
+	}
+
+	let exit_signal = exit_signal.fuse();
+
+	futures::pin_mut!(
+		source_state,
+		source_go_offline_future,
+		source_tick_stream,
+		target_state,
+		target_go_offline_future,
+		target_tick_stream,
+		exit_signal
+	);
+
+	loop {
+		futures::select! {
+			new_source_state = source_state => {
+				source_state_required = false;
+
+				source_client_is_online = process_future_result(
+					new_source_state,
+					&mut source_retry_backoff,
+					|new_source_state| {
+						log::debug!(
+							target: "bridge",
+							"Received state from {} node: {:?}",
+							P::SOURCE_NAME,
+							new_source_state,
+						);
+						for lane_state_senders in &lanes_state_senders {
+							for sender in &lane_state_senders.source {
+								let _ = sender.unbounded_send(new_source_state.clone());
+							}
+							if let Some(lane_metrics) = lane_state_senders.metrics.as_ref() {
+								lane_metrics.update_source_state::<P>(new_source_state.clone());
+							}
+						}
+					},
+					&mut source_go_offline_future,
+					async_std::task::sleep,
+					|| format!("Error retrieving state from {} node", P::SOURCE_NAME),
+				).fail_if_connection_error(FailedClient::Source)?;
+			},
+			_ = source_go_offline_future => {
+				source_client_is_online = true;
+			},
+			_ = source_tick_stream.next() => {
+				source_state_required = true;
+			},
+			new_target_state = target_state => {
+				target_state_required = false;
+
+				target_client_is_online = process_future_result(
+					new_target_state,
+					&mut target_retry_backoff,
+					|new_target_state| {
+						log::debug!(
+							target: "bridge",
+							"Received state from {} node: {:?}",
+							P::TARGET_NAME,
+							new_target_state,
+						);
+						for lane_state_senders in &lanes_state_senders {
+							for sender in &lane_state_senders.target {
+								let _ = sender.unbounded_send(new_target_state.clone());
+							}
+							if let Some(lane_metrics) = lane_state_senders.metrics.as_ref() {
+								lane_metrics.update_target_state::<P>(new_target_state.clone());
+							}
+						}
+					},
+					&mut target_go_offline_future,
+					async_std::task::sleep,
+					|| format!("Error retrieving state from {} node", P::TARGET_NAME),
+				).fail_if_connection_error(FailedClient::Target)?;
+			},
+			_ = target_go_offline_future => {
+				target_client_is_online = true;
+			},
+			_ = target_tick_stream.next() => {
+				target_state_required = true;
+			},
+
+			race_error = race_loops.select_next_some() => {
+				match race_error {
+					Ok(_) => unreachable!("only ends with error; qed"),
+					Err(err) => return Err(err),
+				}
+			},
+
+			() = exit_signal => {
+				return Ok(());
+			}
+		}
+
+		if source_client_is_online && source_state_required {
+			log::debug!(target: "bridge", "Asking {} node about its state", P::SOURCE_NAME);
+			source_state.set(source_client.state().fuse());
+			source_client_is_online = false;
+		}
+
+		if target_client_is_online && target_state_required {
+			log::debug!(target: "bridge", "Asking {} node about its state", P::TARGET_NAME);
+			target_state.set(target_client.state().fuse());
+			target_client_is_online = false;
+		}
+	}
+}
@@ -18,6 +18,7 @@ use std::fmt::Debug;
 
 use relay_substrate_client::Chain;
 
+pub mod parachains_batch;
 pub mod parachains_loop;
 pub mod parachains_loop_metrics;
 
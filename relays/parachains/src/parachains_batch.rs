@@ -0,0 +1,183 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Planning of parachain heads batches.
+//!
+//! The `submit_parachain_heads` call accepts heads of multiple parachains, proved by a single
+//! storage proof. The planner selects heads that may be delivered in a single transaction,
+//! without exceeding the proof size and weight limits of the target chain.
+
+use bp_parachains::Weight;
+use bp_polkadot_core::parachains::{ParaHeadsProof, ParaId};
+use std::collections::BTreeSet;
+
+/// Limits of the single parachain heads batch.
+///
+/// The cost model here is linear: every parachain head adds the same (expected) number of
+/// bytes to the storage proof and the same weight to the `submit_parachain_heads` call. Actual
+/// proof size is checked once the proof is generated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParachainsBatchLimits {
+	/// Maximal number of parachain heads in the single batch.
+	pub max_parachains: u32,
+	/// Maximal size of the parachain heads storage proof.
+	pub max_proof_size: u32,
+	/// Maximal weight of the `submit_parachain_heads` call.
+	pub max_weight: Weight,
+	/// Expected size of storage proof nodes that are shared by all parachain heads.
+	pub expected_extra_proof_size: u32,
+	/// Expected size of the storage proof of the single parachain head.
+	pub expected_head_proof_size: u32,
+	/// Weight of the `submit_parachain_heads` call, that doesn't depend on number of parachains.
+	pub base_weight: Weight,
+	/// Weight of the single parachain head import (including potential pruning).
+	pub head_weight: Weight,
+	/// Weight of every proof byte above the expected proof size.
+	pub extra_proof_byte_weight: Weight,
+}
+
+impl ParachainsBatchLimits {
+	/// Returns expected size of storage proof of given number of parachain heads.
+	pub fn expected_proof_size(&self, parachains_count: u32) -> u32 {
+		parachains_count
+			.saturating_mul(self.expected_head_proof_size)
+			.saturating_add(self.expected_extra_proof_size)
+	}
+
+	/// Returns expected weight of the `submit_parachain_heads` call with given number of
+	/// parachain heads and given storage proof size.
+	pub fn expected_weight(&self, parachains_count: u32, proof_size: u32) -> Weight {
+		let extra_proof_bytes =
+			proof_size.saturating_sub(self.expected_proof_size(parachains_count));
+		self.base_weight
+			.saturating_add(self.head_weight.saturating_mul(parachains_count as u64))
+			.saturating_add(self.extra_proof_byte_weight.saturating_mul(extra_proof_bytes as u64))
+	}
+
+	/// Returns true if batch with given number of parachain heads and proof of given size fits
+	/// the limits.
+	pub fn fits(&self, parachains_count: u32, proof_size: u32) -> bool {
+		parachains_count <= self.max_parachains &&
+			proof_size <= self.max_proof_size &&
+			self.expected_weight(parachains_count, proof_size).all_lte(self.max_weight)
+	}
+
+	/// Returns maximal number of parachain heads (of expected size) that fits the limits.
+	pub fn max_parachains_in_batch(&self) -> u32 {
+		let mut parachains_count = 0;
+		while parachains_count < self.max_parachains &&
+			self.fits(parachains_count + 1, self.expected_proof_size(parachains_count + 1))
+		{
+			parachains_count += 1;
+		}
+
+		// even if limits are configured incorrectly, we still want to make some progress
+		std::cmp::max(parachains_count, 1)
+	}
+}
+
+/// Select parachains that will be updated in the next `submit_parachain_heads` call.
+///
+/// Parachains that have pending messages are selected first. Remaining slots are occupied
+/// by other outdated parachains, in the order they're given in `outdated_parachains`.
+pub fn select_parachains_batch(
+	limits: &ParachainsBatchLimits,
+	outdated_parachains: Vec<ParaId>,
+	parachains_with_pending_messages: &BTreeSet<ParaId>,
+) -> Vec<ParaId> {
+	let max_parachains = limits.max_parachains_in_batch() as usize;
+	let (mut batch, regular): (Vec<_>, Vec<_>) = outdated_parachains
+		.into_iter()
+		.partition(|para_id| parachains_with_pending_messages.contains(para_id));
+	batch.extend(regular);
+	batch.truncate(max_parachains);
+	batch
+}
+
+/// Returns actual size of the parachain heads storage proof.
+pub fn parachain_heads_proof_size(proof: &ParaHeadsProof) -> u32 {
+	proof.0.iter().fold(0u32, |size, node| size.saturating_add(node.len() as u32))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn test_limits() -> ParachainsBatchLimits {
+		ParachainsBatchLimits {
+			max_parachains: 16,
+			max_proof_size: 1024,
+			max_weight: Weight::from_ref_time(1_000),
+			expected_extra_proof_size: 100,
+			expected_head_proof_size: 100,
+			base_weight: Weight::from_ref_time(100),
+			head_weight: Weight::from_ref_time(100),
+			extra_proof_byte_weight: Weight::from_ref_time(1),
+		}
+	}
+
+	#[test]
+	fn expected_weight_includes_extra_proof_bytes() {
+		let limits = test_limits();
+		assert_eq!(limits.expected_weight(2, 300), Weight::from_ref_time(300));
+		assert_eq!(limits.expected_weight(2, 250), Weight::from_ref_time(300));
+		assert_eq!(limits.expected_weight(2, 350), Weight::from_ref_time(350));
+	}
+
+	#[test]
+	fn max_parachains_in_batch_respects_all_limits() {
+		// weight limit: 100 + 9 * 100 = 1_000
+		assert_eq!(test_limits().max_parachains_in_batch(), 9);
+
+		// proof size limit: 100 + 5 * 100 = 600
+		let mut limits = test_limits();
+		limits.max_proof_size = 600;
+		assert_eq!(limits.max_parachains_in_batch(), 5);
+
+		// count limit
+		let mut limits = test_limits();
+		limits.max_parachains = 3;
+		assert_eq!(limits.max_parachains_in_batch(), 3);
+
+		// we always want to make some progress
+		let mut limits = test_limits();
+		limits.max_weight = Weight::zero();
+		assert_eq!(limits.max_parachains_in_batch(), 1);
+	}
+
+	#[test]
+	fn parachains_with_pending_messages_are_selected_first() {
+		let mut limits = test_limits();
+		limits.max_parachains = 3;
+
+		assert_eq!(
+			select_parachains_batch(
+				&limits,
+				(0..5).map(ParaId).collect(),
+				&vec![ParaId(2), ParaId(4)].into_iter().collect(),
+			),
+			vec![ParaId(2), ParaId(4), ParaId(0)],
+		);
+	}
+
+	#[test]
+	fn all_parachains_are_selected_if_they_fit_limits() {
+		assert_eq!(
+			select_parachains_batch(&test_limits(), (0..5).map(ParaId).collect(), &BTreeSet::new()),
+			(0..5).map(ParaId).collect::<Vec<_>>(),
+		);
+	}
+}
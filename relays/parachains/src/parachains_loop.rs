@@ -14,7 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{parachains_loop_metrics::ParachainsLoopMetrics, ParachainsPipeline};
+use crate::{
+	parachains_batch::{
+		parachain_heads_proof_size, select_parachains_batch, ParachainsBatchLimits,
+	},
+	parachains_loop_metrics::ParachainsLoopMetrics,
+	ParachainsPipeline,
+};
 
 use async_trait::async_trait;
 use bp_parachains::BestParaHeadHash;
@@ -28,8 +34,8 @@ use futures::{
 };
 use relay_substrate_client::{BlockNumberOf, Chain, HeaderIdOf};
 use relay_utils::{
-	metrics::MetricsParams, relay_loop::Client as RelayClient, FailedClient,
-	TrackedTransactionStatus, TransactionTracker,
+	metrics::MetricsParams, relay_loop::Client as RelayClient, FailedClient, InFlightTransaction,
+	RestoredTransactionTracker, StateStore, TrackedTransactionStatus, TransactionTracker,
 };
 use std::{
 	collections::{BTreeMap, BTreeSet},
@@ -49,6 +55,11 @@ pub struct ParachainSyncParams {
 	/// Stall timeout. If we have submitted transaction and we see no state updates for this
 	/// period, we consider our transaction lost.
 	pub stall_timeout: Duration,
+	/// Parachain heads batch limits. If `None`, all outdated parachain heads are submitted
+	/// in a single transaction.
+	pub batch_limits: Option<ParachainsBatchLimits>,
+	/// Optional store, where the loop persists its state.
+	pub state_store: Option<StateStore>,
 }
 
 /// Parachain heads update strategy.
@@ -118,6 +129,15 @@ pub trait SourceClient<P: ParachainsPipeline>: RelayClient {
 		at_block: HeaderIdOf<P::SourceChain>,
 		parachains: &[ParaId],
 	) -> Result<(ParaHeadsProof, Vec<ParaHash>), Self::Error>;
+
+	/// Get parachains that have messages, waiting for parachain head to be updated at the
+	/// target chain.
+	///
+	/// Heads of those parachains are included in batches first. By default, no parachains
+	/// are prioritized.
+	async fn parachains_with_pending_messages(&self) -> Result<BTreeSet<ParaId>, Self::Error> {
+		Ok(BTreeSet::new())
+	}
 }
 
 /// Target client used in parachain heads synchronization loop.
@@ -157,6 +177,9 @@ pub trait TargetClient<P: ParachainsPipeline>: RelayClient {
 
 /// Return prefix that will be used by default to expose Prometheus metrics of the parachains
 /// sync loop.
+/// Key of the submitted parachain heads transaction in the state store.
+const SUBMITTED_HEADS_KEY: &str = "SubmittedHeads";
+
 pub fn metrics_prefix<P: ParachainsPipeline>() -> String {
 	format!("{}_to_{}_Parachains", P::SourceChain::NAME, P::TargetChain::NAME)
 }
@@ -207,7 +230,12 @@ where
 		P::TargetChain::AVERAGE_BLOCK_INTERVAL,
 	);
 
-	let mut submitted_heads_tracker: Option<SubmittedHeadsTracker<P>> = None;
+	let state_store = sync_params
+		.state_store
+		.as_ref()
+		.map(|state_store| state_store.scoped(&metrics_prefix::<P>()));
+	let mut submitted_heads_tracker: Option<SubmittedHeadsTracker<P>> =
+		restore_submitted_heads_tracker::<P>(state_store.as_ref());
 
 	futures::pin_mut!(exit_signal);
 
@@ -262,7 +290,15 @@ where
 
 		// check if our transaction has been mined
 		if let Some(tracker) = submitted_heads_tracker.take() {
-			match tracker.update(&best_target_block, &heads_at_target).await {
+			let status = tracker.update(&best_target_block, &heads_at_target).await;
+			if let SubmittedHeadsStatus::Final(_) = status {
+				// we don't need to wait for the transaction after restart
+				if let Some(ref state_store) = state_store {
+					state_store.forget(SUBMITTED_HEADS_KEY);
+				}
+			}
+
+			match status {
 				SubmittedHeadsStatus::Waiting(tracker) => {
 					// no news about our transaction and we shall keep waiting
 					submitted_heads_tracker = Some(tracker);
@@ -325,18 +361,13 @@ where
 		);
 
 		if is_update_required {
-			let (heads_proofs, head_hashes) = source_client
-				.prove_parachain_heads(best_finalized_relay_block, &updated_ids)
-				.await
-				.map_err(|e| {
-					log::warn!(
-						target: "bridge",
-						"Failed to prove {} parachain heads: {:?}",
-						P::SourceChain::NAME,
-						e,
-					);
-					FailedClient::Source
-				})?;
+			let (updated_ids, heads_proofs, head_hashes) = prove_parachain_heads_batch(
+				&source_client,
+				sync_params.batch_limits.as_ref(),
+				best_finalized_relay_block,
+				updated_ids,
+			)
+			.await?;
 			log::info!(
 				target: "bridge",
 				"Submitting {} parachain heads update transaction to {}",
@@ -367,6 +398,15 @@ where
 					);
 					FailedClient::Target
 				})?;
+			if let Some(ref state_store) = state_store {
+				state_store.save(
+					SUBMITTED_HEADS_KEY,
+					&InFlightTransaction::new(
+						(updated_ids.clone(), best_finalized_relay_block.0),
+						sync_params.stall_timeout,
+					),
+				);
+			}
 			submitted_heads_tracker = Some(SubmittedHeadsTracker::<P>::new(
 				updated_ids,
 				best_finalized_relay_block.0,
@@ -376,6 +416,124 @@ where
 	}
 }
 
+/// Restore parachain heads transaction that has been submitted by the previous relay instance.
+///
+/// Returns `None` if there's no such transaction or if it is already considered lost.
+fn restore_submitted_heads_tracker<P: ParachainsPipeline>(
+	state_store: Option<&StateStore>,
+) -> Option<SubmittedHeadsTracker<P>>
+where
+	P::SourceChain: Chain<BlockNumber = RelayBlockNumber>,
+{
+	let state_store = state_store?;
+	let transaction: InFlightTransaction<(Vec<ParaId>, RelayBlockNumber)> =
+		state_store.load(SUBMITTED_HEADS_KEY)?;
+	let remaining_time = match transaction.remaining_time() {
+		Some(remaining_time) => remaining_time,
+		None => {
+			state_store.forget(SUBMITTED_HEADS_KEY);
+			return None
+		},
+	};
+
+	let (awaiting_update, relay_block_number) = transaction.data;
+	log::info!(
+		target: "bridge",
+		"Restored {} parachain heads transaction at {} (parachains: {:?}, relay block: {}). \
+		Will wait for it for {}s",
+		P::SourceChain::NAME,
+		P::TargetChain::NAME,
+		awaiting_update,
+		relay_block_number,
+		remaining_time.as_secs(),
+	);
+
+	Some(SubmittedHeadsTracker::new(
+		awaiting_update,
+		relay_block_number,
+		RestoredTransactionTracker::new(remaining_time),
+	))
+}
+
+/// Select parachain heads that fit the batch limits and prove them.
+///
+/// If there are no batch limits, all `updated_ids` heads are proved. Otherwise, the batch is
+/// selected using expected proof size and weight. If the actual proof doesn't fit the limits,
+/// the batch is shrunk until it fits or until it contains the single parachain head.
+async fn prove_parachain_heads_batch<P: ParachainsPipeline>(
+	source_client: &impl SourceClient<P>,
+	batch_limits: Option<&ParachainsBatchLimits>,
+	at_relay_block: HeaderIdOf<P::SourceChain>,
+	updated_ids: Vec<ParaId>,
+) -> Result<(Vec<ParaId>, ParaHeadsProof, Vec<ParaHash>), FailedClient> {
+	let batch_limits = match batch_limits {
+		Some(batch_limits) => batch_limits,
+		None => return prove_parachain_heads(source_client, at_relay_block, updated_ids).await,
+	};
+
+	let parachains_with_pending_messages =
+		source_client.parachains_with_pending_messages().await.map_err(|e| {
+			log::warn!(
+				target: "bridge",
+				"Failed to read {} parachains with pending messages: {:?}",
+				P::SourceChain::NAME,
+				e,
+			);
+			FailedClient::Source
+		})?;
+	let mut batch =
+		select_parachains_batch(batch_limits, updated_ids, &parachains_with_pending_messages);
+	loop {
+		let (batch_ids, proof, hashes) =
+			prove_parachain_heads(source_client, at_relay_block, batch).await?;
+		let proof_size = parachain_heads_proof_size(&proof);
+		if batch_ids.len() == 1 || batch_limits.fits(batch_ids.len() as u32, proof_size) {
+			log::debug!(
+				target: "bridge",
+				"Selected {} {} parachain heads for the batch. Proof size: {}",
+				batch_ids.len(),
+				P::SourceChain::NAME,
+				proof_size,
+			);
+			return Ok((batch_ids, proof, hashes))
+		}
+
+		log::debug!(
+			target: "bridge",
+			"Proof of {} {} parachain heads doesn't fit the batch limits. Proof size: {}. \
+				Going to shrink the batch",
+			batch_ids.len(),
+			P::SourceChain::NAME,
+			proof_size,
+		);
+
+		// prioritized parachains are at the beginning of the batch, so we only remove the tail
+		batch = batch_ids;
+		batch.truncate(batch.len() / 2);
+	}
+}
+
+/// Prove given parachain heads.
+async fn prove_parachain_heads<P: ParachainsPipeline>(
+	source_client: &impl SourceClient<P>,
+	at_relay_block: HeaderIdOf<P::SourceChain>,
+	parachains: Vec<ParaId>,
+) -> Result<(Vec<ParaId>, ParaHeadsProof, Vec<ParaHash>), FailedClient> {
+	source_client
+		.prove_parachain_heads(at_relay_block, &parachains)
+		.await
+		.map(|(proof, hashes)| (parachains, proof, hashes))
+		.map_err(|e| {
+			log::warn!(
+				target: "bridge",
+				"Failed to prove {} parachain heads: {:?}",
+				P::SourceChain::NAME,
+				e,
+			);
+			FailedClient::Source
+		})
+}
+
 /// Given heads at source and target clients, returns set of heads that are out of sync.
 fn select_parachains_to_update<P: ParachainsPipeline>(
 	heads_at_source: BTreeMap<ParaId, AvailableHeader<ParaHash>>,
@@ -828,6 +986,8 @@ mod tests {
 			parachains: vec![ParaId(PARA_ID)],
 			strategy: ParachainSyncStrategy::Any,
 			stall_timeout: Duration::from_secs(60),
+			batch_limits: None,
+			state_store: None,
 		}
 	}
 
@@ -1115,6 +1275,39 @@ mod tests {
 		));
 	}
 
+	#[test]
+	fn submitted_heads_tracker_is_restored_from_state_store() {
+		let dir = tempfile::tempdir().unwrap();
+		let state_store = StateStore::open(dir.path()).unwrap();
+		state_store.save(
+			SUBMITTED_HEADS_KEY,
+			&InFlightTransaction::new((vec![ParaId(PARA_ID)], 10u32), Duration::from_secs(60)),
+		);
+
+		let tracker =
+			restore_submitted_heads_tracker::<TestParachainsPipeline>(Some(&state_store)).unwrap();
+		assert_eq!(tracker.awaiting_update, vec![ParaId(PARA_ID)].into_iter().collect());
+		assert_eq!(tracker.relay_block_number, 10);
+	}
+
+	#[test]
+	fn lost_submitted_heads_tracker_is_not_restored_from_state_store() {
+		let dir = tempfile::tempdir().unwrap();
+		let state_store = StateStore::open(dir.path()).unwrap();
+		state_store.save(
+			SUBMITTED_HEADS_KEY,
+			&InFlightTransaction::new((vec![ParaId(PARA_ID)], 10u32), Duration::from_secs(0)),
+		);
+
+		assert!(
+			restore_submitted_heads_tracker::<TestParachainsPipeline>(Some(&state_store)).is_none()
+		);
+		assert_eq!(
+			state_store.load::<InFlightTransaction<(Vec<ParaId>, u32)>>(SUBMITTED_HEADS_KEY),
+			None,
+		);
+	}
+
 	#[test]
 	fn parachain_is_not_updated_if_it_is_unknown_to_both_clients() {
 		assert_eq!(
@@ -1241,6 +1434,8 @@ mod tests {
 			parachains: vec![ParaId(PARA_ID), ParaId(PARA_1_ID)],
 			strategy: ParachainSyncStrategy::Any,
 			stall_timeout: Duration::from_secs(60),
+			batch_limits: None,
+			state_store: None,
 		};
 
 		assert!(!is_update_required(&sync_params, &[]));
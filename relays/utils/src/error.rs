@@ -11,7 +11,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
 
-use std::net::AddrParseError;
+use std::{net::AddrParseError, path::PathBuf};
 use thiserror::Error;
 
 /// Result type used by relay utilities.
@@ -43,4 +43,7 @@ pub enum Error {
 	/// Prometheus error.
 	#[error("{0}")]
 	Prometheus(#[from] substrate_prometheus_endpoint::prometheus::Error),
+	/// Failed to open the relay state store.
+	#[error("Failed to open relay state store at {0:?}: {1}")]
+	StateStore(PathBuf, String),
 }
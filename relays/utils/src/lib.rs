@@ -20,6 +20,9 @@ pub use bp_runtime::HeaderId;
 pub use error::Error;
 pub use relay_loop::{relay_loop, relay_metrics};
 pub use sp_runtime::traits::{UniqueSaturatedFrom, UniqueSaturatedInto};
+pub use state_store::{
+	InFlightTransaction, MaybeRestoredTransactionTracker, RestoredTransactionTracker, StateStore,
+};
 
 use async_trait::async_trait;
 use backoff::{backoff::Backoff, ExponentialBackoff};
@@ -47,6 +50,7 @@ pub mod error;
 pub mod initialize;
 pub mod metrics;
 pub mod relay_loop;
+pub mod state_store;
 
 /// Block number traits shared by all chains that relay is able to serve.
 pub trait BlockNumberBase:
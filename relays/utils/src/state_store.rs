@@ -0,0 +1,277 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Persistent relay state.
+//!
+//! Relay loops are keeping their state in memory. So after restart they need to re-read
+//! everything from the nodes and they may resubmit transactions that are still in the pool.
+//! The [`StateStore`] is an optional embedded (file-backed) database, where loops may save
+//! some parts of their state to resume from after restart.
+
+use crate::{Error, TrackedTransactionStatus, TransactionTracker};
+
+use async_trait::async_trait;
+use codec::{Decode, Encode};
+use std::{
+	fmt::Debug,
+	marker::PhantomData,
+	path::{Path, PathBuf},
+	sync::Arc,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The only column of the state database.
+const STATE_COLUMN: u8 = 0;
+
+/// Persistent relay state store.
+///
+/// All values are stored in the single column of the `parity-db` database. Keys of different
+/// loops are separated using scopes (see [`StateStore::scoped`]). The store is optional, so
+/// database errors are never fatal - they're logged and the loop just keeps running with its
+/// in-memory state.
+#[derive(Clone)]
+pub struct StateStore {
+	/// Path to the database directory.
+	path: PathBuf,
+	/// The database itself.
+	db: Arc<parity_db::Db>,
+	/// Prefix of all keys of this store.
+	scope: String,
+}
+
+impl StateStore {
+	/// Open the state store at given directory. The database is created if it is missing.
+	pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+		let path = path.as_ref().to_path_buf();
+		let options = parity_db::Options::with_columns(&path, 1);
+		let db = parity_db::Db::open_or_create(&options)
+			.map_err(|e| Error::StateStore(path.clone(), e.to_string()))?;
+		Ok(StateStore { path, db: Arc::new(db), scope: String::new() })
+	}
+
+	/// Returns the same store, where all keys are additionally prefixed with given scope.
+	pub fn scoped(&self, scope: &str) -> Self {
+		StateStore {
+			path: self.path.clone(),
+			db: self.db.clone(),
+			scope: format!("{}{}/", self.scope, scope),
+		}
+	}
+
+	/// Read value from the store.
+	///
+	/// Returns `None` if value is missing or if we have failed to read or decode it.
+	pub fn load<T: Decode>(&self, key: &str) -> Option<T> {
+		let key = self.full_key(key);
+		let raw_value = self
+			.db
+			.get(STATE_COLUMN, key.as_bytes())
+			.map_err(|e| {
+				log::warn!(
+					target: "bridge",
+					"Failed to read {} from the relay state store at {:?}: {:?}",
+					key,
+					self.path,
+					e,
+				)
+			})
+			.ok()??;
+		T::decode(&mut &raw_value[..])
+			.map_err(|e| {
+				log::warn!(
+					target: "bridge",
+					"Failed to decode {} from the relay state store at {:?}: {:?}",
+					key,
+					self.path,
+					e,
+				)
+			})
+			.ok()
+	}
+
+	/// Write value to the store.
+	pub fn save<T: Encode>(&self, key: &str, value: &T) {
+		self.commit(key, Some(value.encode()))
+	}
+
+	/// Remove value from the store.
+	pub fn forget(&self, key: &str) {
+		self.commit(key, None)
+	}
+
+	/// Insert or remove value.
+	fn commit(&self, key: &str, value: Option<Vec<u8>>) {
+		let key = self.full_key(key);
+		if let Err(e) = self.db.commit(vec![(STATE_COLUMN, key.as_bytes(), value)]) {
+			log::warn!(
+				target: "bridge",
+				"Failed to update {} at the relay state store at {:?}: {:?}",
+				key,
+				self.path,
+				e,
+			);
+		}
+	}
+
+	/// Returns full (scoped) key.
+	fn full_key(&self, key: &str) -> String {
+		format!("{}{}", self.scope, key)
+	}
+}
+
+impl Debug for StateStore {
+	fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		fmt.debug_struct("StateStore")
+			.field("path", &self.path)
+			.field("scope", &self.scope)
+			.finish()
+	}
+}
+
+/// Transaction that has been submitted by the relay, but its fate is yet unknown.
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq)]
+pub struct InFlightTransaction<T> {
+	/// Loop-specific transaction data.
+	pub data: T,
+	/// Unix timestamp (in seconds) after which the transaction is considered lost.
+	pub valid_till: u64,
+}
+
+impl<T> InFlightTransaction<T> {
+	/// Create new in-flight transaction, that is considered lost after given timeout.
+	pub fn new(data: T, stall_timeout: Duration) -> Self {
+		InFlightTransaction {
+			data,
+			valid_till: unix_time_now().saturating_add(stall_timeout.as_secs()),
+		}
+	}
+
+	/// Returns time left before the transaction is considered lost. Returns `None` if the
+	/// transaction is already considered lost.
+	pub fn remaining_time(&self) -> Option<Duration> {
+		self.valid_till
+			.checked_sub(unix_time_now())
+			.filter(|remaining| *remaining != 0)
+			.map(Duration::from_secs)
+	}
+}
+
+/// Tracker of the transaction that has been submitted before the relay restart.
+///
+/// We can't track such transaction directly, so the tracker only waits until its stall timeout
+/// expires and then reports that the transaction has been lost. Loops are expected to stop
+/// waiting as soon as they see effects of the transaction at the target node.
+pub struct RestoredTransactionTracker<HeaderId> {
+	remaining_time: Duration,
+	_phantom: PhantomData<fn() -> HeaderId>,
+}
+
+impl<HeaderId> RestoredTransactionTracker<HeaderId> {
+	/// Create new tracker of restored transaction.
+	pub fn new(remaining_time: Duration) -> Self {
+		RestoredTransactionTracker { remaining_time, _phantom: Default::default() }
+	}
+}
+
+#[async_trait]
+impl<HeaderId: Clone + Send> TransactionTracker for RestoredTransactionTracker<HeaderId> {
+	type HeaderId = HeaderId;
+
+	async fn wait(self) -> TrackedTransactionStatus<HeaderId> {
+		async_std::task::sleep(self.remaining_time).await;
+		TrackedTransactionStatus::Lost
+	}
+}
+
+/// Tracker of either transaction that has been submitted by this relay instance, or the
+/// transaction, restored from the state store.
+pub enum MaybeRestoredTransactionTracker<T: TransactionTracker> {
+	/// The transaction has been submitted by this relay instance.
+	Submitted(T),
+	/// The transaction has been restored from the state store.
+	Restored(RestoredTransactionTracker<T::HeaderId>),
+}
+
+#[async_trait]
+impl<T: TransactionTracker> TransactionTracker for MaybeRestoredTransactionTracker<T> {
+	type HeaderId = T::HeaderId;
+
+	async fn wait(self) -> TrackedTransactionStatus<T::HeaderId> {
+		match self {
+			MaybeRestoredTransactionTracker::Submitted(tracker) => tracker.wait().await,
+			MaybeRestoredTransactionTracker::Restored(tracker) => tracker.wait().await,
+		}
+	}
+}
+
+/// Returns current unix time in seconds.
+fn unix_time_now() -> u64 {
+	SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn state_store_works() {
+		let dir = tempfile::tempdir().unwrap();
+		let store = StateStore::open(dir.path()).unwrap();
+
+		assert_eq!(store.load::<u64>("key"), None);
+		store.save("key", &42u64);
+		assert_eq!(store.load::<u64>("key"), Some(42));
+		store.forget("key");
+		assert_eq!(store.load::<u64>("key"), None);
+	}
+
+	#[test]
+	fn state_store_scopes_are_isolated() {
+		let dir = tempfile::tempdir().unwrap();
+		let store = StateStore::open(dir.path()).unwrap();
+		let scope1 = store.scoped("scope1");
+		let scope2 = store.scoped("scope2");
+
+		scope1.save("key", &1u64);
+		scope2.save("key", &2u64);
+		assert_eq!(store.load::<u64>("key"), None);
+		assert_eq!(scope1.load::<u64>("key"), Some(1));
+		assert_eq!(scope2.load::<u64>("key"), Some(2));
+	}
+
+	#[test]
+	fn state_store_survives_reopen() {
+		let dir = tempfile::tempdir().unwrap();
+		StateStore::open(dir.path()).unwrap().scoped("scope").save("key", &42u64);
+		assert_eq!(StateStore::open(dir.path()).unwrap().scoped("scope").load("key"), Some(42u64));
+	}
+
+	#[test]
+	fn in_flight_transaction_expires() {
+		assert!(InFlightTransaction::new((), Duration::from_secs(60)).remaining_time().is_some());
+		assert_eq!(InFlightTransaction::new((), Duration::from_secs(0)).remaining_time(), None);
+	}
+
+	#[test]
+	fn restored_transaction_is_reported_lost() {
+		assert_eq!(
+			async_std::task::block_on(
+				RestoredTransactionTracker::<()>::new(Duration::from_millis(1)).wait()
+			),
+			TrackedTransactionStatus::Lost,
+		);
+	}
+}